@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+use std::{
+    error::Error,
+    fs::File,
+    io::{
+        self,
+        Read,
+    },
+};
+
+#[derive(Deserialize)]
+struct Entry {
+    // missing for custom GitHub emojis like :octocat:
+    emoji: Option<String>,
+    aliases: Vec<String>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = clap::Command::new(clap::crate_name!())
+        .version(clap::crate_version!())
+        .author(clap::crate_authors!("\n"))
+        .about(clap::crate_description!())
+        .arg(clap::Arg::new("file")
+             .value_name("INPUT")
+             .help("File emoji.json of gemoji"),
+        )
+        .get_matches();
+
+    let mut input = String::new();
+    if let Some(file) = args.get_one("file").map(|name: &String| File::open(name)) {
+        file?.read_to_string(&mut input)?;
+    } else {
+        io::stdin().read_to_string(&mut input)?;
+    }
+
+    let data = serde_json::from_str::<Vec<Entry>>(&input)?;
+    let mut data = data.iter()
+        .filter_map(|e| e.emoji.as_ref().map(|emoji| (&e.aliases, emoji)))
+        .flat_map(|(aliases, emoji)| aliases.iter().map(move |x| (x, emoji)))
+        .collect::<Vec<_>>();
+
+    // the table gets searched with binary search
+    data.sort_by_key(|e| e.0);
+    data.dedup_by_key(|e| e.0);
+
+    println!("[");
+    for (key, val) in data {
+        println!(r#"  ("{}", "{}"),"#, key, val.escape_default());
+    }
+    println!("]");
+
+    Ok(())
+}
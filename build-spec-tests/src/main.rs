@@ -7,7 +7,11 @@ use html5ever::{
     namespace_url,
     ns,
     LocalName,
-    rcdom::RcDom,
+    rcdom::{
+        Handle,
+        NodeData,
+        RcDom,
+    },
     serialize::{
         AttrRef,
         Serialize,
@@ -22,6 +26,7 @@ use std::{
     collections::{
         HashMap,
         HashSet,
+        VecDeque,
     },
     env,
     fs::File,
@@ -32,6 +37,7 @@ use std::{
         Lines
     },
     iter::Enumerate,
+    mem,
     num::IntErrorKind::Empty,
     ops::{
         RangeFrom,
@@ -55,8 +61,19 @@ struct TestSerializer {
     inside_li: bool,
     inside_pre: bool,
     inside_style: bool,
-    space_to_tab: bool,
     eol: &'static str,
+    /// Delimiter and width of the markers of the ordered lists in the input
+    list_markers: VecDeque<(char, usize)>,
+    /// Bullets of the unordered lists in the input
+    bullets: VecDeque<char>,
+    /// Whether the lists in the output are loose
+    loose_lists: VecDeque<bool>,
+    /// Alignments of the columns of the tables in the output
+    table_alignments: VecDeque<Vec<&'static str>>,
+    /// A paragraph for the checkbox of a task list item was opened
+    inside_task: bool,
+    /// The next text follows a checkbox
+    after_checkbox: bool,
 }
 
 impl TestSerializer {
@@ -66,6 +83,16 @@ impl TestSerializer {
         }
         self.add_comma = new_val;
     }
+
+    /// Closes the paragraph opened for the checkbox of a tight task list item
+    fn close_task(&mut self) {
+        if self.inside_task {
+            print!(")");
+            self.add_comma = true;
+            self.inside_div = false;
+            self.inside_task = false;
+        }
+    }
 }
 
 impl Serializer for TestSerializer {
@@ -83,10 +110,19 @@ impl Serializer for TestSerializer {
                 .unwrap_or(&"")
         };
 
+        if !matches!(
+            name.local,
+            local_name!("em") | local_name!("strong") | local_name!("del") | local_name!("code")
+                | local_name!("a") | local_name!("img") | local_name!("br")
+        ) {
+            self.close_task();
+        }
+
         self.handle_comma(false);
 
         match name.local {
             local_name!("em") => print!("emph!("),
+            local_name!("del") => print!("del!("),
             local_name!("strong") => print!("strong!("),
             local_name!("h1") => { print!("heading!(1"); self.add_comma = true; self.inside_div = true; }
             local_name!("h2") => { print!("heading!(2"); self.add_comma = true; self.inside_div = true; }
@@ -102,25 +138,18 @@ impl Serializer for TestSerializer {
             }
 
             local_name!("hr") => {
-                print!("Break");
+                print!("thematic_break!()");
                 self.add_comma = true;
             }
 
             local_name!("br") => {
-                print!("HardBreak");
+                print!("hard_break!()");
                 self.add_comma = true;
             }
 
             local_name!("a") => {
-                let mut url = percent_decode(attr("href")).decode_utf8().unwrap();
-                let mut alt = attr("title").to_string();
-                if self.space_to_tab {
-                    use std::borrow::Cow;
-                    use std::ops::Deref;
-
-                    url = Cow::from(url.deref().replace(' ', "\t"));
-                    alt = alt.replace(' ', "\t");
-                }
+                let url = percent_decode(attr("href")).decode_utf8().unwrap();
+                let alt = attr("title");
                 print!(r#"link!("{}", "{}""#, url.escape_default(), alt.escape_default());
                 self.add_comma = true;
             }
@@ -132,10 +161,6 @@ impl Serializer for TestSerializer {
                 );
                 let alt = attr("alt");
                 if !alt.is_empty() {
-                    let mut alt = alt.to_string();
-                    if self.space_to_tab {
-                        alt = alt.replace(' ', "\t");
-                    }
                     print!(r#", plain!("{}")"#, alt.escape_default());
                 }
                 print!(")");
@@ -145,18 +170,30 @@ impl Serializer for TestSerializer {
             local_name!("ol") => {
                 print!("ordered_list!(");
                 let start = attr("start");
-                if !start.is_empty() {
-                    print!("\"{start}\", ");
+                let (delim, width) = self.list_markers.pop_front().unwrap_or(('.', 1));
+
+                if !start.is_empty() || delim != '.' || width > 1 {
+                    let start = if start.is_empty() { "1" } else { start };
+                    let delim = if delim == '.' { "" } else { ")" };
+                    print!("\"{start:0>width$}{delim}\", ");
                 }
 
+                if self.loose_lists.pop_front().unwrap_or(false) {
+                    print!("loose, ");
+                }
             }
 
             local_name!("ul") => {
-                print!("unordered_list!(");
+                let bullet = self.bullets.pop_front().unwrap_or('-');
+                print!("unordered_list!('{bullet}', ");
+
+                if self.loose_lists.pop_front().unwrap_or(false) {
+                    print!("loose, ");
+                }
             }
 
             local_name!("li") => {
-                print!("vec![");
+                print!("item!(");
                 self.inside_li = true;
             }
 
@@ -180,6 +217,35 @@ impl Serializer for TestSerializer {
                 self.inside_code = true;
             }
 
+            local_name!("input") if *attr("type") == "checkbox" => {
+                if !self.inside_div {
+                    // the checkbox is part of the first paragraph of the item
+                    print!("paragraph!(");
+                    self.inside_div = true;
+                    self.inside_task = true;
+                }
+
+                let checked = attrs.iter().any(|(n, _)| &n.local == "checked");
+                print!("checkbox!({})", checked);
+                self.add_comma = true;
+                self.after_checkbox = true;
+            }
+
+            local_name!("table") => {
+                let alignments = self.table_alignments.pop_front().unwrap_or_default();
+                print!("table!([{}]", alignments.join(", "));
+                self.add_comma = true;
+            }
+
+            local_name!("thead") | local_name!("tbody") => (),
+
+            local_name!("tr") => print!("["),
+
+            local_name!("th") | local_name!("td") => {
+                print!("[");
+                self.inside_div = true;
+            }
+
             local_name!("style") => {
                 print!("html_block!(\"<style>");
                 self.inside_div = true;
@@ -212,16 +278,32 @@ impl Serializer for TestSerializer {
             _ => (),
         }
 
+        if name.local == local_name!("li") {
+            self.close_task();
+        }
+
         match name.local {
-            local_name!("hr") | local_name!("br") | local_name!("img") => (),
+            local_name!("hr") | local_name!("br") | local_name!("img") | local_name!("input")
+                | local_name!("thead") | local_name!("tbody") => (),
 
             local_name!("pre") => {
                 self.inside_pre = false;
             }
 
-            local_name!("li") => {
+            local_name!("tr") => {
+                print!("]");
+                self.add_comma = true;
+            }
+
+            local_name!("th") | local_name!("td") => {
                 print!("]");
                 self.add_comma = true;
+                self.inside_div = false;
+            }
+
+            local_name!("li") => {
+                print!(")");
+                self.add_comma = true;
                 self.inside_li = false;
             }
 
@@ -245,20 +327,27 @@ impl Serializer for TestSerializer {
     fn write_text(&mut self, text: &str) -> io::Result<()> {
         // println!("'{}'", text.escape_default());
 
+        let text = if mem::take(&mut self.after_checkbox) {
+            text.trim_start_matches([' ', '\t'])
+        } else {
+            text
+        };
+
+        if text.is_empty() {
+            return Ok(());
+        }
+
         if !self.inside_div {
             if text == "\n" {
                 // ignore
             } else if self.inside_li {
-                let text = text.trim_end().escape_default().to_string();
+                // text after a block like `<li><h2>Bar</h2>\nbaz</li>`
+                let text = text.trim_matches('\n').trim_end().escape_default().to_string();
 
-                let text = if self.space_to_tab {
-                    text.replace(' ', "\t").replace("\\n", r#""), SoftBreak, plain!(""#)
-                } else {
-                    text.replace("\\n", r#""), SoftBreak, plain!(""#)
-                };
+                let text = text.replace("\\n", r#""), soft_break!(), plain!(""#);
 
+                self.handle_comma(true);
                 print!(r#"paragraph!(plain!("{}"))"#, text);
-                self.add_comma = true;
             } else {
                 println!("text not insde of a block: <{}>", text.escape_default());
             }
@@ -269,41 +358,31 @@ impl Serializer for TestSerializer {
         self.handle_comma(true);
 
         if self.inside_code {
-            let mut text = text.replace('\n', self.eol);
-            if self.space_to_tab {
-                text = text.replace(' ', "\t");
-            }
+            let text = text.replace('\n', self.eol);
 
             print!("\"{}\"", text.escape_debug());
             self.add_comma = false;
         } else if self.inside_style {
-            let mut text = text.replace('\n', self.eol);
-            if self.space_to_tab {
-                text = text.replace(' ', "\t");
-            }
+            let text = text.replace('\n', self.eol);
 
             print!("{}", text.escape_debug());
         } else if text == "\n" {
-            print!("SoftBreak");
+            print!("soft_break!()");
         } else {
             let text = text.escape_debug().to_string();
             let mut text = text.as_str();
             let (mut before, mut after) = ("", "");
 
             if text.starts_with("\\n") {
-                before = "SoftBreak, ";
+                before = "soft_break!(), ";
                 text = &text[2..];
             }
             if text.ends_with("\\n") {
-                after = ", SoftBreak";
+                after = ", soft_break!()";
                 text = &text[..text.len() - 2];
             }
 
-            let text = if self.space_to_tab {
-                text.replace(' ', "\t").replace("\\n", r#""), SoftBreak, plain!(""#)
-            } else {
-                text.replace("\\n", r#""), SoftBreak, plain!(""#)
-            };
+            let text = text.replace("\\n", r#""), soft_break!(), plain!(""#);
 
             print!("{}plain!(\"{}\"){}", before, text, after);
         }
@@ -315,9 +394,6 @@ impl Serializer for TestSerializer {
     fn write_comment(&mut self, text: &str) -> io::Result<()> {
         self.handle_comma(true);
         let mut text = text.replace('\n', self.eol);
-        if self.space_to_tab {
-            text = text.replace(' ', "\t");
-        }
 
         if !(text.starts_with('?') && text.ends_with('?')) {
             text.insert_str(0, "!--");
@@ -348,16 +424,48 @@ impl Serializer for TestSerializer {
     }
 }
 
+/// Spec the examples are taken from
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Spec {
+    CommonMark,
+    /// GitHub Flavored Markdown; the examples of its extensions are marked
+    /// like `example table`
+    Gfm,
+}
+
+impl Spec {
+    fn name(self) -> &'static str {
+        match self {
+            Spec::CommonMark => "Commonmark Spec",
+            Spec::Gfm => "GFM Spec",
+        }
+    }
+
+    fn file_url(self) -> &'static str {
+        match self {
+            Spec::CommonMark => "https://github.com/commonmark/commonmark-spec/blob/master/spec.txt",
+            Spec::Gfm => "https://github.com/github/cmark-gfm/blob/master/test/spec.txt",
+        }
+    }
+
+    fn example_url(self) -> &'static str {
+        match self {
+            Spec::CommonMark => "https://spec.commonmark.org/0.29/#example-",
+            Spec::Gfm => "https://github.github.com/gfm/#example-",
+        }
+    }
+}
+
 fn print_test_prelude(
     ignore: bool, test_no: usize, name_suffix: &str, line: usize,
-    expected_html: &str, input: &str
+    expected_html: &str, input: &str, spec: Spec, extension: &str
 ) {
     print!(
         r##"
 #[test]{ignore}
-/// Test case generated from Commonmark Spec line {line}
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L{line}>
-/// <https://spec.commonmark.org/0.29/#example-{test_no}>
+/// Test case generated from {spec_name} line {line}{extension}
+/// <{file_url}#L{line}>
+/// <{example_url}{test_no}>
 fn t{test_no}{name_suffix}() {{
     init!("{input}");
 
@@ -366,17 +474,227 @@ fn t{test_no}{name_suffix}() {{
         [
             "##,
         ignore = if ignore { "\n#[ignore]" } else { "" },
+        spec_name = spec.name(),
+        extension = if extension.is_empty() {
+            String::new()
+        } else {
+            format!(" (extension {extension})")
+        },
+        file_url = spec.file_url(),
+        example_url = spec.example_url(),
         expected_html = expected_html.escape_debug(),
         input = input.escape_debug(),
     );
 }
 
+/// Marker of the first item of a list
+enum ListMarker {
+    Bullet(char),
+    Ordered(char, usize),
+}
+
+impl ListMarker {
+    /// Returns the marker at the beginning of *line* and its length
+    fn parse(line: &str) -> Option<(Self, usize)> {
+        let width = line.chars().take_while(char::is_ascii_digit).count();
+        let mut rest = line[width..].chars();
+
+        let marker = match rest.next() {
+            Some(c @ ('-' | '*' | '+')) if width == 0 => Self::Bullet(c),
+            Some(c @ ('.' | ')')) if (1..10).contains(&width) => Self::Ordered(c, width),
+            _ => return None,
+        };
+
+        if matches!(rest.next(), None | Some(' ' | '\t' | '\u{2192}')) {
+            Some((marker, width + 1))
+        } else {
+            None
+        }
+    }
+
+    fn char(&self) -> char {
+        match self {
+            Self::Bullet(c) | Self::Ordered(c, _) => *c,
+        }
+    }
+}
+
+fn is_thematic_break(line: &str) -> bool {
+    let marks = line.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    marks.len() >= 3 && ["*", "-", "_"].iter().any(|x| marks.chars().all(|c| x.starts_with(c)))
+}
+
+/// Collects the markers of the first items of the lists in order of their
+/// appearance
+///
+/// HTML has no information about them. The nesting of the lists is tracked
+/// by the indentation of the markers; a new list starts, if the marker
+/// character changes.
+fn list_markers(input: &str) -> VecDeque<ListMarker> {
+    struct OpenList {
+        // minimal indentation of the marker to belong to this list
+        base: usize,
+        // indentation of the content of the last item
+        content: usize,
+        marker: char,
+    }
+
+    let mut markers = VecDeque::new();
+    let mut open = Vec::<OpenList>::new();
+    let mut after_blank = false;
+
+    for line in input.split(['\n', '\r']) {
+        let mut col = 0;
+        let mut rest = line;
+
+        loop {
+            let ws = rest.chars()
+                .take_while(|c| matches!(c, ' ' | '\t' | '\u{2192}' | '>'))
+                .map(|c| if c == ' ' || c == '>' { 1 } else { 4 - col % 4 })
+                .sum::<usize>();
+            rest = rest.trim_start_matches([' ', '\t', '\u{2192}', '>']);
+            col += ws;
+
+            let (marker, len) = match ListMarker::parse(rest) {
+                Some(x) if !is_thematic_break(rest) => x,
+
+                _ => {
+                    if rest.is_empty() {
+                        after_blank = true;
+                    } else {
+                        if after_blank || rest.starts_with(['#', '`', '~', '<'])
+                            || is_thematic_break(rest)
+                        {
+                            while open.last().is_some_and(|l| col < l.content) {
+                                open.pop();
+                            }
+                        }
+
+                        after_blank = false;
+                    }
+
+                    break;
+                }
+            };
+
+            after_blank = false;
+
+            while open.last().is_some_and(|l| col < l.base) {
+                open.pop();
+            }
+
+            let content = col + len + 1;
+            match open.last_mut() {
+                Some(l) if col < l.content && l.marker == marker.char() => {
+                    l.content = content;
+                }
+
+                last => {
+                    let base = match last {
+                        Some(l) if col < l.content => {
+                            let base = l.base;
+                            open.pop();
+                            base
+                        }
+
+                        Some(l) => l.content,
+                        None => 0,
+                    };
+
+                    open.push(OpenList { base, content, marker: marker.char() });
+                    markers.push_back(marker);
+                }
+            }
+
+            rest = &rest[len..];
+            col += len;
+        }
+    }
+
+    markers
+}
+
+/// Collects for each list, in the order of the start tags, whether it is
+/// loose, i.e. the content of its items is wrapped in `<p>`
+fn loose_lists(node: &Handle, list: &mut VecDeque<bool>) {
+    let is_elem = |node: &Handle, names: &[LocalName]| matches!(
+        &node.data, NodeData::Element { name, .. } if names.contains(&name.local)
+    );
+
+    if is_elem(node, &[local_name!("ul"), local_name!("ol")]) {
+        list.push_back(
+            node.children.borrow().iter()
+                .filter(|li| is_elem(li, &[local_name!("li")]))
+                .any(|li| li.children.borrow().iter().any(|x| is_elem(x, &[local_name!("p")])))
+        );
+    }
+
+    for child in node.children.borrow().iter() {
+        loose_lists(child, list);
+    }
+}
+
+/// Collects the alignments of the columns of each table in the order of the
+/// start tags from the `align` attributes of the header cells
+fn table_alignments(node: &Handle, list: &mut VecDeque<Vec<&'static str>>) {
+    fn is_elem(node: &Handle, local: &LocalName) -> bool {
+        matches!(&node.data, NodeData::Element { name, .. } if &name.local == local)
+    }
+
+    fn children(node: &Handle, local: LocalName) -> Vec<Handle> {
+        node.children.borrow().iter().filter(|x| is_elem(x, &local)).cloned().collect()
+    }
+
+    let align = |th: &Handle| match &th.data {
+        NodeData::Element { attrs, .. } => {
+            match attrs.borrow().iter()
+                .find(|a| a.name.local == local_name!("align"))
+                .map(|a| a.value.to_string())
+                .as_deref()
+            {
+                Some("left") => "Left",
+                Some("center") => "Center",
+                Some("right") => "Right",
+                _ => "None",
+            }
+        }
+
+        _ => "None",
+    };
+
+    if is_elem(node, &local_name!("table")) {
+        let header = children(node, local_name!("thead")).iter()
+            .flat_map(|head| children(head, local_name!("tr")))
+            .next();
+
+        list.push_back(
+            header.map(|tr| children(&tr, local_name!("th")).iter().map(align).collect())
+                .unwrap_or_default()
+        );
+    }
+
+    for child in node.children.borrow().iter() {
+        table_alignments(child, list);
+    }
+}
+
 fn gen_test(
     ignore: bool, ex_no: usize, name_ext: &str, line_no: usize,
     input: &str, output: &str, divergent_output: Option<&String>,
-    serializer: &mut TestSerializer
+    settings: &str, spec: Spec, extension: &str, serializer: &mut TestSerializer
 ) -> io::Result<()> {
-    print_test_prelude(ignore, ex_no, name_ext, line_no, output, input);
+    print_test_prelude(ignore, ex_no, name_ext, line_no, output, input, spec, extension);
+    serializer.list_markers.clear();
+    serializer.bullets.clear();
+    serializer.loose_lists.clear();
+    serializer.table_alignments.clear();
+
+    for marker in list_markers(input) {
+        match marker {
+            ListMarker::Bullet(c) => serializer.bullets.push_back(c),
+            ListMarker::Ordered(c, width) => serializer.list_markers.push_back((c, width)),
+        }
+    }
 
     if let Some(div) = divergent_output {
         print!("{}", div.replace('\n', "\n            "));
@@ -392,6 +710,9 @@ fn gen_test(
             vec![], // context_attrs
         ).one(output);
 
+        loose_lists(&dom.document, &mut serializer.loose_lists);
+        table_alignments(&dom.document, &mut serializer.table_alignments);
+
         // the first child is <html>
         dom.document.children.borrow()[0].serialize(
             serializer,
@@ -399,25 +720,15 @@ fn gen_test(
         )?;
     }
 
-    if serializer.space_to_tab {
-        println!(
-            "
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(\"{}\", ParserSettings::Html))),
-    );\n}}",
-            // replace → by TAB
-            input.replace('\u{2192}', "\t").escape_default()
-        );
-    } else {
-        println!(
-            "
+    println!(
+        "
         ].as_slice(),
-        body(&mut StringData::new(\"{}\", ParserSettings::Html)),
+        body(&mut StringData::new(\"{}\", {})),
     );\n}}",
-            // replace → by TAB
-            input.replace('\u{2192}', "\t").escape_default()
-        );
-    }
+        // replace → by TAB
+        input.replace('\u{2192}', "\t").escape_default(),
+        settings
+    );
 
     Ok(())
 }
@@ -462,12 +773,12 @@ fn t{}_html() {{
     );
 }
 
-fn gen_exception(ex_no: usize, input: &str, output: &str) -> io::Result<()> {
+fn gen_exception(ex_no: usize, input: &str, output: &str, spec: Spec) -> io::Result<()> {
     println!("
-[Example {ex_no}](https://spec.commonmark.org/0.29/#example-{ex_no}):
+[Example {ex_no}]({url}{ex_no}):
 
 ```````````````````````````````` rust
-{input}.");
+{input}.", url = spec.example_url());
 
     let output = output
         .replace('\u{2192}', "\t") // replace → by TAB
@@ -574,7 +885,11 @@ fn print_test_suite_prelude() {
     );
 
     for arg in args {
-        print!(" {}", arg);
+        if arg.contains(|c: char| !(c.is_ascii_alphanumeric() || "_-.,/:".contains(c))) {
+            print!(" '{}'", arg);
+        } else {
+            print!(" {}", arg);
+        }
     }
     println!("\n//");
 
@@ -582,30 +897,6 @@ fn print_test_suite_prelude() {
 mod common;
 use common::*;
 
-fn space2tab(mut body: Vec<Block>) -> Vec<Block> {
-    body.iter_mut().for_each(|e| {
-        match e {
-            Block::Code(_, ref mut txt, _) | Block::Html(ref mut txt, _)
-                | Block::LinkDef(ref mut txt, ..) =>
-            {
-                *txt = txt.replace(' ', "\t");
-            }
-
-            Block::Paragraph(par, _) => {
-                par.iter_mut().for_each(|e| {
-                    match e {
-                        Inline::Code(txt, _) => *txt = txt.replace(' ', "\t"),
-                        _ => (),
-                    }
-                })
-            }
-
-            _ => (),
-        }
-    });
-
-    body
-}
 "#);
 }
 
@@ -700,6 +991,16 @@ fn main() -> anyhow::Result<()> {
               .value_name("IGNORE")
               .help("List of tests to mark as ignore")
               .num_args(1)
+        ).arg(clap::Arg::new("settings")
+              .short('p')
+              .value_name("SETTINGS")
+              .help("Rust expression of the ParserSettings for the tests")
+              .default_value("ParserSettings::Html")
+              .num_args(1)
+        ).arg(clap::Arg::new("gfm")
+              .short('g')
+              .help("The input is the GFM Spec; link the tests to it")
+              .action(clap::ArgAction::SetTrue)
         ).arg(clap::Arg::new("exception_mode")
               .short('x')
               .help("Print exception file format instead of tests")
@@ -721,6 +1022,8 @@ fn main() -> anyhow::Result<()> {
         |x| x.split(',').flat_map(|e| e.parse::<usize>()).collect()
     );
 
+    let settings = args.get_one::<String>("settings").unwrap();
+    let spec = if args.get_flag("gfm") { Spec::Gfm } else { Spec::CommonMark };
     let exception_mode = args.get_flag("exception_mode");
     if !exception_mode {
         print_test_suite_prelude();
@@ -746,7 +1049,12 @@ fn main() -> anyhow::Result<()> {
     let mut test_no = 0;
     for e in parse_file(args.get_one::<String>("spec").unwrap())? {
         let (kind, line, input, mut output) = e?;
-        assert_eq!(kind, "example");
+
+        // the GFM Spec marks the examples of extensions like `example table`
+        let extension = match kind.strip_prefix("example") {
+            Some(ext) if ext.is_empty() || ext.starts_with(' ') => ext.trim(),
+            _ => panic!("unknown kind of example in line {}: {}", line, kind),
+        };
 
         test_no += 1;
 
@@ -755,7 +1063,7 @@ fn main() -> anyhow::Result<()> {
         }
 
         if exception_mode {
-            gen_exception(test_no, &input, &output)?;
+            gen_exception(test_no, &input, &output, spec)?;
             continue;
         }
 
@@ -771,6 +1079,7 @@ fn main() -> anyhow::Result<()> {
         gen_test(
             ignore.contains(&test_no), test_no, "", line,
             &input, &output, ex_rust,
+            settings, spec, extension,
             &mut TestSerializer { eol: "\n", ..Default::default() }
         )?;
 
@@ -785,13 +1094,14 @@ fn main() -> anyhow::Result<()> {
 */
         }
 
-        if test_no != 325 {
+        if spec != Spec::CommonMark || test_no != 325 {
             let input_cr = input.replace('\n', "\r");
             let sp_cr = ex_rust.map(|x| x.replace("\\n", "\\r"));
             if input_cr != input || sp_cr.as_ref() != ex_rust {
                 gen_test(
                     ignore.contains(&test_no), test_no, "_cr", line,
                     &input_cr, &output, sp_cr.as_ref(),
+                    settings, spec, extension,
                     &mut TestSerializer { eol: "\r", ..Default::default() }
                 )?;
             }
@@ -802,6 +1112,7 @@ fn main() -> anyhow::Result<()> {
                 gen_test(
                     ignore.contains(&test_no), test_no, "_cr_nl", line,
                     &input_cr_nl, &output, sp_cr_nl.as_ref(),
+                    settings, spec, extension,
                     &mut TestSerializer { eol: "\r\n", ..Default::default() }
                 )?;
             }
@@ -812,56 +1123,10 @@ fn main() -> anyhow::Result<()> {
             gen_test(
                 ignore.contains(&test_no), test_no, "_no_nl", line,
                 &input_no_nl, &output, ex_rust,
+                settings, spec, extension,
                 &mut TestSerializer { eol: "\n", ..Default::default() }
             )?;
         }
-
-        // these tests aren't translatable to a TAB version
-        if [116, 196, 330, 552, 583, 632, 639, 646].contains(&test_no) {
-            continue;
-        }
-
-        let mut input_with_tabs = String::with_capacity(input.len());
-        let mut pos = 0;
-        for ch in input.chars() {
-            match ch {
-                ' ' => {
-                    pos += 1;
-                    input_with_tabs.push(if pos <= 4 { ' ' } else { '\t' });
-                }
-
-                '\n' => {
-                    pos = 0;
-                    input_with_tabs.push(ch);
-                }
-
-                _ => {
-                    pos = 5;
-                    input_with_tabs.push(ch);
-                }
-            }
-        }
-        input_with_tabs = input_with_tabs.replace("\t\t\n", "  \n");
-
-        let mut revert_backticks = input_with_tabs.replacen("`\t", "` ", 1);
-        if revert_backticks != input_with_tabs {
-            if let Some(idx) = revert_backticks.rfind("\t`") {
-                revert_backticks.replace_range(idx..idx + 1, " ");
-
-                if input_with_tabs != revert_backticks {
-                    input_with_tabs = revert_backticks;
-                }
-            }
-        }
-
-        if input_with_tabs != input {
-            gen_test(
-                ignore.contains(&test_no), test_no, "_tab", line,
-                &input_with_tabs, &output,
-                ex_rust.map(|x| x.replace(' ', "\t")).as_ref(),
-                &mut TestSerializer { space_to_tab: true, eol: "\n", ..Default::default() }
-            )?;
-        }
     }
 
     Ok(())
@@ -0,0 +1,271 @@
+use crate::log;
+
+use super::{
+    LINE_WS,
+    ParserData,
+    ParserSettings,
+    StringData,
+    Tools,
+    Transaction,
+};
+
+/// Attributes `{#id .class key=value}` of an element
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Attributes {
+    /// Identifier `#id` or `id=…`
+    pub id: Option<String>,
+
+    /// Classes `.class` or `class="…"` in the order of appearance
+    pub classes: Vec<String>,
+
+    /// All other `key=value` pairs in the order of appearance
+    pub pairs: Vec<(String, String)>,
+}
+
+impl Attributes {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.id.is_none() && self.classes.is_empty() && self.pairs.is_empty()
+    }
+
+    /// Returns the value of the last pair with the *key*
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.pairs.iter()
+            .rfind(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn push(&mut self, key: String, val: String) {
+        match key.as_str() {
+            "id" => self.id = Some(val),
+            "class" => self.classes.extend(val.split_whitespace().map(String::from)),
+            _ => self.pairs.push((key, val)),
+        }
+    }
+}
+
+fn is_name_char(ch: char) -> bool {
+    ch.is_alphanumeric() || "-_:.".contains(ch)
+}
+
+/// Parses an attribute block `{#id .class key=value key="some value"}`
+///
+/// The input is left untouched, if the syntax is invalid or the block is
+/// empty.
+pub fn attributes(data: &mut impl ParserData) -> Option<Attributes> {
+    if !data.looking_at('{') {
+        return None;
+    }
+
+    let mut data = Transaction::new(data);
+    data.advance();
+    log!(d, data, "attributes", "begin");
+
+    let mut attrs = Attributes::default();
+
+    macro_rules! leave {
+        () => ({
+            log!(w, data, "attributes", "end without match");
+            return None;
+        });
+    }
+
+    loop {
+        data.skip_all(LINE_WS);
+
+        match data.peek() {
+            Some('}') => {
+                data.advance();
+                break;
+            }
+
+            Some('#') => {
+                data.advance();
+
+                let mut id = String::new();
+                if data.copy_all(&mut id, is_name_char) == 0 {
+                    leave!();
+                }
+
+                attrs.id = Some(id);
+            }
+
+            Some('.') => {
+                data.advance();
+
+                let mut class = String::new();
+                if data.copy_all(&mut class, is_name_char) == 0 {
+                    leave!();
+                }
+
+                attrs.classes.push(class);
+            }
+
+            Some(c) if is_name_char(c) => {
+                let mut key = String::new();
+                data.copy_all(&mut key, is_name_char);
+
+                if !data.skip('=') {
+                    leave!();
+                }
+
+                let mut val = String::new();
+                match data.peek() {
+                    Some(del @ ('"' | '\'')) => {
+                        data.advance();
+
+                        loop {
+                            match data.peek() {
+                                None | Some('\r' | '\n') => leave!(),
+
+                                Some('\\') => {
+                                    match data.next() {
+                                        Some(c) if c.is_ascii_punctuation() => {
+                                            val.push(c);
+                                            data.advance();
+                                        }
+
+                                        _ => val.push('\\'),
+                                    }
+                                }
+
+                                Some(c) => {
+                                    data.advance();
+
+                                    if c == del {
+                                        break;
+                                    }
+
+                                    val.push(c);
+                                }
+                            }
+                        }
+                    }
+
+                    _ => {
+                        if data.copy_all(
+                            &mut val, |c: char| !c.is_whitespace() && !"{}\"'=".contains(c)
+                        ) == 0 {
+                            leave!();
+                        }
+                    }
+                }
+
+                attrs.push(key, val);
+            }
+
+            _ => leave!(),
+        }
+
+        if !data.looking_at(LINE_WS) && !data.looking_at('}') {
+            leave!();
+        }
+    }
+
+    if attrs.is_empty() {
+        leave!();
+    }
+
+    log!(d, data, "attributes", "end");
+    data.commit();
+    Some(attrs)
+}
+
+/// Searches a trailing attribute block at the end of *line*
+///
+/// The block must be separated by whitespace from the text before. Returns
+/// the byte offset of the block within *line* and the parsed attributes.
+pub(crate) fn trailing_attributes(line: &str) -> Option<(usize, Attributes)> {
+    let line = line.trim_end_matches(LINE_WS);
+    if !line.ends_with('}') {
+        return None;
+    }
+
+    for (idx, _) in line.rmatch_indices('{') {
+        if idx > 0 && !line[..idx].ends_with(LINE_WS) {
+            continue;
+        }
+
+        let mut data = StringData::new(&line[idx..], ParserSettings::None);
+        if let Some(attrs) = attributes(&mut data) {
+            if data.peek().is_none() {
+                return Some((idx, attrs));
+            }
+        }
+    }
+
+    None
+}
+
+/// Parses an attribute block that fills the rest of the line
+pub(crate) fn attributes_line(data: &mut impl ParserData) -> Option<Attributes> {
+    let mut data = Transaction::new(data);
+
+    let attrs = attributes(&mut data)?;
+    data.skip_all(LINE_WS);
+
+    if data.skip_newline() || data.peek().is_none() {
+        data.commit();
+        Some(attrs)
+    } else {
+        log!(w, data, "attributes line", "end without match");
+        None
+    }
+}
+
+/// Parses the attributes from the end of an info string of a code block
+pub(crate) fn info_attributes(info: &mut String) -> Attributes {
+    if let Some((idx, attrs)) = trailing_attributes(info) {
+        info.truncate(idx);
+        while info.ends_with(LINE_WS) {
+            info.pop();
+        }
+
+        attrs
+    } else {
+        Attributes::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(inp: &str) -> Option<Attributes> {
+        attributes(&mut StringData::from(inp))
+    }
+
+    #[test]
+    fn all_kinds() {
+        assert_eq!(
+            Some(Attributes {
+                id: Some("intro".into()),
+                classes: vec!["lead".into(), "wide".into()],
+                pairs: vec![
+                    ("width".into(), "300".into()),
+                    ("title".into(), "a \"b\" c".into()),
+                ],
+            }),
+            parse(r#"{#intro .lead width=300 title="a \"b\" c" class=wide}"#)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(None, parse("{}"));
+        assert_eq!(None, parse("{#}"));
+        assert_eq!(None, parse("{key}"));
+        assert_eq!(None, parse("{key=\"open}"));
+        assert_eq!(None, parse("{#id"));
+    }
+
+    #[test]
+    fn trailing() {
+        assert_eq!(None, trailing_attributes("text{#id}"));
+        assert_eq!(None, trailing_attributes("text {#id} more"));
+        assert_eq!(
+            Some((5, Attributes { id: Some("id".into()), ..Default::default() })),
+            trailing_attributes("text {#id}  ")
+        );
+    }
+}
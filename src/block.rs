@@ -4,20 +4,30 @@ use std::{
 };
 
 use super::{
+    Alignment,
+    Attributes,
     Block,
+    Diagnostic,
     Inline,
     LINE_WS,
+    ListItem,
+    ListStart,
     Location,
     LocationHelper,
     LocationPosition,
     NL_CR,
+    ParseError,
     ParserData,
     ParserSettings,
     Pattern,
     Position,
+    TAB_STOP,
+    TableRow,
     Tools,
     Transaction,
+    attributes,
     body,
+    filter_tags,
     html_entity,
     is_ascii_alphabetic,
     is_ascii_alphanumeric,
@@ -25,25 +35,65 @@ use super::{
     paragraph::{self, paragraph},
 };
 
+/// Parses the next block and appends it to *list*
+///
+/// *open_embedded_codes* counts the embedded blocks like `@if x {` that wait
+/// for a line `}`.
 pub fn block(
     data: &mut impl ParserData,
     list: &mut Vec<Block>,
     open_embedded_codes: &mut u16
+) {
+    let loc = data.loc_end(data.loc());
+    let mut open_embedded = vec![loc; usize::from(*open_embedded_codes)];
+
+    block_tracked(data, list, &mut open_embedded);
+    *open_embedded_codes = u16::try_from(open_embedded.len()).unwrap_or(u16::MAX);
+}
+
+/// Like [`block`], but *open_embedded* holds the locations of the open
+/// embedded blocks to report them if they aren't closed
+pub(crate) fn block_tracked(
+    data: &mut impl ParserData,
+    list: &mut Vec<Block>,
+    open_embedded: &mut Vec<Location>
 ) {
     struct InnerData<'a> {
         inner: &'a mut dyn ParserData,
         ignore_char: Option<char>,
         stop_pos: Option<Position>,
         next_block: Option<Block>,
+        attributes: Option<(Attributes, LocationPosition)>,
+        /// Level, begin and end of a setext underline
+        setext: Option<(u8, Position, LocationPosition)>,
+        /// The enclosing blocks were told that the paragraph is closed while
+        /// the start of a line gets checked for an interruption
+        paragraph_paused: bool,
+        /// An embedded block is open, whose line `}` ends the block
+        embedded_open: bool,
     }
 
     impl<'a> InnerData<'a> {
-        fn new(inner: &'a mut dyn ParserData, ignore_char: Option<char>) -> Self {
+        fn new(
+            inner: &'a mut dyn ParserData, ignore_char: Option<char>, embedded_open: bool
+        ) -> Self {
+            // ListInterruptNever wins, but the combination is a mistake
+            debug_assert!(
+                !inner.settings().contains(
+                    ParserSettings::ListInterruptAnyNumber | ParserSettings::ListInterruptNever
+                ),
+                "ListInterruptAnyNumber and ListInterruptNever exclude each other"
+            );
+
             Self {
                 inner,
                 ignore_char,
                 stop_pos: None,
                 next_block: None,
+                attributes: None,
+                setext: None,
+                paragraph_paused: false,
+                embedded_open,
             }
         }
 
@@ -59,6 +109,10 @@ pub fn block(
             self.inner.settings()
         }
 
+        fn admonition_types(&self) -> &[&str] {
+            self.inner.admonition_types()
+        }
+
         fn pos(&self) -> Position {
             self.stop_pos.unwrap_or_else(|| self.inner.pos())
         }
@@ -76,6 +130,11 @@ pub fn block(
                 return;
             }
 
+            if self.paragraph_paused {
+                self.paragraph_paused = false;
+                self.inner.set_paragraph_open(true);
+            }
+
             let data = &mut self.inner;
             let prev = data.peek().unwrap();
 
@@ -105,6 +164,13 @@ pub fn block(
                 Some(mut ch) if prev == '\r' || prev == '\n' => {
                     let after_newline = data.pos();
 
+                    if self.ignore_char.is_none() {
+                        // the enclosing blocks must not take a block that
+                        // interrupts the paragraph as lazy continuation
+                        data.set_paragraph_open(false);
+                        self.paragraph_paused = true;
+                    }
+
                     macro_rules! stop {
                         () => ({
                             log!(t, data, "block", "stopped {}", after_newline);
@@ -112,7 +178,9 @@ pub fn block(
                         });
                     }
 
+                    let column = data.column();
                     let indent = data.skip_all(LINE_WS);
+                    let indent_columns = data.column() - column;
 
                     if indent > 0 {
                         if data.looking_at(NL_CR) {
@@ -126,61 +194,120 @@ pub fn block(
                         }
                     }
 
+                    if indent_columns >= 4 && data.has_setting(ParserSettings::IndentedCode) {
+                        // neither a code block nor anything else can
+                        // interrupt the paragraph
+                        data.rewind(after_newline);
+                        return;
+                    }
+
+                    if matches!(ch, '=' | '-') && self.ignore_char.is_none()
+                        && data.has_setting(ParserSettings::SetextHeadings)
+                        && !data.lazy_line()
+                    {
+                        if let Some(level) = setext_underline(data) {
+                            self.setext = Some((level, after_newline, data.loc()));
+                            stop!();
+                            return;
+                        }
+                    }
+
+                    let loc_begin = data.loc();
                     match ch {
                         c if self.ignore_char.map_or(false, |ig| c == ig) => (),
 
                         '>' => stop!(),
 
+                        '}' if self.embedded_open => {
+                            if embedded_close(data).is_some() {
+                                stop!();
+                            } else if indent > 0 {
+                                data.rewind(after_newline);
+                            }
+                        }
+
                         '#' => {
-                            if let Some((lvl, ct, loc)) = heading(data) {
-                                self.next_block = Some(Block::Heading(lvl, ct, loc));
+                            if let Some((lvl, ct, attrs, loc)) = heading(data) {
+                                self.next_block = Some(Block::Heading(lvl, ct, attrs, loc));
                                 stop!();
                             } else if indent > 0 {
-                                data.reset(after_newline).unwrap();
+                                data.rewind(after_newline);
                             }
                         }
 
                         '`' | '~' => {
-                            if let Some((info, ct, loc)) = code_fenced(data, indent) {
-                                self.next_block = Some(Block::Code(info, ct, loc));
+                            if let Some((info, ct, attrs, loc, info_loc))
+                                = code_fenced(data, indent_columns)
+                            {
+                                self.next_block = Some(Block::Code(info, ct, attrs, loc, info_loc));
                                 stop!();
                             } else if indent > 0 {
-                                data.reset(after_newline).unwrap();
+                                data.rewind(after_newline);
                             }
                         }
 
                         '*' | '-' | '_' if thematic_break(data) => {
-                            self.next_block = Some(Block::Break);
+                            self.next_block = Some(Block::Break(data.loc_end(loc_begin)));
                             stop!();
                         }
 
+                        ':' if data.has_setting(ParserSettings::Containers) => {
+                            if let Some(blk) = container(data) {
+                                self.next_block = Some(blk);
+                                stop!();
+                            } else if indent > 0 {
+                                data.rewind(after_newline);
+                            }
+                        }
+
                         '*' | '-' | '+' => {
                             let pos = data.pos();
                             data.advance();
 
                             if data.looking_at(LINE_WS) {
                                 stop!();
-                                data.reset(after_newline).unwrap();
+                                data.rewind(after_newline);
                             } else {
-                                data.reset(pos).unwrap();
+                                data.rewind(pos);
                             }
                         }
 
-                        '1' => {
+                        '{' if self.ignore_char.is_none()
+                            && data.has_setting(ParserSettings::Attributes) =>
+                        {
+                            if let Some(attrs) = attributes::attributes_line(data) {
+                                self.attributes = Some((attrs, data.loc()));
+                                stop!();
+                            } else if indent > 0 {
+                                data.rewind(after_newline);
+                            }
+                        }
+
+                        '<' if data.has_setting(
+                            ParserSettings::Html | ParserSettings::HtmlInterrupt
+                        ) && html_interrupts(data) => {
+                            stop!();
+                            data.rewind(after_newline);
+                        }
+
+                        '0' ..= '9' if !data.has_setting(ParserSettings::ListInterruptNever) => {
                             let pos = data.pos();
-                            data.advance();
 
-                            if data.skip('.') && data.looking_at(LINE_WS) {
+                            if list_start(data).is_some_and(|st| {
+                                st.number == 1
+                                    || data.has_setting(ParserSettings::ListInterruptAnyNumber)
+                            }) && data.looking_at(LINE_WS)
+                            {
                                 stop!();
-                                data.reset(after_newline).unwrap();
+                                data.rewind(after_newline);
                             } else {
-                                data.reset(pos).unwrap();
+                                data.rewind(pos);
                             }
                         }
 
                         _ => {
                             if indent > 0 {
-                                data.reset(after_newline).unwrap();
+                                data.rewind(after_newline);
                             }
                         }
                     }
@@ -197,39 +324,68 @@ pub fn block(
             }
             Ok(())
         }
+
+        fn column(&self) -> usize {
+            self.inner.column()
+        }
+
+        fn set_paragraph_open(&mut self, open: bool) {
+            self.inner.set_paragraph_open(open);
+        }
+
+        fn lazy_line(&self) -> bool {
+            self.inner.lazy_line()
+        }
+
+        fn report(&mut self, diag: Diagnostic) {
+            self.inner.report(diag);
+        }
+
+        fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+            self.inner.take_diagnostics()
+        }
     }
 
     log!(d, data, "block", "begin");
 
-    let (peek_char, indent) = loop {
+    let (peek_char, indent, indent_columns) = loop {
         while data.skip_newline() { }
 
-        // TODO: muss abhängig von TAB gezählt werden.
+        let column = data.column();
         let indent = data.skip_all(LINE_WS);
 
         match data.peek() {
             None => return,
             Some('\n' | '\r') => continue,
-            Some(c) => break (c, indent),
+            Some(c) => break (c, indent, data.column() - column),
         }
     };
 
     log!(t, data, "block", "first char");
 
+    if indent_columns >= 4 && data.has_setting(ParserSettings::IndentedCode) {
+        let (ct, loc) = code_indented(data, indent_columns - 4);
+        let info_loc = Location { begin: loc.begin, end: loc.begin };
+        list.push(Block::Code(String::new(), ct, Attributes::default(), loc, info_loc));
+        log!(d, data, "block", "end");
+        return;
+    }
+
+    let loc_begin = data.loc();
     match peek_char {
         '#' => {
-            if let Some((lvl, ct, loc)) = heading(data) {
-                list.push(Block::Heading(lvl, ct, loc));
+            if let Some((lvl, ct, attrs, loc)) = heading(data) {
+                list.push(Block::Heading(lvl, ct, attrs, loc));
                 log!(d, data, "block", "end");
                 return;
             }
         }
 
         '>' => {
-            let mut data = InnerData::new(data, Some('>'));
+            let mut data = InnerData::new(data, Some('>'), !open_embedded.is_empty());
 
-            if let Some((ct, loc)) = quote(&mut data) {
-                list.push(Block::Quote(ct, loc));
+            if let Some(blk) = quote(&mut data) {
+                list.push(blk);
                 log!(d, data, "block", "end");
                 data.do_hint(list);
                 return;
@@ -237,51 +393,59 @@ pub fn block(
         }
 
         '`' | '~' => {
-            if let Some((info, ct, loc)) = code_fenced(data, indent) {
-                list.push(Block::Code(info, ct, loc));
+            if let Some((info, ct, attrs, loc, info_loc)) = code_fenced(data, indent_columns) {
+                list.push(Block::Code(info, ct, attrs, loc, info_loc));
                 log!(d, data, "block", "end");
                 return;
             }
         }
 
         '*' | '-' | '_' if thematic_break(data) => {
-            list.push(Block::Break);
+            list.push(Block::Break(data.loc_end(loc_begin)));
             log!(d, data, "block", "end");
             return;
         }
 
         '*' | '-' | '+' => {
-            if let Some(ul) = unordered_list(data) {
-                list.push(Block::UnorderedList(ul));
+            if let Some(ul) = unordered_list(data, indent_columns) {
+                list.push(ul);
                 log!(d, data, "block", "end");
                 return;
             }
         }
 
         '0' ..= '9' => {
-            if let Some((start_no, ol)) = ordered_list(data) {
-                list.push(Block::OrderedList(start_no, ol));
+            if let Some(ol) = ordered_list(data, indent_columns) {
+                list.push(ol);
                 log!(d, data, "block", "end");
                 return;
             }
         },
 
+        ':' if data.has_setting(ParserSettings::Containers) => {
+            if let Some(blk) = container(data) {
+                list.push(blk);
+                log!(d, data, "block", "end");
+                return;
+            }
+        }
+
         '@' if data.has_setting(ParserSettings::Embedded) => {
-            if let Some(blk) = embedded(data, open_embedded_codes) {
+            if let Some(blk) = embedded(data, open_embedded) {
                 list.push(blk);
                 log!(d, data, "block", "end");
                 return;
             }
         }
 
-        '}' if *open_embedded_codes > 0 => {
+        '}' if !open_embedded.is_empty() => {
             let mut data = Transaction::new(data);
             let mut buf = String::from('}');
             let loc_begin = data.loc();
 
             data.advance();
             if data.skip_newline() || data.peek().is_none() {
-                *open_embedded_codes -= 1;
+                open_embedded.pop();
                 list.push(Block::EmbeddedBlock(buf, data.loc_end(loc_begin)));
 
                 log!(d, data, "block", "end");
@@ -309,8 +473,27 @@ pub fn block(
             }
         }
 
+        '}' if data.has_setting(ParserSettings::Embedded) => {
+            // the line stays text of a paragraph
+            match embedded_close(data) {
+                Some((loc, false)) => data.report(ParseError::StrayClose(loc).into()),
+                Some((loc, true)) => data.report(ParseError::StrayElse(loc).into()),
+                None => (),
+            }
+        }
+
         '<' if data.has_setting(ParserSettings::Html) => {
-            if html(data, indent, list, open_embedded_codes) {
+            let count = list.len();
+
+            if html(data, indent, list, open_embedded) {
+                if data.has_setting(ParserSettings::TagFilter) {
+                    for blk in &mut list[count..] {
+                        if let Block::Html(html, _) = blk {
+                            filter_tags(html);
+                        }
+                    }
+                }
+
                 log!(d, data, "block", "end");
                 return;
             }
@@ -319,14 +502,189 @@ pub fn block(
         _ => (),
     }
 
-    let mut data = InnerData::new(data, None);
+    if data.has_setting(ParserSettings::DefinitionLists) {
+        if let Some(dl) = definition_list(data) {
+            list.push(dl);
+            log!(d, data, "block", "end");
+            return;
+        }
+    }
+
+    if data.has_setting(ParserSettings::Tables) {
+        if let Some(tbl) = table(data) {
+            list.push(tbl);
+            log!(d, data, "block", "end");
+            return;
+        }
+    }
+
+    let mut data = InnerData::new(data, None, !open_embedded.is_empty());
+
+    data.set_paragraph_open(true);
+    let mut par = paragraph(&mut data, !open_embedded.is_empty());
+    data.set_paragraph_open(false);
+
+    if let (Block::Paragraph(_, attrs, loc), Some((a, end))) =
+        (&mut par, data.attributes.take())
+    {
+        *attrs = a;
+        loc.end = end;
+    }
+
+    if let Some((level, underline, end)) = data.setext.take() {
+        par = match par {
+            Block::Paragraph(ct, attrs, mut loc) => {
+                loc.end = end;
+                Block::Heading(level, ct, attrs, loc)
+            }
+
+            // the underline becomes a block of its own
+            blk => {
+                data.rewind(underline);
+                blk
+            }
+        };
+    }
 
-    list.push( paragraph(&mut data, *open_embedded_codes > 0) );
+    list.push(par);
     log!(d, data, "block", "end");
     data.do_hint(list);
 }
 
-fn code_fenced(data: &mut impl ParserData, indent: usize) -> Option<(String, String, Location)> {
+/// Parses the opening fence `::: name {attributes}` of a container
+fn container_head(data: &mut impl ParserData) -> Option<(usize, String, Attributes)> {
+    let mut data = Transaction::new(data);
+
+    let colons = data.skip_all(':');
+    if colons < 3 {
+        return None;
+    }
+
+    data.skip_all(LINE_WS);
+
+    let mut name = String::new();
+    data.copy_all(&mut name, |c: char| c.is_alphanumeric() || c == '-' || c == '_');
+    data.skip_all(LINE_WS);
+
+    let attrs = attributes(&mut data).unwrap_or_default();
+    if name.is_empty() && attrs.is_empty() {
+        return None;
+    }
+
+    data.skip_all(LINE_WS);
+    data.skip_all(':');
+    data.skip_all(LINE_WS);
+
+    if !data.skip_newline() && data.peek().is_some() {
+        return None;
+    }
+
+    data.commit();
+    Some((colons, name, attrs))
+}
+
+/// Searches the closing fence of a container opened with *colons* colons
+///
+/// A closing fence needs at least as many colons as the fence of the
+/// innermost open container; fences in code blocks are ignored. Returns the
+/// position of the beginning of the line with the closing fence.
+fn container_end(data: &mut impl ParserData, colons: usize) -> Option<Position> {
+    let mut data = Transaction::new(data);
+    let mut open = vec![colons];
+    let mut code_fence: Option<(char, usize)> = None;
+
+    loop {
+        let line_begin = data.pos();
+        data.skip_all(LINE_WS);
+
+        match data.peek() {
+            None => return None,
+
+            Some(c @ ('`' | '~')) => {
+                let cnt = data.skip_all(c);
+
+                match code_fence {
+                    _ if cnt < 3 => (),
+                    None => code_fence = Some((c, cnt)),
+                    Some((fc, fcnt)) if fc == c && cnt >= fcnt => code_fence = None,
+                    Some(_) => (),
+                }
+            }
+
+            Some(':') if code_fence.is_none() => {
+                if let Some((cnt, ..)) = container_head(&mut data) {
+                    open.push(cnt);
+                    continue;
+                }
+
+                let cnt = data.skip_all(':');
+                data.skip_all(LINE_WS);
+
+                if open.last().is_some_and(|&open_cnt| cnt >= open_cnt)
+                    && (data.looking_at(NL_CR) || data.peek().is_none())
+                {
+                    open.pop();
+
+                    if open.is_empty() {
+                        return Some(line_begin);
+                    }
+                }
+            }
+
+            _ => (),
+        }
+
+        while !data.skip_newline() {
+            data.peek()?;
+            data.advance();
+        }
+    }
+}
+
+/// Parses a fenced container `::: name` … `:::`
+fn container(data: &mut impl ParserData) -> Option<Block> {
+    let loc_begin = data.loc();
+    let mut data = Transaction::new(data);
+
+    let (colons, name, attributes) = container_head(&mut data)?;
+    log!(d, data, "container", "begin");
+
+    let end = if let Some(x) = container_end(&mut data, colons) {
+        x
+    } else {
+        log!(d, data, "container", "end without match");
+        return None;
+    };
+
+    let content = body(&mut BoundedData { inner: &mut data, end });
+
+    data.rewind(end);
+    data.skip_all(LINE_WS);
+    data.skip_all(':');
+    data.skip_all(LINE_WS);
+    data.skip_newline();
+
+    let location = data.loc_end(loc_begin);
+    log!(d, data, "container", "end");
+    data.commit();
+
+    Some(Block::Container { name, attributes, content, location })
+}
+
+/// Location of the *info* string of a fenced code block that starts at
+/// *begin*; *ends* holds the end of each character of the untrimmed string
+fn info_location(info: &str, begin: LocationPosition, ends: &[LocationPosition]) -> Location {
+    let end = info.chars().count().checked_sub(1).map_or(begin, |i| ends[i]);
+    Location { begin, end }
+}
+
+/// Parses a fenced code block whose fence is indented by *indent* columns
+///
+/// Returns the info string, content, attributes, location and location of
+/// the info string.
+fn code_fenced(
+    data: &mut impl ParserData, indent: usize
+) -> Option<(String, String, Attributes, Location, Location)> {
     let fence_char = match data.peek() {
         Some(c @ ('`' | '~')) => c,
         _ => return None,
@@ -343,14 +701,25 @@ fn code_fenced(data: &mut impl ParserData, indent: usize) -> Option<(String, Str
     data.skip_all(LINE_WS);
     log!(d, data, "fenced code block", "begin");
 
+    let info_begin = data.loc();
     let mut info = String::new();
+    let mut info_ends = Vec::new();
     loop {
+        info_ends.resize(info.chars().count(), data.loc());
+
         match data.peek() {
             None => {
+                let attrs = if data.has_setting(ParserSettings::Attributes) {
+                    attributes::info_attributes(&mut info)
+                } else {
+                    Attributes::default()
+                };
+
                 let loc = data.loc_end(loc_begin);
+                let info_loc = info_location(&info, info_begin, &info_ends);
                 log!(d, data, "fenced code block", "end");
                 data.commit();
-                return Some((info, String::new(), loc));
+                return Some((info, String::new(), attrs, loc, info_loc));
             }
 
             Some('\r' | '\n') => {
@@ -389,10 +758,26 @@ fn code_fenced(data: &mut impl ParserData, indent: usize) -> Option<(String, Str
         info.pop();
     }
 
+    let attrs = if data.has_setting(ParserSettings::Attributes) {
+        attributes::info_attributes(&mut info)
+    } else {
+        Attributes::default()
+    };
+    let info_loc = info_location(&info, info_begin, &info_ends);
+
     let mut ct = String::new();
 
     'out: loop {
-        let spaces_before = data.skip_all(' ').saturating_sub(indent);
+        // remove the indentation of the fence; the rest of a tab removed in
+        // part becomes spaces
+        let line_column = data.column();
+        let mut spaces_before = 0;
+        while data.column() - line_column < indent && data.looking_at(LINE_WS) {
+            data.advance();
+            spaces_before = (data.column() - line_column).saturating_sub(indent);
+        }
+        spaces_before += data.skip_all(' ');
+
         if spaces_before < 4 {
             let f_len = data.skip_all(fence_char);
             if f_len >= fence_len {
@@ -450,10 +835,138 @@ fn code_fenced(data: &mut impl ParserData, indent: usize) -> Option<(String, Str
     let loc = data.loc_end(loc_begin);
     log!(d, data, "fenced code block", "end");
     data.commit();
-    Some((info, ct, loc))
+    Some((info, ct, attrs, loc, info_loc))
+}
+
+/// Parses an indented code block; *data* is after the indentation of the
+/// first line, which exceeds four columns by *extra*
+fn code_indented(data: &mut impl ParserData, extra: usize) -> (String, Location) {
+    log!(d, data, "indented code block", "begin");
+    let loc_begin = data.loc();
+
+    let mut ct = String::new();
+    let mut blank_lines = String::new();
+    let mut spaces_before = extra;
+    let mut end = data.pos();
+
+    loop {
+        let mut line = " ".repeat(spaces_before);
+        data.copy_all(&mut line, |c| !NL_CR.matches(c));
+        let blank = line.chars().all(|c| LINE_WS.contains(&c));
+
+        match data.peek() {
+            Some('\r') => {
+                line.push('\r');
+                data.advance();
+                if data.peek() == Some('\n') {
+                    line.push('\n');
+                    data.advance();
+                }
+            }
+
+            Some(c) => {
+                line.push(c);
+                data.advance();
+            }
+
+            None => line.push('\n'),
+        }
+
+        if blank {
+            blank_lines.push_str(&line);
+        } else {
+            ct.push_str(&blank_lines);
+            blank_lines.clear();
+            ct.push_str(&line);
+            end = data.pos();
+        }
+
+        // remove four columns of indentation; the rest of a tab removed in
+        // part becomes spaces
+        let line_pos = data.pos();
+        let line_column = data.column();
+        spaces_before = 0;
+        while data.column() - line_column < 4 && data.looking_at(LINE_WS) {
+            data.advance();
+            spaces_before = (data.column() - line_column).saturating_sub(4);
+        }
+
+        if data.peek().is_none() {
+            break;
+        } else if data.column() - line_column < 4 && !data.looking_at(NL_CR) {
+            data.rewind(line_pos);
+            break;
+        }
+    }
+
+    // trailing blank lines aren't part of the block
+    data.rewind(end);
+    let loc = data.loc_end(loc_begin);
+
+    log!(d, data, "indented code block", "end");
+    (ct, loc)
+}
+
+fn definition_list(data: &mut impl ParserData) -> Option<Block> {
+    log!(d, data, "definition list", "begin");
+    let loc_begin = data.loc();
+
+    let mut list = Vec::new();
+
+    while let Some(term) = definition_term(data) {
+        let pos = data.pos();
+        let line_column = data.column();
+        let def_begin = data.loc();
+        data.expect_char(':');
+
+        if let Some((defs, _)) = list_items(data, 0, line_column, def_begin, |d| d.skip(':')) {
+            list.push((term, defs.into_iter().map(|(blocks, _)| blocks).collect()));
+        } else {
+            data.rewind(pos);
+            break;
+        }
+    }
+
+    if list.is_empty() {
+        log!(w, data, "definition list", "end without match");
+        None
+    } else {
+        log!(d, data, "definition list", "end");
+        Some(Block::DefinitionList(list, data.loc_end(loc_begin)))
+    }
+}
+
+/// Parses a single line as term, if the next line starts a definition `: ...`
+fn definition_term(data: &mut impl ParserData) -> Option<Vec<Inline>> {
+    if data.peek().is_none() || data.looking_at(':') {
+        return None;
+    }
+
+    let mut data = Transaction::new(data);
+    log!(d, data, "definition term", "begin");
+
+    let term = match paragraph(&mut LineData::new(&mut data), false) {
+        Block::Paragraph(ct, ..) if !ct.is_empty() => ct,
+        _ => {
+            log!(w, data, "definition term", "end without match");
+            return None;
+        }
+    };
+
+    let pos = data.pos();
+    if !(data.skip(':') && data.looking_at(LINE_WS)) {
+        log!(w, data, "definition term", "end without match");
+        return None;
+    }
+
+    data.rewind(pos);
+
+    log!(d, data, "definition term", "end");
+    data.commit();
+    Some(term)
 }
 
-fn embedded(data: &mut impl ParserData, open_embedded_codes: &mut u16) -> Option<Block> {
+fn embedded(data: &mut impl ParserData, open_embedded: &mut Vec<Location>) -> Option<Block> {
     if !data.looking_at('@') {
         return None;
     }
@@ -467,6 +980,7 @@ fn embedded(data: &mut impl ParserData, open_embedded_codes: &mut u16) -> Option
 
             return Some(Block::Paragraph(
                 vec![Inline::Text("@".into(), data.loc_end(loc_begin))],
+                Attributes::default(),
                 data.loc_end(loc_begin),
             ));
         }
@@ -497,10 +1011,13 @@ fn embedded(data: &mut impl ParserData, open_embedded_codes: &mut u16) -> Option
     let loc_end;
 
     if ch == '{' && data.skip_newline() {
+        let open_loc = data.loc_end(loc_begin);
         loc_begin = data.loc();
 
         loop {
             if data.peek().is_none() {
+                log!(w, data, "embedded block", "not closed");
+                data.report(ParseError::UnclosedEmbedded(open_loc).into());
                 loc_end = data.loc();
                 break;
             }
@@ -522,18 +1039,26 @@ fn embedded(data: &mut impl ParserData, open_embedded_codes: &mut u16) -> Option
         }
     } else {
         buf.push(ch);
-        data.copy_until(&mut buf, NL_CR);
-        let line_end = buf.len() - 1;
+        let line_end = if data.copy_until(&mut buf, NL_CR) {
+            buf.len() - 1
+        } else {
+            buf.len()
+        };
 
         if buf.ends_with('\r') && data.skip('\n') {
             buf.push('\n');
         }
 
+        // the first line opens the block
+        let open_loc = data.loc_end(loc_begin);
+
         if buf.starts_with("/*") && !buf[..line_end].ends_with("*/") {
             loop {
                 data.copy_until(&mut buf, '/');
 
                 if data.peek().is_none() {
+                    log!(w, data, "embedded block", "comment not closed");
+                    data.report(ParseError::UnclosedEmbedded(open_loc).into());
                     loc_end = data.loc();
                     break;
                 }
@@ -547,13 +1072,15 @@ fn embedded(data: &mut impl ParserData, open_embedded_codes: &mut u16) -> Option
                 }
             }
         } else if buf[..line_end].ends_with('{') {
-            *open_embedded_codes += 1;
             loc_end = data.loc();
+            open_embedded.push(open_loc);
         } else if buf[..line_end].ends_with('(') {
             loop {
                 data.copy_until(&mut buf, ')');
 
                 if data.peek().is_none() {
+                    log!(w, data, "embedded block", "expression not closed");
+                    data.report(ParseError::UnclosedEmbedded(open_loc).into());
                     loc_end = data.loc();
                     break;
                 }
@@ -575,49 +1102,276 @@ fn embedded(data: &mut impl ParserData, open_embedded_codes: &mut u16) -> Option
     Some(Block::EmbeddedBlock(buf, Location { begin: loc_begin, end: loc_end }))
 }
 
-fn heading(data: &mut impl ParserData) -> Option<(u8, Vec<Inline>, Location)> {
-    struct InnerData<'a, T: ParserData> {
-        inner: &'a mut T,
-        stopped: bool,
+/// Reports the embedded blocks that are still open at the end of the body
+pub(crate) fn report_unclosed(data: &mut impl ParserData, open_embedded: Vec<Location>) {
+    for loc in open_embedded {
+        data.report(ParseError::UnclosedEmbedded(loc).into());
     }
+}
 
-    impl<T: ParserData> ParserData for InnerData<'_, T> {
-        fn settings(&self) -> ParserSettings {
-            self.inner.settings()
-        }
-
-        fn pos(&self) -> Position {
-            self.inner.pos()
-        }
+/// Checks for a line `}` or `} else {` that closes an embedded block and
+/// returns its location and whether it's an `else`
+///
+/// The data doesn't move.
+fn embedded_close(data: &mut impl ParserData) -> Option<(Location, bool)> {
+    let mut data = Transaction::new(data);
+    let loc_begin = data.loc();
 
-        fn peek(&self) -> Option<char> {
-            if self.stopped {
-                None
-            } else {
-                self.inner.peek()
-            }
-        }
+    if !data.skip('}') {
+        return None;
+    }
 
-        fn advance(&mut self) {
-            if self.stopped {
-                return;
-            }
+    if data.peek().is_none() || data.looking_at(NL_CR) {
+        return Some((data.loc_end(loc_begin), false));
+    }
 
+    let mut buf = String::new();
+    data.skip_all(LINE_WS);
+    data.copy_all(&mut buf, &['e', 'l', 's'][..]);
+    data.skip_all(LINE_WS);
+
+    if buf == "else" && data.skip('{') && data.looking_at(NL_CR) {
+        return Some((data.loc_end(loc_begin), true));
+    }
+
+    None
+}
+
+/// Restricts the input to the rest of the current line
+///
+/// The newline gets consumed, when the end of the line is reached. If an *end*
+/// is given, the input stops at this position without consuming the rest of
+/// the line.
+struct LineData<'a, T: ParserData> {
+    inner: &'a mut T,
+    stopped: bool,
+    end: Option<Position>,
+    /// Position before the consumed line ending
+    line_end: Position,
+}
+
+impl<'a, T: ParserData> LineData<'a, T> {
+    fn new(inner: &'a mut T) -> Self {
+        Self {
+            line_end: inner.pos(),
+            inner,
+            stopped: false,
+            end: None,
+        }
+    }
+
+    fn with_end(inner: &'a mut T, end: Option<Position>) -> Self {
+        Self {
+            stopped: end.is_some_and(|e| inner.pos() >= e),
+            line_end: inner.pos(),
+            inner,
+            end,
+        }
+    }
+}
+
+impl<T: ParserData> ParserData for LineData<'_, T> {
+    fn settings(&self) -> ParserSettings {
+        self.inner.settings()
+    }
+
+    fn admonition_types(&self) -> &[&str] {
+        self.inner.admonition_types()
+    }
+
+    fn pos(&self) -> Position {
+        self.inner.pos()
+    }
+
+    fn peek(&self) -> Option<char> {
+        if self.stopped {
+            None
+        } else {
+            self.inner.peek()
+        }
+    }
+
+    fn advance(&mut self) {
+        if self.stopped {
+            return;
+        }
+
+        self.inner.advance();
+        self.line_end = self.inner.pos();
+
+        if self.end.is_some_and(|e| self.inner.pos() >= e)
+            || self.inner.peek().is_none() || self.inner.skip_newline()
+        {
+            log!(t, self.inner, "line", "stopped");
+            self.stopped = true;
+        }
+    }
+
+    fn reset(&mut self, pos: Position) -> Result<(), ()> {
+        self.inner.reset(pos)?;
+        self.stopped = self.end.is_some_and(|e| pos >= e) || self.inner.peek().is_none();
+        self.line_end = pos;
+        Ok(())
+    }
+
+    fn content_end(&self) -> Position {
+        if self.stopped {
+            self.line_end
+        } else {
+            self.inner.pos()
+        }
+    }
+
+    fn column(&self) -> usize {
+        self.inner.column()
+    }
+
+    fn set_paragraph_open(&mut self, open: bool) {
+        self.inner.set_paragraph_open(open);
+    }
+
+    fn lazy_line(&self) -> bool {
+        self.inner.lazy_line()
+    }
+
+    fn report(&mut self, diag: Diagnostic) {
+        self.inner.report(diag);
+    }
+
+    fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.inner.take_diagnostics()
+    }
+}
+
+/// Data that ends at a fixed position
+struct BoundedData<'a> {
+    inner: &'a mut dyn ParserData,
+    end: Position,
+}
+
+impl ParserData for BoundedData<'_> {
+    fn settings(&self) -> ParserSettings {
+        self.inner.settings()
+    }
+
+    fn admonition_types(&self) -> &[&str] {
+        self.inner.admonition_types()
+    }
+
+    fn pos(&self) -> Position {
+        self.inner.pos()
+    }
+
+    fn peek(&self) -> Option<char> {
+        if self.inner.pos() >= self.end {
+            None
+        } else {
+            self.inner.peek()
+        }
+    }
+
+    fn advance(&mut self) {
+        if self.inner.pos() < self.end {
             self.inner.advance();
+        }
+    }
 
-            if self.inner.peek().is_none() || self.inner.skip_newline() {
-                log!(t, self.inner, "heading", "stopped");
-                self.stopped = true;
-            }
+    fn reset(&mut self, pos: Position) -> Result<(), ()> {
+        self.inner.reset(pos)
+    }
+
+    fn column(&self) -> usize {
+        self.inner.column()
+    }
+
+    fn set_paragraph_open(&mut self, open: bool) {
+        self.inner.set_paragraph_open(open);
+    }
+
+    fn lazy_line(&self) -> bool {
+        self.inner.lazy_line()
+    }
+
+    fn report(&mut self, diag: Diagnostic) {
+        self.inner.report(diag);
+    }
+
+    fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.inner.take_diagnostics()
+    }
+}
+
+/// Columns of a tab that remain after a part of it was consumed as
+/// indentation
+///
+/// The remaining columns are seen as spaces in front of the character after
+/// the tab.
+#[derive(Default)]
+struct PartialTab {
+    /// Position after the tab
+    pos: Position,
+    /// Columns not yet seen as spaces
+    remaining: usize,
+}
+
+impl PartialTab {
+    fn peek(&self, inner: &dyn ParserData) -> Option<char> {
+        if self.remaining > 0 {
+            Some(' ')
+        } else {
+            inner.peek()
         }
+    }
 
-        fn reset(&mut self, pos: Position) -> Result<(), ()> {
-            self.inner.reset(pos)?;
-            self.stopped = false;
-            Ok(())
+    /// Consumes a remaining column; returns false if there is none
+    fn advance(&mut self) -> bool {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            true
+        } else {
+            false
         }
     }
 
+    /// Drops the remaining columns if *pos* is not directly after the tab
+    ///
+    /// A position can't tell how many of the columns were consumed, hence
+    /// they aren't restored.
+    fn reset(&mut self, pos: Position) {
+        if pos != self.pos {
+            self.remaining = 0;
+        }
+    }
+
+    fn column(&self, inner: &dyn ParserData) -> usize {
+        inner.column().saturating_sub(self.remaining)
+    }
+
+    /// Consumes one column of indentation, which might be a part of a tab
+    fn skip_column(&mut self, inner: &mut dyn ParserData) -> bool {
+        if self.advance() {
+            return true;
+        }
+
+        match inner.peek() {
+            Some(' ') => inner.advance(),
+
+            Some('\t') => {
+                let width = TAB_STOP - inner.column() % TAB_STOP;
+                inner.advance();
+
+                self.pos = inner.pos();
+                self.remaining = width - 1;
+            }
+
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+fn heading(data: &mut impl ParserData) -> Option<(u8, Vec<Inline>, Attributes, Location)> {
     let loc_begin = data.loc();
     log!(d, data, "heading", "begin");
 
@@ -636,7 +1390,7 @@ fn heading(data: &mut impl ParserData) -> Option<(u8, Vec<Inline>, Location)> {
             log!(d, data, "heading", "end");
             data.commit();
 
-            return Some((level, Vec::new(), loc));
+            return Some((level, Vec::new(), Attributes::default(), loc));
         }
 
         if !data.looking_at(LINE_WS) {
@@ -648,42 +1402,112 @@ fn heading(data: &mut impl ParserData) -> Option<(u8, Vec<Inline>, Location)> {
     }
 
     let pos = data.pos();
-    let mut data = InnerData {
-        inner: data,
-        stopped: false,
-    };
+    let mut attrs = Attributes::default();
+    let mut end = None;
 
-    let par = paragraph(&mut data, false);
+    if data.settings().intersects(
+        ParserSettings::Attributes | ParserSettings::AtxClosingSequence
+    ) {
+        let mut line = String::new();
+        data.copy_all(&mut line, |c| !NL_CR.matches(c));
+        data.rewind(pos);
+
+        if data.has_setting(ParserSettings::Attributes) {
+            if let Some((idx, a)) = attributes::trailing_attributes(&line) {
+                log!(t, data, "heading", "attributes at {}", pos + idx);
+                attrs = a;
+                line.truncate(idx);
+                end = Some(pos + idx);
+            }
+        }
 
-    assert!(data.stopped, "paragraph() stopped before EOL");
+        if data.has_setting(ParserSettings::AtxClosingSequence) {
+            if let Some(idx) = closing_sequence(&line) {
+                log!(t, data, "heading", "closing sequence at {}", pos + idx);
+                end = Some(pos + idx);
+            }
+        }
+    }
 
-    let (ct, loc) = match par {
-        Block::Paragraph(ct, mut loc) => {
-            loc.begin = loc_begin;
+    let mut data = LineData::with_end(data, end);
 
-            log!(d, data, "heading", "end");
-            (ct, loc)
-        }
+    let par = paragraph(&mut data, false);
+
+    // paragraph() only stops when the data ends and LineData ends at the line
+    // ending
+    debug_assert!(data.stopped, "paragraph() stopped before EOL");
 
-        Block::LinkDef(..) => {
-            let data = data.inner;
-            data.reset(pos).unwrap();
+    let ct = match par {
+        Block::Paragraph(ct, ..) => ct,
+
+        // a link definition
+        _ => {
+            data.rewind(pos);
             let inner_loc_begin = data.loc();
 
             let mut buf = String::new();
-            data.copy_all(&mut buf, |c| !NL_CR.matches(c));
-            let loc = data.loc_end(loc_begin);
-            let inner_loc = data.loc_end(inner_loc_begin);
-            data.skip_newline();
+            data.copy_all(&mut buf, |_| true);
+            while buf.ends_with(&LINE_WS[..]) {
+                buf.pop();
+            }
 
-            log!(d, data, "heading", "end");
-            (vec![Inline::Text(buf, inner_loc)], loc)
+            let inner_loc = Location {
+                begin: inner_loc_begin,
+                end: inner_loc_begin + buf.len(),
+            };
+
+            vec![Inline::Text(buf, inner_loc)]
         }
+    };
+
+    let data = data.inner;
+    if end.is_some() {
+        // skip the closing sequence and the attributes
+        data.skip_all(|c| !NL_CR.matches(c));
+        data.skip_newline();
+    }
+
+    log!(d, data, "heading", "end");
+    Some((level, ct, attrs, data.loc_end(loc_begin)))
+}
+
+/// Parses a setext heading underline `===` or `---` and returns the level
+fn setext_underline(data: &mut impl ParserData) -> Option<u8> {
+    let ch = data.peek()?;
+    let mut data = Transaction::new(data);
 
-        x => unreachable!("{:?}", x),
+    let level = match ch {
+        '=' => 1,
+        '-' => 2,
+        _ => return None,
     };
 
-    Some((level, ct, loc))
+    data.skip_all(ch);
+    data.skip_all(LINE_WS);
+    if !(data.skip_newline() || data.peek().is_none()) {
+        return None;
+    }
+
+    data.commit();
+    Some(level)
+}
+
+/// Finds the optional closing sequence of `#` at the end of the content of an
+/// ATX heading and returns the end of the content without it
+///
+/// The sequence must be preceded by a space or tab unless it's the whole
+/// content.
+fn closing_sequence(line: &str) -> Option<usize> {
+    let line = line.trim_end_matches(&LINE_WS[..]);
+    let content = line.trim_end_matches('#');
+
+    if content.len() == line.len()
+        || !(content.is_empty() || content.ends_with(&LINE_WS[..]))
+    {
+        return None;
+    }
+
+    Some(content.trim_end_matches(&LINE_WS[..]).len())
 }
 
 // from https://spec.commonmark.org/0.29/#html-blocks
@@ -698,14 +1522,45 @@ const HTML_TAG_NAMES : &[&str] = &[
     "th", "thead", "title", "tr", "track", "ul"
 ];
 
+/// Checks if an HTML block of the cases 1 to 6 starts; only these interrupt a
+/// paragraph
+fn html_interrupts(data: &mut impl ParserData) -> bool {
+    let mut data = Transaction::new(data);
+    if !data.skip('<') {
+        return false;
+    }
+
+    if data.skip('!') {
+        // case 2: <!--, case 4: <!DOCTYPE, case 5: <![CDATA[
+        return data.looking_at(|c: char| c == '-' || c == '[' || c.is_ascii_uppercase());
+    } else if data.looking_at('?') {
+        // case 3
+        return true;
+    }
+
+    let is_closing_tag = data.skip('/');
+    let mut name = String::new();
+    data.copy_all(&mut name, is_ascii_alphanumeric);
+    name.make_ascii_lowercase();
+
+    let name_end = matches!(data.peek(), None | Some(' ' | '\t' | '\r' | '\n' | '>'))
+        || (data.skip('/') && data.looking_at('>'));
+
+    name_end && (
+        HTML_TAG_NAMES.binary_search(&name.as_str()).is_ok()
+            || (!is_closing_tag && ["script", "pre", "style"].contains(&name.as_str()))
+    )
+}
+
 fn html(
-    data: &mut impl ParserData, indent: usize, list: &mut Vec<Block>, op_br: &mut u16
+    data: &mut impl ParserData, indent: usize, list: &mut Vec<Block>,
+    op_br: &mut Vec<Location>
 ) -> bool {
     fn is_ascii_uppercase(ch: char) -> bool { char::is_ascii_uppercase(&ch) }
 
     fn finish_with_blank_line(
         mut data: Transaction<impl ParserData>, mut buf: String,
-        list: &mut Vec<Block>, op_br: &mut u16, loc_begin: LocationPosition
+        list: &mut Vec<Block>, op_br: &mut Vec<Location>, loc_begin: LocationPosition
     ) {
         let stop_chars = if data.has_setting(ParserSettings::Embedded) {
             &['\r', '\n', '@'][..]
@@ -743,7 +1598,7 @@ fn html(
                         }
 
                         paragraph::Embedded::Block(x, br) => {
-                            *op_br += br;
+                            op_br.extend((0..br).map(|_| loc.clone()));
                             list.push(Block::EmbeddedBlock(x, loc));
                         }
                     }
@@ -758,7 +1613,7 @@ fn html(
                 }
 
                 if data.has_setting(ParserSettings::Embedded) && (
-                    data.looking_at('@') || (*op_br > 0 && data.looking_at('}'))
+                    data.looking_at('@') || (!op_br.is_empty() && data.looking_at('}'))
                 ) {
                     break;
                 }
@@ -985,7 +1840,7 @@ fn html(
         data.skip_all(' ');
 
         if data.looking_at(NL_CR) || data.peek().is_none() {
-            data.reset(pos).unwrap();
+            data.rewind(pos);
             finish_with_blank_line(data, buf, list, op_br, loc_begin);
 
             true
@@ -996,20 +1851,73 @@ fn html(
     }
 }
 
+/// Checks whether the line at the current position continues an open
+/// paragraph lazily, i.e. it's neither blank nor the start of another block
+///
+/// Unlike inside of the paragraph, every list item starts a new block,
+/// because the line doesn't belong to the enclosing quote or item anymore.
+fn lazy_continuation(data: &mut impl ParserData) -> bool {
+    let mut data = Transaction::new(data);
+
+    let column = data.column();
+    data.skip_all(LINE_WS);
+    let indent_columns = data.column() - column;
+
+    if indent_columns >= 4 && data.has_setting(ParserSettings::IndentedCode) {
+        return true;
+    }
+
+    match data.peek() {
+        None | Some('\n' | '\r' | '>') => false,
+
+        Some('#') => heading(&mut data).is_none(),
+        Some('`' | '~') => code_fenced(&mut data, indent_columns).is_none(),
+        Some('*' | '-' | '_') if thematic_break(&mut data) => false,
+        Some(':') if data.has_setting(ParserSettings::Containers) => container(&mut data).is_none(),
+
+        Some('*' | '-' | '+') => {
+            data.advance();
+            !(data.peek().is_none() || data.looking_at(LINE_WS) || data.looking_at(NL_CR))
+        }
+
+        Some('0' ..= '9') => {
+            list_start(&mut data).is_none()
+                || !(data.peek().is_none() || data.looking_at(LINE_WS) || data.looking_at(NL_CR))
+        }
+
+        Some(_) => true,
+    }
+}
+
+/// Parses the items of a list after the marker of the first item, which
+/// starts at *loc_begin*
+///
+/// Returns the items and whether the list is tight, i.e. neither the items
+/// nor the blocks of an item are separated by blank lines.
 fn list_items(
     data: &mut impl ParserData,
     extra_indent: u8,
+    line_column: usize,
+    loc_begin: LocationPosition,
     skip_marker: impl Fn(&mut dyn ParserData) -> bool
-) -> Option<Vec<Vec<Block>>> {
+) -> Option<(Vec<ListItem>, bool)> {
     if data.peek().is_none() {
         log!(d, data, "list items", "end");
-        return Some(vec![ Vec::new() ]);
+        return Some((vec![ (Vec::new(), data.loc_end(loc_begin)) ], true));
     }
 
-    if data.skip_newline() {
+    let marker_loc = data.loc_end(loc_begin);
+    let content_indent = data.has_setting(ParserSettings::ListContentIndent);
+    if content_indent {
+        // the indentation gets measured for each item
+        if !(data.looking_at(LINE_WS) || data.looking_at(NL_CR)) {
+            log!(w, data, "list items", "end without match");
+            return None;
+        }
+    } else if data.skip_newline() {
         if data.peek().is_none() || data.skip_newline() {
             log!(d, data, "list items", "end");
-            return Some(vec![ Vec::new() ]);
+            return Some((vec![ (Vec::new(), data.loc_end(loc_begin)) ], true));
         }
 
         if !data.skip(LINE_WS) {
@@ -1018,9 +1926,12 @@ fn list_items(
         }
 
         log!(e, data, "list items", "with empty line");
+        data.report(Diagnostic::warning(
+            "list-item-empty-start", "list item starts with an empty line", marker_loc,
+        ));
     }
 
-    if !data.skip(LINE_WS) {
+    if !content_indent && !data.skip(LINE_WS) {
         log!(w, data, "list items", "end without match");
         return None;
     }
@@ -1029,6 +1940,138 @@ fn list_items(
         inner: &'a mut dyn ParserData,
         stopped: bool,
         extra_indent: u8,
+        /// Column of the content of the item relative to the start of the
+        /// line with [`ParserSettings::ListContentIndent`]
+        content_column: Option<usize>,
+        /// Column where the current line starts inside of the enclosing
+        /// blocks
+        line_column: usize,
+        tab: PartialTab,
+
+        /// No content since the last newline
+        line_blank: bool,
+        /// A blank line without content after it was seen
+        blank_line: bool,
+        /// Position of the first content after the last blank line
+        after_blank: Option<Position>,
+        /// Waiting for the first content of a block of the item
+        block_start: bool,
+        /// The current block of the item follows a blank line
+        blank_before_block: bool,
+        paragraph_open: bool,
+        /// The current line is a lazy continuation line
+        lazy: bool,
+    }
+
+    impl InnerData<'_> {
+        fn is_content(ch: Option<char>) -> bool {
+            ch.is_some_and(|c| !NL_CR.matches(c) && !LINE_WS.matches(c))
+        }
+
+        fn track_blank_lines(&mut self, prev: char) {
+            match self.inner.peek() {
+                // inside a \r\n
+                Some('\n') if prev == '\r' => (),
+
+                Some(c) if NL_CR.matches(c) => {
+                    self.blank_line |= self.line_blank;
+                    self.line_blank = true;
+                }
+
+                c if Self::is_content(c) => {
+                    if self.blank_line {
+                        self.after_blank = Some(self.inner.pos());
+                    }
+
+                    if self.block_start {
+                        self.blank_before_block = self.blank_line;
+                        self.block_start = false;
+                    }
+
+                    self.line_blank = false;
+                    self.blank_line = false;
+                }
+
+                _ => (),
+            }
+        }
+
+        /// Measures the column of the content after the marker of an item
+        ///
+        /// The content follows one to four columns of spaces; with more or a
+        /// blank line, it starts one column after the marker.
+        fn measure_content_column(&mut self) {
+            // no tab is consumed in part directly after the marker
+            let data = &mut self.inner;
+            let marker_end = data.column();
+            let pos = data.pos();
+
+            data.skip_all(LINE_WS);
+            let spaces = data.column() - marker_end;
+            let blank = data.peek().is_none() || data.looking_at(NL_CR);
+
+            if blank {
+                // an item can begin with at most one blank line; otherwise
+                // it's empty and the blank lines get skipped
+                data.skip_newline();
+
+                let mut line_pos = data.pos();
+                data.skip_all(LINE_WS);
+
+                if data.looking_at(NL_CR) {
+                    while data.skip_newline() {
+                        line_pos = data.pos();
+                        data.skip_all(LINE_WS);
+                    }
+
+                    data.rewind(line_pos);
+                    self.stopped = true;
+                    self.blank_line = true;
+                } else {
+                    data.rewind(pos);
+                }
+            } else if spaces > 4 {
+                data.rewind(pos);
+                self.tab.skip_column(self.inner);
+            }
+
+            self.content_column = Some(
+                marker_end.saturating_sub(self.line_column) + if blank || spaces > 4 { 1 } else { spaces }
+            );
+        }
+
+        /// Keeps a line without the indentation of the item if it's a lazy
+        /// continuation of a paragraph
+        fn keep_lazy_line(&mut self, line_pos: Position) -> bool {
+            if !(self.paragraph_open
+                && self.inner.has_setting(ParserSettings::LazyContinuation))
+            {
+                return false;
+            }
+
+            let pos = self.inner.pos();
+            self.inner.rewind(line_pos);
+
+            if lazy_continuation(&mut self.inner) {
+                self.tab.reset(line_pos);
+                self.lazy = true;
+                true
+            } else {
+                self.inner.rewind(pos);
+                false
+            }
+        }
+
+        /// Prepares the tracking of blank lines for the next block of the item
+        fn begin_block(&mut self) {
+            if Self::is_content(self.peek()) {
+                self.block_start = false;
+                self.blank_before_block = self.after_blank == Some(self.pos());
+            } else {
+                self.block_start = true;
+                self.blank_before_block = false;
+            }
+        }
     }
 
     impl ParserData for InnerData<'_> {
@@ -1036,6 +2079,10 @@ fn list_items(
             self.inner.settings()
         }
 
+        fn admonition_types(&self) -> &[&str] {
+            self.inner.admonition_types()
+        }
+
         fn pos(&self) -> Position {
             self.inner.pos()
         }
@@ -1044,7 +2091,7 @@ fn list_items(
             if self.stopped {
                 None
             } else {
-                self.inner.peek()
+                self.tab.peek(self.inner)
             }
         }
 
@@ -1053,6 +2100,11 @@ fn list_items(
                 return;
             }
 
+            if self.tab.advance() {
+                self.track_blank_lines(' ');
+                return;
+            }
+
             let data = &mut self.inner;
             let prev = data.peek().unwrap();
 
@@ -1065,21 +2117,47 @@ fn list_items(
                 }
 
                 Some(ch) if !NL_CR.matches(ch) && NL_CR.matches(prev) => {
-                    let mut ident = self.extra_indent;
-                    while ident > 0 && data.skip(' ') {
-                        ident -= 1;
-                    }
+                    let line_pos = self.inner.pos();
+                    self.lazy = false;
+
+                    if let Some(column) = self.content_column {
+                        self.line_column = self.inner.column();
+
+                        while self.tab.column(self.inner) - self.line_column < column
+                            && self.tab.skip_column(self.inner)
+                        { }
+
+                        if self.tab.column(self.inner) - self.line_column < column
+                            && !self.inner.looking_at(NL_CR)
+                            && !self.keep_lazy_line(line_pos)
+                        {
+                            // the line is left for the following blocks
+                            log!(t, self.inner, "unordered list", "stopped");
+                            self.inner.rewind(line_pos);
+                            self.tab.reset(line_pos);
+                            self.stopped = true;
+                            return;
+                        }
 
-                    // after newline
-                    if !data.skip(LINE_WS) {
-                        log!(t, data, "unordered list", "stopped");
-                        self.stopped = true;
-                        self.extra_indent -= ident;
+                        self.track_blank_lines(prev);
                         return;
                     }
 
-                    if ch == ' ' && !data.skip(' ') {
-                        log!(t, data, "unordered list", "stopped");
+                    // after newline; a tab might be consumed in part
+                    let mut ident = self.extra_indent;
+                    while ident > 0 && self.tab.skip_column(self.inner) {
+                        ident -= 1;
+                    }
+
+                    if !self.tab.skip_column(self.inner) {
+                        if !self.keep_lazy_line(line_pos) {
+                            log!(t, self.inner, "unordered list", "stopped");
+                            self.stopped = true;
+                            self.extra_indent -= ident;
+                            return;
+                        }
+                    } else if !self.tab.skip_column(self.inner) && !self.keep_lazy_line(line_pos) {
+                        log!(t, self.inner, "unordered list", "stopped");
                         self.stopped = true;
                         self.extra_indent += 1;
                     }
@@ -1087,47 +2165,153 @@ fn list_items(
 
                 _ => (),
             }
+
+            if !self.stopped {
+                self.track_blank_lines(prev);
+            }
         }
 
         fn reset(&mut self, pos: Position) -> Result<(), ()> {
             self.inner.reset(pos)?;
+            self.tab.reset(pos);
             self.stopped = false;
             Ok(())
         }
+
+        fn column(&self) -> usize {
+            self.tab.column(self.inner)
+        }
+
+        fn set_paragraph_open(&mut self, open: bool) {
+            self.paragraph_open = open;
+            self.inner.set_paragraph_open(open);
+        }
+
+        fn lazy_line(&self) -> bool {
+            self.lazy || self.inner.lazy_line()
+        }
+
+        fn report(&mut self, diag: Diagnostic) {
+            self.inner.report(diag);
+        }
+
+        fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+            self.inner.take_diagnostics()
+        }
     }
 
     let mut list = Vec::new();
+    let mut tight = true;
+    let mut item_begin = loc_begin;
     let mut data = InnerData {
         inner: data,
         stopped: false,
         extra_indent,
+        content_column: None,
+        line_column,
+        tab: PartialTab::default(),
+        line_blank: false,
+        blank_line: false,
+        after_blank: None,
+        block_start: false,
+        blank_before_block: false,
+        paragraph_open: false,
+        lazy: false,
     };
 
     loop {
         log!(t, data, "list item", "begin");
 
-        list.push( body(&mut data) );
+        // an item starting with an empty line can be followed by a blank line
+        data.line_blank = data.peek().is_some_and(|c| NL_CR.matches(c));
+        data.blank_line = false;
+
+        if content_indent {
+            data.measure_content_column();
+        }
+
+        let checkbox = if data.has_setting(ParserSettings::Checkboxes) {
+            task_marker(&mut data)
+        } else {
+            None
+        };
+
+        let mut blocks = Vec::new();
+        let mut open_embedded = Vec::new();
+
+        while data.peek().is_some() {
+            let count = blocks.len();
+
+            data.begin_block();
+            block_tracked(&mut data, &mut blocks, &mut open_embedded);
+
+            if count > 0 && blocks.len() > count && data.blank_before_block {
+                log!(t, data, "list item", "blank line between blocks");
+                tight = false;
+            }
+        }
+
+        report_unclosed(&mut data, open_embedded);
+
+        if let Some((checked, loc)) = checkbox {
+            match blocks.first_mut() {
+                Some(Block::Paragraph(ct, ..)) => ct.insert(0, Inline::Checkbox(checked, loc)),
+                _ => {
+                    let par = vec![Inline::Checkbox(checked, loc.clone())];
+                    blocks.insert(0, Block::Paragraph(par, Attributes::default(), loc));
+                }
+            }
+        }
+
+        list.push((blocks, data.loc_end(item_begin)));
 
         {
+            let blank_line = data.blank_line;
             let data = &mut data.inner;
             let pos = data.pos();
 
             log!(t, data, "list item", "end");
 
+            if content_indent {
+                // the marker of the next item is indented by at most three
+                // columns and a thematic break ends the list
+                let column = data.column();
+                data.skip_all(LINE_WS);
+
+                if data.column() - column > 3 || thematic_break(data) {
+                    data.rewind(pos);
+                    break;
+                }
+            }
+
+            item_begin = data.loc();
             if !skip_marker(*data) {
+                // a reset would restart an enclosing item that stopped
+                if data.pos() != pos {
+                    data.rewind(pos);
+                }
                 break;
             }
 
+            if blank_line {
+                log!(t, data, "list item", "blank line between items");
+                tight = false;
+            }
+
             if data.peek().is_none() {
-                list.push(Vec::new());
+                list.push((Vec::new(), data.loc_end(item_begin)));
                 break;
             }
 
             if !data.looking_at(LINE_WS) {
                 if data.looking_at(NL_CR) {
                     log!(e, data, "list item", "start with empty line");
+                    data.report(Diagnostic::warning(
+                        "list-item-empty-start", "list item starts with an empty line",
+                        data.loc_end(item_begin),
+                    ));
                 } else {
-                    data.reset(pos).unwrap();
+                    data.rewind(pos);
                     break;
                 }
             }
@@ -1137,43 +2321,112 @@ fn list_items(
 
         if data.extra_indent > 1 {
             log!(e, data, "list item", "with extra indent of {}", data.extra_indent);
+            let loc = data.loc_end(item_begin);
+            data.report(Diagnostic::warning(
+                "list-item-extra-indent",
+                format!(
+                    "list item is indented by {} columns more than the first one",
+                    data.extra_indent
+                ),
+                loc,
+            ));
         }
     }
 
-    Some(list)
+    Some((list, tight))
 }
 
-fn ordered_list(data: &mut impl ParserData) -> Option<(String, Vec<Vec<Block>>)> {
-    log!(d, data, "ordered list", "begin");
+/// Parses the marker `[ ]` or `[x]` of a task list item and returns whether
+/// it's checked
+///
+/// The marker must be followed by whitespace.
+fn task_marker(data: &mut impl ParserData) -> Option<(bool, Location)> {
+    let mut data = Transaction::new(data);
 
-    fn is_ascii_digit(ch: char) -> bool {
-        ch.is_ascii_digit()
+    data.skip_all(LINE_WS);
+    let loc_begin = data.loc();
+
+    if !data.skip('[') {
+        return None;
     }
 
+    let checked = match data.peek()? {
+        ' ' | '\t' => false,
+        'x' | 'X' => true,
+        _ => return None,
+    };
+
+    data.advance();
+    if !data.skip(']') || !data.looking_at(LINE_WS) {
+        return None;
+    }
+
+    let loc = data.loc_end(loc_begin);
+    log!(t, data, "list item", "task marker");
+    data.commit();
+
+    Some((checked, loc))
+}
+
+/// Parses the marker `123.` or `123)` of an ordered list item
+fn list_start(data: &mut impl ParserData) -> Option<ListStart> {
+    let mut data = Transaction::new(data);
+
+    let mut digits = String::new();
+    if !(1..10).contains(&data.copy_all(&mut digits, |c: char| c.is_ascii_digit())) {
+        return None;
+    }
+
+    let delimiter = match data.peek() {
+        Some(c @ ('.' | ')')) => c,
+        _ => return None,
+    };
+
+    data.advance();
+    data.commit();
+
+    Some(ListStart {
+        number: digits.parse().ok()?,
+        width: u8::try_from(digits.len()).ok()?,
+        delimiter,
+    })
+}
+
+/// Parses an ordered list; *indent* are the columns in front of the marker
+fn ordered_list(data: &mut impl ParserData, indent: usize) -> Option<Block> {
+    log!(d, data, "ordered list", "begin");
+
     let pos = data.pos();
-    let mut first_no = String::new();
+    let line_column = data.column() - indent;
+    let loc_begin = data.loc();
 
-    if !(
-        (1..10).contains(&data.copy_all(&mut first_no, is_ascii_digit))
-            && data.skip(['.', ')'])
-    ) {
+    let start = if let Some(x) = list_start(data) {
+        x
+    } else {
         log!(w, data, "ordered list", "end without match");
-        data.reset(pos).unwrap();
         return None;
-    }
+    };
 
-    if let Some(list) = list_items(
-        data, 1, |d| d.skip_all("0123456789") > 0 && d.skip(['.', ')'])
+    if let Some((list, tight)) = list_items(
+        data, 1, line_column, loc_begin, |d| {
+            let pos = d.pos();
+            if d.skip_all("0123456789") == 0 {
+                return false;
+            }
+
+            // a different delimiter starts a new list
+            d.skip(start.delimiter) || { d.rewind(pos); false }
+        }
     ) {
         log!(d, data, "ordered list", "end");
-        Some((first_no, list))
+        Some(Block::OrderedList(start, tight, list, data.loc_end(loc_begin)))
     } else {
-        data.reset(pos).unwrap();
+        data.rewind(pos);
         None
     }
 }
 
-fn quote(data: &mut impl ParserData) -> Option<(Vec<Block>, Location)> {
+fn quote(data: &mut impl ParserData) -> Option<Block> {
     if !data.skip('>') {
         return None;
     }
@@ -1181,11 +2434,30 @@ fn quote(data: &mut impl ParserData) -> Option<(Vec<Block>, Location)> {
     log!(d, data, "quote", "begin");
     let loc_begin = data.loc() - 1;
 
-    data.skip(LINE_WS);
-
     struct InnerData<'a> {
         inner: &'a mut dyn ParserData,
-        stopped: bool,
+        stop_pos: Option<Position>,
+        tab: PartialTab,
+        paragraph_open: bool,
+        /// The current line is a lazy continuation line
+        lazy: bool,
+    }
+
+    impl InnerData<'_> {
+        /// Ends the quote at a line without `>` unless it's a lazy
+        /// continuation of a paragraph
+        fn lazy_or_stop(&mut self) {
+            if !self.inner.has_setting(ParserSettings::LazyContinuation) {
+                return;
+            }
+
+            if self.paragraph_open && lazy_continuation(&mut self.inner) {
+                self.lazy = true;
+            } else {
+                log!(t, self.inner, "quote", "stopped");
+                self.stop_pos = Some(self.inner.pos());
+            }
+        }
     }
 
     impl ParserData for InnerData<'_> {
@@ -1193,20 +2465,24 @@ fn quote(data: &mut impl ParserData) -> Option<(Vec<Block>, Location)> {
             self.inner.settings()
         }
 
+        fn admonition_types(&self) -> &[&str] {
+            self.inner.admonition_types()
+        }
+
         fn pos(&self) -> Position {
             self.inner.pos()
         }
 
         fn peek(&self) -> Option<char> {
-            if self.stopped {
+            if self.stop_pos.is_some() {
                 None
             } else {
-                self.inner.peek()
+                self.tab.peek(self.inner)
             }
         }
 
         fn advance(&mut self) {
-            if self.stopped {
+            if self.stop_pos.is_some() || self.tab.advance() {
                 return;
             }
 
@@ -1214,10 +2490,14 @@ fn quote(data: &mut impl ParserData) -> Option<(Vec<Block>, Location)> {
 
             self.inner.advance();
 
+            if NL_CR.matches(prev) {
+                self.lazy = false;
+            }
+
             match self.inner.peek() {
                 None => {
                     log!(t, self.inner, "quote", "stopped");
-                    self.stopped = true;
+                    self.stop_pos = Some(self.inner.pos());
                 }
 
                 // inside a \r\n
@@ -1226,7 +2506,7 @@ fn quote(data: &mut impl ParserData) -> Option<(Vec<Block>, Location)> {
                 // after newline
                 Some('>') if NL_CR.matches(prev) => {
                     self.inner.advance();
-                    self.inner.skip(LINE_WS);
+                    self.tab.skip_column(self.inner);
                 }
 
                 Some(' ' | '\t') if NL_CR.matches(prev) => {
@@ -1236,38 +2516,335 @@ fn quote(data: &mut impl ParserData) -> Option<(Vec<Block>, Location)> {
                     match self.inner.peek() {
                         None => {
                             log!(t, self.inner, "quote", "stopped");
-                            self.stopped = true;
+                            self.stop_pos = Some(self.inner.pos());
                         }
 
                         Some('>') => {
                             self.inner.advance();
-                            self.inner.skip(LINE_WS);
+                            self.tab.skip_column(self.inner);
                         }
 
-                        _ => self.inner.reset(start_pos).unwrap(),
+                        _ => {
+                            self.inner.rewind(start_pos);
+                            self.lazy_or_stop();
+                        }
                     }
                 }
 
+                Some(_) if NL_CR.matches(prev) => self.lazy_or_stop(),
+
                 _ => ()
             }
         }
 
         fn reset(&mut self, pos: Position) -> Result<(), ()> {
             self.inner.reset(pos)?;
-            self.stopped = false;
+            self.tab.reset(pos);
+            if self.stop_pos.is_some_and(|p| pos < p) {
+                self.stop_pos = None;
+            }
             Ok(())
         }
+
+        fn column(&self) -> usize {
+            self.tab.column(self.inner)
+        }
+
+        fn set_paragraph_open(&mut self, open: bool) {
+            self.paragraph_open = open;
+            self.inner.set_paragraph_open(open);
+        }
+
+        fn lazy_line(&self) -> bool {
+            self.lazy || self.inner.lazy_line()
+        }
+
+        fn report(&mut self, diag: Diagnostic) {
+            self.inner.report(diag);
+        }
+
+        fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+            self.inner.take_diagnostics()
+        }
     }
 
     let mut data = InnerData {
         inner: data,
-        stopped: false,
+        stop_pos: None,
+        tab: PartialTab::default(),
+        paragraph_open: false,
+        lazy: false,
+    };
+
+    // the optional space after `>` might be a part of a tab
+    data.tab.skip_column(data.inner);
+
+    let head = if data.has_setting(ParserSettings::Admonitions) {
+        admonition_head(&mut data)
+    } else {
+        None
     };
 
     let content = body(&mut data);
 
     log!(d, data, "quote", "end");
-    Some((content, data.loc_end(loc_begin)))
+    let loc = data.loc_end(loc_begin);
+
+    Some(if let Some((kind, title)) = head {
+        Block::Admonition(kind, title, content, loc)
+    } else {
+        Block::Quote(content, loc)
+    })
+}
+
+/// Parses the first line `[!TYPE] title` of an admonition
+fn admonition_head(data: &mut impl ParserData) -> Option<(String, Vec<Inline>)> {
+    let mut data = Transaction::new(data);
+
+    if !data.skip('[') || !data.skip('!') {
+        return None;
+    }
+
+    log!(d, data, "admonition", "begin");
+
+    let mut kind = String::new();
+    data.copy_all(&mut kind, is_ascii_alphabetic);
+    kind.make_ascii_lowercase();
+
+    if !data.skip(']') || !data.admonition_types().contains(&kind.as_str()) {
+        log!(d, data, "admonition", "end without match");
+        return None;
+    }
+
+    data.skip_all(LINE_WS);
+
+    let title = if data.skip_newline() || data.peek().is_none() {
+        Vec::new()
+    } else {
+        match paragraph(&mut LineData::new(&mut data), false) {
+            Block::Paragraph(ct, ..) => ct,
+            _ => {
+                log!(d, data, "admonition", "end without match");
+                return None;
+            }
+        }
+    };
+
+    log!(d, data, "admonition", "end");
+    data.commit();
+    Some((kind, title))
+}
+
+/// Parses a table with a header row, a delimiter row `|---|:-:|` and the
+/// following rows up to a blank line or the begin of another block
+fn table(data: &mut impl ParserData) -> Option<Block> {
+    log!(d, data, "table", "begin");
+
+    let loc_begin = data.loc();
+    let mut data = Transaction::new(data);
+
+    let (header_cells, header_end, header_pipe) = table_row(&mut data);
+    if !data.skip_newline() {
+        log!(d, data, "table", "end without match");
+        return None;
+    }
+
+    let alignments = match table_delimiter(&mut data) {
+        Some((al, delimiter_pipe)) if al.len() == header_cells.len()
+            && (header_pipe || delimiter_pipe) => al,
+
+        _ => {
+            log!(d, data, "table", "end without match");
+            return None;
+        }
+    };
+
+    let header = table_cells(&mut data, &header_cells, alignments.len());
+    data.rewind(header_end);
+    data.skip_newline();
+    data.skip_all(|c| !NL_CR.matches(c));
+    data.skip_newline();
+
+    let mut rows = Vec::new();
+    loop {
+        let pos = data.pos();
+        data.skip_all(LINE_WS);
+        let end = data.peek().is_none() || data.looking_at(NL_CR) || table_interrupted(&mut data);
+        data.rewind(pos);
+
+        if end {
+            break;
+        }
+
+        let (cells, line_end, _) = table_row(&mut data);
+        rows.push(table_cells(&mut data, &cells, alignments.len()));
+        data.rewind(line_end);
+        data.skip_newline();
+    }
+
+    let loc = data.loc_end(loc_begin);
+    log!(d, data, "table", "end");
+    data.commit();
+
+    Some(Block::Table(alignments, header, rows, loc))
+}
+
+/// Splits a row of a table into its cells and returns their begin and end,
+/// the end of the line and whether the row contains a pipe
+///
+/// Whitespace around the content isn't part of a cell and a pipe escaped by a
+/// backslash doesn't separate cells.
+fn table_row(data: &mut impl ParserData) -> (Vec<(Position, Position)>, Position, bool) {
+    data.skip_all(LINE_WS);
+    let mut pipe = data.skip('|');
+    let mut cells = Vec::new();
+
+    loop {
+        data.skip_all(LINE_WS);
+        let begin = data.pos();
+        let mut end = begin;
+
+        while let Some(c) = data.peek().filter(|&c| c != '|' && !NL_CR.matches(c)) {
+            data.advance();
+
+            if c == '\\' && data.peek().is_some_and(|c| !NL_CR.matches(c)) {
+                data.advance();
+            }
+
+            if !LINE_WS.matches(c) {
+                end = data.pos();
+            }
+        }
+
+        if data.skip('|') {
+            pipe = true;
+            cells.push((begin, end));
+        } else {
+            // nothing after a trailing pipe
+            if begin != end {
+                cells.push((begin, end));
+            }
+
+            return (cells, data.pos(), pipe);
+        }
+    }
+}
+
+/// Parses the delimiter row `| --- | :-: |` of a table and returns the
+/// alignments of the columns and whether the row contains a pipe
+fn table_delimiter(data: &mut impl ParserData) -> Option<(Vec<Alignment>, bool)> {
+    data.skip_all(LINE_WS);
+    let mut pipe = data.skip('|');
+    let mut alignments = Vec::new();
+
+    loop {
+        data.skip_all(LINE_WS);
+        if data.peek().is_none() || data.looking_at(NL_CR) {
+            break;
+        }
+
+        let left = data.skip(':');
+        if data.skip_all('-') == 0 {
+            return None;
+        }
+        let right = data.skip(':');
+
+        alignments.push(match (left, right) {
+            (false, false) => Alignment::None,
+            (true, false) => Alignment::Left,
+            (true, true) => Alignment::Center,
+            (false, true) => Alignment::Right,
+        });
+
+        data.skip_all(LINE_WS);
+        if data.skip('|') {
+            pipe = true;
+        } else if !(data.peek().is_none() || data.looking_at(NL_CR)) {
+            return None;
+        }
+    }
+
+    if alignments.is_empty() {
+        None
+    } else {
+        Some((alignments, pipe))
+    }
+}
+
+/// Parses the content of the cells of a row; missing cells are empty and
+/// excess cells get dropped
+fn table_cells(
+    data: &mut impl ParserData, cells: &[(Position, Position)], count: usize
+) -> TableRow {
+    let mut row = cells.iter()
+        .take(count)
+        .map(|&(begin, end)| table_cell(data, begin, end))
+        .collect::<TableRow>();
+
+    row.resize_with(count, Vec::new);
+    row
+}
+
+fn table_cell(data: &mut impl ParserData, begin: Position, end: Position) -> Vec<Inline> {
+    if begin == end {
+        return Vec::new();
+    }
+
+    data.rewind(begin);
+    let mut data = BoundedData { inner: data, end };
+
+    let mut ct = match paragraph(&mut data, false) {
+        Block::Paragraph(ct, ..) => ct,
+
+        // no link definitions inside of a table
+        _ => {
+            data.rewind(begin);
+            let loc_begin = data.loc();
+
+            let mut buf = String::new();
+            data.copy_all(&mut buf, |_| true);
+            vec![Inline::Text(buf, data.loc_end(loc_begin))]
+        }
+    };
+
+    unescape_pipes(&mut ct);
+    ct
+}
+
+/// Removes the backslash of escaped pipes in code spans of a table cell
+///
+/// The pipes must be escaped to not separate the cells, but backslashes are
+/// kept in code spans.
+fn unescape_pipes(list: &mut [Inline]) {
+    for el in list {
+        match el {
+            Inline::Code(code, _) => *code = code.replace("\\|", "|"),
+
+            Inline::Emph(ct, _) | Inline::Strong(ct, _)
+                | Inline::Del(ct, _) | Inline::Sup(ct, _) | Inline::Sub(ct, _)
+                | Inline::Mark(ct, _) | Inline::Ins(ct, _)
+                | Inline::Image(ct, ..) | Inline::ImageRef(ct, ..)
+                | Inline::Link(ct, ..) | Inline::LinkRef(ct, ..)
+                => unescape_pipes(ct),
+
+            Inline::Incomplete(el) => unescape_pipes(std::slice::from_mut(&mut **el)),
+
+            _ => (),
+        }
+    }
+}
+
+/// Checks whether the line starts a block that ends a table
+fn table_interrupted(data: &mut impl ParserData) -> bool {
+    let mut data = Transaction::new(data);
+
+    match data.peek() {
+        Some('>') => true,
+        Some('#') => heading(&mut data).is_some(),
+        Some('`' | '~') => code_fenced(&mut data, 0).is_some(),
+        Some('*' | '-' | '_') => thematic_break(&mut data),
+        _ => false,
+    }
 }
 
 fn thematic_break(data: &mut impl ParserData) -> bool {
@@ -1304,7 +2881,8 @@ fn thematic_break(data: &mut impl ParserData) -> bool {
     }
 }
 
-fn unordered_list(data: &mut impl ParserData) -> Option<Vec<Vec<Block>>> {
+/// Parses an unordered list; *indent* are the columns in front of the marker
+fn unordered_list(data: &mut impl ParserData, indent: usize) -> Option<Block> {
     log!(d, data, "unordered list", "begin");
 
     let marker = match data.peek() {
@@ -1313,13 +2891,17 @@ fn unordered_list(data: &mut impl ParserData) -> Option<Vec<Vec<Block>>> {
     };
 
     let pos = data.pos();
+    let line_column = data.column() - indent;
+    let loc_begin = data.loc();
     data.advance();
 
-    if let Some(list) = list_items(data, 0, |d| d.skip(marker)) {
+    if let Some((list, tight)) = list_items(
+        data, 0, line_column, loc_begin, |d| d.skip(marker)
+    ) {
         log!(d, data, "unordered list", "end");
-        Some(list)
+        Some(Block::UnorderedList(marker, tight, list, data.loc_end(loc_begin)))
     } else {
-        data.reset(pos).unwrap();
+        data.rewind(pos);
         None
     }
 }
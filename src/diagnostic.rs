@@ -0,0 +1,68 @@
+use std::fmt::{self, Display};
+
+use super::{Location, ParseError};
+
+/// How serious a [`Diagnostic`] is
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The input is broken and the result is probably not what was intended
+    Error,
+    /// The input is valid, but might not do what was intended
+    Warning,
+    Info,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        })
+    }
+}
+
+/// Problem found in the input while parsing
+///
+/// The parser reports them with [`ParserData::report`](super::ParserData::report)
+/// and a [`Document`](super::Document) keeps them in
+/// [`Document::diagnostics`](super::Document::diagnostics).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Short identifier of the kind of problem like `trailing-space-break`
+    pub code: &'static str,
+    pub message: String,
+    pub location: Location,
+    /// The error of [`Document::try_parse`](super::Document::try_parse) that
+    /// the diagnostic reports
+    pub error: Option<ParseError>,
+}
+
+impl Diagnostic {
+    #[must_use]
+    pub fn new(
+        severity: Severity, code: &'static str, message: impl Into<String>,
+        location: Location,
+    ) -> Self {
+        Self { severity, code, message: message.into(), location, error: None }
+    }
+
+    #[must_use]
+    pub fn error(code: &'static str, message: impl Into<String>, location: Location) -> Self {
+        Self::new(Severity::Error, code, message, location)
+    }
+
+    #[must_use]
+    pub fn warning(code: &'static str, message: impl Into<String>, location: Location) -> Self {
+        Self::new(Severity::Warning, code, message, location)
+    }
+}
+
+/// Prints the diagnostic like `warning[code]: message`; add the location with
+/// a [`LineIndex`](super::LineIndex)
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.message)
+    }
+}
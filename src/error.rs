@@ -0,0 +1,67 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::{Diagnostic, Location};
+
+/// Error of [`Document::try_parse`](super::Document::try_parse) and
+/// [`try_body`](super::try_body)
+///
+/// The infallible functions report the same problems as
+/// [`Diagnostic`]s and keep the text as it is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// An embedded block like `@if x {` that no line `}` closes
+    UnclosedEmbedded(Location),
+    /// A line `}` without an open embedded block
+    StrayClose(Location),
+    /// A line `} else {` without an open embedded block
+    StrayElse(Location),
+}
+
+impl ParseError {
+    #[must_use]
+    pub fn location(&self) -> &Location {
+        match self {
+            ParseError::UnclosedEmbedded(loc)
+                | ParseError::StrayClose(loc)
+                | ParseError::StrayElse(loc) => loc,
+        }
+    }
+
+    /// Code of the [`Diagnostic`] that reports the error
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseError::UnclosedEmbedded(_) => "unclosed-embedded",
+            ParseError::StrayClose(_) => "stray-embedded-close",
+            ParseError::StrayElse(_) => "stray-embedded-else",
+        }
+    }
+
+    /// Returns the error a diagnostic reports
+    #[must_use]
+    pub fn from_diagnostic(diag: &Diagnostic) -> Option<Self> {
+        diag.error.clone()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ParseError::UnclosedEmbedded(_) => "embedded block isn't closed",
+            ParseError::StrayClose(_) => "`}` without an open embedded block",
+            ParseError::StrayElse(_) => "`} else {` without an open embedded block",
+        })
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for Diagnostic {
+    fn from(err: ParseError) -> Self {
+        let diag = Diagnostic::error(err.code(), err.to_string(), err.location().clone());
+        Diagnostic { error: Some(err), ..diag }
+    }
+}
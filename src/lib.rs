@@ -5,17 +5,38 @@ use std::{
     fmt::{self, Display},
     fs,
     io,
+    num::ParseIntError,
     ops::{Add, Deref, Sub},
     path::Path,
     str::FromStr,
 };
 
+mod attributes;
+pub use attributes::{attributes, Attributes};
+
 mod block;
 pub use block::block;
 
+mod diagnostic;
+pub use diagnostic::{Diagnostic, Severity};
+
+mod error;
+pub use error::ParseError;
+
+mod line_index;
+#[cfg(feature = "location")]
+pub use line_index::DisplayLocation;
+pub use line_index::{LineCol, LineIndex};
+
 mod paragraph;
 pub use paragraph::paragraph;
 
+mod refs;
+pub use refs::normalize_label;
+
+mod smart;
+pub use smart::{smart_punctuation, Quotes};
+
 mod string;
 pub use string::StringData;
 
@@ -24,6 +45,9 @@ mod tests;
 
 pub mod test_utils;
 
+mod toc;
+pub use toc::{inline_text, slugify, TocEntry};
+
 mod tools;
 pub use tools::{
     Tools,
@@ -33,6 +57,25 @@ pub use tools::{
 mod trans;
 pub use trans::Transaction;
 
+mod visit;
+pub use visit::{
+    Fold,
+    Visit,
+    VisitMut,
+    fold_block_content,
+    fold_blocks,
+    fold_inline_content,
+    fold_inlines,
+    walk_block,
+    walk_block_mut,
+    walk_blocks,
+    walk_blocks_mut,
+    walk_inline,
+    walk_inline_mut,
+    walk_inlines,
+    walk_inlines_mut,
+};
+
 #[cfg(feature = "log")]
 #[macro_export]
 macro_rules! log {
@@ -73,9 +116,14 @@ macro_rules! log {
 pub static LINE_WS : [char; 2] = [' ', '\t'];
 /// New line `\n`, Carriage return `\r`
 pub static NL_CR : [char; 2] = ['\n', '\r'];
+/// Width of a tab when counting the indentation of blocks
+pub const TAB_STOP : usize = 4;
 
 pub type Position = usize;
 
+/// Types of admonitions recognized by default like those of GitHub
+pub static ADMONITION_TYPES : [&str; 5] = ["note", "tip", "important", "warning", "caution"];
+
 mod ps_help {
     #![allow(non_upper_case_globals)]
     use bitflags::bitflags;
@@ -87,18 +135,97 @@ mod ps_help {
             const Embedded = 1 << 0;
             /// Parse HTML, otherwise it's plain text
             const Html = 1 << 1;
-            // /// Deletions ~abc~
-            // const Del = 1 << 4;
-            // /// Tables |…|…|
-            // const Tables = 1 << 6;
-            // /// Allow incomplete input at end
-            // ///
-            // /// `text *text` gets parsed as `Plain(text ) Emph(Plain(text))`
-            // /// and `<a href="` get valid `Html`. This makes it easier for syntax
-            // /// highlighting in editors.
-            // const IncompleteEnd = 1 << 7,
-            // /// Checkboxes * [X], Input [___]
-            // const Checkboxes = 1 << 8,
+            /// Definition lists `term` followed by `: definition`
+            const DefinitionLists = 1 << 2;
+            /// Attribute blocks `{#id .class key=value}` at the end of
+            /// headings, after paragraphs, links and images and in the info
+            /// string of fenced code blocks
+            const Attributes = 1 << 3;
+            /// Bare URLs `https://…`, `www.…` and email addresses become links
+            /// like the [extended
+            /// autolinks](https://github.github.com/gfm/#autolinks-extension-)
+            /// of GFM
+            const ExtendedAutolinks = 1 << 5;
+            /// Quotes starting with `[!NOTE]`, `[!WARNING]` etc. become
+            /// admonitions; see [`ParserData::admonition_types`]
+            const Admonitions = 1 << 9;
+            /// Fenced containers `::: name` … `:::`
+            const Containers = 1 << 10;
+            /// Two or more spaces at the end of a line create a
+            /// [`Inline::HardBreak`]
+            const TrailingSpaceBreaks = 1 << 11;
+            /// Warn about two or more spaces at the end of a line, because
+            /// they are invisible, but might create a hard line break
+            const TrailingSpaceLint = 1 << 12;
+            /// Ordered lists with any start number interrupt a paragraph;
+            /// by default only those starting with 1
+            ///
+            /// Don't combine it with [`ParserSettings::ListInterruptNever`],
+            /// which wins; debug builds panic on both.
+            const ListInterruptAnyNumber = 1 << 13;
+            /// Ordered lists never interrupt a paragraph; this wins over
+            /// [`ParserSettings::ListInterruptAnyNumber`]
+            const ListInterruptNever = 1 << 14;
+            /// Shortcodes like `:tada:` become the Unicode emoji; unknown
+            /// shortcodes are kept as text
+            const Emoji = 1 << 15;
+            /// Deletions `~~abc~~`
+            const Del = 1 << 4;
+            /// Superscripts `^abc^`
+            const Sup = 1 << 16;
+            /// Subscripts `~abc~`; deletions use two tildes, see
+            /// [`ParserSettings::Del`]
+            const Sub = 1 << 17;
+            /// Highlighted text `==abc==`
+            const Mark = 1 << 18;
+            /// Insertions `++abc++`
+            const Ins = 1 << 19;
+            /// Paragraphs underlined with `===` or `---` become headings
+            const SetextHeadings = 1 << 20;
+            /// Lines indented by four or more columns become code blocks
+            const IndentedCode = 1 << 21;
+            /// A sequence of `#` at the end of an ATX heading isn't part of
+            /// the content
+            const AtxClosingSequence = 1 << 22;
+            /// Lines of a list item must be indented up to the column of its
+            /// content like in CommonMark; by default two columns (three for
+            /// ordered lists) are enough
+            const ListContentIndent = 1 << 23;
+            /// Tables `| a | b |` with a delimiter row `|---|:-:|` after the
+            /// header like the [tables](https://github.github.com/gfm/#tables-extension-)
+            /// of GFM
+            const Tables = 1 << 6;
+            /// Items of lists starting with `[ ]` or `[x]` get a
+            /// [`Inline::Checkbox`] like the [task list
+            /// items](https://github.github.com/gfm/#task-list-items-extension-)
+            /// of GFM
+            const Checkboxes = 1 << 8;
+            /// Escapes the `<` of raw HTML for the tags `title`, `textarea`,
+            /// `style`, `xmp`, `iframe`, `noembed`, `noframes`, `script` and
+            /// `plaintext` like the [disallowed raw
+            /// HTML](https://github.github.com/gfm/#disallowed-raw-html-extension-)
+            /// of GFM
+            const TagFilter = 1 << 24;
+            /// Allow incomplete input at the end of a paragraph
+            ///
+            /// `text *text` gets parsed as `Plain(text ) Emph(Plain(text))`
+            /// and `<a href="` gets valid `Html`; both are wrapped in
+            /// [`Inline::Incomplete`]. The same applies to links, images,
+            /// code spans and embedded code `@(…`. This makes it easier for
+            /// syntax highlighting in editors.
+            const IncompleteEnd = 1 << 7;
+            /// Lines that continue a paragraph inside a quote or list item
+            /// don't need the `>` or the indentation like the [lazy
+            /// continuation lines](https://spec.commonmark.org/0.29/#lazy-continuation-line)
+            /// of CommonMark; any other line ends the quote or list item
+            ///
+            /// By default a quote takes every following line up to a blank
+            /// line or a block that interrupts a paragraph, and a list item
+            /// none without the indentation.
+            const LazyContinuation = 1 << 25;
+            /// HTML blocks interrupt a paragraph like in CommonMark; by
+            /// default a line starting with a tag continues the paragraph
+            const HtmlInterrupt = 1 << 26;
         }
     }
 
@@ -114,6 +241,38 @@ mod ps_help {
         pub fn common_mark() -> Self {
             ParserSettings::Html
         }
+
+        /// Settings that follow the CommonMark spec in every respect
+        ///
+        /// In contrast to [`common_mark`](Self::common_mark), which keeps the
+        /// deviations of actmd, this enables the standard behaviour.
+        #[must_use]
+        pub fn strict_common_mark() -> Self {
+            ParserSettings::Html
+                | ParserSettings::TrailingSpaceBreaks
+                | ParserSettings::SetextHeadings
+                | ParserSettings::IndentedCode
+                | ParserSettings::AtxClosingSequence
+                | ParserSettings::ListContentIndent
+                | ParserSettings::LazyContinuation
+                | ParserSettings::HtmlInterrupt
+        }
+
+        /// Settings for GitHub Flavored Markdown
+        ///
+        /// This is [`strict_common_mark`](Self::strict_common_mark) with
+        /// tables, deletions `~~…~~`, task list items, extended autolinks and
+        /// the filter of disallowed raw HTML. Deletions with a single tilde
+        /// aren't supported, because they are subscripts in actmd.
+        #[must_use]
+        pub fn gfm() -> Self {
+            Self::strict_common_mark()
+                | ParserSettings::Tables
+                | ParserSettings::Del
+                | ParserSettings::Checkboxes
+                | ParserSettings::ExtendedAutolinks
+                | ParserSettings::TagFilter
+        }
     }
 }
 
@@ -122,10 +281,56 @@ pub use ps_help::ParserSettings;
 pub trait ParserData {
     fn settings(&self) -> ParserSettings;
 
+    /// Types of admonitions `> [!TYPE]` in lowercase
+    fn admonition_types(&self) -> &[&str] {
+        &ADMONITION_TYPES
+    }
+
     fn peek(&self) -> Option<char>;
     fn advance(&mut self);
     fn pos(&self) -> Position;
     fn reset(&mut self, pos: Position) -> Result<(), ()>;
+
+    /// Position where the content ends
+    ///
+    /// Data that stops at the end of a line consumes the line ending; it
+    /// returns the position before it.
+    fn content_end(&self) -> Position {
+        self.pos()
+    }
+
+    /// Column of the current position in its line with tabs expanded to the
+    /// next multiple of [`TAB_STOP`]
+    ///
+    /// Data that can't tell the column returns 0; then every tab at the
+    /// start of a line is counted as a full tab stop.
+    fn column(&self) -> usize {
+        0
+    }
+
+    /// Tells the enclosing quotes and list items whether a paragraph is open
+    /// that can take lazy continuation lines
+    ///
+    /// See [`ParserSettings::LazyContinuation`].
+    fn set_paragraph_open(&mut self, _open: bool) {}
+
+    /// Whether the current line is a lazy continuation line of an enclosing
+    /// quote or list item
+    fn lazy_line(&self) -> bool {
+        false
+    }
+
+    /// Records a problem found in the input
+    ///
+    /// Data that goes back with [`reset`](Self::reset) drops the problems it
+    /// recorded after the new position, because the parser will find them
+    /// again. Data that can't keep them ignores them.
+    fn report(&mut self, _diag: Diagnostic) {}
+
+    /// Removes and returns the recorded problems
+    fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        Vec::new()
+    }
 }
 
 impl ParserData for &mut dyn ParserData {
@@ -133,6 +338,10 @@ impl ParserData for &mut dyn ParserData {
         (**self).settings()
     }
 
+    fn admonition_types(&self) -> &[&str] {
+        (**self).admonition_types()
+    }
+
     fn peek(&self) -> Option<char> {
         (**self).peek()
     }
@@ -148,6 +357,30 @@ impl ParserData for &mut dyn ParserData {
     fn reset(&mut self, pos: Position) -> Result<(), ()> {
         (**self).reset(pos)
     }
+
+    fn content_end(&self) -> Position {
+        (**self).content_end()
+    }
+
+    fn column(&self) -> usize {
+        (**self).column()
+    }
+
+    fn set_paragraph_open(&mut self, open: bool) {
+        (**self).set_paragraph_open(open);
+    }
+
+    fn lazy_line(&self) -> bool {
+        (**self).lazy_line()
+    }
+
+    fn report(&mut self, diag: Diagnostic) {
+        (**self).report(diag);
+    }
+
+    fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        (**self).take_diagnostics()
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -240,6 +473,24 @@ pub struct Location {
     pub end: LocationPosition,
 }
 
+/// Locations of the parts of a link, image or link definition
+///
+/// A part that is missing, like the title in `[a](b)`, has an empty location
+/// where it would start. The locations exclude the brackets, the angle
+/// brackets around the URL and the delimiters of the title.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "location"), derive(Default))]
+pub struct LinkParts {
+    /// Text of a link, description of an image or label of a definition;
+    /// for a reference the label, e.g. `b` in `[a][b]`, or the text for
+    /// `[a][]` and `[a]`
+    pub label: Location,
+    /// URL of a link, image or definition; empty at the end of a reference
+    pub url: Location,
+    /// Title of a link, image or definition; empty at the end of a reference
+    pub title: Location,
+}
+
 #[cfg(feature = "location")]
 impl Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -249,6 +500,7 @@ impl Display for Location {
 
 trait LocationHelper {
     fn loc(&self) -> LocationPosition;
+    fn loc_content_end(&self) -> LocationPosition;
     fn loc_end(&self, loc_begin: LocationPosition) -> Location;
 }
 
@@ -257,6 +509,10 @@ impl<T: ParserData> LocationHelper for T {
         LocationPosition::from(self)
     }
 
+    fn loc_content_end(&self) -> LocationPosition {
+        self.content_end().into()
+    }
+
     fn loc_end(&self, begin: LocationPosition) -> Location {
         Location {
             begin,
@@ -268,36 +524,97 @@ impl<T: ParserData> LocationHelper for T {
 /// Top level elements of a document
 #[derive(Debug, PartialEq)]
 pub enum Block {
-    /// Headline `# ...` (`level, content, location`)
-    Heading(u8, Vec<Inline>, Location),
+    /// Headline `# ...` (`level, content, attributes, location`)
+    ///
+    /// With [`ParserSettings::Attributes`] a trailing `{#id .class key=value}`
+    /// gets removed from the content and stored as attributes. In HTML the
+    /// attributes get rendered on the `<h1>`…`<h6>` tag.
+    Heading(u8, Vec<Inline>, Attributes, Location),
 
-    /// Paragraph (`content, location`)
-    Paragraph(Vec<Inline>, Location),
+    /// Paragraph (`content, attributes, location`)
+    ///
+    /// With [`ParserSettings::Attributes`] a line `{#id .class key=value}`
+    /// directly after the paragraph sets the attributes.
+    Paragraph(Vec<Inline>, Attributes, Location),
 
     /// Quotation `> ...` (`content, location`)
     Quote(Vec<Block>, Location),
 
-    /// Fenced code block ```` ```info... ```` (`info, content, location`)
-    Code(String, String, Location),
+    /// Quotation starting with `[!TYPE]` (`type, title, content, location`)
+    ///
+    /// Only with [`ParserSettings::Admonitions`] and for the types of
+    /// [`ParserData::admonition_types`]. The type is in lowercase and the
+    /// title is the rest of the first line, e.g. `> [!TIP] Read this`, or
+    /// empty. In HTML rendered as `<div class="admonition tip">` with the
+    /// title or the capitalized type as first paragraph.
+    Admonition(String, Vec<Inline>, Vec<Block>, Location),
+
+    /// Fenced code block ```` ```info... ```` (`info, content, attributes,
+    /// location, info location`)
+    ///
+    /// With [`ParserSettings::Attributes`] a trailing `{#id .class key=value}`
+    /// gets removed from the info string and stored as attributes. The info
+    /// location covers the trimmed info string without the attributes and is
+    /// empty after the fence, if there is none.
+    Code(String, String, Attributes, Location, Location),
+
+    /// Ordered list `1. ...` (`start, tight, items, location`)
+    ///
+    /// A list is tight, if neither its items nor the blocks of an item are
+    /// separated by blank lines. In HTML the paragraphs of the items of a
+    /// tight list get rendered without `<p>`.
+    OrderedList(ListStart, bool, Vec<ListItem>, Location),
 
-    /// Ordered list `1. ...` (`start, content`)
-    OrderedList(String, Vec<Vec<Block>>),
+    /// Unordered list `* ...` (`bullet, tight, items, location`)
+    ///
+    /// The bullet is `*`, `-` or `+`. See [`Block::OrderedList`] for tight
+    /// lists.
+    UnorderedList(char, bool, Vec<ListItem>, Location),
 
-    /// Unordered list `* ...` (`content`)
-    UnorderedList(Vec<Vec<Block>>),
+    /// Definition list `term` followed by `: ...` (`(term, definitions)...,
+    /// location`)
+    ///
+    /// Each term has one or more definitions and each definition can contain
+    /// several blocks, if its lines are indented like those of a list item. In
+    /// HTML rendered as `<dl>` with `<dt>` for the terms and `<dd>` for the
+    /// definitions.
+    DefinitionList(Vec<Definition>, Location),
+
+    /// Fenced container `::: name {attributes}` … `:::`
+    ///
+    /// Only with [`ParserSettings::Containers`]. The content ends at the next
+    /// line of at least as many colons as the opening fence, which allows
+    /// nesting of containers. The name can be empty, if the opening fence
+    /// has attributes. How a container gets rendered is up to the output
+    /// generator, e.g. as `<div class="name">`.
+    Container {
+        name: String,
+        attributes: Attributes,
+        content: Vec<Block>,
+        location: Location,
+    },
+
+    /// Table with a header row (`alignments, header, rows, location`)
+    ///
+    /// Only with [`ParserSettings::Tables`]. Every row has as many cells as
+    /// there are alignments; missing cells are empty and excess cells get
+    /// dropped. In HTML rendered as `<table>` with `<thead>` and `<tbody>`.
+    Table(Vec<Alignment>, TableRow, Vec<TableRow>, Location),
 
     /// HTML section (`content, location`)
+    ///
+    /// With [`ParserSettings::TagFilter`] disallowed tags are escaped.
     Html(String, Location),
 
     /// Thematic break `* * *`
     ///
     /// In HTML rendered as `<hr/>`
-    Break,
+    Break(Location),
 
-    /// Link definition (`label, url, title, location`)
+    /// Link definition (`label, url, title, location, parts`)
     ///
     /// Used to resolve [`Inline::ImageRef`] and [`Inline::LinkRef`]
-    LinkDef(String, String, String, Location),
+    LinkDef(String, String, String, Location, LinkParts),
 
     /// Embedded code block `@{...}`
     ///
@@ -322,47 +639,82 @@ pub enum Inline {
     /// Code segment `` `...` `` in a paragraph
     Code(String, Location),
 
+    /// Checkbox `[ ]` or `[x]` at the start of a list item (`checked,
+    /// location`)
+    ///
+    /// Only with [`ParserSettings::Checkboxes`]. In HTML rendered as `<input
+    /// type="checkbox" disabled="">`.
+    Checkbox(bool, Location),
+
     /// Common break between lines
     ///
-    /// This should not influence the rendering. See also [HardBreak]
-    SoftBreak,
+    /// This should not influence the rendering. See also [HardBreak]. The
+    /// location covers the line ending.
+    SoftBreak(Location),
 
     /// A special marked linebreak `...\`
     ///
-    /// This should create an visual linebreak in the output.
-    HardBreak,
+    /// This should create an visual linebreak in the output. The location
+    /// covers the backslash or the trailing spaces and the line ending.
+    HardBreak(Location),
 
     /// Emphasized section
-    Emph(Vec<Inline>),
+    Emph(Vec<Inline>, Location),
 
     /// Stronger emphasized section
-    Strong(Vec<Inline>),
+    Strong(Vec<Inline>, Location),
 
-    /// `Image(description/alt text, src url, title)`
-    ///
-    /// * `![1](2 "3")` => `Image(1, 2, 3)`
-    /// * `![1](2)` => `Image(1, 2, "")`
-    Image(Vec<Inline>, String, String, Location),
+    /// Deleted section `~~…~~` (with [`ParserSettings::Del`])
+    Del(Vec<Inline>, Location),
+
+    /// Superscript `^…^` (with [`ParserSettings::Sup`])
+    Sup(Vec<Inline>, Location),
+
+    /// Subscript `~…~` (with [`ParserSettings::Sub`])
+    Sub(Vec<Inline>, Location),
+
+    /// Highlighted section `==…==` (with [`ParserSettings::Mark`])
+    Mark(Vec<Inline>, Location),
 
-    /// `ImageRef(description, label)` (must be resolved with Block::LinkDef)
+    /// Inserted section `++…++` (with [`ParserSettings::Ins`])
+    Ins(Vec<Inline>, Location),
+
+    /// `Image(description/alt text, src url, title, attributes, location,
+    /// parts)`
+    ///
+    /// Like in CommonMark the description can contain links and images. Use
+    /// [`Inline::alt_text`] to get the plain text for the `alt` attribute.
+    ///
+    /// * `![1](2 "3")` => `Image(1, 2, 3, {})`
+    /// * `![1](2)` => `Image(1, 2, "", {})`
+    /// * `![1](2){width=3}` => `Image(1, 2, "", {width=3})` (with
+    ///   [`ParserSettings::Attributes`])
+    Image(Vec<Inline>, String, String, Attributes, Location, LinkParts),
+
+    /// `ImageRef(description, label, location, parts)` (must be resolved with
+    /// Block::LinkDef, see [`Document::resolve_references`])
     ///
     /// * `![1][2]` => `ImageRef(1, 2)`
-    /// * `![1][]` => `ImageRef(1, "")`
-    /// * `![1]` => `ImageRef(1, "")`
-    ImageRef(Vec<Inline>, String, Location),
+    /// * `![1][]` => `ImageRef(1, "1")`
+    /// * `![1]` => `ImageRef(1, "1")`
+    ImageRef(Vec<Inline>, String, Location, LinkParts),
 
-    /// `Link(link text, url, title)`
+    /// `Link(link text, url, title, attributes, location, parts)`
     ///
-    /// * `[1](2 "3")` => `Link(1, 2, 3)`
-    /// * `[1](2)` => `Link(1, 2, "")`
-    Link(Vec<Inline>, String, String, Location),
-
-    /// `LinkRef(text, label)` (must be resolved with Block::LinkDef)
+    /// * `[1](2 "3")` => `Link(1, 2, 3, {})`
+    /// * `[1](2)` => `Link(1, 2, "", {})`
+    /// * `[1](2){.3}` => `Link(1, 2, "", {.3})` (with
+    ///   [`ParserSettings::Attributes`])
+    Link(Vec<Inline>, String, String, Attributes, Location, LinkParts),
+
+    /// `LinkRef(text, label, location, parts)` (must be resolved with
+    /// Block::LinkDef, see [`Document::resolve_references`])
     ///
     /// * `[1][2]` => `LinkRef(1, 2)`
-    /// * `[1][]` => `LinkRef(1, "")`
-    /// * `[1]` => `LinkRef(1, "")`
-    LinkRef(Vec<Inline>, String, Location),
+    /// * `[1][]` => `LinkRef(1, "1")`
+    /// * `[1]` => `LinkRef(1, "1")`
+    /// * `[*1*]` => `LinkRef(Emph(1), "*1*")`; the label is the raw text
+    LinkRef(Vec<Inline>, String, Location, LinkParts),
 
     /// Embedded code block `@{...}`
     ///
@@ -374,6 +726,99 @@ pub enum Inline {
     ///
     /// The expression returns a value that should be included in the output.
     EmbeddedExpr(String, Location),
+
+    /// Element that isn't closed at the end of a paragraph, e.g. `*text` =>
+    /// `Incomplete(Emph(text))`
+    ///
+    /// Only with [`ParserSettings::IncompleteEnd`]
+    Incomplete(Box<Inline>),
+}
+
+impl Inline {
+    /// Flattens the description of an image to a plain string, e.g. for the
+    /// `alt` attribute
+    ///
+    /// Returns `None`, if this is neither [`Inline::Image`] nor
+    /// [`Inline::ImageRef`]. See [`inline_text`] for the flattening.
+    #[must_use]
+    pub fn alt_text(&self) -> Option<String> {
+        match self {
+            Inline::Image(desc, ..) | Inline::ImageRef(desc, ..) => Some(inline_text(desc)),
+            _ => None,
+        }
+    }
+}
+
+/// Term of a [`Block::DefinitionList`] with its definitions
+pub type Definition = (Vec<Inline>, Vec<Vec<Block>>);
+
+/// Blocks of an item of a [`Block::OrderedList`] or [`Block::UnorderedList`]
+/// with the location from the list marker to the end of the item
+pub type ListItem = (Vec<Block>, Location);
+
+/// Cells of a row of a [`Block::Table`]
+pub type TableRow = Vec<Vec<Inline>>;
+
+/// Alignment of a column of a [`Block::Table`] given by the colons in the
+/// delimiter row
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    /// `---`
+    None,
+    /// `:--`
+    Left,
+    /// `:-:`
+    Center,
+    /// `--:`
+    Right,
+}
+
+/// Marker of the first item of a [`Block::OrderedList`], e.g. `007)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ListStart {
+    /// Number of the first item
+    pub number: u64,
+
+    /// Count of digits including leading zeros, e.g. 3 for `007`
+    pub width: u8,
+
+    /// Delimiter `.` or `)` after the number
+    pub delimiter: char,
+}
+
+impl Default for ListStart {
+    fn default() -> Self {
+        Self {
+            number: 1,
+            width: 1,
+            delimiter: '.',
+        }
+    }
+}
+
+impl Display for ListStart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:0width$}{}", self.number, self.delimiter, width = usize::from(self.width))
+    }
+}
+
+impl FromStr for ListStart {
+    type Err = ParseIntError;
+
+    /// Parses a marker like `3`, `003.` or `3)`; the delimiter defaults to
+    /// `.`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, delimiter) = match s.chars().next_back() {
+            Some(c @ ('.' | ')')) => (&s[..s.len() - 1], c),
+            _ => (s, '.'),
+        };
+
+        Ok(Self {
+            number: digits.parse()?,
+            width: u8::try_from(digits.len()).unwrap_or(u8::MAX),
+            delimiter,
+        })
+    }
 }
 
 /// String with all ASCII characters in lowercase
@@ -423,6 +868,7 @@ pub struct Document {
     src: Box<str>,
     head: Head,
     body: Vec<Block>,
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl Document {
@@ -432,9 +878,26 @@ impl Document {
             src: src.into(),
             head: head(data),
             body: body(data),
+            diagnostics: data.take_diagnostics(),
         }
     }
 
+    /// Parses a document like [`parse`](Self::parse), but fails on broken
+    /// embedded code; see [`try_body`]
+    pub fn try_parse(
+        src: impl Into<Box<str>>, data: &mut impl ParserData
+    ) -> Result<Self, ParseError> {
+        let head = head(data);
+        let body = try_body(data)?;
+
+        Ok(Self {
+            src: src.into(),
+            head,
+            body,
+            diagnostics: data.take_diagnostics(),
+        })
+    }
+
     pub fn src(&self) -> &str {
         &self.src
     }
@@ -466,6 +929,11 @@ impl Document {
     pub fn body_mut(&mut self) -> &mut Vec<Block> {
         &mut self.body
     }
+
+    /// Problems found while parsing the document
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
 }
 
 impl FromStr for Document {
@@ -549,20 +1017,43 @@ pub fn head_in(data: &mut impl ParserData, header: &mut Head) {
     }
 }
 
+/// Parses the blocks of a document
+///
+/// Problems like an unclosed embedded block get reported as [`Diagnostic`]s.
 pub fn body(data: &mut impl ParserData) -> Vec<Block> {
-    let mut open_embedded_codes = 0;
+    let mut open_embedded = Vec::new();
     let mut blocks = Vec::new();
 
     while data.peek().is_some() {
-        block::block(data, &mut blocks, &mut open_embedded_codes);
+        block::block_tracked(data, &mut blocks, &mut open_embedded);
     }
 
-    // TODO: verbessern
-    assert_eq!(0, open_embedded_codes);
+    block::report_unclosed(data, open_embedded);
 
     blocks
 }
 
+/// Parses the blocks of a document like [`body`], but fails on broken
+/// embedded code
+///
+/// On an error the data stays at the position where it was.
+pub fn try_body(data: &mut impl ParserData) -> Result<Vec<Block>, ParseError> {
+    let mut data = Transaction::new(data);
+    let blocks = body(&mut data);
+
+    let diags = data.take_diagnostics();
+    if let Some(err) = diags.iter().find_map(ParseError::from_diagnostic) {
+        return Err(err);
+    }
+
+    for diag in diags {
+        data.report(diag);
+    }
+
+    data.commit();
+    Ok(blocks)
+}
+
 const NAMED_HTML_ENTITY : &[(&str, &str)] = &include!("html_entities.rs");
 
 fn html_entity(data: &mut impl ParserData, buf: &mut String) {
@@ -677,3 +1168,33 @@ fn is_ascii_alphabetic(ch: char) -> bool {
 fn is_ascii_alphanumeric(ch: char) -> bool {
     char::is_ascii_alphanumeric(&ch)
 }
+
+// from https://github.github.com/gfm/#disallowed-raw-html-extension-
+const DISALLOWED_HTML_TAGS : &[&str] = &[
+    "iframe", "noembed", "noframes", "plaintext", "script", "style", "textarea",
+    "title", "xmp"
+];
+
+/// Escapes the `<` of opening and closing tags of [`DISALLOWED_HTML_TAGS`]
+/// for [`ParserSettings::TagFilter`]
+fn filter_tags(html: &mut String) {
+    let mut idx = 0;
+
+    while let Some(off) = html[idx..].find('<') {
+        idx += off;
+
+        let name_begin = idx + 1 + usize::from(html[idx + 1..].starts_with('/'));
+        let name_len = html[name_begin..].find(|c: char| !is_ascii_alphanumeric(c))
+            .unwrap_or(html.len() - name_begin);
+        let name = &html[name_begin..name_begin + name_len];
+        let after = html[name_begin + name_len..].chars().next();
+
+        if DISALLOWED_HTML_TAGS.iter().any(|t| t.eq_ignore_ascii_case(name))
+            && after.map_or(true, |c| c.is_ascii_whitespace() || c == '>' || c == '/')
+        {
+            html.replace_range(idx..=idx, "&lt;");
+        }
+
+        idx += 1;
+    }
+}
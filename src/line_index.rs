@@ -0,0 +1,271 @@
+use std::fmt::{self, Display};
+
+#[cfg(feature = "location")]
+use super::Location;
+use super::Position;
+
+/// Line and column of a [`Position`]
+///
+/// All values start at 0. `col` counts UTF-8 bytes and `col_utf16` UTF-16
+/// code units from the start of the line, as the Language Server Protocol
+/// expects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LineCol {
+    pub line: usize,
+    pub col: usize,
+    pub col_utf16: usize,
+}
+
+/// Prints the line and the column, starting at 1, like `3:7`
+impl Display for LineCol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line + 1, self.col + 1)
+    }
+}
+
+/// Converts the byte offsets of [`Position`] into lines and columns
+///
+/// The index must be built from the text that was parsed, not from
+/// [`Document::src`](super::Document::src), which only holds the name of the
+/// source. Lines end at `\r\n`, `\r` or `\n` like with
+/// [`Tools::skip_newline`](super::Tools::skip_newline).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineIndex {
+    text: Box<str>,
+    /// Positions where the lines start; the first is always 0
+    line_starts: Vec<Position>,
+}
+
+impl LineIndex {
+    #[must_use]
+    pub fn new(text: impl Into<Box<str>>) -> Self {
+        let text = text.into();
+        let bytes = text.as_bytes();
+        let mut line_starts = vec![0];
+
+        let mut idx = 0;
+        while idx < bytes.len() {
+            match bytes[idx] {
+                b'\r' if bytes.get(idx + 1) == Some(&b'\n') => {
+                    idx += 2;
+                    line_starts.push(idx);
+                }
+
+                b'\r' | b'\n' => {
+                    idx += 1;
+                    line_starts.push(idx);
+                }
+
+                _ => idx += 1,
+            }
+        }
+
+        Self { text, line_starts }
+    }
+
+    /// Number of lines; text that ends with a line ending has an empty last
+    /// line
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the text of *line* without the line ending
+    #[must_use]
+    pub fn line(&self, line: usize) -> Option<&str> {
+        let begin = *self.line_starts.get(line)?;
+        let end = self.line_starts.get(line + 1).copied()
+            .unwrap_or(self.text.len());
+
+        Some(self.text[begin..end].trim_end_matches(['\r', '\n']))
+    }
+
+    /// Returns the line and column of *pos*
+    ///
+    /// Positions within a line ending count as the end of the line. Returns
+    /// `None` if *pos* is after the end of the text or not at the boundary of
+    /// a character.
+    #[must_use]
+    pub fn line_col(&self, pos: Position) -> Option<LineCol> {
+        if !self.text.is_char_boundary(pos) {
+            return None;
+        }
+
+        let line = self.line_starts.partition_point(|&start| start <= pos) - 1;
+        let text = self.line(line)?;
+        let col = (pos - self.line_starts[line]).min(text.len());
+
+        Some(LineCol {
+            line,
+            col,
+            col_utf16: text[..col].encode_utf16().count(),
+        })
+    }
+
+    /// Returns the position of *col* UTF-8 bytes after the start of *line*
+    ///
+    /// Returns `None` if the line doesn't exist, the column is after the end
+    /// of the line or not at the boundary of a character.
+    #[must_use]
+    pub fn position(&self, line: usize, col: usize) -> Option<Position> {
+        let text = self.line(line)?;
+
+        if col > text.len() || !text.is_char_boundary(col) {
+            return None;
+        }
+
+        Some(self.line_starts[line] + col)
+    }
+
+    /// Returns the position of *col* UTF-16 code units after the start of
+    /// *line*
+    ///
+    /// Returns `None` if the line doesn't exist, the column is after the end
+    /// of the line or within a surrogate pair.
+    #[must_use]
+    pub fn position_utf16(&self, line: usize, col: usize) -> Option<Position> {
+        let text = self.line(line)?;
+        let mut units = 0;
+
+        for (idx, ch) in text.char_indices() {
+            if units == col {
+                return Some(self.line_starts[line] + idx);
+            }
+
+            units += ch.len_utf16();
+            if units > col {
+                return None;
+            }
+        }
+
+        (units == col).then(|| self.line_starts[line] + text.len())
+    }
+
+    /// Returns an object to print *loc* as lines and columns, like `1:3..2:5`
+    ///
+    /// Positions that are outside the text get printed as byte offsets.
+    #[cfg(feature = "location")]
+    #[must_use]
+    pub fn display<'a>(&'a self, loc: &'a Location) -> DisplayLocation<'a> {
+        DisplayLocation { index: self, loc }
+    }
+}
+
+/// Prints a [`Location`] with lines and columns; see [`LineIndex::display`]
+#[cfg(feature = "location")]
+pub struct DisplayLocation<'a> {
+    index: &'a LineIndex,
+    loc: &'a Location,
+}
+
+#[cfg(feature = "location")]
+impl Display for DisplayLocation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let pos = |p: Position| self.index.line_col(p)
+            .map_or_else(|| p.to_string(), |lc| lc.to_string());
+
+        write!(f, "{}..{}", pos(self.loc.begin.0), pos(self.loc.end.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lc(line: usize, col: usize, col_utf16: usize) -> Option<LineCol> {
+        Some(LineCol { line, col, col_utf16 })
+    }
+
+    #[test]
+    fn line_starts() {
+        let idx = LineIndex::new("a\nb\r\nc\rd");
+        assert_eq!(idx.line_count(), 4);
+        assert_eq!(idx.line(0), Some("a"));
+        assert_eq!(idx.line(1), Some("b"));
+        assert_eq!(idx.line(2), Some("c"));
+        assert_eq!(idx.line(3), Some("d"));
+        assert_eq!(idx.line(4), None);
+
+        let idx = LineIndex::new("a\n");
+        assert_eq!(idx.line_count(), 2);
+        assert_eq!(idx.line(1), Some(""));
+
+        let idx = LineIndex::new("");
+        assert_eq!(idx.line_count(), 1);
+        assert_eq!(idx.line(0), Some(""));
+    }
+
+    #[test]
+    fn line_col() {
+        let idx = LineIndex::new("ab\r\ncd\re");
+        assert_eq!(idx.line_col(0), lc(0, 0, 0));
+        assert_eq!(idx.line_col(2), lc(0, 2, 2));
+        assert_eq!(idx.line_col(3), lc(0, 2, 2));
+        assert_eq!(idx.line_col(4), lc(1, 0, 0));
+        assert_eq!(idx.line_col(6), lc(1, 2, 2));
+        assert_eq!(idx.line_col(7), lc(2, 0, 0));
+        assert_eq!(idx.line_col(8), lc(2, 1, 1));
+        assert_eq!(idx.line_col(9), None);
+    }
+
+    #[test]
+    fn line_col_unicode() {
+        // ä: 2 bytes, 1 unit; 😀: 4 bytes, 2 units
+        let idx = LineIndex::new("x\nä😀y");
+        assert_eq!(idx.line_col(4), lc(1, 2, 1));
+        assert_eq!(idx.line_col(5), None);
+        assert_eq!(idx.line_col(8), lc(1, 6, 3));
+        assert_eq!(idx.line_col(9), lc(1, 7, 4));
+    }
+
+    #[test]
+    fn position() {
+        let idx = LineIndex::new("x\r\nä😀y\n");
+        assert_eq!(idx.position(0, 0), Some(0));
+        assert_eq!(idx.position(0, 1), Some(1));
+        assert_eq!(idx.position(0, 2), None);
+        assert_eq!(idx.position(1, 2), Some(5));
+        assert_eq!(idx.position(1, 1), None);
+        assert_eq!(idx.position(1, 7), Some(10));
+        assert_eq!(idx.position(1, 8), None);
+        assert_eq!(idx.position(2, 0), Some(11));
+        assert_eq!(idx.position(3, 0), None);
+
+        assert_eq!(idx.position_utf16(1, 0), Some(3));
+        assert_eq!(idx.position_utf16(1, 1), Some(5));
+        assert_eq!(idx.position_utf16(1, 2), None);
+        assert_eq!(idx.position_utf16(1, 3), Some(9));
+        assert_eq!(idx.position_utf16(1, 4), Some(10));
+        assert_eq!(idx.position_utf16(1, 5), None);
+        assert_eq!(idx.position_utf16(2, 0), Some(11));
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "a\tb\r\n\rä😀\n\nz";
+        let idx = LineIndex::new(text);
+
+        for (pos, _) in text.char_indices().chain([(text.len(), ' ')]) {
+            if matches!(text.as_bytes().get(pos.wrapping_sub(1)), Some(b'\r'))
+                && text.as_bytes().get(pos) == Some(&b'\n')
+            {
+                continue;
+            }
+
+            let lc = idx.line_col(pos).unwrap();
+            assert_eq!(idx.position(lc.line, lc.col), Some(pos), "{}", pos);
+            assert_eq!(idx.position_utf16(lc.line, lc.col_utf16), Some(pos));
+        }
+    }
+
+    #[cfg(feature = "location")]
+    #[test]
+    fn display() {
+        let idx = LineIndex::new("ab\ncd");
+        let loc = Location { begin: 1.into(), end: 4.into() };
+        assert_eq!(idx.display(&loc).to_string(), "1:2..2:2");
+
+        let loc = Location { begin: 1.into(), end: 9.into() };
+        assert_eq!(idx.display(&loc).to_string(), "1:2..9");
+    }
+}
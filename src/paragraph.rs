@@ -3,9 +3,12 @@ use crate::log;
 use std::mem;
 
 use super::{
+    Attributes,
     Block,
+    Diagnostic,
     Inline,
     LINE_WS,
+    LinkParts,
     NL_CR,
     ParserData,
     ParserSettings,
@@ -13,17 +16,22 @@ use super::{
     Location,
     LocationHelper,
     LocationPosition,
+    Position,
     Tools,
     Transaction,
+    attributes,
+    filter_tags,
     html_entity,
     is_ascii_alphabetic,
 };
 
 use Inline as I;
 
+mod autolink;
+mod emoji;
 mod link;
 
-use link::{link_end, linkdef};
+use link::{link_end, linkdef, text_label};
 
 #[derive(Debug)]
 enum E {
@@ -32,11 +40,17 @@ enum E {
     Both,
 }
 
+/// Open delimiter with its position in the list and the plain text
+///
+/// The [`LocationPosition`] is the input position of the first delimiter;
+/// unlike the text around them, delimiters are never escaped, so the
+/// locations of the enclosed texts can be derived from it. Images and links
+/// also keep the result of [`text_label`] for `[text][]` and `[text]`.
 #[derive(Debug)]
 enum Entity {
-    Emph(E, char, (usize, usize), usize),
-    Image((usize, usize)),
-    Link((usize, usize)),
+    Emph(E, char, (usize, usize), usize, LocationPosition),
+    Image((usize, usize), LocationPosition, Option<(String, Position)>),
+    Link((usize, usize), LocationPosition, Option<(String, Position)>),
     NestedLink,
 }
 
@@ -66,15 +80,21 @@ impl Paragraph {
     }
 
     fn push_no_plain(&mut self, el: Inline) {
-        assert!(self.plain.is_empty());
+        // the callers add the pending text before, because it ends where the
+        // element begins
+        debug_assert!(self.plain.is_empty(), "pending text {:?}", self.plain);
         log!(t, "paragraph", "adding {:?}", el);
         self.list.push(el);
     }
 
+    /// Adds the pending plain text, which ends at *plain_begin*, the start
+    /// of the next plain text
+    ///
+    /// The text can be shorter than its location because of escapes and
+    /// entities, so the end can't be derived from its length.
     fn push_plain(&mut self, plain_begin: LocationPosition) {
         if !self.plain.is_empty() {
             let begin = mem::replace(&mut self.plain_begin, plain_begin);
-            // TODO: let end = begin + self.plain.len();
             let end = plain_begin;
 
             let el = I::Text(mem::take(&mut self.plain), Location { begin, end });
@@ -88,24 +108,24 @@ impl Paragraph {
         self.plain.push(ch);
     }
 
-    fn into_list(self) -> Vec<Inline> {
-        let mut list = self.list;
-        if !self.plain.is_empty() {
-            let end = self.plain_begin + self.plain.len();
-
-            list.push(I::Text(
-                self.plain,
-                Location {
-                    begin: self.plain_begin,
-                    end,
-                },
-            ));
-        }
-
-        list
+    /// Returns the elements with the pending plain text, which ends at
+    /// *plain_end*
+    fn into_list(mut self, plain_end: LocationPosition) -> Vec<Inline> {
+        self.push_plain(plain_end);
+        self.list
     }
 }
 
+/// Skips the line ending and returns the location right after it
+///
+/// This isn't the location of *data* afterwards, because containers like quotes
+/// skip the prefix of the next line together with the line ending.
+fn skip_line_ending(data: &mut impl ParserData) -> LocationPosition {
+    let loc = data.loc();
+    let len = usize::from(data.skip('\r')) + usize::from(data.skip('\n'));
+    loc + len
+}
+
 pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
     let par_loc_begin = data.loc();
     log!(d, data, "paragraph", "begin");
@@ -116,22 +136,23 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
     while let Some(peek_ch) = data.peek() {
         match peek_ch {
             '\n' | '\r' => {
-                par.push_plain(data.loc());
-                data.skip_newline();
+                let loc_begin = data.loc();
+                par.push_plain(loc_begin);
+                let loc = Location { begin: loc_begin, end: skip_line_ending(data) };
                 data.skip_all(LINE_WS);
 
                 if data.peek().is_none() {
                     break;
                 }
 
-                par.push_no_plain(I::SoftBreak);
+                par.push_no_plain(I::SoftBreak(loc));
                 par.plain_begin = data.loc();
             }
 
             '`' => {
                 let loc = data.loc();
-                if let Some(code) = code(data) {
-                    par.push(I::Code(code, data.loc_end(loc)), loc);
+                if let Some((code, complete)) = code(data) {
+                    par.push(incomplete_unless(complete, I::Code(code, data.loc_end(loc))), loc);
                     par.plain_begin = data.loc();
                 } else {
                     data.copy_all(&mut par.plain, '`');
@@ -140,20 +161,41 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
 
             '<' => {
                 if let Some((url, txt, loc, inner_loc)) = autourl(data) {
+                    let plain_end = loc.begin;
                     par.push(
-                        I::Link(vec![I::Text(txt, inner_loc)], url, String::new(), loc),
-                        data.loc()
+                        I::Link(
+                            vec![I::Text(txt, inner_loc.clone())], url, String::new(),
+                            Attributes::default(), loc, autolink_parts(inner_loc)
+                        ),
+                        plain_end
                     );
+                    par.plain_begin = data.loc();
                 } else if !data.has_setting(ParserSettings::Html) {
                     par.push_char('<');
                     data.advance();
                 } else {
                     let plain_end = data.loc();
 
-                    if let Some(html) = html(data) {
+                    if let Some(mut html) = html(data) {
+                        if data.has_setting(ParserSettings::TagFilter) {
+                            for el in &mut html {
+                                if let I::Html(s, _) = el {
+                                    filter_tags(s);
+                                }
+                            }
+                        }
+
                         par.push_plain(plain_end);
                         par.list.extend(html);
                         par.plain_begin = data.loc();
+                    } else if let Some(mut html) = incomplete_html(data) {
+                        if data.has_setting(ParserSettings::TagFilter) {
+                            filter_tags(&mut html);
+                        }
+
+                        let el = I::Html(html, data.loc_end(plain_end));
+                        par.push(I::Incomplete(Box::new(el)), plain_end);
+                        par.plain_begin = data.loc();
                     } else {
                         par.push_char('<');
                         data.advance();
@@ -163,16 +205,19 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
 
             // image begin
             '!' => {
+                let loc = data.loc();
                 data.advance();
                 let pos_in_plain = par.plain.len();
                 par.push_char('!');
 
-                if data.skip('[') {
+                if data.looking_at('[') {
+                    let text = text_label(data);
+                    data.advance();
                     par.push_char('[');
 
                     log!(d, data, "paragraph", "image begin");
                     par.open_brackets.push(
-                        Entity::Image((par.list.len(), pos_in_plain))
+                        Entity::Image((par.list.len(), pos_in_plain), loc, text)
                     );
                 }
             }
@@ -185,17 +230,28 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
                     }
                 }
 
+                let loc = data.loc();
+                let text = text_label(data);
                 data.advance();
                 log!(d, data, "paragraph", "link begin");
-                par.open_brackets.push(Entity::Link( (par.list.len(), par.plain.len()) ));
+                par.open_brackets.push(
+                    Entity::Link((par.list.len(), par.plain.len()), loc, text)
+                );
                 par.push_char('[');
             }
 
             ']' => link_end(&mut par, data),
 
-            '*' | '_' => emph(&mut par, data),
+            '*' | '_' => emph(&mut par, data, peek_ch),
+
+            '^' if data.has_setting(ParserSettings::Sup) => emph(&mut par, data, peek_ch),
+            '~' if data.settings().intersects(ParserSettings::Sub | ParserSettings::Del)
+                => emph(&mut par, data, peek_ch),
+            '=' if data.has_setting(ParserSettings::Mark) => emph(&mut par, data, peek_ch),
+            '+' if data.has_setting(ParserSettings::Ins) => emph(&mut par, data, peek_ch),
 
             '@' if data.has_setting(ParserSettings::Embedded) => {
+                let plain_end = data.loc();
                 data.advance();
 
                 if let Some((em, loc)) = embedded(data, true) {
@@ -208,7 +264,10 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
                         }
                     };
 
-                    par.push(el, data.loc());
+                    par.push(el, plain_end);
+                    par.plain_begin = data.loc();
+                } else if let Some(el) = incomplete_embedded(data) {
+                    par.push(I::Incomplete(Box::new(el)), plain_end);
                 } else {
                     par.push_char('@');
                 }
@@ -220,13 +279,14 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
                     data.advance();
                     par.open_braces -= 1;
 
-                    if Some(&I::SoftBreak) == par.list.last() {
+                    if matches!(par.list.last(), Some(I::SoftBreak(_))) {
                         par.list.pop();
                     }
 
                     let loc = data.loc_end(loc_begin);
                     data.skip_all(' ');
-                    par.push(I::EmbeddedBlock("}".to_string(), loc), data.loc());
+                    par.push(I::EmbeddedBlock("}".to_string(), loc), loc_begin);
+                    par.plain_begin = data.loc();
                 } else if stop_on_emb_end {
                     break;
                 } else {
@@ -245,7 +305,7 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
                 match data.peek() {
                     // https://spec.commonmark.org/0.29/#hard-line-breaks
                     Some('\r' | '\n') => {
-                        data.skip_newline();
+                        let loc_end = skip_line_ending(data);
 
                         if data.peek().is_none() {
                             par.push_char('\\');
@@ -253,7 +313,7 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
                             break;
                         }
                         par.push_plain(loc_before_bs);
-                        par.push_no_plain(I::HardBreak);
+                        par.push_no_plain(I::HardBreak(Location { begin: loc_before_bs, end: loc_end }));
 
                         data.skip_all(LINE_WS);
                         par.plain_begin = data.loc();
@@ -275,11 +335,42 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
                 data.copy_all(&mut par.plain, LINE_WS);
 
                 if data.looking_at(NL_CR) || data.peek().is_none() {
+                    // https://spec.commonmark.org/0.29/#hard-line-breaks
+                    let is_break = data.looking_at(NL_CR)
+                        && par.plain[ws_start..].ends_with("  ");
+
                     par.plain.truncate(ws_start);
                     par.push_plain(loc);
+
+                    if is_break && data.has_setting(ParserSettings::TrailingSpaceLint) {
+                        log!(w, data, "paragraph", "invisible hard break by trailing spaces");
+                        data.report(Diagnostic::warning(
+                            "trailing-space-break",
+                            "invisible hard line break by trailing spaces",
+                            data.loc_end(loc),
+                        ));
+                    }
+
+                    if is_break && data.has_setting(ParserSettings::TrailingSpaceBreaks) {
+                        let loc_end = skip_line_ending(data);
+
+                        if data.peek().is_none() {
+                            break;
+                        }
+
+                        par.push_no_plain(I::HardBreak(Location { begin: loc, end: loc_end }));
+
+                        data.skip_all(LINE_WS);
+                        par.plain_begin = data.loc();
+                    }
                 }
             }
 
+            _ if data.has_setting(ParserSettings::ExtendedAutolinks)
+                && autolink::extended_autolink(&mut par, data) => (),
+
+            ':' if data.has_setting(ParserSettings::Emoji) && emoji::shortcode(&mut par, data) => (),
+
             c => {
                 par.push_char(c);
                 data.advance();
@@ -287,6 +378,11 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
         }
     }
 
+    let end = data.loc_content_end();
+    if data.has_setting(ParserSettings::IncompleteEnd) {
+        incomplete_end(&mut par, end);
+    }
+
     let mut emph_found = false;
     for e in &mut par.open_brackets {
         use Entity::*;
@@ -299,11 +395,102 @@ pub fn paragraph(data: &mut impl ParserData, stop_on_emb_end: bool) -> Block {
     }
 
     if emph_found {
-        link::emph_cleanup(&mut par, data.loc());
+        link::emph_cleanup(&mut par, end);
     }
 
     log!(d, data, "paragraph", "end");
-    Block::Paragraph(par.into_list(), data.loc_end(par_loc_begin))
+    let list = par.into_list(end);
+    Block::Paragraph(list, Attributes::default(), data.loc_end(par_loc_begin))
+}
+
+/// Wraps *el* in [`Inline::Incomplete`] unless it's *complete*
+fn incomplete_unless(complete: bool, el: Inline) -> Inline {
+    if complete {
+        el
+    } else {
+        I::Incomplete(Box::new(el))
+    }
+}
+
+/// Turns the emphasis, links and images that are still open at the end of
+/// the paragraph into incomplete elements, the innermost first
+fn incomplete_end(par: &mut Paragraph, plain_end: LocationPosition) {
+    loop {
+        // close the pairs of emphasis after the last open link
+        link::emph_cleanup(par, plain_end);
+        par.push_plain(plain_end);
+
+        let (kind, pos, delim_len, begin) = match par.open_brackets.pop() {
+            None => break,
+
+            Some(Entity::Emph(E::Start | E::Both, ch, pos, len, loc))
+                => (Some(ch), pos, len, loc),
+
+            Some(Entity::Link(pos, loc, _)) => (None, pos, 1, loc),
+            Some(Entity::Image(pos, loc, _)) => (None, pos, 2, loc),
+            Some(Entity::Emph(E::End, ..) | Entity::NestedLink) => continue,
+        };
+
+        let loc = Location { begin, end: plain_end };
+        let ct = clip_segment(&mut par.list, pos, delim_len, None, begin);
+
+        // the label is the text up to the end, like for a shortcut reference
+        let parts = || LinkParts {
+            label: Location { begin: begin + delim_len, end: plain_end },
+            url: Location { begin: plain_end, end: plain_end },
+            title: Location { begin: plain_end, end: plain_end },
+        };
+
+        let el = match kind {
+            Some(ch) => emph_inline(ch, delim_len, ct, loc),
+            None if delim_len == 1 => I::LinkRef(ct, String::new(), loc, parts()),
+            None => I::ImageRef(ct, String::new(), loc, parts()),
+        };
+
+        log!(t, "paragraph", "incomplete {:?}", el);
+        par.list.push(I::Incomplete(Box::new(el)));
+    }
+}
+
+/// Takes the rest of the input as an HTML tag `<a href="`, which isn't
+/// closed
+fn incomplete_html(data: &mut impl ParserData) -> Option<String> {
+    if !data.has_setting(ParserSettings::IncompleteEnd) {
+        return None;
+    }
+
+    let mut data = Transaction::new(data);
+    let mut html = String::new();
+    data.copy_all(&mut html, |c| c != '>');
+
+    let is_tag = html[1..].starts_with(|c: char| c.is_ascii_alphabetic() || "/!?".contains(c));
+    if !is_tag || data.peek().is_some() {
+        return None;
+    }
+
+    data.commit();
+    Some(html)
+}
+
+/// Takes the rest of the input as embedded code `@(…` or `@{…`, which isn't
+/// closed
+fn incomplete_embedded(data: &mut impl ParserData) -> Option<Inline> {
+    if !data.has_setting(ParserSettings::IncompleteEnd) {
+        return None;
+    }
+
+    let ch = data.peek().filter(|&c| c == '(' || c == '{')?;
+    let loc_begin = data.loc();
+    data.advance();
+
+    let mut buf = String::new();
+    data.copy_all(&mut buf, |_| true);
+
+    Some(if ch == '(' {
+        I::EmbeddedExpr(buf, data.loc_end(loc_begin))
+    } else {
+        I::EmbeddedBlock(buf, data.loc_end(loc_begin))
+    })
 }
 
 fn autourl(data: &mut impl ParserData) -> Option<(String, String, Location, Location)> {
@@ -426,36 +613,34 @@ fn autourl(data: &mut impl ParserData) -> Option<(String, String, Location, Loca
     }
 }
 
+/// Parts of an autolink, whose text at *loc* is also the URL
+fn autolink_parts(loc: Location) -> LinkParts {
+    LinkParts {
+        label: loc.clone(),
+        url: loc.clone(),
+        title: Location { begin: loc.end, end: loc.end },
+    }
+}
+
+/// Removes the inline elements after the opening delimiter of *delim_len*
+/// characters at *start* up to *end* and returns them; the delimiter starts
+/// at *delim_loc* in the input
 fn clip_segment(
     inlines: &mut Vec<Inline>, start: (usize, usize), delim_len: usize,
-    end: Option<usize>
+    end: Option<usize>, delim_loc: LocationPosition
 ) -> Vec<Inline> {
-    trait AsPlain {
-        fn as_plain(&mut self) -> (&mut String, &mut Location);
-    }
-
-    impl AsPlain for I {
-        fn as_plain(&mut self) -> (&mut String, &mut Location) {
-            if let I::Text(txt, loc) = self {
-                (txt, loc)
-            } else {
-                unreachable!("Found {:?} instead of Plain", self);
-            }
-        }
-    }
+    let Some(I::Text(txt, loc)) = inlines.get_mut(start.0) else {
+        // the delimiters are recorded with the position in the pending text,
+        // which is always added as text
+        debug_assert!(false, "no text at {:?}", start);
+        return Vec::new();
+    };
 
     if start.1 == 0 {
-        let mut drop_first = false;
-
-        let (txt, loc) = inlines[start.0].as_plain();
-        if txt.len() == delim_len {
-            drop_first = true;
-        } else if delim_len == 1 {
-            txt.remove(0);
-            loc.begin = loc.begin + 1;
-        } else {
+        let drop_first = txt.len() == delim_len;
+        if !drop_first {
             txt.replace_range(0..delim_len, "");
-            loc.begin = loc.begin + delim_len;
+            loc.begin = delim_loc + delim_len;
         }
 
         let mut drain = if let Some(end) = end {
@@ -465,14 +650,13 @@ fn clip_segment(
         };
 
         if drop_first {
-            assert!(drain.next().is_some());
+            drain.next();
         }
 
         drain.collect()
-    } else if start.1 == inlines[start.0].as_plain().0.len() - delim_len {
-        let (txt, loc) = inlines[start.0].as_plain();
+    } else if start.1 == txt.len() - delim_len {
         txt.truncate(start.1);
-        loc.end = loc.end - delim_len;
+        loc.end = delim_loc;
 
         if let Some(end) = end {
             inlines.drain(start.0 + 1 .. end)
@@ -480,15 +664,14 @@ fn clip_segment(
             inlines.drain(start.0 + 1..)
         }.collect()
     } else {
-        let (src, loc) = inlines[start.0].as_plain();
-        let plain = src[start.1 + delim_len..].to_string();
-        src.truncate(start.1);
+        let plain = txt[start.1 + delim_len..].to_string();
+        txt.truncate(start.1);
 
         let plain_loc = Location {
-            begin: loc.begin + start.1 + delim_len,
+            begin: delim_loc + delim_len,
             end: loc.end,
         };
-        loc.end = loc.begin + start.1;
+        loc.end = delim_loc;
 
         let mut content = vec![ I::Text(plain, plain_loc) ];
         if let Some(end) = end {
@@ -503,7 +686,11 @@ fn clip_segment(
     }
 }
 
-fn code(data: &mut impl ParserData) -> Option<String> {
+/// Parses a code span and returns its content and whether it's closed
+///
+/// The code span must be closed unless [`ParserSettings::IncompleteEnd`] is
+/// set.
+fn code(data: &mut impl ParserData) -> Option<(String, bool)> {
     let mut data = Transaction::new(data);
     let tag_len = data.skip_all('`');
     if tag_len == 0 {
@@ -511,9 +698,21 @@ fn code(data: &mut impl ParserData) -> Option<String> {
     }
 
     let mut text = String::new();
+    let mut complete = true;
 
     loop {
-        match data.peek()? {
+        let ch = match data.peek() {
+            Some(c) => c,
+
+            None if data.has_setting(ParserSettings::IncompleteEnd) => {
+                complete = false;
+                break;
+            }
+
+            None => return None,
+        };
+
+        match ch {
             '`' => {
                 let len = data.skip_all('`');
 
@@ -544,7 +743,7 @@ fn code(data: &mut impl ParserData) -> Option<String> {
     }
 
     data.commit();
-    Some(text)
+    Some((text, complete))
 }
 
 pub enum Embedded {
@@ -564,7 +763,7 @@ pub(crate) fn embedded(data: &mut impl ParserData, foo: bool) -> Option<(Embedde
                 buf.pop();
                 Some((Embedded::Block(buf, 0), data.loc_end(loc_begin)))
             } else {
-                data.reset(pos).unwrap();
+                data.rewind(pos);
                 None
             }
         }
@@ -576,7 +775,7 @@ pub(crate) fn embedded(data: &mut impl ParserData, foo: bool) -> Option<(Embedde
                 buf.pop();
                 Some((Embedded::Expr(buf), data.loc_end(loc_begin)))
             } else {
-                data.reset(pos).unwrap();
+                data.rewind(pos);
                 None
             }
         }
@@ -612,7 +811,7 @@ pub(crate) fn embedded(data: &mut impl ParserData, foo: bool) -> Option<(Embedde
                 }
 
                 _ => {
-                    data.reset(pos).unwrap();
+                    data.rewind(pos);
                     None
                 }
             }
@@ -649,14 +848,14 @@ pub(crate) fn embedded(data: &mut impl ParserData, foo: bool) -> Option<(Embedde
                                 }
                             }
 
-                            data.reset(pos).unwrap();
+                            data.rewind(pos);
                             buf.truncate(buf_len);
                             break;
                         }
 
                         Some((Embedded::Block(buf, braces), data.loc_end(loc_begin)))
                     } else {
-                        data.reset(pos).unwrap();
+                        data.rewind(pos);
                         buf.truncate(buf_len);
                         None
                     }
@@ -671,7 +870,7 @@ pub(crate) fn embedded(data: &mut impl ParserData, foo: bool) -> Option<(Embedde
                         if data.copy_until_match(&mut buf, '(', ')') {
                             Some((Embedded::Block(buf, 0), data.loc_end(loc_begin)))
                         } else {
-                            data.reset(pos).unwrap();
+                            data.rewind(pos);
                             buf.pop();
                             Some((Embedded::Expr(buf), data.loc_end(loc_begin)))
                         }
@@ -683,13 +882,13 @@ pub(crate) fn embedded(data: &mut impl ParserData, foo: bool) -> Option<(Embedde
         }
 
         _ => {
-            data.reset(pos).unwrap();
+            data.rewind(pos);
             None
         }
     }
 }
 
-fn emph(par: &mut Paragraph, data: &mut impl ParserData) {
+fn emph(par: &mut Paragraph, data: &mut impl ParserData, delim_ch: char) {
     #![allow(clippy::nonminimal_bool)]
     /*
       This function contains some expressions taken from the Commonmark Spec,
@@ -697,24 +896,38 @@ fn emph(par: &mut Paragraph, data: &mut impl ParserData) {
       possible to disable this warning only for those lines.
      */
 
-    let delim_ch = match data.peek() {
-        Some(c @ ('*' | '_')) => c,
-        x => unreachable!("Invalid start of emph: {:?}", x),
-    };
-
     log!(d, data, "emph", "begin");
 
     let delim_len = data.skip_all(delim_ch);
+    let is_valid_run = match delim_ch {
+        '^' => delim_len == 1,
+        '=' | '+' => delim_len == 2,
+        // `~sub~` and `~~del~~`
+        '~' => (delim_len == 1 && data.has_setting(ParserSettings::Sub))
+            || (delim_len == 2 && data.has_setting(ParserSettings::Del)),
+        _ => true,
+    };
+
+    if !is_valid_run {
+        for _ in 0..delim_len {
+            par.push_char(delim_ch);
+        }
+
+        log!(d, data, "emph", "end with plain");
+        return;
+    }
+
     let prev_char = par.plain.chars().last()
     // the delimiter comes after an content element (image, link, ...)
     // https://spec.commonmark.org/0.29/#example-403
         .or_else(|| par.list.last().map(|x| match x {
-            I::HardBreak | I::SoftBreak => ' ',
+            I::HardBreak(_) | I::SoftBreak(_) => ' ',
             _ => '.',
         }));
     let (is_left_side, is_right_side) = emph_delimiter_evaluation(prev_char, data.peek());
 
-    let is_opening = if delim_ch == '*' {
+    // all markers except `_` follow the rules of `*`
+    let is_opening = if delim_ch != '_' {
         // rule 1. + 5.
         is_left_side && !is_right_side
     } else {
@@ -725,7 +938,7 @@ fn emph(par: &mut Paragraph, data: &mut impl ParserData) {
     };
 
 
-    let mut is_closing = if delim_ch == '*' {
+    let mut is_closing = if delim_ch != '_' {
         // rule 3. + 7.
         is_right_side && !is_left_side
     } else {
@@ -738,7 +951,7 @@ fn emph(par: &mut Paragraph, data: &mut impl ParserData) {
     log!(t, "emph", "opening = {}, closing = {}", is_opening, is_closing);
 
     let is_both = is_opening == is_closing;
-    if delim_ch == '*' && !is_opening && !is_closing &&
+    if delim_ch != '_' && !is_opening && !is_closing &&
         is_left_side && is_right_side
     {
         // intraword emphasis
@@ -747,15 +960,21 @@ fn emph(par: &mut Paragraph, data: &mut impl ParserData) {
         is_closing = true;
     }
 
+    let run_loc = data.loc() - delim_len;
     if is_closing {
-        let remaining_chars = emph_end(par, delim_ch, delim_len, is_both);
+        let remaining_chars = emph_end(par, delim_ch, delim_len, is_both, run_loc);
+        if par.plain.is_empty() {
+            // a closed emphasis took the plain text
+            par.plain_begin = run_loc + (delim_len - remaining_chars);
+        }
 
         if remaining_chars > 0 {
             if is_both {
                 par.open_brackets.push(Entity::Emph(
                     E::Both, delim_ch,
                     (par.list.len(), par.plain.len()),
-                    remaining_chars
+                    remaining_chars,
+                    run_loc + (delim_len - remaining_chars)
                 ));
             }
 
@@ -763,14 +982,13 @@ fn emph(par: &mut Paragraph, data: &mut impl ParserData) {
                 par.push_char(delim_ch);
             }
         }
-
-        par.plain_begin = data.loc() - par.plain.len();
     } else {
         if is_opening {
             par.open_brackets.push(Entity::Emph(
                 E::Start, delim_ch,
                 (par.list.len(), par.plain.len()),
-                delim_len
+                delim_len,
+                run_loc
             ));
         }
 
@@ -782,7 +1000,7 @@ fn emph(par: &mut Paragraph, data: &mut impl ParserData) {
     log!(d, data, "emph", "end");
 }
 
-fn emph_delimiter_evaluation(prev: Option<char>, next: Option<char>) -> (bool, bool) {
+pub(crate) fn emph_delimiter_evaluation(prev: Option<char>, next: Option<char>) -> (bool, bool) {
     #![allow(clippy::nonminimal_bool)]
     /*
       This function contains some expressions taken from the Commonmark Spec,
@@ -805,30 +1023,38 @@ fn emph_delimiter_evaluation(prev: Option<char>, next: Option<char>) -> (bool, b
     (left, right)
 }
 
+/// Closes the emphasis with the closing delimiters, which start at
+/// *closing_loc*, and returns the number of unused delimiters
 fn emph_end(
-    par: &mut Paragraph, delim_ch: char, closing_len: usize, closing_is_both: bool
+    par: &mut Paragraph, delim_ch: char, closing_len: usize, closing_is_both: bool,
+    closing_loc: LocationPosition
 ) -> usize {
-    let mut matching_start_idx = None;
+    let mut matching_start = None;
     let mut open_link_found = false;
 
     for (idx, e) in par.open_brackets.iter().enumerate().rev() {
         match e {
-            Entity::Emph(emph, c, _, l) => {
+            Entity::Emph(emph, c, s, l, loc) => {
                 let opening_is_both = match emph {
                     E::Both => true,
                     E::Start => false,
                     E::End => continue,
                 };
 
-                if *c == delim_ch && (
+                let is_match = if delim_ch == '*' || delim_ch == '_' {
                     (!opening_is_both && !closing_is_both) ||
                     // rule 9. + 10. from
                     // https://spec.commonmark.org/0.29/#emphasis-and-strong-emphasis
                     // https://spec.commonmark.org/0.29/#example-410
                         (l + closing_len) % 3 != 0 ||
                         (l % 3 == 0 && closing_len % 3 == 0)
-                ) {
-                    matching_start_idx = Some(idx);
+                } else {
+                    // the other markers are not split: `~~a~` is no subscript
+                    *l == closing_len
+                };
+
+                if *c == delim_ch && is_match {
+                    matching_start = Some((idx, *s, *l, *loc));
                     break;
                 }
             }
@@ -839,96 +1065,106 @@ fn emph_end(
         }
     }
 
-    if matching_start_idx.is_none() {
+    let Some((idx, opening_pos, opening_len, opening_loc)) = matching_start else {
         return closing_len;
-    }
+    };
 
-    let (opening_pos, opening_len);
+    if open_link_found {
+        // push a marker in the list, maybe the link becomes invalid
+        par.open_brackets.push(Entity::Emph(
+            E::End, delim_ch, (par.list.len(), par.plain.len()), closing_len, closing_loc
+        ));
 
-    if let Some(idx) = matching_start_idx {
-        use Entity::Emph;
+        for _ in 0..closing_len {
+            par.plain.push(delim_ch);
+        }
 
-        if open_link_found {
-            // push a marker in the list, maybe the link becomes invalid
-            par.open_brackets.push(Emph(
-                E::End, delim_ch, (par.list.len(), par.plain.len()), closing_len
-            ));
+        return 0;
+    }
 
-            for _ in 0..closing_len {
-                par.plain.push(delim_ch);
-            }
+    let mut i = 0;
+    par.open_brackets.retain(|x| {
+        let idx = idx + 1;
+        i += 1;
+        match x {
+            // remove other open EmphStart: `*a _b*`, and the ends of an
+            // emphasis started before a link, which stay text: `~~[++~~++`
+            Entity::Emph(..) => i < idx,
 
-            return 0;
+            _ => true
         }
+    });
+
+    /// Encloses everything after the *len* opening delimiters at *pos* and
+    /// *begin* in the input up to the closing delimiters at *end*
+    fn close(
+        par: &mut Paragraph, delim_ch: char, pos: (usize, usize), len: usize,
+        begin: LocationPosition, end: LocationPosition
+    ) {
+        par.push_plain(end);
+        let ct = clip_segment(&mut par.list, pos, len, None, begin);
+
+        let loc = Location { begin, end: end + len };
+        par.push_no_plain(emph_inline(delim_ch, len, ct, loc));
+    }
 
-        match &par.open_brackets[idx] {
-            Emph(E::Start | E::Both, _, s, l) => {
-                opening_pos = *s;
-                opening_len = *l;
-            }
+    if opening_len <= closing_len {
+        close(par, delim_ch, opening_pos, opening_len, opening_loc, closing_loc);
 
-            x => unreachable!("{:?}", x),
+        if opening_len < closing_len {
+            return emph_end(
+                par, delim_ch, closing_len - opening_len, closing_is_both,
+                closing_loc + opening_len
+            );
         }
-
-        let mut i = 0;
-        par.open_brackets.retain(|x| {
-            let idx = idx + 1;
-            i += 1;
-            match x {
-                // there should be no Ends left, because !open_link_found
-                Emph(E::End, ..) => panic!("{:?} found", x),
-
-                // remove other open EmphStart: `*a _b*`
-                Emph(..) => i < idx,
-
-                _ => true
-            }
-        });
     } else {
-        return closing_len;
-    };
+        par.open_brackets.push(Entity::Emph(
+            E::Start, delim_ch, opening_pos, opening_len - closing_len, opening_loc
+        ));
 
-    // TODO: besseren Namen
-    fn foo(par: &mut Paragraph, pos: (usize, usize), len: usize) {
-        par.push_plain(Default::default());
-        let ct = clip_segment(&mut par.list, pos, len, None);
-
-        if len == 1 {
-            par.push_no_plain(Inline::Emph(ct));
-        } else {
-            let mut new = Inline::Strong(ct);
-            let mut l = len - 2;
+        let pos = (opening_pos.0, opening_pos.1 + opening_len - closing_len);
+        let begin = opening_loc + (opening_len - closing_len);
 
-            while l >= 2 {
-                new = Inline::Strong(vec![new]);
-                l -= 2;
-            }
+        close(par, delim_ch, pos, closing_len, begin, closing_loc);
+    }
 
-            if l == 1 {
-                new = Inline::Emph(vec![new]);
-            }
+    0
+}
 
-            par.push_no_plain(new);
-        }
+/// Creates the element for `ct` enclosed by `len` delimiters `delim_ch`
+/// at *loc*
+///
+/// Each nested element of a longer run covers its delimiters, e.g. for
+/// `***a***` the [`Inline::Emph`] covers all and the [`Inline::Strong`] the
+/// inner two delimiters on each side.
+fn emph_inline(delim_ch: char, len: usize, ct: Vec<Inline>, loc: Location) -> Inline {
+    match delim_ch {
+        '^' => return I::Sup(ct, loc),
+        '~' if len == 1 => return I::Sub(ct, loc),
+        '~' => return I::Del(ct, loc),
+        '=' => return I::Mark(ct, loc),
+        '+' => return I::Ins(ct, loc),
+        _ => (),
     }
 
-    if opening_len <= closing_len {
-        foo(par, opening_pos, opening_len);
+    if len == 1 {
+        return I::Emph(ct, loc);
+    }
 
-        if opening_len < closing_len {
-            return emph_end(par, delim_ch, closing_len - opening_len, closing_is_both);
-        }
-    } else {
-        par.open_brackets.push(
-            Entity::Emph(E::Start, delim_ch, opening_pos, opening_len - closing_len)
-        );
+    let inner_loc = |l: usize| Location { begin: loc.begin + l, end: loc.end - l };
+    let mut l = len - 2;
+    let mut new = I::Strong(ct, inner_loc(l));
 
-        let pos = (opening_pos.0, opening_pos.1 + opening_len - closing_len);
+    while l >= 2 {
+        l -= 2;
+        new = I::Strong(vec![new], inner_loc(l));
+    }
 
-        foo(par, pos, closing_len);
+    if l == 1 {
+        new = I::Emph(vec![new], loc);
     }
 
-    0
+    new
 }
 
 fn html(data: &mut impl ParserData) -> Option<Vec<Inline>> {
@@ -1094,7 +1330,9 @@ fn html(data: &mut impl ParserData) -> Option<Vec<Inline>> {
                                     let mut val_open_braces = 0;
 
                                     loop {
-                                        data.copy_until(&mut html, &[ch, '@', '}'][..]);
+                                        if !data.copy_until(&mut html, &[ch, '@', '}'][..]) {
+                                            leave!();
+                                        }
 
                                         if html.ends_with(ch) {
                                             break;
@@ -1113,19 +1351,16 @@ fn html(data: &mut impl ParserData) -> Option<Vec<Inline>> {
                                                     }
                                                 );
                                             }
-                                        } else if html.ends_with('}') {
-                                            if val_open_braces > 0 {
-                                                val_open_braces -= 1;
+                                        } else if val_open_braces > 0 {
+                                            // html ends with '}'
+                                            val_open_braces -= 1;
 
-                                                push!(Inline::EmbeddedBlock(
-                                                    "}".to_string(),
-                                                    data.loc_end(data.loc() - 1)
-                                                ));
+                                            push!(Inline::EmbeddedBlock(
+                                                "}".to_string(),
+                                                data.loc_end(data.loc() - 1)
+                                            ));
 
-                                                data.skip_all(' ');
-                                            }
-                                        } else {
-                                            leave!();
+                                            data.skip_all(' ');
                                         }
                                     }
                                 } else if !data.copy_until(&mut html, ch) {
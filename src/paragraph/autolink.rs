@@ -0,0 +1,226 @@
+use crate::log;
+
+use super::{
+    Attributes,
+    Entity,
+    Inline as I,
+    LocationHelper,
+    Paragraph,
+    ParserData,
+    Tools,
+    Transaction,
+    autolink_parts,
+};
+
+fn is_domain_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '-' || ch == '.'
+}
+
+fn is_email_local_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ".+-_".contains(ch)
+}
+
+/// Character before the current position of the paragraph or `None` at the
+/// beginning of a line
+fn prev_char(par: &Paragraph) -> Option<char> {
+    par.plain.chars().last()
+        .or_else(|| par.list.last().and_then(|x| match x {
+            I::HardBreak(_) | I::SoftBreak(_) => None,
+            I::Text(txt, _) => txt.chars().last(),
+            _ => Some('.'),
+        }))
+}
+
+fn inside_link(par: &Paragraph) -> bool {
+    par.open_brackets.iter().any(|x| matches!(x, Entity::Link(..) | Entity::Image(..)))
+}
+
+// https://github.github.com/gfm/#valid-domain
+fn valid_domain(domain: &str) -> bool {
+    let segments = domain.split('.').collect::<Vec<_>>();
+
+    segments.len() > 1
+        && segments.iter().all(|x| !x.is_empty())
+        && segments.iter().rev().take(2).all(|x| !x.contains('_'))
+}
+
+// https://github.github.com/gfm/#extended-autolink-path-validation
+fn trim_link_end(link: &mut String) {
+    loop {
+        match link.chars().last() {
+            Some('?' | '!' | '.' | ',' | ':' | '*' | '_' | '~') => {
+                link.pop();
+            }
+
+            Some(')') if link.matches(')').count() > link.matches('(').count() => {
+                link.pop();
+            }
+
+            Some(';') => {
+                let name_len = link[..link.len() - 1].chars().rev()
+                    .take_while(char::is_ascii_alphanumeric)
+                    .count();
+
+                let amp_pos = link.len() - 1 - name_len;
+                if name_len > 0 && link[..amp_pos].ends_with('&') {
+                    link.truncate(amp_pos - 1);
+                } else {
+                    break;
+                }
+            }
+
+            _ => break,
+        }
+    }
+}
+
+/// Parses an [extended www or url
+/// autolink](https://github.github.com/gfm/#extended-www-autolink)
+fn url_autolink(par: &mut Paragraph, data: &mut impl ParserData) -> bool {
+    if !prev_char(par).map_or(true, |c| c.is_whitespace() || "*_~(".contains(c)) {
+        return false;
+    }
+
+    let mut data = Transaction::new(data);
+    let loc_begin = data.loc();
+    let pos_begin = data.pos();
+
+    let mut link = String::new();
+    data.copy_all(&mut link, |c: char| !c.is_whitespace() && c != '<');
+
+    let domain_begin = if link.starts_with("www.") {
+        0
+    } else if let Some(scheme) = ["http://", "https://"].iter().find(|x| link.starts_with(*x)) {
+        scheme.len()
+    } else {
+        return false;
+    };
+
+    log!(d, data, "extended autolink", "begin");
+
+    trim_link_end(&mut link);
+
+    let domain = link[domain_begin..].split(|c| !is_domain_char(c)).next().unwrap_or_default();
+    if !valid_domain(domain) {
+        log!(d, data, "extended autolink", "end without match");
+        return false;
+    }
+
+    data.rewind(pos_begin + link.len());
+    let loc = data.loc_end(loc_begin);
+
+    let url = if domain_begin == 0 {
+        format!("http://{}", link)
+    } else {
+        link.clone()
+    };
+
+    par.push(
+        I::Link(
+            vec![I::Text(link, loc.clone())], url, String::new(), Attributes::default(),
+            loc.clone(), autolink_parts(loc)
+        ),
+        loc_begin,
+    );
+    par.plain_begin = data.loc();
+
+    log!(d, data, "extended autolink", "end");
+    data.commit();
+    true
+}
+
+/// Parses an [extended email
+/// autolink](https://github.github.com/gfm/#extended-email-autolink)
+fn email_autolink(par: &mut Paragraph, data: &mut impl ParserData) -> bool {
+    if prev_char(par).is_some_and(is_email_local_char) {
+        return false;
+    }
+
+    let mut data = Transaction::new(data);
+    let loc_begin = data.loc();
+
+    let mut local = String::new();
+    if data.copy_all(&mut local, is_email_local_char) == 0 || !data.skip('@') {
+        return false;
+    }
+
+    log!(d, data, "email autolink", "begin");
+
+    let domain_begin = data.pos();
+    let mut domain = String::new();
+    data.copy_all(&mut domain, |c: char| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    while domain.ends_with('.') {
+        domain.pop();
+    }
+
+    if domain.ends_with(['-', '_']) || !domain.contains('.') || domain.contains("..") {
+        log!(d, data, "email autolink", "end without match");
+        return false;
+    }
+
+    data.rewind(domain_begin + domain.len());
+    let loc = data.loc_end(loc_begin);
+
+    let addr = format!("{}@{}", local, domain);
+    par.push(
+        I::Link(
+            vec![I::Text(addr.clone(), loc.clone())],
+            format!("mailto:{}", addr),
+            String::new(),
+            Attributes::default(),
+            loc.clone(),
+            autolink_parts(loc),
+        ),
+        loc_begin,
+    );
+    par.plain_begin = data.loc();
+
+    log!(d, data, "email autolink", "end");
+    data.commit();
+    true
+}
+
+/// Recognizes bare URLs, `www.` addresses and email addresses
+///
+/// Returns `true`, if a link was added to *par*.
+pub(super) fn extended_autolink(par: &mut Paragraph, data: &mut impl ParserData) -> bool {
+    if inside_link(par) {
+        return false;
+    }
+
+    match data.peek() {
+        Some('h' | 'w') => url_autolink(par, data) || email_autolink(par, data),
+        Some(c) if is_email_local_char(c) => email_autolink(par, data),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trim() {
+        for (inp, exp) in [
+            ("www.a.b/x?", "www.a.b/x"),
+            ("www.a.b/(x)", "www.a.b/(x)"),
+            ("www.a.b/x)", "www.a.b/x"),
+            ("www.a.b/(x))).", "www.a.b/(x)"),
+            ("www.a.b/x&amp;", "www.a.b/x"),
+            ("www.a.b/x&amp;y", "www.a.b/x&amp;y"),
+            ("www.a.b/x&;", "www.a.b/x&;"),
+        ] {
+            let mut link = inp.to_string();
+            trim_link_end(&mut link);
+            assert_eq!(exp, link, "{}", inp);
+        }
+    }
+
+    #[test]
+    fn domain() {
+        assert!(valid_domain("www.commonmark.org"));
+        assert!(valid_domain("a_b.example.com"));
+        assert!(!valid_domain("www.exa_mple.com"));
+        assert!(!valid_domain("localhost"));
+    }
+}
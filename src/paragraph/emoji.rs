@@ -0,0 +1,70 @@
+use crate::log;
+
+use super::{
+    Paragraph,
+    ParserData,
+    Tools,
+    Transaction,
+};
+
+/// Pairs of shortcode and emoji sorted by the shortcode
+const EMOJI_SHORTCODES: &[(&str, &str)] = &include!("../emoji_shortcodes.rs");
+
+fn is_shortcode_char(ch: char) -> bool {
+    ch.is_ascii_lowercase() || ch.is_ascii_digit() || "_+-".contains(ch)
+}
+
+/// Checks, if the word before the current position looks like a bare URL
+fn inside_url(par: &Paragraph) -> bool {
+    let word = par.plain.rsplit(char::is_whitespace).next().unwrap_or_default();
+    word.contains("://") || word.starts_with("www.")
+}
+
+fn lookup(code: &str) -> Option<&'static str> {
+    EMOJI_SHORTCODES.binary_search_by_key(&code, |(key, _)| key).ok()
+        .map(|idx| EMOJI_SHORTCODES[idx].1)
+}
+
+/// Replaces a shortcode `:name:` by its emoji
+///
+/// Returns `true`, if the emoji was added to *par*.
+pub(super) fn shortcode(par: &mut Paragraph, data: &mut impl ParserData) -> bool {
+    if par.plain.chars().last().is_some_and(char::is_alphanumeric) || inside_url(par) {
+        return false;
+    }
+
+    let mut data = Transaction::new(data);
+    data.expect_char(':');
+
+    let mut code = String::new();
+    if data.copy_all(&mut code, is_shortcode_char) == 0 || !data.skip(':') {
+        return false;
+    }
+
+    if let Some(emoji) = lookup(&code) {
+        log!(d, data, "emoji", "{} => {}", code, emoji);
+        par.plain.push_str(emoji);
+        data.commit();
+        true
+    } else {
+        log!(t, data, "emoji", "unknown shortcode {}", code);
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        assert!(EMOJI_SHORTCODES.windows(2).all(|x| x[0].0 < x[1].0));
+    }
+
+    #[test]
+    fn lookup_codes() {
+        assert_eq!(Some("\u{1f389}"), lookup("tada"));
+        assert_eq!(Some("\u{1f44d}"), lookup("+1"));
+        assert_eq!(None, lookup("no_such_emoji"));
+    }
+}
@@ -1,21 +1,30 @@
 use crate::log;
 
+use std::mem;
+
 use super::{
+    Attributes,
     Block,
     E,
     Entity,
     Inline as I,
     LINE_WS,
+    LinkParts,
     LocationHelper,
     NL_CR,
     Paragraph,
     ParserData,
+    ParserSettings,
     Location,
     LocationPosition,
+    Position,
     Tools,
     Transaction,
+    attributes,
     clip_segment,
+    emph_inline,
     html_entity,
+    incomplete_unless,
 };
 
 fn is_segment_empty(par: &Paragraph, par_begin: usize, plain_begin: usize) -> bool {
@@ -23,19 +32,25 @@ fn is_segment_empty(par: &Paragraph, par_begin: usize, plain_begin: usize) -> bo
         return par.plain.len() == plain_begin;
     }
 
-    if let I::Text(txt, _) = &par.list[par_begin] {
-        if txt.len() > plain_begin {
-            return false;
-        }
-    } else {
-        unreachable!("Expected Inline::Plain, but found {:?}", par.list[par_begin]);
+    // the bracket is recorded with its position in the pending text, which is
+    // always added as text
+    debug_assert!(matches!(par.list[par_begin], I::Text(..)), "{:?}", par.list[par_begin]);
+    if !matches!(&par.list[par_begin], I::Text(txt, _) if txt.len() <= plain_begin) {
+        return false;
     }
 
     par.list[par_begin + 1..].iter()
-        .all(|x| matches!(x, I::HardBreak | I::SoftBreak))
+        .all(|x| matches!(x, I::HardBreak(_) | I::SoftBreak(_)))
 }
 
-fn link_arg(data: &mut impl ParserData) -> Option<(String, String)> {
+/// Empty location at the current position
+fn empty_loc(data: &impl ParserData) -> Location {
+    data.loc_end(data.loc())
+}
+
+/// Parses the argument `(url "title")` of a link and returns the URL, the
+/// title and their locations
+fn link_arg(data: &mut impl ParserData) -> Option<(String, String, Location, Location)> {
     let mut data = Transaction::new(data);
 
     if !data.skip('(') {
@@ -53,7 +68,7 @@ fn link_arg(data: &mut impl ParserData) -> Option<(String, String)> {
         }
     }
 
-    let url = link_arg_url(&mut data)?;
+    let (url, url_loc) = link_arg_url(&mut data)?;
 
     let mut space_seen = data.skip_all(LINE_WS) > 0;
 
@@ -62,39 +77,93 @@ fn link_arg(data: &mut impl ParserData) -> Option<(String, String)> {
         data.skip_all(LINE_WS);
     }
 
-    let title;
+    let (title, title_loc);
     if space_seen {
-        title = link_arg_title(&mut data)?;
+        (title, title_loc) = link_arg_title(&mut data)?;
 
         if data.skip_newline() {
             data.skip_all(LINE_WS);
         }
     } else {
         title = String::new();
+        title_loc = empty_loc(&data);
     }
 
     if data.skip(')') {
         log!(t, data, "link arg", "end");
         data.commit();
-        Some((url, title))
+        Some((url, title, url_loc, title_loc))
     } else {
         log!(w, data, "link end", "end without match");
         None
     }
 }
 
-fn link_arg_url(data: &mut impl ParserData) -> Option<String> {
+/// Takes the rest of the input as the argument `(url "title` of a link,
+/// which isn't closed
+///
+/// Only with [`ParserSettings::IncompleteEnd`]
+fn incomplete_link_arg(
+    data: &mut impl ParserData
+) -> Option<(String, String, Location, Location)> {
+    if !data.has_setting(ParserSettings::IncompleteEnd) {
+        return None;
+    }
+
+    let mut data = Transaction::new(data);
+    if !data.skip('(') {
+        return None;
+    }
+
+    let arg_begin = data.loc();
+    let mut arg = String::new();
+    data.copy_all(&mut arg, |c| c != ')');
+    if data.peek().is_some() {
+        return None;
+    }
+
+    let trimmed = arg.trim_start();
+    let (url, title) = trimmed.split_once(char::is_whitespace).unwrap_or((trimmed, &trimmed[trimmed.len()..]));
+    let url = url.strip_prefix('<').unwrap_or(url);
+    let title = title.trim().trim_start_matches(['"', '\'', '(']);
+
+    // the parts are slices of the unprocessed argument
+    let loc = |part: &str| {
+        let begin = arg_begin + (part.as_ptr() as usize - arg.as_ptr() as usize);
+        Location { begin, end: begin + part.len() }
+    };
+
+    log!(t, data, "link arg", "incomplete");
+    data.commit();
+    Some((url.to_string(), title.to_string(), loc(url), loc(title)))
+}
+
+/// Attributes `{…}` directly after the link argument `(…)`
+fn link_attributes(data: &mut impl ParserData) -> Attributes {
+    if data.has_setting(ParserSettings::Attributes) {
+        attributes(data).unwrap_or_default()
+    } else {
+        Attributes::default()
+    }
+}
+
+/// Parses the URL of a link or link definition and returns it with its
+/// location without the angle brackets
+fn link_arg_url(data: &mut impl ParserData) -> Option<(String, Location)> {
     let mut url = String::new();
 
     log!(t, data, "link url", "begin");
 
     if data.looking_at('<') {
+        let loc_begin = data.loc() + 1;
+
         loop {
             match data.next()? {
                 '>' => {
+                    let loc = data.loc_end(loc_begin);
                     data.advance();
                     log!(t, data, "link url", "end");
-                    return Some(url);
+                    return Some((url, loc));
                 }
 
                 '\\' => {
@@ -117,13 +186,14 @@ fn link_arg_url(data: &mut impl ParserData) -> Option<String> {
         }
     }
 
+    let loc_begin = data.loc();
     let mut open_parentheses = 0;
     loop {
         match data.peek() {
             Some(')') => {
                 if open_parentheses == 0 {
                     log!(t, data, "link url", "end");
-                    return Some(url);
+                    return Some((url, data.loc_end(loc_begin)));
                 }
 
                 open_parentheses -= 1;
@@ -145,7 +215,7 @@ fn link_arg_url(data: &mut impl ParserData) -> Option<String> {
             None | Some(' ' | '\t' | '\n' | '\r') => {
                 return if open_parentheses == 0 {
                     log!(t, data, "link url", "end");
-                    Some(url)
+                    Some((url, data.loc_end(loc_begin)))
                 } else {
                     log!(w, data, "link url", "end without match");
                     None
@@ -167,17 +237,20 @@ fn link_arg_url(data: &mut impl ParserData) -> Option<String> {
     }
 }
 
-fn link_arg_title(data: &mut impl ParserData) -> Option<String> {
+/// Parses the title of a link or link definition and returns it with its
+/// location without the delimiters
+fn link_arg_title(data: &mut impl ParserData) -> Option<(String, Location)> {
     let (left_del, right_del) = match data.peek() {
         Some(del @ ('\'' | '"')) => (del, del),
 
         Some('(') => ('(', ')'),
 
-        _ => return Some(String::new()),
+        _ => return Some((String::new(), empty_loc(data))),
     };
 
     log!(d, data, "link title", "begin");
     data.advance();
+    let loc_begin = data.loc();
 
     let mut title = String::new();
     loop {
@@ -211,11 +284,12 @@ fn link_arg_title(data: &mut impl ParserData) -> Option<String> {
             c => {
                 // check right_del before left_del, because they might be the same
                 if c == right_del {
+                    let loc = data.loc_end(loc_begin);
                     data.advance();
                     data.skip_all(LINE_WS);
 
                     log!(d, data, "link title", "end");
-                    return Some(title);
+                    return Some((title, loc));
                 }
 
                 if c == left_del {
@@ -233,6 +307,7 @@ fn link_arg_title(data: &mut impl ParserData) -> Option<String> {
 pub(super) fn link_end(par: &mut Paragraph, data: &mut impl ParserData) {
     let plain_end_loc = data.loc();
     data.expect_char(']');
+    let after_bracket = data.pos();
 
     log!(d, data, "link end", "begin");
 
@@ -244,7 +319,7 @@ pub(super) fn link_end(par: &mut Paragraph, data: &mut impl ParserData) {
             Entity::NestedLink => nested_link_found = true,
             Entity::Emph(..) => emph_found = true,
 
-            Entity::Image(_) | Entity::Link(_) => {
+            Entity::Image(..) | Entity::Link(..) => {
                 match_ = Some(idx);
                 break;
             }
@@ -271,20 +346,46 @@ pub(super) fn link_end(par: &mut Paragraph, data: &mut impl ParserData) {
         });
     }
 
-    let loc = data.loc();
+    // location of the text between the brackets
+    let label_loc = |begin: LocationPosition, delim_len| Location {
+        begin: begin + delim_len,
+        end: plain_end_loc,
+    };
+
+    // the raw text between the brackets, unless the label ended at another
+    // bracket like in ``[`]`]``
+    let raw_text = |text: &Option<(String, Position)>| match text {
+        Some((text, end)) if *end == after_bracket => text.clone(),
+        _ => String::new(),
+    };
 
     if data.looking_at('(') {
         use Entity as E;
 
         match match_.map(|i| (i, &par.open_brackets[i])) {
-            Some((idx, E::Image(pos))) => {
-                if let Some((url, title)) = link_arg(data) {
-                    let pos = *pos; // drop reference to par by copying pos
+            Some((idx, E::Image(pos, loc, _))) => {
+                let (arg, complete) = match link_arg(data) {
+                    Some(x) => (Some(x), true),
+                    None => (incomplete_link_arg(data), false),
+                };
+
+                if let Some((url, title, url_loc, title_loc)) = arg {
+                    // drop reference to par by copying pos and loc
+                    let (pos, loc) = (*pos, *loc);
+                    let attrs = link_attributes(data);
 
                     par.push_plain(plain_end_loc);
-                    let content = clip_segment(&mut par.list, pos, 2, None);
+                    let content = clip_segment(&mut par.list, pos, 2, None, loc);
 
-                    par.push_no_plain(I::Image(content, url, title, data.loc_end(loc)));
+                    let parts = LinkParts {
+                        label: label_loc(loc, 2),
+                        url: url_loc,
+                        title: title_loc,
+                    };
+                    par.push_no_plain(incomplete_unless(
+                        complete,
+                        I::Image(content, url, title, attrs, data.loc_end(loc), parts)
+                    ));
 
                     cleanup_par(par, idx, false);
                     par.plain_begin = data.loc();
@@ -294,14 +395,29 @@ pub(super) fn link_end(par: &mut Paragraph, data: &mut impl ParserData) {
                 }
             }
 
-            Some((idx, E::Link(pos))) if !nested_link_found => {
-                if let Some((url, title)) = link_arg(data) {
-                    let pos = *pos; // drop reference to par by copying pos
+            Some((idx, E::Link(pos, loc, _))) if !nested_link_found => {
+                let (arg, complete) = match link_arg(data) {
+                    Some(x) => (Some(x), true),
+                    None => (incomplete_link_arg(data), false),
+                };
+
+                if let Some((url, title, url_loc, title_loc)) = arg {
+                    // drop reference to par by copying pos and loc
+                    let (pos, loc) = (*pos, *loc);
+                    let attrs = link_attributes(data);
 
                     par.push_plain(plain_end_loc);
-                    let content = clip_segment(&mut par.list, pos, 1, None);
+                    let content = clip_segment(&mut par.list, pos, 1, None, loc);
 
-                    par.push_no_plain(I::Link(content, url, title, data.loc_end(loc)));
+                    let parts = LinkParts {
+                        label: label_loc(loc, 1),
+                        url: url_loc,
+                        title: title_loc,
+                    };
+                    par.push_no_plain(incomplete_unless(
+                        complete,
+                        I::Link(content, url, title, attrs, data.loc_end(loc), parts)
+                    ));
                     par.open_brackets[idx] = E::NestedLink;
 
                     cleanup_par(par, idx, true);
@@ -317,22 +433,30 @@ pub(super) fn link_end(par: &mut Paragraph, data: &mut impl ParserData) {
     }
 
     match match_.map(|i| (i, &par.open_brackets[i])) {
-        Some((idx, Entity::Image(pos))) => {
+        Some((idx, Entity::Image(pos, loc, text))) => {
             let before_label = data.pos();
             let label = link_label(data);
 
-            if label.as_ref().map_or(false, |x| !x.trim().is_empty()) ||
+            if label.as_ref().map_or(false, |(x, _)| !x.trim().is_empty()) ||
                 (!emph_found && !is_segment_empty(par, pos.0, pos.1 + 2))
             {
-                let pos = *pos; // drop reference to par by copying pos
+                // drop reference to par by copying pos, loc and the text
+                let (pos, loc, text) = (*pos, *loc, raw_text(text));
                 par.push_plain(plain_end_loc);
-                let content = clip_segment(&mut par.list, pos, 2, None);
+                let content = clip_segment(&mut par.list, pos, 2, None, loc);
+
+                // `[text][]` and `[text]` use the text as label
+                let (label, label_loc) = match label {
+                    Some((label, label_loc)) if !label.is_empty() => (label, label_loc),
+                    _ => (text, label_loc(loc, 2)),
+                };
+                let parts = LinkParts {
+                    label: label_loc,
+                    url: empty_loc(data),
+                    title: empty_loc(data),
+                };
 
-                par.push_no_plain(I::ImageRef(
-                    content,
-                    label.unwrap_or_default(),
-                    data.loc_end(loc),
-                ));
+                par.push_no_plain(I::ImageRef(content, label, data.loc_end(loc), parts));
 
                 cleanup_par(par, idx, false);
                 par.plain_begin = data.loc();
@@ -341,25 +465,33 @@ pub(super) fn link_end(par: &mut Paragraph, data: &mut impl ParserData) {
                 return;
             }
 
-            data.reset(before_label).unwrap();
+            data.rewind(before_label);
         }
 
-        Some((idx, Entity::Link(pos))) if !nested_link_found => {
+        Some((idx, Entity::Link(pos, loc, text))) if !nested_link_found => {
             let before_label = data.pos();
             let label = link_label(data);
 
-            if label.as_ref().map_or(false, |x| !x.trim().is_empty()) ||
+            if label.as_ref().map_or(false, |(x, _)| !x.trim().is_empty()) ||
                 (!emph_found && !is_segment_empty(par, pos.0, pos.1 + 1))
             {
-                let pos = *pos; // drop reference to par by copying pos
+                // drop reference to par by copying pos, loc and the text
+                let (pos, loc, text) = (*pos, *loc, raw_text(text));
                 par.push_plain(plain_end_loc);
-                let content = clip_segment(&mut par.list, pos, 1, None);
+                let content = clip_segment(&mut par.list, pos, 1, None, loc);
 
-                par.push_no_plain(I::LinkRef(
-                    content,
-                    label.unwrap_or_default(),
-                    data.loc_end(loc),
-                ));
+                // `[text][]` and `[text]` use the text as label
+                let (label, label_loc) = match label {
+                    Some((label, label_loc)) if !label.is_empty() => (label, label_loc),
+                    _ => (text, label_loc(loc, 1)),
+                };
+                let parts = LinkParts {
+                    label: label_loc,
+                    url: empty_loc(data),
+                    title: empty_loc(data),
+                };
+
+                par.push_no_plain(I::LinkRef(content, label, data.loc_end(loc), parts));
 
                 cleanup_par(par, idx, false);
                 par.plain_begin = data.loc();
@@ -368,7 +500,7 @@ pub(super) fn link_end(par: &mut Paragraph, data: &mut impl ParserData) {
                 return;
             }
 
-            data.reset(before_label).unwrap();
+            data.rewind(before_label);
         }
 
         _ => (),
@@ -396,12 +528,18 @@ pub(super) fn emph_cleanup(par: &mut Paragraph, plain_begin: LocationPosition) {
         match e {
             Image(..) | Link(..) => break,
 
-            Emph(kind, ..) => match kind {
+            Emph(kind, delim, _, len, _) => match kind {
                 E::End => closed_emph.push(idx),
 
                 _ => {
-                    if let Some(x) = closed_emph.pop() {
-                        emph_pairs.push((idx, x));
+                    // only an end with the same delimiter and length fits
+                    let end = closed_emph.pop_if(|&mut x| matches!(
+                        &par.open_brackets[x],
+                        Emph(_, end_delim, _, end_len, _) if end_delim == delim && end_len == len
+                    ));
+
+                    if let Some(end) = end {
+                        emph_pairs.push((idx, end));
                     } else {
                         break;
                     }
@@ -420,204 +558,150 @@ pub(super) fn emph_cleanup(par: &mut Paragraph, plain_begin: LocationPosition) {
     log!(i, "emph cleanup", "emph_pairs = {:?}", emph_pairs);
     par.push_plain(plain_begin);
 
-    let mut lowest_start = None;
-    let mut end_offset = 0;
-    for (start_idx, end_idx) in emph_pairs {
-        use Entity::*;
-
-        let (start, end, delim_len) = match &par.open_brackets[start_idx] {
-            Emph(E::Start | E::Both, start_delim, start_pos, start_len) => {
-                match &par.open_brackets[end_idx] {
-                    Emph(E::End, del, pos, len) => {
-                        assert_eq!(del, start_delim);
-                        assert_eq!(len, start_len);
-
-                        (start_pos, pos, *len)
-                    }
-
-                    x => unreachable!("end_idx should be an Emph(End): {:?}", x)
-                }
-            }
-
-            x => unreachable!(
-                "start_idx should be a starting Emph(): {:?}", x
-            )
-        };
+    /// Delimiter run of an emphasis pair in a text of the list
+    struct Mark {
+        pos: (usize, usize),
+        len: usize,
+        delim: char,
+        loc: LocationPosition,
+        opening: bool,
+        pair: usize,
+    }
 
-        trait AsPlain {
-            fn as_plain(&mut self) -> (&mut String, &mut Location);
+    let mut marks = Vec::new();
+    for (pair, &(start_idx, end_idx)) in emph_pairs.iter().enumerate() {
+        use Entity::Emph;
+
+        if let (Emph(_, delim, start, len, start_loc), Emph(_, _, end, _, end_loc))
+            = (&par.open_brackets[start_idx], &par.open_brackets[end_idx])
+        {
+            marks.push(Mark {
+                pos: *start, len: *len, delim: *delim, loc: *start_loc, opening: true, pair
+            });
+            marks.push(Mark {
+                pos: *end, len: *len, delim: *delim, loc: *end_loc, opening: false, pair
+            });
         }
+    }
 
-        impl AsPlain for I {
-            fn as_plain(&mut self) -> (&mut String, &mut Location) {
-                if let I::Text(txt, loc) = self {
-                    (txt, loc)
-                } else {
-                    unreachable!("Found {:?} instead of Plain", self);
-                }
-            }
+    // a mark that doesn't point to its delimiters in a text stays text
+    marks.retain(|m| matches!(
+        par.list.get(m.pos.0),
+        Some(I::Text(txt, _)) if txt.get(m.pos.1..m.pos.1 + m.len)
+            .is_some_and(|d| d.chars().all(|c| c == m.delim))
+    ));
+    marks.sort_by_key(|m| m.pos);
+
+    // emphasis that is still open with its opening mark and content
+    let mut stack: Vec<(&Mark, Vec<I>)> = Vec::new();
+    let mut list = Vec::new();
+
+    fn push_text(list: &mut Vec<I>, text: &str, loc: Location) {
+        if text.is_empty() {
+            return;
         }
 
-        let end = (end.0 + end_offset, end.1);
-        let (mut ct, ins_pos);
-        if start.0 == end.0 {
-            if start.1 == 0 {
-                ins_pos = end.0;
-
-                let (text, text_loc) = par.list[start.0].as_plain();
-                let loc = Location {
-                    begin: text_loc.begin + start.1 + delim_len,
-                    end: text_loc.begin + end.1,
-                };
-                ct = vec![I::Text(text[start.1 + delim_len..end.1].to_string(), loc)];
-
-                text.replace_range(..end.1 + delim_len, "");
-                text_loc.begin = text_loc.begin + end.1 + delim_len;
-
-                end_offset += 1;
-            } else {
-                ins_pos = end.0 + 1;
-
-                let (text, text_loc) = par.list[start.0].as_plain();
-                let loc = Location {
-                    begin: text_loc.begin + start.1 + delim_len,
-                    end: text_loc.begin + end.1,
-                };
-                ct = vec![I::Text(text[start.1 + delim_len..end.1].to_string(), loc)];
-
-                if text.len() == end.1 + delim_len {
-                    text.truncate(start.1);
-                    text_loc.end = text_loc.begin + text.len();
-                    end_offset += 1;
-                } else {
-                    let loc = Location {
-                        begin: text_loc.begin + end.1 + delim_len,
-                        end: text_loc.end,
-                    };
-                    let new = I::Text(text[end.1 + delim_len..].to_string(), loc);
+        if let Some(I::Text(prev, prev_loc)) = list.last_mut() {
+            prev.push_str(text);
+            prev_loc.end = loc.end;
+        } else {
+            list.push(I::Text(text.to_string(), loc));
+        }
+    }
 
-                    text.truncate(start.1);
-                    text_loc.end = text_loc.begin + text.len();
-                    par.list.push(new);
+    let mut marks_it = marks.iter().peekable();
+    for (idx, el) in mem::take(&mut par.list).into_iter().enumerate() {
+        let (txt, txt_loc) = match el {
+            I::Text(txt, loc) if marks_it.peek().is_some_and(|m| m.pos.0 == idx) => (txt, loc),
 
-                    end_offset += 2;
-                }
+            el => {
+                stack.last_mut().map_or(&mut list, |(_, ct)| ct).push(el);
+                continue;
             }
+        };
 
-            if par.open_brackets.len() > end_idx {
-                let removed_len = end.1 + delim_len - 1;
-
-                for el in &mut par.open_brackets[end_idx + 1..] {
-                    if let Entity::Emph(_, _, pos, _) = el {
-                        if pos.0 > end.0 {
-                            break;
-                        }
+        let mut offset = 0;
+        let mut begin = txt_loc.begin;
 
-                        pos.1 -= removed_len;
-                    }
-                }
-            }
-        } else {
-            let seg_ends_at_plain_end
-                = par.list[end.0].as_plain().0.len() == end.1 + delim_len;
-            let end_idx_2 = if seg_ends_at_plain_end {
-                end.0 + 1
-            } else {
-                end.0
-            };
+        while let Some(mark) = marks_it.next_if(|m| m.pos.0 == idx) {
+            let ct = stack.last_mut().map_or(&mut list, |(_, ct)| ct);
+            push_text(ct, &txt[offset..mark.pos.1], Location { begin, end: mark.loc });
 
-            ct = clip_segment(&mut par.list, *start, delim_len, Some(end_idx_2));
-            end_offset -= end_idx_2 - start.0;
-            ins_pos = if start.1 == 0 { start.0 } else { start.0 + 1 };
+            offset = mark.pos.1 + mark.len;
+            begin = mark.loc + mark.len;
 
-            if seg_ends_at_plain_end {
-                ct.last_mut().unwrap().as_plain().0.truncate(end.1 - 1);
+            if mark.opening {
+                stack.push((mark, Vec::new()));
+            } else if let Some((open, ct)) = stack.pop_if(|(open, _)| open.pair == mark.pair) {
+                let loc = Location { begin: open.loc, end: mark.loc + mark.len };
+                stack.last_mut().map_or(&mut list, |(_, ct)| ct)
+                    .push(emph_inline(mark.delim, mark.len, ct, loc));
             } else {
-                let (text, text_loc) = par.list[ins_pos].as_plain();
-                let loc = Location {
-                    begin: text_loc.begin,
-                    end: text_loc.begin + end.1 - 1,
-                };
-                ct.push(I::Text(text[..end.1 - 1].to_string(), loc));
-
-                text.replace_range(..end.1 - 1 + delim_len, "");
-                text_loc.begin = text_loc.begin + end.1 - 1 + delim_len;
-
-                if par.open_brackets.len() > end_idx_2 {
-                    let removed_len = end.1 - 1 + delim_len;
-
-                    for el in &mut par.open_brackets[end_idx_2 + 1..] {
-                        if let Entity::Emph(_, _, pos, _) = el {
-                            if pos.0 > end.0 {
-                                break;
-                            }
-
-                            pos.1 -= removed_len;
-                        }
-                    }
-                }
+                let ct = stack.last_mut().map_or(&mut list, |(_, ct)| ct);
+                let loc = Location { begin: mark.loc, end: begin };
+                push_text(ct, &txt[mark.pos.1..offset], loc);
             }
         }
 
-        if delim_len == 1 {
-            par.list.insert(ins_pos, I::Emph(ct));
-        } else {
-            let mut new = I::Strong(ct);
-            let mut l = delim_len - 2;
+        let ct = stack.last_mut().map_or(&mut list, |(_, ct)| ct);
+        push_text(ct, &txt[offset..], Location { begin, end: txt_loc.end });
+    }
 
-            while l >= 2 {
-                new = I::Strong(vec![new]);
-                l -= 2;
-            }
+    // the openings without a closing become text again
+    while let Some((open, ct)) = stack.pop() {
+        let parent = stack.last_mut().map_or(&mut list, |(_, ct)| ct);
+        let delims = open.delim.to_string().repeat(open.len);
+        push_text(parent, &delims, Location { begin: open.loc, end: open.loc + open.len });
 
-            if l == 1 {
-                new = I::Emph(vec![new]);
+        for el in ct {
+            match el {
+                I::Text(txt, loc) => push_text(parent, &txt, loc),
+                el => parent.push(el),
             }
-
-            par.list.insert(ins_pos, new);
         }
-
-        lowest_start = Some(start_idx);
     }
 
+    par.list = list;
+
     if matches!(par.list.last(), Some(I::Text(..))) {
         if let Some(I::Text(plain, loc)) = par.list.pop() {
-            assert!(par.plain.is_empty());
+            // the pending text was added before the marks were collected
+            debug_assert!(par.plain.is_empty());
             par.plain_begin = loc.begin;
             par.plain = plain;
-        } else {
-            unreachable!();
         }
     }
 
+    let lowest_start = emph_pairs.iter().map(|&(start_idx, _)| start_idx).min();
     if let Some(idx) = lowest_start {
-        let idx = idx + 1;
         let mut i = 0;
         par.open_brackets.retain(|x| {
             i += 1;
-            i < idx || !matches!(x, Entity::Emph(..))
+            i <= idx || !matches!(x, Entity::Emph(..))
         });
-    } else {
-        unreachable!("The for loop should have been run at least once");
     }
 }
 
-fn link_label(data: &mut impl ParserData) -> Option<String> {
+/// Parses a label `[…]` and returns it with its location without the
+/// brackets
+fn link_label(data: &mut impl ParserData) -> Option<(String, Location)> {
     if !data.looking_at('[') {
         return None;
     }
 
     let mut label = String::new();
     let mut data = Transaction::new(data);
+    let loc_begin = data.loc() + 1;
 
     loop {
         match data.next()? {
             '[' => return None,
 
             ']' => {
+                let loc = data.loc_end(loc_begin);
                 data.advance();
                 data.commit();
-                return Some(label);
+                return Some((label, loc));
             }
 
             '\\' => {
@@ -633,25 +717,38 @@ fn link_label(data: &mut impl ParserData) -> Option<String> {
     }
 }
 
+/// Reads the text between the brackets at the current position like
+/// [`link_label`] without moving and returns it with the position after the
+/// closing bracket
+pub(super) fn text_label(data: &mut impl ParserData) -> Option<(String, Position)> {
+    let mut data = Transaction::new(data);
+    let (label, _) = link_label(&mut data)?;
+    Some((label, data.pos()))
+}
+
 pub fn linkdef(data: &mut impl ParserData) -> Option<Block> {
     let mut data = Transaction::new(data);
     log!(d, data, "link def", "begin");
 
     let loc_begin = data.loc();
-    let label = link_label(&mut data)?;
+    let (label, label_loc) = link_label(&mut data)?;
     if label.trim().is_empty() {
         return None;
     }
 
-    let (url, title) = linkdef_arg(&mut data)?;
+    let (url, title, url_loc, title_loc) = linkdef_arg(&mut data)?;
 
     log!(d, data, "link def", "end");
     let loc = data.loc_end(loc_begin);
     data.commit();
-    Some(Block::LinkDef(label, url, title, loc))
+
+    let parts = LinkParts { label: label_loc, url: url_loc, title: title_loc };
+    Some(Block::LinkDef(label, url, title, loc, parts))
 }
 
-fn linkdef_arg(data: &mut impl ParserData) -> Option<(String, String)> {
+/// Parses the argument `: url "title"` of a link definition and returns the
+/// URL, the title and their locations
+fn linkdef_arg(data: &mut impl ParserData) -> Option<(String, String, Location, Location)> {
     if !data.looking_at(':') {
         return None;
     }
@@ -660,13 +757,15 @@ fn linkdef_arg(data: &mut impl ParserData) -> Option<(String, String)> {
     data.skip_all(LINE_WS);
     if data.skip_newline() {
         data.skip_all(LINE_WS);
+    }
 
-        if data.looking_at(NL_CR) {
-            return None;
-        }
+    // the destination is missing, even an empty one needs `<>`
+    if data.peek().is_none() || data.looking_at(NL_CR) {
+        return None;
     }
 
-    let url = link_arg_url(data)?;
+    let (url, url_loc) = link_arg_url(data)?;
+    let no_title = Location { begin: url_loc.end, end: url_loc.end };
 
     let space_seen = data.skip_all(LINE_WS) > 0;
 
@@ -674,23 +773,23 @@ fn linkdef_arg(data: &mut impl ParserData) -> Option<(String, String)> {
         let pos = data.pos();
         data.skip_all(LINE_WS);
 
-        let title = link_arg_title(data)?;
+        let (title, title_loc) = link_arg_title(data)?;
 
         if data.skip_newline() || data.peek().is_none() {
-            Some((url, title))
+            Some((url, title, url_loc, title_loc))
         } else {
-            data.reset(pos).unwrap();
-            Some((url, String::new()))
+            data.rewind(pos);
+            Some((url, String::new(), url_loc, no_title))
         }
     } else {
-        let title = if space_seen {
+        let (title, title_loc) = if space_seen {
             link_arg_title(data)?
         } else {
-            String::new()
+            (String::new(), no_title)
         };
 
         if data.skip_newline() || data.peek().is_none() {
-            Some((url, title))
+            Some((url, title, url_loc, title_loc))
         } else {
             None
         }
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use super::{
+    Attributes,
+    Document,
+    Inline,
+    LinkParts,
+    Location,
+    Visit,
+    VisitMut,
+    walk_inline_mut,
+};
+
+/// Normalizes the label of a link reference or definition for matching
+///
+/// Like in CommonMark the label gets trimmed, every sequence of whitespace
+/// becomes a single space and the case gets folded, e.g. `ẞ` matches `SS`.
+#[must_use]
+pub fn normalize_label(label: &str) -> String {
+    label.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+        .to_uppercase()
+        .to_lowercase()
+}
+
+/// URL, title and their locations of the
+/// [`Block::LinkDef`](crate::Block::LinkDef) of a normalized label
+type Definitions = HashMap<String, (String, String, LinkParts)>;
+
+impl Visit for Definitions {
+    fn visit_link_def(
+        &mut self, label: &str, url: &str, title: &str, _loc: &Location, parts: &LinkParts
+    ) {
+        self.entry(normalize_label(label))
+            .or_insert_with(|| (url.into(), title.into(), parts.clone()));
+    }
+}
+
+/// Replaces the references and collects the labels without a definition
+struct Resolver<'a> {
+    defs: &'a Definitions,
+    unresolved: Vec<(String, Location)>,
+}
+
+impl VisitMut for Resolver<'_> {
+    fn visit_inline_mut(&mut self, el: &mut Inline) {
+        walk_inline_mut(self, el);
+
+        let (Inline::LinkRef(ct, label, loc, parts) | Inline::ImageRef(ct, label, loc, parts))
+            = el else {
+            return;
+        };
+
+        let Some((url, title, def_parts)) = self.defs.get(&normalize_label(label)) else {
+            self.unresolved.push((label.clone(), loc.clone()));
+            return;
+        };
+
+        let ct = std::mem::take(ct);
+        let (url, title) = (url.clone(), title.clone());
+        let loc = loc.clone();
+        let parts = LinkParts {
+            label: parts.label.clone(),
+            url: def_parts.url.clone(),
+            title: def_parts.title.clone(),
+        };
+
+        *el = if matches!(el, Inline::LinkRef(..)) {
+            Inline::Link(ct, url, title, Attributes::default(), loc, parts)
+        } else {
+            Inline::Image(ct, url, title, Attributes::default(), loc, parts)
+        };
+    }
+}
+
+impl Document {
+    /// Replaces every [`Inline::LinkRef`] and [`Inline::ImageRef`] by a
+    /// [`Inline::Link`] or [`Inline::Image`] with the URL and title of the
+    /// matching [`Block::LinkDef`](crate::Block::LinkDef)
+    ///
+    /// The definitions get collected from the whole document, including
+    /// quotes, lists and containers, and the labels are compared after
+    /// [`normalize_label`]. If a label is defined more than once, the first
+    /// definition wins. Like the definitions, `[text][]` and `[text]` use the
+    /// raw text as label, e.g. `*foo*` for `[*foo*]`. The definitions stay in
+    /// the document and the [`LinkParts`] of a replaced reference keep the
+    /// location of its label, but get the locations of the URL and title of
+    /// the definition.
+    ///
+    /// Returns the labels and locations of the references without a
+    /// definition; they are left unchanged.
+    pub fn resolve_references(&mut self) -> Vec<(String, Location)> {
+        let mut defs = Definitions::new();
+        defs.visit_blocks(&self.body);
+
+        let mut resolver = Resolver { defs: &defs, unresolved: Vec::new() };
+        resolver.visit_blocks_mut(&mut self.body);
+        resolver.unresolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels() {
+        assert_eq!("foo bar", normalize_label("  Foo \t\n BAR "));
+        assert_eq!(normalize_label("SS"), normalize_label("ẞ"));
+        assert_eq!(normalize_label("Straße"), normalize_label("STRASSE"));
+        assert_eq!("αγω", normalize_label("ΑΓΩ"));
+    }
+}
@@ -0,0 +1,285 @@
+use super::{
+    Block,
+    Document,
+    Inline,
+    inline_text,
+    paragraph::emph_delimiter_evaluation,
+};
+
+/// Typographic quotes of a language used by [`smart_punctuation`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quotes {
+    /// Opening and closing double quotes
+    pub double: (char, char),
+
+    /// Opening and closing single quotes
+    pub single: (char, char),
+
+    /// Apostrophe within a word like `don't`
+    pub apostrophe: char,
+}
+
+impl Quotes {
+    /// “English” and ‘English’
+    pub const ENGLISH: Quotes = Quotes {
+        double: ('\u{201c}', '\u{201d}'),
+        single: ('\u{2018}', '\u{2019}'),
+        apostrophe: '\u{2019}',
+    };
+
+    /// „German“ and ‚German‘
+    pub const GERMAN: Quotes = Quotes {
+        double: ('\u{201e}', '\u{201c}'),
+        single: ('\u{201a}', '\u{2018}'),
+        apostrophe: '\u{2019}',
+    };
+
+    /// Quotes for a language tag like `de`, `de-AT` or `en_US`
+    ///
+    /// Unknown languages get [`Quotes::ENGLISH`].
+    #[must_use]
+    pub fn for_lang(lang: &str) -> Self {
+        let primary = lang.trim().split(['-', '_']).next().unwrap_or_default();
+
+        if primary.eq_ignore_ascii_case("de") {
+            Quotes::GERMAN
+        } else {
+            Quotes::ENGLISH
+        }
+    }
+}
+
+impl Default for Quotes {
+    fn default() -> Self {
+        Quotes::ENGLISH
+    }
+}
+
+/// Part of the inline content seen by the transformation
+enum Segment<'a> {
+    Text(&'a mut String),
+
+    /// Content that must not be changed represented by a character for the
+    /// flanking rules
+    Other(char),
+}
+
+impl Segment<'_> {
+    fn first_char(&self) -> Option<char> {
+        match self {
+            Segment::Text(txt) => txt.chars().next(),
+            Segment::Other(c) => Some(*c),
+        }
+    }
+}
+
+fn is_autolink(ct: &[Inline], url: &str) -> bool {
+    let text = inline_text(ct);
+
+    text == url
+        || url.strip_prefix("mailto:") == Some(&text)
+        || url.strip_prefix("http://") == Some(&text)
+}
+
+fn collect<'a>(list: &'a mut [Inline], segments: &mut Vec<Segment<'a>>) {
+    for elem in list {
+        match elem {
+            Inline::Text(txt, _) => segments.push(Segment::Text(txt)),
+
+            Inline::SoftBreak(_) | Inline::HardBreak(_) => segments.push(Segment::Other(' ')),
+
+            Inline::Link(ct, url, ..) | Inline::Image(ct, url, ..) if is_autolink(ct, url)
+                => segments.push(Segment::Other('a')),
+
+            Inline::Emph(ct, _) | Inline::Strong(ct, _)
+                | Inline::Del(ct, _) | Inline::Sup(ct, _) | Inline::Sub(ct, _)
+                | Inline::Mark(ct, _) | Inline::Ins(ct, _)
+                | Inline::Image(ct, ..) | Inline::ImageRef(ct, ..)
+                | Inline::Link(ct, ..) | Inline::LinkRef(ct, ..)
+                => collect(ct, segments),
+
+            Inline::Incomplete(el) => collect(std::slice::from_mut(&mut **el), segments),
+
+            Inline::Code(..) | Inline::Html(..) | Inline::Checkbox(..)
+                | Inline::EmbeddedBlock(..) | Inline::EmbeddedExpr(..)
+                => segments.push(Segment::Other('a')),
+        }
+    }
+}
+
+fn transform_inlines(list: &mut [Inline], quotes: &Quotes) {
+    let mut segments = Vec::new();
+    collect(list, &mut segments);
+
+    let mut prev = None;
+    for idx in 0..segments.len() {
+        let next_segment = segments[idx + 1..].iter().find_map(Segment::first_char);
+
+        if let Segment::Text(txt) = &mut segments[idx] {
+            let new = transform_text(txt, prev, next_segment, quotes);
+            prev = txt.chars().last().or(prev);
+            **txt = new;
+        } else {
+            prev = segments[idx].first_char();
+        }
+    }
+}
+
+fn transform_text(
+    txt: &str, mut prev: Option<char>, next_segment: Option<char>, quotes: &Quotes
+) -> String {
+    let mut res = String::with_capacity(txt.len());
+    let mut chars = txt.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' | '\'' => {
+                let next = chars.peek().copied().or(next_segment);
+                let (left, right) = emph_delimiter_evaluation(prev, next);
+                let (open, close) = if ch == '"' { quotes.double } else { quotes.single };
+
+                res.push(match (left, right) {
+                    (true, false) => open,
+                    (false, true) => close,
+                    (true, true) if ch == '\'' => quotes.apostrophe,
+                    _ => ch,
+                });
+            }
+
+            '.' if chars.next_if_eq(&'.').is_some() => {
+                if chars.next_if_eq(&'.').is_some() {
+                    res.push('…');
+                } else {
+                    res.push_str("..");
+                }
+            }
+
+            '-' if chars.peek() == Some(&'-') => {
+                let mut len = 1;
+                while chars.next_if_eq(&'-').is_some() {
+                    len += 1;
+                }
+
+                // like CommonMark implementations: prefer em dashes, but use
+                // only one kind if possible
+                let (em, en) = if len % 3 == 0 {
+                    (len / 3, 0)
+                } else if len % 2 == 0 {
+                    (0, len / 2)
+                } else if len % 3 == 2 {
+                    (len / 3, 1)
+                } else {
+                    (len / 3 - 1, 2)
+                };
+
+                res.extend(std::iter::repeat_n('—', em));
+                res.extend(std::iter::repeat_n('–', en));
+            }
+
+            _ => res.push(ch),
+        }
+
+        prev = Some(ch);
+    }
+
+    res
+}
+
+/// Replaces straight quotes, `--`, `---` and `...` by their typographic
+/// counterparts in the text of all blocks
+///
+/// Opening and closing quotes get distinguished by the same flanking rules
+/// as emphasis. Code, HTML, embedded code and autolinks are kept unchanged.
+/// Escaped characters like `\"` can't be told apart from others anymore and
+/// get replaced, too.
+pub fn smart_punctuation(body: &mut [Block], quotes: &Quotes) {
+    for blk in body {
+        match blk {
+            Block::Heading(_, ct, ..) | Block::Paragraph(ct, ..) => transform_inlines(ct, quotes),
+
+            Block::Admonition(_, title, ct, _) => {
+                transform_inlines(title, quotes);
+                smart_punctuation(ct, quotes);
+            }
+
+            Block::Quote(ct, _) | Block::Container { content: ct, .. }
+                => smart_punctuation(ct, quotes),
+
+            Block::OrderedList(_, _, items, _) | Block::UnorderedList(_, _, items, _) => {
+                for (it, _) in items {
+                    smart_punctuation(it, quotes);
+                }
+            }
+
+            Block::DefinitionList(list, _) => {
+                for (term, defs) in list {
+                    transform_inlines(term, quotes);
+
+                    for def in defs {
+                        smart_punctuation(def, quotes);
+                    }
+                }
+            }
+
+            Block::Table(_, header, rows, _) => {
+                for cell in header.iter_mut().chain(rows.iter_mut().flatten()) {
+                    transform_inlines(cell, quotes);
+                }
+            }
+
+            Block::Code(..) | Block::Html(..) | Block::Break(_) | Block::LinkDef(..)
+                | Block::EmbeddedBlock(..) | Block::EmbeddedExpr(..) => (),
+        }
+    }
+}
+
+impl Document {
+    /// Applies [`smart_punctuation`] with the quotes of the language from the
+    /// head field `lang`
+    pub fn smart_punctuation(&mut self) {
+        let quotes = Quotes::for_lang(self.last_head_val("lang").unwrap_or_default());
+        smart_punctuation(&mut self.body, &quotes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn smart(txt: &str, quotes: &Quotes) -> String {
+        transform_text(txt, None, None, quotes)
+    }
+
+    #[test]
+    fn dashes() {
+        let en = Quotes::ENGLISH;
+        assert_eq!("a - b – c — d", smart("a - b -- c --- d", &en));
+        assert_eq!("––", smart("----", &en));
+        assert_eq!("—–", smart("-----", &en));
+        assert_eq!("——", smart("------", &en));
+        assert_eq!("—––", smart("-------", &en));
+    }
+
+    #[test]
+    fn ellipsis() {
+        let en = Quotes::ENGLISH;
+        assert_eq!("a… b.. c…. d", smart("a... b.. c.... d", &en));
+    }
+
+    #[test]
+    fn quotes() {
+        assert_eq!("„Das ist’s“, sagt ‚er‘.", smart(r#""Das ist's", sagt 'er'."#, &Quotes::GERMAN));
+        assert_eq!("“It’s ‘his’ car.”", smart(r#""It's 'his' car.""#, &Quotes::ENGLISH));
+        assert_eq!(r#"a " b ' c"#, smart(r#"a " b ' c"#, &Quotes::ENGLISH));
+    }
+
+    #[test]
+    fn lang() {
+        assert_eq!(Quotes::GERMAN, Quotes::for_lang("de"));
+        assert_eq!(Quotes::GERMAN, Quotes::for_lang("DE-at"));
+        assert_eq!(Quotes::GERMAN, Quotes::for_lang("de_CH"));
+        assert_eq!(Quotes::ENGLISH, Quotes::for_lang("en-US"));
+        assert_eq!(Quotes::ENGLISH, Quotes::for_lang("fr"));
+        assert_eq!(Quotes::ENGLISH, Quotes::for_lang(""));
+    }
+}
@@ -1,19 +1,25 @@
 use std::str::CharIndices;
 
 use super::{
+    ADMONITION_TYPES,
+    Diagnostic,
     ParserData,
     ParserSettings,
     Position,
+    TAB_STOP,
 };
 
 pub struct StringData<'a> {
     settings: ParserSettings,
+    admonition_types: &'a [&'a str],
 
     data: &'a str,
     slice: CharIndices<'a>,
     start: usize,
     pos: usize,
     peek: Option<char>,
+    /// Reported problems with the position at the time of the report
+    diagnostics: Vec<(Position, Diagnostic)>,
 }
 
 impl<'a> StringData<'a> {
@@ -23,12 +29,14 @@ impl<'a> StringData<'a> {
     ) -> Self {
         let mut val = Self {
             settings,
+            admonition_types: &ADMONITION_TYPES,
 
             data,
             slice: data.char_indices(),
             start: 0,
             pos: 0,
             peek: None,
+            diagnostics: Vec::new(),
         };
 
         if let Some((pos, ch)) = val.slice.next() {
@@ -40,6 +48,15 @@ impl<'a> StringData<'a> {
 
         val
     }
+
+    /// Replaces the [default types](ADMONITION_TYPES) of admonitions
+    ///
+    /// The types must be in lowercase.
+    #[must_use]
+    pub fn with_admonition_types(mut self, types: &'a [&'a str]) -> Self {
+        self.admonition_types = types;
+        self
+    }
 }
 
 impl ParserData for StringData<'_> {
@@ -47,6 +64,10 @@ impl ParserData for StringData<'_> {
         self.settings
     }
 
+    fn admonition_types(&self) -> &[&str] {
+        self.admonition_types
+    }
+
     fn peek(&self) -> Option<char> {
         self.peek
     }
@@ -59,7 +80,7 @@ impl ParserData for StringData<'_> {
         match self.slice.next() {
             None => {
                 self.peek = None;
-                self.pos += 1;
+                self.pos = self.data.len() - self.start;
             }
 
             Some((pos, ch)) => {
@@ -84,9 +105,29 @@ impl ParserData for StringData<'_> {
         self.peek = self.slice.next().map(|(_, x)| x);
         self.start = pos;
         self.pos = 0;
+        self.diagnostics.retain(|(at, _)| *at <= pos);
 
         Ok(())
     }
+
+    fn column(&self) -> usize {
+        let before = &self.data[..self.pos().min(self.data.len())];
+        let line = before.rfind(['\n', '\r']).map_or(before, |idx| &before[idx + 1..]);
+
+        line.chars().fold(0, |col, ch| if ch == '\t' {
+            col + TAB_STOP - col % TAB_STOP
+        } else {
+            col + 1
+        })
+    }
+
+    fn report(&mut self, diag: Diagnostic) {
+        self.diagnostics.push((self.pos(), diag));
+    }
+
+    fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.diagnostics.drain(..).map(|(_, diag)| diag).collect()
+    }
 }
 
 impl<'a> From<&'a str> for StringData<'a> {
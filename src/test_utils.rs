@@ -1,8 +1,3 @@
-use super::{
-    Block,
-    Inline,
-};
-
 macro_rules! str_arg_loc {
     ($name:ident, $id:expr) => (
         str_arg_loc!(($) $name, $id);
@@ -32,13 +27,22 @@ macro_rules! str_arg_loc {
     );
 }
 
-macro_rules! vec_arg {
-    ($name:ident, $id:expr) => ( vec_arg! { ($) , $name, $id } );
+macro_rules! loc_arg {
+    ($name:ident, $id:expr) => ( loc_arg! { ($) , $name, $id } );
 
     ( ($dollar:tt), $name:ident, $id:expr) => {
+        #[cfg(not(feature = "location"))]
+        #[macro_export]
+        macro_rules! $name {
+            ( $dollar( <$begin:literal, $end:literal> )? ) => ( $id(Location::default()) );
+        }
+
+        #[cfg(feature = "location")]
         #[macro_export]
         macro_rules! $name {
-            ( $dollar($args:expr),* ) => ( $id(vec![ $dollar( $args ),* ]) )
+            ( <$begin:literal, $end:literal> ) => (
+                $id(Location { begin: $begin.into(), end: $end.into() })
+            )
         }
     };
 }
@@ -77,119 +81,429 @@ macro_rules! vec_arg_loc {
 // Helper macros for block level
 //
 pub use crate::block as b;
-pub use Block::Break;
 
-vec_arg_loc!(paragraph, Block::Paragraph);
+loc_arg!(thematic_break, Block::Break);
+
+/// paragraph!([[attributes],] content...)
+#[macro_export]
+#[cfg(not(feature = "location"))]
+macro_rules! paragraph {
+    ( $( <$begin:literal, $end:literal> )? [$attrs:expr] $(, $args:expr)* ) => (
+        Block::Paragraph( vec![ $( $args ),* ], $attrs, Location::default() )
+    );
+
+    ( <$begin:literal, $end:literal> $($args:expr),* ) => ( paragraph!($($args),*) );
+
+    ( $($args:expr),* ) => (
+        Block::Paragraph( vec![ $( $args ),* ], Attributes::default(), Location::default() )
+    );
+}
+
+/// paragraph!([[attributes],] content...)
+#[macro_export]
+#[cfg(feature = "location")]
+macro_rules! paragraph {
+    ( <$begin:literal, $end:literal> [$attrs:expr] $(, $args:expr)* ) => (
+        Block::Paragraph(
+            vec![ $( $args ),* ], $attrs,
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    );
+
+    ( <$begin:literal, $end:literal> $($args:expr),* ) => (
+        Block::Paragraph(
+            vec![ $( $args ),* ], Attributes::default(),
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    );
+}
+
 str_arg_loc!(html_block, Block::Html);
 
 str_arg_loc!(emb_block_block, Block::EmbeddedBlock);
 str_arg_loc!(emb_expr_block, Block::EmbeddedExpr);
 
+/// heading!(level, [[attributes],] content...)
 #[macro_export]
 #[cfg(not(feature = "location"))]
 macro_rules! heading {
+    (
+        $( <$begin:literal, $end:literal> )? $level:literal, [$attrs:expr] $(, $args:expr)*
+    ) => (
+        Block::Heading( $level, vec![ $( $args ),* ], $attrs, Location::default() )
+    );
+
     (
         $( <$begin:literal, $end:literal> )? $level:literal $(,)? $($args:expr),*
     ) => (
-        Block::Heading( $level, vec![ $( $args ),* ], Location::default() )
+        Block::Heading(
+            $level, vec![ $( $args ),* ], Attributes::default(), Location::default()
+        )
     );
 }
 
+/// heading!(level, [[attributes],] content...)
 #[macro_export]
 #[cfg(feature = "location")]
 macro_rules! heading {
     (
-        <$begin:literal, $end:literal> $level:literal, $($args:expr),*
+        <$begin:literal, $end:literal> $level:literal, [$attrs:expr] $(, $args:expr)*
     ) => (
         Block::Heading(
-            $level, vec![ $( $args ),* ],
+            $level, vec![ $( $args ),* ], $attrs,
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    );
+
+    (
+        <$begin:literal, $end:literal> $level:literal $(,)? $($args:expr),*
+    ) => (
+        Block::Heading(
+            $level, vec![ $( $args ),* ], Attributes::default(),
             Location { begin: $begin.into(), end: $end.into() }
         )
     );
 }
 
+/// attrs!(#"id" ."class"... "key" = "value"...)
+#[macro_export]
+macro_rules! attrs {
+    (
+        $( # $id:literal )? $( . $class:literal )* $( $key:literal = $val:literal )*
+    ) => (
+        Attributes {
+            id: None $( .or(Some($id.to_string())) )?,
+            classes: vec![ $( $class.to_string() ),* ],
+            pairs: vec![ $( ($key.to_string(), $val.to_string()) ),* ],
+        }
+    );
+}
+
 vec_arg_loc!(quote, Block::Quote);
 
+/// container!(name, [[attributes],] content...)
+#[macro_export]
+#[cfg(not(feature = "location"))]
+macro_rules! container {
+    ( $( <$begin:literal, $end:literal> )? $name:literal, [$attrs:expr] $(, $args:expr)* ) => (
+        Block::Container {
+            name: $name.to_string(),
+            attributes: $attrs,
+            content: vec![ $( $args ),* ],
+            location: Location::default(),
+        }
+    );
+
+    ( $( <$begin:literal, $end:literal> )? $name:literal $(, $args:expr)* ) => (
+        container!($name, [Attributes::default()] $(, $args)*)
+    );
+}
+
+/// container!(name, [[attributes],] content...)
+#[macro_export]
+#[cfg(feature = "location")]
+macro_rules! container {
+    ( <$begin:literal, $end:literal> $name:literal, [$attrs:expr] $(, $args:expr)* ) => (
+        Block::Container {
+            name: $name.to_string(),
+            attributes: $attrs,
+            content: vec![ $( $args ),* ],
+            location: Location { begin: $begin.into(), end: $end.into() },
+        }
+    );
+
+    ( <$begin:literal, $end:literal> $name:literal $(, $args:expr)* ) => (
+        container!(<$begin, $end> $name, [Attributes::default()] $(, $args)*)
+    );
+}
+
+/// admonition!(type, [title...], content...)
+#[macro_export]
+#[cfg(not(feature = "location"))]
+macro_rules! admonition {
+    (
+        $( <$begin:literal, $end:literal> )?
+        $kind:literal, [ $( $title:expr ),* ] $(, $args:expr)*
+    ) => (
+        Block::Admonition(
+            $kind.to_string(), vec![ $( $title ),* ], vec![ $( $args ),* ],
+            Location::default()
+        )
+    );
+}
+
+/// admonition!(type, [title...], content...)
+#[macro_export]
+#[cfg(feature = "location")]
+macro_rules! admonition {
+    (
+        <$begin:literal, $end:literal> $kind:literal, [ $( $title:expr ),* ] $(, $args:expr)*
+    ) => (
+        Block::Admonition(
+            $kind.to_string(), vec![ $( $title ),* ], vec![ $( $args ),* ],
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    );
+}
+
+/// code_block!(<begin, end> <info begin, info end> info, [[attributes],] content)
 #[macro_export]
 #[cfg(not(feature = "location"))]
 macro_rules! code_block {
-    ($( <$begin:literal, $end:literal> )? $opt:literal) => ( code_block!($opt, "") );
+    ( $( <$begin:literal, $end:literal> )* $opt:literal ) => ( code_block!($opt, "") );
 
     (
-        $( <$begin:literal, $end:literal> )? $opt:literal, $ct:literal
+        $( <$begin:literal, $end:literal> )* $opt:literal, [$attrs:expr], $ct:literal
     ) => (
-        Block::Code($opt.to_string(), $ct.to_string(), Location::default())
+        Block::Code(
+            $opt.to_string(), $ct.to_string(), $attrs, Location::default(), Location::default()
+        )
+    );
+
+    ( $( <$begin:literal, $end:literal> )* $opt:literal, $ct:literal ) => (
+        code_block!($opt, [Attributes::default()], $ct)
     )
 }
 
+/// code_block!(<begin, end> <info begin, info end> info, [[attributes],] content)
 #[macro_export]
 #[cfg(feature = "location")]
 macro_rules! code_block {
     (
-        <$begin:literal, $end:literal> $opt:literal,
+        <$begin:literal, $end:literal> <$info_begin:literal, $info_end:literal>
+        $opt:literal, [$attrs:expr], $ct:literal
     ) => (
-        code_block!(($begin, $end), $opt, "")
+        Block::Code(
+            $opt.to_string(), $ct.to_string(), $attrs,
+            Location { begin: $begin.into(), end: $end.into() },
+            Location { begin: $info_begin.into(), end: $info_end.into() }
+        )
     );
 
     (
-        <$begin:literal, $end:literal> $opt:literal, $ct:literal
+        <$begin:literal, $end:literal> <$info_begin:literal, $info_end:literal>
+        $opt:literal, $ct:literal
     ) => (
-        Block::Code($opt.to_string(), $ct.to_string(), Location { begin: $begin.into(), end: $end.into() })
+        code_block!(
+            <$begin, $end> <$info_begin, $info_end> $opt, [Attributes::default()], $ct
+        )
     )
 }
 
+/// item!(<begin, end> blocks...)
+///
+/// Item of [`ordered_list!`] and [`unordered_list!`]
+#[macro_export]
+#[cfg(not(feature = "location"))]
+macro_rules! item {
+    ( <$begin:literal, $end:literal> $($args:expr),* $(,)? ) => ( item!($($args),*) );
+
+    ( $($args:expr),* $(,)? ) => (
+        ( vec![ $( $args ),* ], Location::default() )
+    );
+}
+
+/// item!(<begin, end> blocks...)
+///
+/// Item of [`ordered_list!`] and [`unordered_list!`]
 #[macro_export]
+#[cfg(feature = "location")]
+macro_rules! item {
+    ( <$begin:literal, $end:literal> $($args:expr),* $(,)? ) => (
+        ( vec![ $( $args ),* ], Location { begin: $begin.into(), end: $end.into() } )
+    )
+}
+
+/// ordered_list!([start,] [loose,] items...)
+#[macro_export]
+#[cfg(not(feature = "location"))]
 macro_rules! ordered_list {
+    ( <$begin:literal, $end:literal> $($args:tt)* ) => ( ordered_list!($($args)*) );
+
+    ( $start:literal, loose $(, $args:expr)* ) => (
+        Block::OrderedList(
+            $start.parse::<ListStart>().unwrap(), false, vec![ $( $args ),* ],
+            Location::default()
+        )
+    );
+
+    ( $start:literal $(, $args:expr)* ) => (
+        Block::OrderedList(
+            $start.parse::<ListStart>().unwrap(), true, vec![ $( $args ),* ],
+            Location::default()
+        )
+    );
+
+    ( loose $(, $args:expr)* ) => (
+        Block::OrderedList(ListStart::default(), false, vec![ $( $args ),* ], Location::default())
+    );
+
+    ( $($args:expr),* ) => (
+        Block::OrderedList(ListStart::default(), true, vec![ $( $args ),* ], Location::default())
+    )
+}
+
+/// ordered_list!(<begin, end> [start,] [loose,] items...)
+#[macro_export]
+#[cfg(feature = "location")]
+macro_rules! ordered_list {
+    ( <$begin:literal, $end:literal> $start:literal, loose $(, $args:expr)* ) => (
+        Block::OrderedList(
+            $start.parse::<ListStart>().unwrap(), false, vec![ $( $args ),* ],
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    );
+
+    ( <$begin:literal, $end:literal> $start:literal $(, $args:expr)* ) => (
+        Block::OrderedList(
+            $start.parse::<ListStart>().unwrap(), true, vec![ $( $args ),* ],
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    );
+
+    ( <$begin:literal, $end:literal> loose $(, $args:expr)* ) => (
+        Block::OrderedList(
+            ListStart::default(), false, vec![ $( $args ),* ],
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    );
+
+    ( <$begin:literal, $end:literal> $($args:expr),* ) => (
+        Block::OrderedList(
+            ListStart::default(), true, vec![ $( $args ),* ],
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    )
+}
+
+/// unordered_list!(bullet, [loose,] items...)
+#[macro_export]
+#[cfg(not(feature = "location"))]
+macro_rules! unordered_list {
     (
-        $start:literal, $($args:expr),*
+        $( <$begin:literal, $end:literal> )? $bullet:literal, loose $(, $args:expr)*
     ) => (
-        Block::OrderedList($start.to_string(), vec![ $( $args ),* ])
+        Block::UnorderedList($bullet, false, vec![ $( $args ),* ], Location::default())
     );
 
     (
-        $($args:expr),*
+        $( <$begin:literal, $end:literal> )? $bullet:literal $(, $args:expr)*
     ) => (
-        Block::OrderedList("1".to_string(), vec![ $( $args ),* ])
+        Block::UnorderedList($bullet, true, vec![ $( $args ),* ], Location::default())
     )
 }
 
+/// unordered_list!(<begin, end> bullet, [loose,] items...)
 #[macro_export]
+#[cfg(feature = "location")]
 macro_rules! unordered_list {
-    ( $($args:expr),* ) => ( Block::UnorderedList( vec![ $( $args ),* ] ) )
+    ( <$begin:literal, $end:literal> $bullet:literal, loose $(, $args:expr)* ) => (
+        Block::UnorderedList(
+            $bullet, false, vec![ $( $args ),* ],
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    );
+
+    ( <$begin:literal, $end:literal> $bullet:literal $(, $args:expr)* ) => (
+        Block::UnorderedList(
+            $bullet, true, vec![ $( $args ),* ],
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    )
+}
+
+/// definition_list!(<begin, end> (term, definitions)...)
+#[macro_export]
+#[cfg(not(feature = "location"))]
+macro_rules! definition_list {
+    ( <$begin:literal, $end:literal> $($args:expr),* $(,)? ) => ( definition_list!($($args),*) );
+
+    ( $($args:expr),* $(,)? ) => (
+        Block::DefinitionList( vec![ $( $args ),* ], Location::default() )
+    );
+}
+
+/// definition_list!(<begin, end> (term, definitions)...)
+#[macro_export]
+#[cfg(feature = "location")]
+macro_rules! definition_list {
+    ( <$begin:literal, $end:literal> $($args:expr),* $(,)? ) => (
+        Block::DefinitionList(
+            vec![ $( $args ),* ], Location { begin: $begin.into(), end: $end.into() }
+        )
+    )
+}
+
+/// table!([alignments...], [header cells...], [row cells...]...)
+///
+/// Each cell is a list of inline elements `[plain!("a"), ...]`.
+#[macro_export]
+#[cfg(not(feature = "location"))]
+macro_rules! table {
+    (
+        $( <$begin:literal, $end:literal> )? [$($align:ident),*],
+        [ $( [$($head:expr),*] ),* ] $(, [ $( [$($cell:expr),*] ),* ] )*
+    ) => (
+        Block::Table(
+            vec![ $( Alignment::$align ),* ],
+            vec![ $( vec![ $( $head ),* ] ),* ],
+            vec![ $( vec![ $( vec![ $( $cell ),* ] ),* ] ),* ],
+            Location::default()
+        )
+    )
+}
+
+#[macro_export]
+#[cfg(feature = "location")]
+macro_rules! table {
+    (
+        <$begin:literal, $end:literal> [$($align:ident),*],
+        [ $( [$($head:expr),*] ),* ] $(, [ $( [$($cell:expr),*] ),* ] )*
+    ) => (
+        Block::Table(
+            vec![ $( Alignment::$align ),* ],
+            vec![ $( vec![ $( $head ),* ] ),* ],
+            vec![ $( vec![ $( vec![ $( $cell ),* ] ),* ] ),* ],
+            Location { begin: $begin.into(), end: $end.into() }
+        )
+    )
 }
 
-/// linkdef!(label, url, title)
+/// linkdef!(<begin, end> <label> <url> <title> label, url, title)
 #[cfg(not(feature = "location"))]
 #[macro_export]
 macro_rules! linkdef {
-    ( $( <$begin:literal, $end:literal> )? $label:literal, $url:literal ) => (
+    ( $( <$begin:literal, $end:literal> )* $label:literal, $url:literal ) => (
         linkdef!($label, $url, "")
     );
 
     (
-        $( <$begin:literal, $end:literal> )?
+        $( <$begin:literal, $end:literal> )*
         $label:literal, $url:literal, $title:literal
     ) => (
         Block::LinkDef(
-            $label.to_string(), $url.to_string(), $title.to_string(), Location::default()
+            $label.to_string(), $url.to_string(), $title.to_string(), Location::default(),
+            LinkParts::default()
         )
     )
 }
 
-/// linkdef!(label, url, title)
+/// linkdef!(<begin, end> <label> <url> <title> label, url, title)
 #[cfg(feature = "location")]
 #[macro_export]
 macro_rules! linkdef {
-    ( <$begin:literal, $end:literal> $label:literal, $url:literal ) => (
-        linkdef!(<$begin, $end> $label, $url, "")
-    );
-
     (
-        <$begin:literal, $end:literal> $label:literal, $url:literal, $title:literal
+        <$begin:literal, $end:literal> <$lb:literal, $le:literal> <$ub:literal, $ue:literal>
+        <$tb:literal, $te:literal> $label:literal, $url:literal $(, $title:literal)?
     ) => (
         Block::LinkDef(
-            $label.to_string(), $url.to_string(), $title.to_string(),
+            $label.to_string(), $url.to_string(), String::new() $( + $title )?,
             Location { begin: $begin.into(), end: $end.into() },
+            LinkParts {
+                label: Location { begin: $lb.into(), end: $le.into() },
+                url: Location { begin: $ub.into(), end: $ue.into() },
+                title: Location { begin: $tb.into(), end: $te.into() },
+            }
         )
     )
 }
@@ -198,135 +512,226 @@ macro_rules! linkdef {
 //
 // Helper macros for inline level
 //
-pub use Inline::{HardBreak, SoftBreak};
+loc_arg!(soft_break, Inline::SoftBreak);
+loc_arg!(hard_break, Inline::HardBreak);
 
 str_arg_loc!(plain, Inline::Text);
 str_arg_loc!(code, Inline::Code);
 str_arg_loc!(html, Inline::Html);
-vec_arg!(emph, Inline::Emph);
-vec_arg!(strong, Inline::Strong);
+vec_arg_loc!(emph, Inline::Emph);
+vec_arg_loc!(strong, Inline::Strong);
+vec_arg_loc!(del, Inline::Del);
+vec_arg_loc!(sup, Inline::Sup);
+vec_arg_loc!(sub, Inline::Sub);
+vec_arg_loc!(mark, Inline::Mark);
+vec_arg_loc!(ins, Inline::Ins);
+
+/// checkbox!(checked)
+#[macro_export]
+#[cfg(not(feature = "location"))]
+macro_rules! checkbox {
+    ( $( <$begin:literal, $end:literal> )? $checked:literal ) => (
+        Inline::Checkbox($checked, Location::default())
+    )
+}
+
+#[macro_export]
+#[cfg(feature = "location")]
+macro_rules! checkbox {
+    ( <$begin:literal, $end:literal> $checked:literal ) => (
+        Inline::Checkbox($checked, Location { begin: $begin.into(), end: $end.into() })
+    )
+}
+
+/// incomplete!(element)
+#[macro_export]
+macro_rules! incomplete {
+    ( $el:expr ) => ( Inline::Incomplete(Box::new($el)) )
+}
 
 str_arg_loc!(emb_block, Inline::EmbeddedBlock);
 str_arg_loc!(emb_expr, Inline::EmbeddedExpr);
 
-/// link!(url, title, inner_content...)
+/// link!(<begin, end> <label> <url> <title> url, title, [[attributes],] inner_content...)
 #[cfg(not(feature = "location"))]
 #[macro_export]
 macro_rules! link {
     (
-        $( <$begin:literal, $end:literal> )?
-        $url:literal, $title:literal $( , $args:expr)*
+        $( <$begin:literal, $end:literal> )*
+        $url:literal, $title:literal, [$attrs:expr] $( , $args:expr)*
     ) => (
         Inline::Link(
-            vec![ $( $args ),* ], $url.to_string(), $title.to_string(),
-            Location::default()
+            vec![ $( $args ),* ], $url.to_string(), $title.to_string(), $attrs,
+            Location::default(), LinkParts::default()
         )
+    );
+
+    ( $( <$begin:literal, $end:literal> )* $url:literal, $title:literal $( , $args:expr)* ) => (
+        link!($url, $title, [Attributes::default()] $( , $args)*)
     )
 }
 
-/// link!(url, title, inner_content...)
+/// link!(<begin, end> <label> <url> <title> url, title, [[attributes],] inner_content...)
 #[cfg(feature = "location")]
 #[macro_export]
 macro_rules! link {
-    ( <$begin:literal, $end:literal> $url:literal, $title:literal $( , $args:expr)* ) => (
+    (
+        <$begin:literal, $end:literal> <$lb:literal, $le:literal> <$ub:literal, $ue:literal>
+        <$tb:literal, $te:literal> $url:literal, $title:literal, [$attrs:expr] $( , $args:expr)*
+    ) => (
         Inline::Link(
-            vec![ $( $args ),* ], $url.to_string(), $title.to_string(),
+            vec![ $( $args ),* ], $url.to_string(), $title.to_string(), $attrs,
             Location { begin: $begin.into(), end: $end.into() },
+            LinkParts {
+                label: Location { begin: $lb.into(), end: $le.into() },
+                url: Location { begin: $ub.into(), end: $ue.into() },
+                title: Location { begin: $tb.into(), end: $te.into() },
+            },
+        )
+    );
+
+    (
+        <$begin:literal, $end:literal> <$lb:literal, $le:literal> <$ub:literal, $ue:literal>
+        <$tb:literal, $te:literal> $url:literal, $title:literal $( , $args:expr)*
+    ) => (
+        link!(
+            <$begin, $end> <$lb, $le> <$ub, $ue> <$tb, $te>
+            $url, $title, [Attributes::default()] $( , $args)*
         )
     )
 }
 
-/// linkref!([label,] text...)
+/// linkref!(<begin, end> <label> [label,] text...)
 #[cfg(not(feature = "location"))]
 #[macro_export]
 macro_rules! linkref {
-    (
-        $( <$begin:literal, $end:literal> )? $label:literal, $($text:expr),*
-    ) => (
-        Inline::LinkRef(vec![ $( $text ),* ], $label.to_string(), Location::default())
+    ( <$begin:literal, $end:literal> <$lb:literal, $le:literal> $($text:tt)* ) => (
+        linkref!($($text)*)
     );
 
-    ( <$begin:literal, $end:literal> $($text:expr),* ) => ( linkref!("",  $($text),*) );
+    ( $label:literal, $($text:expr),* ) => (
+        Inline::LinkRef(
+            vec![ $( $text ),* ], $label.to_string(), Location::default(), LinkParts::default()
+        )
+    );
 
-    ( $($text:expr),* ) => ( linkref!("",  $($text),*) );
+    ( $($text:expr),* ) => ( linkref!("", $($text),*) );
 }
 
-/// linkref!([label,] text...)
+/// linkref!(<begin, end> <label> [label,] text...)
+///
+/// The URL and title of a reference are empty at its end.
 #[cfg(feature = "location")]
 #[macro_export]
 macro_rules! linkref {
-    ( <$begin:literal, $end:literal> $label:literal, $($text:expr),* ) => (
+    (
+        <$begin:literal, $end:literal> <$lb:literal, $le:literal> $label:literal,
+        $($text:expr),*
+    ) => (
         Inline::LinkRef(
             vec![ $( $text ),* ], $label.to_string(),
             Location { begin: $begin.into(), end: $end.into() },
+            LinkParts {
+                label: Location { begin: $lb.into(), end: $le.into() },
+                url: Location { begin: $end.into(), end: $end.into() },
+                title: Location { begin: $end.into(), end: $end.into() },
+            },
         )
     );
 
-    ( <$begin:literal, $end:literal> $($text:expr),* ) => (
-        Inline::LinkRef(
-            vec![ $( $text ),* ], String::new(),
-            Location { begin: $begin.into(), end: $end.into() },
-        )
+    ( <$begin:literal, $end:literal> <$lb:literal, $le:literal> $($text:expr),* ) => (
+        linkref!(<$begin, $end> <$lb, $le> "", $($text),*)
     )
 }
 
-/// image!(url, title, inner_content...)
+/// image!(<begin, end> <label> <url> <title> url, title, [[attributes],] inner_content...)
 #[cfg(not(feature = "location"))]
 #[macro_export]
 macro_rules! image {
     (
-        $( <$begin:literal, $end:literal> )?
-        $url:literal, $title:literal $( , $args:expr)*
+        $( <$begin:literal, $end:literal> )*
+        $url:literal, $title:literal, [$attrs:expr] $( , $args:expr)*
     ) => (
         Inline::Image(
-            vec![ $( $args ),* ], $url.to_string(), $title.to_string(),
-            Location::default()
+            vec![ $( $args ),* ], $url.to_string(), $title.to_string(), $attrs,
+            Location::default(), LinkParts::default()
         )
+    );
+
+    ( $( <$begin:literal, $end:literal> )* $url:literal, $title:literal $( , $args:expr)* ) => (
+        image!($url, $title, [Attributes::default()] $( , $args)*)
     )
 }
 
-/// image!(url, title, inner_content...)
+/// image!(<begin, end> <label> <url> <title> url, title, [[attributes],] inner_content...)
 #[cfg(feature = "location")]
 #[macro_export]
 macro_rules! image {
-    ( <$begin:literal, $end:literal> $url:literal, $title:literal $( , $args:expr)* ) => (
+    (
+        <$begin:literal, $end:literal> <$lb:literal, $le:literal> <$ub:literal, $ue:literal>
+        <$tb:literal, $te:literal> $url:literal, $title:literal, [$attrs:expr] $( , $args:expr)*
+    ) => (
         Inline::Image(
-            vec![ $( $args ),* ], $url.to_string(), $title.to_string(),
+            vec![ $( $args ),* ], $url.to_string(), $title.to_string(), $attrs,
             Location { begin: $begin.into(), end: $end.into() },
+            LinkParts {
+                label: Location { begin: $lb.into(), end: $le.into() },
+                url: Location { begin: $ub.into(), end: $ue.into() },
+                title: Location { begin: $tb.into(), end: $te.into() },
+            },
+        )
+    );
+
+    (
+        <$begin:literal, $end:literal> <$lb:literal, $le:literal> <$ub:literal, $ue:literal>
+        <$tb:literal, $te:literal> $url:literal, $title:literal $( , $args:expr)*
+    ) => (
+        image!(
+            <$begin, $end> <$lb, $le> <$ub, $ue> <$tb, $te>
+            $url, $title, [Attributes::default()] $( , $args)*
         )
     )
 }
 
-/// imageref!([label,] text...)
+/// imageref!(<begin, end> <label> [label,] text...)
 #[cfg(not(feature = "location"))]
 #[macro_export]
 macro_rules! imageref {
-    (
-        $( <$begin:literal, $end:literal> )? $label:literal, $($text:expr),*
-    ) => (
-        Inline::ImageRef(vec![ $( $text ),* ], $label.to_string(), Location::default())
+    ( <$begin:literal, $end:literal> <$lb:literal, $le:literal> $($text:tt)* ) => (
+        imageref!($($text)*)
     );
 
-    ( <$begin:literal, $end:literal> $($text:expr),* ) => ( imageref!("",  $($text),*) );
+    ( $label:literal, $($text:expr),* ) => (
+        Inline::ImageRef(
+            vec![ $( $text ),* ], $label.to_string(), Location::default(), LinkParts::default()
+        )
+    );
 
-    ( $($text:expr),* ) => ( imageref!("",  $($text),*) );
+    ( $($text:expr),* ) => ( imageref!("", $($text),*) );
 }
 
-/// imageref!([label,] text...)
+/// imageref!(<begin, end> <label> [label,] text...)
+///
+/// The URL and title of a reference are empty at its end.
 #[cfg(feature = "location")]
 #[macro_export]
 macro_rules! imageref {
-    ( <$begin:literal, $end:literal> $label:literal, $($text:expr),* ) => (
+    (
+        <$begin:literal, $end:literal> <$lb:literal, $le:literal> $label:literal,
+        $($text:expr),*
+    ) => (
         Inline::ImageRef(
             vec![ $( $text ),* ], $label.to_string(),
             Location { begin: $begin.into(), end: $end.into() },
+            LinkParts {
+                label: Location { begin: $lb.into(), end: $le.into() },
+                url: Location { begin: $end.into(), end: $end.into() },
+                title: Location { begin: $end.into(), end: $end.into() },
+            },
         )
     );
 
-    ( <$begin:literal, $end:literal> $($text:expr),* ) => (
-        Inline::ImageRef(
-            vec![ $( $text ),* ], String::new(),
-            Location { begin: $begin.into(), end: $end.into() },
-        )
+    ( <$begin:literal, $end:literal> <$lb:literal, $le:literal> $($text:expr),* ) => (
+        imageref!(<$begin, $end> <$lb, $le> "", $($text),*)
     )
 }
@@ -12,7 +12,7 @@ macro_rules! par_check {
         #[test]
         fn $name() {
             match paragraph(&mut StringData::new($inp, ParserSettings::Html), false) {
-                Block::Paragraph(ct, _)
+                Block::Paragraph(ct, ..)
                     => assert_eq!(vec![ $( $val ),* ] as Vec<Inline>, ct),
 
                 x => assert_matches!(x, Block::Paragraph(..)),
@@ -41,7 +41,7 @@ macro_rules! par_check {
             );
 
             match par {
-                Block::Paragraph(ct, _)
+                Block::Paragraph(ct, ..)
                     => assert_eq!(vec![ $( $val ),* ] as Vec<Inline>, ct),
 
                 x => assert_matches!(x, Block::Paragraph(..)),
@@ -299,8 +299,8 @@ body_check!(
     "text [foo] text\n\n\
      x[foo]: /wrong\n\n\
      [foo]: /right",
-    paragraph!(plain!("text "), linkref!(plain!("foo")), plain!(" text")),
-    paragraph!(plain!("x"), linkref!(plain!("foo")), plain!(": /wrong")),
+    paragraph!(plain!("text "), linkref!("foo", plain!("foo")), plain!(" text")),
+    paragraph!(plain!("x"), linkref!("foo", plain!("foo")), plain!(": /wrong")),
     linkdef!("foo", "/right")
 );
 
@@ -335,7 +335,7 @@ body_check!(
      [[foo][]](/img)\n\n\
      [foo]: /url",
     paragraph!(link!("/img", "", plain!("[foo]"))),
-    paragraph!(plain!("["), linkref!(plain!("foo")), plain!("](/img)")),
+    paragraph!(plain!("["), linkref!("foo", plain!("foo")), plain!("](/img)")),
     linkdef!("foo", "/url")
 );
 
@@ -0,0 +1,210 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    Attributes,
+    Block,
+    Document,
+    Inline,
+    Location,
+    Visit,
+    VisitMut,
+};
+
+/// Entry of a table of contents created by [`Document::toc`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TocEntry {
+    pub level: u8,
+
+    /// Plain text of the heading
+    pub text: String,
+
+    /// Explicit or generated identifier of the heading
+    pub id: String,
+
+    pub location: Location,
+
+    /// Entries of the headings with a higher level up to the next heading
+    /// with the same or a lower level
+    pub children: Vec<TocEntry>,
+}
+
+/// Creates a slug from *text* like GitHub does for the anchors of headings
+///
+/// The text gets converted to lowercase, all characters except letters,
+/// numbers, `_`, `-` and space get removed and every space gets replaced by
+/// `-`.
+#[must_use]
+pub fn slugify(text: &str) -> String {
+    text.trim()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Concatenates the text of all inline elements
+///
+/// Markup gets dropped, the description of images is used as their text and
+/// breaks become a space.
+#[must_use]
+pub fn inline_text(list: &[Inline]) -> String {
+    struct Text(String);
+
+    impl Visit for Text {
+        fn visit_text(&mut self, text: &str, _loc: &Location) {
+            self.0.push_str(text);
+        }
+
+        fn visit_inline_code(&mut self, code: &str, _loc: &Location) {
+            self.0.push_str(code);
+        }
+
+        fn visit_soft_break(&mut self, _loc: &Location) {
+            self.0.push(' ');
+        }
+
+        fn visit_hard_break(&mut self, _loc: &Location) {
+            self.0.push(' ');
+        }
+    }
+
+    let mut text = Text(String::new());
+    text.visit_inlines(list);
+    text.0
+}
+
+/// Hands out unique identifiers for the headings of a document
+struct Slugger {
+    used: HashSet<String>,
+    counter: HashMap<String, usize>,
+}
+
+impl Slugger {
+    fn new(body: &[Block]) -> Self {
+        let mut used = HashSet::new();
+        for_each_heading(body, &mut |_, _, attrs, _| {
+            if let Some(id) = &attrs.id {
+                used.insert(id.clone());
+            }
+        });
+
+        Self { used, counter: HashMap::new() }
+    }
+
+    fn id(&mut self, ct: &[Inline]) -> String {
+        let slug = slugify(&inline_text(ct));
+
+        loop {
+            let cnt = self.counter.entry(slug.clone()).or_insert(0);
+            let id = if *cnt == 0 { slug.clone() } else { format!("{}-{}", slug, cnt) };
+            *cnt += 1;
+
+            if self.used.insert(id.clone()) {
+                return id;
+            }
+        }
+    }
+}
+
+fn for_each_heading(
+    body: &[Block],
+    fun: &mut impl FnMut(u8, &[Inline], &Attributes, &Location)
+) {
+    struct Headings<F>(F);
+
+    impl<F: FnMut(u8, &[Inline], &Attributes, &Location)> Visit for Headings<F> {
+        fn visit_heading(&mut self, level: u8, ct: &[Inline], attrs: &Attributes, loc: &Location) {
+            (self.0)(level, ct, attrs, loc);
+        }
+    }
+
+    Headings(fun).visit_blocks(body);
+}
+
+fn for_each_heading_mut(body: &mut Vec<Block>, fun: &mut impl FnMut(&[Inline], &mut Attributes)) {
+    struct Headings<F>(F);
+
+    impl<F: FnMut(&[Inline], &mut Attributes)> VisitMut for Headings<F> {
+        fn visit_heading_mut(
+            &mut self, _level: &mut u8, ct: &mut Vec<Inline>, attrs: &mut Attributes,
+            _loc: &mut Location
+        ) {
+            (self.0)(ct, attrs);
+        }
+    }
+
+    Headings(fun).visit_blocks_mut(body);
+}
+
+impl Document {
+    /// Sets the identifier of every [`Block::Heading`] without one
+    ///
+    /// The identifiers get created with [`slugify`] from the text of the
+    /// headings. If an identifier is already in use, a suffix `-1`, `-2`, …
+    /// gets appended. Explicit identifiers, e.g. from attributes `{#id}`, are
+    /// kept.
+    pub fn assign_heading_ids(&mut self) {
+        let mut slugger = Slugger::new(&self.body);
+
+        for_each_heading_mut(&mut self.body, &mut |ct, attrs| {
+            if attrs.id.is_none() {
+                attrs.id = Some(slugger.id(ct));
+            }
+        });
+    }
+
+    /// Creates a table of contents of all headings with a level between
+    /// *min_level* and *max_level*
+    ///
+    /// The identifiers are the same as those set by
+    /// [`assign_heading_ids`](Self::assign_heading_ids).
+    #[must_use]
+    pub fn toc(&self, min_level: u8, max_level: u8) -> Vec<TocEntry> {
+        let mut slugger = Slugger::new(&self.body);
+        let mut toc = Vec::new();
+
+        for_each_heading(&self.body, &mut |level, ct, attrs, loc| {
+            // assign an id even to skipped headings to keep the suffixes in
+            // line with assign_heading_ids
+            let id = attrs.id.clone().unwrap_or_else(|| slugger.id(ct));
+
+            if (min_level..=max_level).contains(&level) {
+                insert_entry(&mut toc, TocEntry {
+                    level,
+                    text: inline_text(ct),
+                    id,
+                    location: loc.clone(),
+                    children: Vec::new(),
+                });
+            }
+        });
+
+        toc
+    }
+}
+
+fn insert_entry(list: &mut Vec<TocEntry>, entry: TocEntry) {
+    match list.last_mut() {
+        Some(last) if last.level < entry.level => insert_entry(&mut last.children, entry),
+        _ => list.push(entry),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugs() {
+        assert_eq!("hello-world", slugify("Hello World"));
+        assert_eq!("whats-new-in-v12", slugify("What's new in v1.2?"));
+        assert_eq!("grüße--welt", slugify("Grüße – Welt"));
+        assert_eq!("snake_case-and-kebab-case", slugify("snake_case and kebab-case"));
+        assert_eq!("", slugify("!!!"));
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
     ParserData,
     ParserSettings,
+    Position,
 };
 
 pub trait Pattern {
@@ -43,6 +44,8 @@ pub trait Tools {
 
     fn looking_at(&self, pat: impl Pattern) -> bool;
     fn expect_char(&mut self, ch: char);
+    /// Goes back to *pos*, which the data had before
+    fn rewind(&mut self, pos: Position);
     fn next(&mut self) -> Option<char>;
     fn skip(&mut self, pat: impl Pattern) -> bool;
     fn skip_newline(&mut self) -> bool;
@@ -80,6 +83,13 @@ impl<T: ParserData + ?Sized> Tools for T {
         self.advance();
     }
 
+    fn rewind(&mut self, pos: Position) {
+        // a position the data had before lies in the input and not in front
+        // of the start of a transaction around the data, so it can go back
+        let res = self.reset(pos);
+        debug_assert!(res.is_ok(), "Can't go back to {pos}");
+    }
+
     fn next(&mut self) -> Option<char> {
         self.advance();
         self.peek()
@@ -1,4 +1,5 @@
 use super::{
+    Diagnostic,
     ParserData,
     ParserSettings,
     Position,
@@ -7,6 +8,9 @@ use super::{
 pub struct Transaction<'a, Data: ParserData>{
     inner: Option<&'a mut Data>,
     start: Position,
+    /// Problems reported within the transaction; they get passed to the
+    /// inner data on commit
+    diagnostics: Vec<(Position, Diagnostic)>,
 }
 
 impl<'a, D: ParserData> Transaction<'a, D> {
@@ -14,11 +18,16 @@ impl<'a, D: ParserData> Transaction<'a, D> {
         Self {
             start: inner.pos(),
             inner: Some(inner),
+            diagnostics: Vec::new(),
         }
     }
 
     pub fn commit(mut self) -> &'a D {
         let inner = self.inner.take().unwrap();
+        for (_, diag) in std::mem::take(&mut self.diagnostics) {
+            inner.report(diag);
+        }
+
         std::mem::forget(self);
         inner
     }
@@ -37,6 +46,10 @@ impl<D: ParserData> ParserData for Transaction<'_, D> {
         self.inner().settings()
     }
 
+    fn admonition_types(&self) -> &[&str] {
+        self.inner().admonition_types()
+    }
+
     fn peek(&self) -> Option<char> {
         self.inner().peek()
     }
@@ -53,24 +66,51 @@ impl<D: ParserData> ParserData for Transaction<'_, D> {
         if pos < self.start {
             Err(())
         } else {
-            self.inner_mut().reset(pos)
+            self.inner_mut().reset(pos)?;
+            self.diagnostics.retain(|(at, _)| *at <= pos);
+            Ok(())
         }
     }
+
+    fn content_end(&self) -> Position {
+        self.inner().content_end()
+    }
+
+    fn column(&self) -> usize {
+        self.inner().column()
+    }
+
+    fn set_paragraph_open(&mut self, open: bool) {
+        self.inner_mut().set_paragraph_open(open);
+    }
+
+    fn lazy_line(&self) -> bool {
+        self.inner().lazy_line()
+    }
+
+    fn report(&mut self, diag: Diagnostic) {
+        let pos = self.pos();
+        self.diagnostics.push((pos, diag));
+    }
+
+    fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.diagnostics.drain(..).map(|(_, diag)| diag).collect()
+    }
 }
 
 impl<D: ParserData> Drop for Transaction<'_, D> {
     fn drop(&mut self) {
-        // TODO: Was soll mit self.inner.messages passieren?
-        if self.inner.as_mut().unwrap().reset(self.start).is_err() {
-            todo!()
-        }
+        // the diagnostics of the transaction get dropped with it; the inner
+        // data was at the start, so it can go back there
+        let start = self.start;
+        let _ = self.inner_mut().reset(start);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use super::super::{StringData, Tools};
+    use super::super::{Location, StringData, Tools};
 
     #[test]
     fn simple_commit() {
@@ -140,4 +180,47 @@ mod tests {
 
         assert_eq!((1, Some('b')), val.pos_peek());
     }
+
+    fn diag(code: &'static str) -> Diagnostic {
+        Diagnostic::warning(code, "", Location { begin: 0.into(), end: 0.into() })
+    }
+
+    #[test]
+    fn diagnostics() {
+        let mut val = StringData::from("abc");
+        let val = &mut val;
+
+        {
+            let mut trans = Transaction::new(val);
+            trans.advance();
+            trans.report(diag("a"));
+        }
+
+        assert_eq!(val.take_diagnostics(), []);
+
+        {
+            let mut trans = Transaction::new(val);
+            trans.advance();
+            trans.report(diag("b"));
+            trans.commit();
+        }
+
+        assert_eq!(val.take_diagnostics().len(), 1);
+    }
+
+    #[test]
+    fn diagnostics_reset() {
+        let mut val = StringData::from("abc");
+        let val = &mut val;
+
+        val.advance();
+        val.report(diag("a"));
+        val.advance();
+        val.report(diag("b"));
+        val.reset(1).unwrap();
+
+        let diags = val.take_diagnostics();
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].code, "a");
+    }
 }
@@ -0,0 +1,651 @@
+use super::{
+    Alignment,
+    Attributes,
+    Block,
+    Definition,
+    Inline,
+    LinkParts,
+    ListItem,
+    ListStart,
+    Location,
+    TableRow,
+};
+
+/// Traversal of the blocks and inline elements of a document
+///
+/// There is a method for every variant of [`Block`] and [`Inline`]; by
+/// default it descends into the children of the node. An overriding method
+/// can call [`visit_blocks`](Self::visit_blocks) or
+/// [`visit_inlines`](Self::visit_inlines) to keep descending.
+#[allow(unused_variables)]
+pub trait Visit {
+    fn visit_blocks(&mut self, list: &[Block]) {
+        walk_blocks(self, list);
+    }
+
+    /// Calls the method of the variant of *blk*
+    fn visit_block(&mut self, blk: &Block) {
+        walk_block(self, blk);
+    }
+
+    fn visit_heading(&mut self, level: u8, content: &[Inline], attrs: &Attributes, loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_paragraph(&mut self, content: &[Inline], attrs: &Attributes, loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_quote(&mut self, content: &[Block], loc: &Location) {
+        self.visit_blocks(content);
+    }
+
+    fn visit_admonition(&mut self, kind: &str, title: &[Inline], content: &[Block], loc: &Location) {
+        self.visit_inlines(title);
+        self.visit_blocks(content);
+    }
+
+    fn visit_code(
+        &mut self, info: &str, content: &str, attrs: &Attributes, loc: &Location,
+        info_loc: &Location
+    ) {}
+
+    fn visit_ordered_list(
+        &mut self, start: &ListStart, tight: bool, items: &[ListItem], loc: &Location
+    ) {
+        for (it, _) in items {
+            self.visit_blocks(it);
+        }
+    }
+
+    fn visit_unordered_list(
+        &mut self, bullet: char, tight: bool, items: &[ListItem], loc: &Location
+    ) {
+        for (it, _) in items {
+            self.visit_blocks(it);
+        }
+    }
+
+    fn visit_definition_list(&mut self, list: &[Definition], loc: &Location) {
+        for (term, defs) in list {
+            self.visit_inlines(term);
+
+            for def in defs {
+                self.visit_blocks(def);
+            }
+        }
+    }
+
+    fn visit_container(
+        &mut self, name: &str, attrs: &Attributes, content: &[Block], loc: &Location
+    ) {
+        self.visit_blocks(content);
+    }
+
+    fn visit_table(
+        &mut self, alignments: &[Alignment], header: &TableRow, rows: &[TableRow], loc: &Location
+    ) {
+        for cell in header.iter().chain(rows.iter().flatten()) {
+            self.visit_inlines(cell);
+        }
+    }
+
+    fn visit_html(&mut self, html: &str, loc: &Location) {}
+
+    fn visit_break(&mut self, loc: &Location) {}
+
+    fn visit_link_def(
+        &mut self, label: &str, url: &str, title: &str, loc: &Location, parts: &LinkParts
+    ) {}
+
+    fn visit_embedded_block(&mut self, code: &str, loc: &Location) {}
+
+    fn visit_embedded_expr(&mut self, expr: &str, loc: &Location) {}
+
+    fn visit_inlines(&mut self, list: &[Inline]) {
+        walk_inlines(self, list);
+    }
+
+    /// Calls the method of the variant of *el*
+    fn visit_inline(&mut self, el: &Inline) {
+        walk_inline(self, el);
+    }
+
+    fn visit_text(&mut self, text: &str, loc: &Location) {}
+
+    fn visit_inline_html(&mut self, html: &str, loc: &Location) {}
+
+    fn visit_inline_code(&mut self, code: &str, loc: &Location) {}
+
+    fn visit_checkbox(&mut self, checked: bool, loc: &Location) {}
+
+    fn visit_soft_break(&mut self, loc: &Location) {}
+
+    fn visit_hard_break(&mut self, loc: &Location) {}
+
+    fn visit_emph(&mut self, content: &[Inline], loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_strong(&mut self, content: &[Inline], loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_del(&mut self, content: &[Inline], loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_sup(&mut self, content: &[Inline], loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_sub(&mut self, content: &[Inline], loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_mark(&mut self, content: &[Inline], loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_ins(&mut self, content: &[Inline], loc: &Location) {
+        self.visit_inlines(content);
+    }
+
+    fn visit_image(
+        &mut self, desc: &[Inline], url: &str, title: &str, attrs: &Attributes, loc: &Location,
+        parts: &LinkParts
+    ) {
+        self.visit_inlines(desc);
+    }
+
+    fn visit_image_ref(
+        &mut self, desc: &[Inline], label: &str, loc: &Location, parts: &LinkParts
+    ) {
+        self.visit_inlines(desc);
+    }
+
+    fn visit_link(
+        &mut self, text: &[Inline], url: &str, title: &str, attrs: &Attributes, loc: &Location,
+        parts: &LinkParts
+    ) {
+        self.visit_inlines(text);
+    }
+
+    fn visit_link_ref(
+        &mut self, text: &[Inline], label: &str, loc: &Location, parts: &LinkParts
+    ) {
+        self.visit_inlines(text);
+    }
+
+    fn visit_inline_embedded_block(&mut self, code: &str, loc: &Location) {}
+
+    fn visit_inline_embedded_expr(&mut self, expr: &str, loc: &Location) {}
+
+    fn visit_incomplete(&mut self, el: &Inline) {
+        self.visit_inline(el);
+    }
+}
+
+pub fn walk_blocks<V: Visit + ?Sized>(v: &mut V, list: &[Block]) {
+    for blk in list {
+        v.visit_block(blk);
+    }
+}
+
+pub fn walk_block<V: Visit + ?Sized>(v: &mut V, blk: &Block) {
+    match blk {
+        Block::Heading(level, ct, attrs, loc) => v.visit_heading(*level, ct, attrs, loc),
+        Block::Paragraph(ct, attrs, loc) => v.visit_paragraph(ct, attrs, loc),
+        Block::Quote(ct, loc) => v.visit_quote(ct, loc),
+        Block::Admonition(kind, title, ct, loc) => v.visit_admonition(kind, title, ct, loc),
+        Block::Code(info, ct, attrs, loc, info_loc)
+            => v.visit_code(info, ct, attrs, loc, info_loc),
+
+        Block::OrderedList(start, tight, items, loc)
+            => v.visit_ordered_list(start, *tight, items, loc),
+
+        Block::UnorderedList(bullet, tight, items, loc)
+            => v.visit_unordered_list(*bullet, *tight, items, loc),
+
+        Block::DefinitionList(list, loc) => v.visit_definition_list(list, loc),
+
+        Block::Container { name, attributes, content, location }
+            => v.visit_container(name, attributes, content, location),
+
+        Block::Table(alignments, header, rows, loc)
+            => v.visit_table(alignments, header, rows, loc),
+
+        Block::Html(html, loc) => v.visit_html(html, loc),
+        Block::Break(loc) => v.visit_break(loc),
+        Block::LinkDef(label, url, title, loc, parts)
+            => v.visit_link_def(label, url, title, loc, parts),
+        Block::EmbeddedBlock(code, loc) => v.visit_embedded_block(code, loc),
+        Block::EmbeddedExpr(expr, loc) => v.visit_embedded_expr(expr, loc),
+    }
+}
+
+pub fn walk_inlines<V: Visit + ?Sized>(v: &mut V, list: &[Inline]) {
+    for el in list {
+        v.visit_inline(el);
+    }
+}
+
+pub fn walk_inline<V: Visit + ?Sized>(v: &mut V, el: &Inline) {
+    match el {
+        Inline::Text(text, loc) => v.visit_text(text, loc),
+        Inline::Html(html, loc) => v.visit_inline_html(html, loc),
+        Inline::Code(code, loc) => v.visit_inline_code(code, loc),
+        Inline::Checkbox(checked, loc) => v.visit_checkbox(*checked, loc),
+        Inline::SoftBreak(loc) => v.visit_soft_break(loc),
+        Inline::HardBreak(loc) => v.visit_hard_break(loc),
+        Inline::Emph(ct, loc) => v.visit_emph(ct, loc),
+        Inline::Strong(ct, loc) => v.visit_strong(ct, loc),
+        Inline::Del(ct, loc) => v.visit_del(ct, loc),
+        Inline::Sup(ct, loc) => v.visit_sup(ct, loc),
+        Inline::Sub(ct, loc) => v.visit_sub(ct, loc),
+        Inline::Mark(ct, loc) => v.visit_mark(ct, loc),
+        Inline::Ins(ct, loc) => v.visit_ins(ct, loc),
+
+        Inline::Image(desc, url, title, attrs, loc, parts)
+            => v.visit_image(desc, url, title, attrs, loc, parts),
+
+        Inline::ImageRef(desc, label, loc, parts)
+            => v.visit_image_ref(desc, label, loc, parts),
+
+        Inline::Link(text, url, title, attrs, loc, parts)
+            => v.visit_link(text, url, title, attrs, loc, parts),
+
+        Inline::LinkRef(text, label, loc, parts)
+            => v.visit_link_ref(text, label, loc, parts),
+        Inline::EmbeddedBlock(code, loc) => v.visit_inline_embedded_block(code, loc),
+        Inline::EmbeddedExpr(expr, loc) => v.visit_inline_embedded_expr(expr, loc),
+        Inline::Incomplete(el) => v.visit_incomplete(el),
+    }
+}
+
+/// Traversal like [`Visit`] that can change the nodes in place
+///
+/// The methods for the lists of nodes and for the single nodes get the
+/// whole node, so they can also replace it.
+#[allow(unused_variables)]
+pub trait VisitMut {
+    fn visit_blocks_mut(&mut self, list: &mut Vec<Block>) {
+        walk_blocks_mut(self, list);
+    }
+
+    /// Calls the method of the variant of *blk*
+    fn visit_block_mut(&mut self, blk: &mut Block) {
+        walk_block_mut(self, blk);
+    }
+
+    fn visit_heading_mut(
+        &mut self, level: &mut u8, content: &mut Vec<Inline>, attrs: &mut Attributes,
+        loc: &mut Location
+    ) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_paragraph_mut(
+        &mut self, content: &mut Vec<Inline>, attrs: &mut Attributes, loc: &mut Location
+    ) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_quote_mut(&mut self, content: &mut Vec<Block>, loc: &mut Location) {
+        self.visit_blocks_mut(content);
+    }
+
+    fn visit_admonition_mut(
+        &mut self, kind: &mut String, title: &mut Vec<Inline>, content: &mut Vec<Block>,
+        loc: &mut Location
+    ) {
+        self.visit_inlines_mut(title);
+        self.visit_blocks_mut(content);
+    }
+
+    fn visit_code_mut(
+        &mut self, info: &mut String, content: &mut String, attrs: &mut Attributes,
+        loc: &mut Location, info_loc: &mut Location
+    ) {}
+
+    fn visit_ordered_list_mut(
+        &mut self, start: &mut ListStart, tight: &mut bool, items: &mut Vec<ListItem>,
+        loc: &mut Location
+    ) {
+        for (it, _) in items {
+            self.visit_blocks_mut(it);
+        }
+    }
+
+    fn visit_unordered_list_mut(
+        &mut self, bullet: &mut char, tight: &mut bool, items: &mut Vec<ListItem>,
+        loc: &mut Location
+    ) {
+        for (it, _) in items {
+            self.visit_blocks_mut(it);
+        }
+    }
+
+    fn visit_definition_list_mut(&mut self, list: &mut Vec<Definition>, loc: &mut Location) {
+        for (term, defs) in list {
+            self.visit_inlines_mut(term);
+
+            for def in defs {
+                self.visit_blocks_mut(def);
+            }
+        }
+    }
+
+    fn visit_container_mut(
+        &mut self, name: &mut String, attrs: &mut Attributes, content: &mut Vec<Block>,
+        loc: &mut Location
+    ) {
+        self.visit_blocks_mut(content);
+    }
+
+    fn visit_table_mut(
+        &mut self, alignments: &mut Vec<Alignment>, header: &mut TableRow,
+        rows: &mut Vec<TableRow>, loc: &mut Location
+    ) {
+        for cell in header.iter_mut().chain(rows.iter_mut().flatten()) {
+            self.visit_inlines_mut(cell);
+        }
+    }
+
+    fn visit_html_mut(&mut self, html: &mut String, loc: &mut Location) {}
+
+    fn visit_break_mut(&mut self, loc: &mut Location) {}
+
+    fn visit_link_def_mut(
+        &mut self, label: &mut String, url: &mut String, title: &mut String, loc: &mut Location,
+        parts: &mut LinkParts
+    ) {}
+
+    fn visit_embedded_block_mut(&mut self, code: &mut String, loc: &mut Location) {}
+
+    fn visit_embedded_expr_mut(&mut self, expr: &mut String, loc: &mut Location) {}
+
+    fn visit_inlines_mut(&mut self, list: &mut Vec<Inline>) {
+        walk_inlines_mut(self, list);
+    }
+
+    /// Calls the method of the variant of *el*
+    fn visit_inline_mut(&mut self, el: &mut Inline) {
+        walk_inline_mut(self, el);
+    }
+
+    fn visit_text_mut(&mut self, text: &mut String, loc: &mut Location) {}
+
+    fn visit_inline_html_mut(&mut self, html: &mut String, loc: &mut Location) {}
+
+    fn visit_inline_code_mut(&mut self, code: &mut String, loc: &mut Location) {}
+
+    fn visit_checkbox_mut(&mut self, checked: &mut bool, loc: &mut Location) {}
+
+    fn visit_soft_break_mut(&mut self, loc: &mut Location) {}
+
+    fn visit_hard_break_mut(&mut self, loc: &mut Location) {}
+
+    fn visit_emph_mut(&mut self, content: &mut Vec<Inline>, loc: &mut Location) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_strong_mut(&mut self, content: &mut Vec<Inline>, loc: &mut Location) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_del_mut(&mut self, content: &mut Vec<Inline>, loc: &mut Location) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_sup_mut(&mut self, content: &mut Vec<Inline>, loc: &mut Location) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_sub_mut(&mut self, content: &mut Vec<Inline>, loc: &mut Location) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_mark_mut(&mut self, content: &mut Vec<Inline>, loc: &mut Location) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_ins_mut(&mut self, content: &mut Vec<Inline>, loc: &mut Location) {
+        self.visit_inlines_mut(content);
+    }
+
+    fn visit_image_mut(
+        &mut self, desc: &mut Vec<Inline>, url: &mut String, title: &mut String,
+        attrs: &mut Attributes, loc: &mut Location, parts: &mut LinkParts
+    ) {
+        self.visit_inlines_mut(desc);
+    }
+
+    fn visit_image_ref_mut(
+        &mut self, desc: &mut Vec<Inline>, label: &mut String, loc: &mut Location,
+        parts: &mut LinkParts
+    ) {
+        self.visit_inlines_mut(desc);
+    }
+
+    fn visit_link_mut(
+        &mut self, text: &mut Vec<Inline>, url: &mut String, title: &mut String,
+        attrs: &mut Attributes, loc: &mut Location, parts: &mut LinkParts
+    ) {
+        self.visit_inlines_mut(text);
+    }
+
+    fn visit_link_ref_mut(
+        &mut self, text: &mut Vec<Inline>, label: &mut String, loc: &mut Location,
+        parts: &mut LinkParts
+    ) {
+        self.visit_inlines_mut(text);
+    }
+
+    fn visit_inline_embedded_block_mut(&mut self, code: &mut String, loc: &mut Location) {}
+
+    fn visit_inline_embedded_expr_mut(&mut self, expr: &mut String, loc: &mut Location) {}
+
+    fn visit_incomplete_mut(&mut self, el: &mut Inline) {
+        self.visit_inline_mut(el);
+    }
+}
+
+pub fn walk_blocks_mut<V: VisitMut + ?Sized>(v: &mut V, list: &mut [Block]) {
+    for blk in list {
+        v.visit_block_mut(blk);
+    }
+}
+
+pub fn walk_block_mut<V: VisitMut + ?Sized>(v: &mut V, blk: &mut Block) {
+    match blk {
+        Block::Heading(level, ct, attrs, loc) => v.visit_heading_mut(level, ct, attrs, loc),
+        Block::Paragraph(ct, attrs, loc) => v.visit_paragraph_mut(ct, attrs, loc),
+        Block::Quote(ct, loc) => v.visit_quote_mut(ct, loc),
+        Block::Admonition(kind, title, ct, loc) => v.visit_admonition_mut(kind, title, ct, loc),
+        Block::Code(info, ct, attrs, loc, info_loc)
+            => v.visit_code_mut(info, ct, attrs, loc, info_loc),
+
+        Block::OrderedList(start, tight, items, loc)
+            => v.visit_ordered_list_mut(start, tight, items, loc),
+
+        Block::UnorderedList(bullet, tight, items, loc)
+            => v.visit_unordered_list_mut(bullet, tight, items, loc),
+
+        Block::DefinitionList(list, loc) => v.visit_definition_list_mut(list, loc),
+
+        Block::Container { name, attributes, content, location }
+            => v.visit_container_mut(name, attributes, content, location),
+
+        Block::Table(alignments, header, rows, loc)
+            => v.visit_table_mut(alignments, header, rows, loc),
+
+        Block::Html(html, loc) => v.visit_html_mut(html, loc),
+        Block::Break(loc) => v.visit_break_mut(loc),
+        Block::LinkDef(label, url, title, loc, parts)
+            => v.visit_link_def_mut(label, url, title, loc, parts),
+        Block::EmbeddedBlock(code, loc) => v.visit_embedded_block_mut(code, loc),
+        Block::EmbeddedExpr(expr, loc) => v.visit_embedded_expr_mut(expr, loc),
+    }
+}
+
+pub fn walk_inlines_mut<V: VisitMut + ?Sized>(v: &mut V, list: &mut [Inline]) {
+    for el in list {
+        v.visit_inline_mut(el);
+    }
+}
+
+pub fn walk_inline_mut<V: VisitMut + ?Sized>(v: &mut V, el: &mut Inline) {
+    match el {
+        Inline::Text(text, loc) => v.visit_text_mut(text, loc),
+        Inline::Html(html, loc) => v.visit_inline_html_mut(html, loc),
+        Inline::Code(code, loc) => v.visit_inline_code_mut(code, loc),
+        Inline::Checkbox(checked, loc) => v.visit_checkbox_mut(checked, loc),
+        Inline::SoftBreak(loc) => v.visit_soft_break_mut(loc),
+        Inline::HardBreak(loc) => v.visit_hard_break_mut(loc),
+        Inline::Emph(ct, loc) => v.visit_emph_mut(ct, loc),
+        Inline::Strong(ct, loc) => v.visit_strong_mut(ct, loc),
+        Inline::Del(ct, loc) => v.visit_del_mut(ct, loc),
+        Inline::Sup(ct, loc) => v.visit_sup_mut(ct, loc),
+        Inline::Sub(ct, loc) => v.visit_sub_mut(ct, loc),
+        Inline::Mark(ct, loc) => v.visit_mark_mut(ct, loc),
+        Inline::Ins(ct, loc) => v.visit_ins_mut(ct, loc),
+
+        Inline::Image(desc, url, title, attrs, loc, parts)
+            => v.visit_image_mut(desc, url, title, attrs, loc, parts),
+
+        Inline::ImageRef(desc, label, loc, parts)
+            => v.visit_image_ref_mut(desc, label, loc, parts),
+
+        Inline::Link(text, url, title, attrs, loc, parts)
+            => v.visit_link_mut(text, url, title, attrs, loc, parts),
+
+        Inline::LinkRef(text, label, loc, parts)
+            => v.visit_link_ref_mut(text, label, loc, parts),
+        Inline::EmbeddedBlock(code, loc) => v.visit_inline_embedded_block_mut(code, loc),
+        Inline::EmbeddedExpr(expr, loc) => v.visit_inline_embedded_expr_mut(expr, loc),
+        Inline::Incomplete(el) => v.visit_incomplete_mut(el),
+    }
+}
+
+/// Transformation of the blocks and inline elements of a document that
+/// replaces every node with zero or more nodes
+///
+/// By default the children of a node get folded and the node is kept. An
+/// overriding method can call [`fold_block_content`] or
+/// [`fold_inline_content`] to fold the children, too.
+pub trait Fold {
+    fn fold_block(&mut self, blk: Block) -> Vec<Block> {
+        vec![fold_block_content(self, blk)]
+    }
+
+    fn fold_inline(&mut self, el: Inline) -> Vec<Inline> {
+        fold_inline_content(self, el)
+    }
+}
+
+pub fn fold_blocks<F: Fold + ?Sized>(f: &mut F, list: Vec<Block>) -> Vec<Block> {
+    list.into_iter().flat_map(|blk| f.fold_block(blk)).collect()
+}
+
+pub fn fold_inlines<F: Fold + ?Sized>(f: &mut F, list: Vec<Inline>) -> Vec<Inline> {
+    list.into_iter().flat_map(|el| f.fold_inline(el)).collect()
+}
+
+fn fold_items<F: Fold + ?Sized>(f: &mut F, items: Vec<ListItem>) -> Vec<ListItem> {
+    items.into_iter().map(|(it, loc)| (fold_blocks(f, it), loc)).collect()
+}
+
+fn fold_row<F: Fold + ?Sized>(f: &mut F, row: TableRow) -> TableRow {
+    row.into_iter().map(|cell| fold_inlines(f, cell)).collect()
+}
+
+/// Folds the children of *blk*
+pub fn fold_block_content<F: Fold + ?Sized>(f: &mut F, blk: Block) -> Block {
+    match blk {
+        Block::Heading(level, ct, attrs, loc)
+            => Block::Heading(level, fold_inlines(f, ct), attrs, loc),
+
+        Block::Paragraph(ct, attrs, loc) => Block::Paragraph(fold_inlines(f, ct), attrs, loc),
+        Block::Quote(ct, loc) => Block::Quote(fold_blocks(f, ct), loc),
+
+        Block::Admonition(kind, title, ct, loc) => {
+            let title = fold_inlines(f, title);
+            Block::Admonition(kind, title, fold_blocks(f, ct), loc)
+        }
+
+        Block::OrderedList(start, tight, items, loc)
+            => Block::OrderedList(start, tight, fold_items(f, items), loc),
+
+        Block::UnorderedList(bullet, tight, items, loc)
+            => Block::UnorderedList(bullet, tight, fold_items(f, items), loc),
+
+        Block::DefinitionList(list, loc) => Block::DefinitionList(
+            list.into_iter()
+                .map(|(term, defs)| {
+                    let term = fold_inlines(f, term);
+                    (term, defs.into_iter().map(|def| fold_blocks(f, def)).collect())
+                })
+                .collect(),
+            loc
+        ),
+
+        Block::Container { name, attributes, content, location } => Block::Container {
+            name,
+            attributes,
+            content: fold_blocks(f, content),
+            location,
+        },
+
+        Block::Table(alignments, header, rows, loc) => {
+            let header = fold_row(f, header);
+            let rows = rows.into_iter().map(|row| fold_row(f, row)).collect();
+            Block::Table(alignments, header, rows, loc)
+        }
+
+        Block::Code(..) | Block::Html(..) | Block::Break(..) | Block::LinkDef(..)
+            | Block::EmbeddedBlock(..) | Block::EmbeddedExpr(..) => blk,
+    }
+}
+
+/// Folds the children of *el*
+///
+/// The element of [`Inline::Incomplete`] gets folded like a child; if it's
+/// replaced by several elements, only the last one stays incomplete.
+pub fn fold_inline_content<F: Fold + ?Sized>(f: &mut F, el: Inline) -> Vec<Inline> {
+    let el = match el {
+        Inline::Emph(ct, loc) => Inline::Emph(fold_inlines(f, ct), loc),
+        Inline::Strong(ct, loc) => Inline::Strong(fold_inlines(f, ct), loc),
+        Inline::Del(ct, loc) => Inline::Del(fold_inlines(f, ct), loc),
+        Inline::Sup(ct, loc) => Inline::Sup(fold_inlines(f, ct), loc),
+        Inline::Sub(ct, loc) => Inline::Sub(fold_inlines(f, ct), loc),
+        Inline::Mark(ct, loc) => Inline::Mark(fold_inlines(f, ct), loc),
+        Inline::Ins(ct, loc) => Inline::Ins(fold_inlines(f, ct), loc),
+
+        Inline::Image(desc, url, title, attrs, loc, parts)
+            => Inline::Image(fold_inlines(f, desc), url, title, attrs, loc, parts),
+
+        Inline::ImageRef(desc, label, loc, parts)
+            => Inline::ImageRef(fold_inlines(f, desc), label, loc, parts),
+
+        Inline::Link(text, url, title, attrs, loc, parts)
+            => Inline::Link(fold_inlines(f, text), url, title, attrs, loc, parts),
+
+        Inline::LinkRef(text, label, loc, parts)
+            => Inline::LinkRef(fold_inlines(f, text), label, loc, parts),
+
+        Inline::Incomplete(el) => {
+            let mut list = f.fold_inline(*el);
+            if let Some(last) = list.pop() {
+                list.push(Inline::Incomplete(Box::new(last)));
+            }
+            return list;
+        }
+
+        Inline::Text(..) | Inline::Html(..) | Inline::Code(..) | Inline::Checkbox(..)
+            | Inline::SoftBreak(..) | Inline::HardBreak(..)
+            | Inline::EmbeddedBlock(..) | Inline::EmbeddedExpr(..) => el,
+    };
+
+    vec![el]
+}
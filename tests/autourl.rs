@@ -22,14 +22,14 @@ par_check!(
 
 par_check!(
     short_scheme, "<xy://example.org>",
-    link!(<0, 0> "xy://example.org", "", plain!(<1, 17> "xy://example.org"))
+    link!(<0, 18> <1, 17> <1, 17> <17, 17> "xy://example.org", "", plain!(<1, 17> "xy://example.org"))
 );
 
 par_check!(
     long_scheme,
     "<abcdefghijklmnopqrstuvwxyzABCDEF://example.org>",
     link!(
-        <0, 0>
+        <0, 48> <1, 47> <1, 47> <47, 47>
         "abcdefghijklmnopqrstuvwxyzABCDEF://example.org",
         "",
         plain!(<1, 47> "abcdefghijklmnopqrstuvwxyzABCDEF://example.org")
@@ -41,3 +41,95 @@ par_check!(
     "<abcdefghijklmnopqrstuvwxyzABCDEFG://example.org>",
     plain!(<0, 49> "<abcdefghijklmnopqrstuvwxyzABCDEFG://example.org>")
 );
+
+mod extended {
+    use super::*;
+
+    par_check!(
+        disabled, "see www.commonmark.org", plain!(<0, 22> "see www.commonmark.org")
+    );
+
+    par_check!(
+        www,
+        ParserSettings::ExtendedAutolinks,
+        "Visit www.commonmark.org/help.",
+        plain!(<0, 6> "Visit "),
+        link!(
+            <6, 29> <6, 29> <6, 29> <29, 29> "http://www.commonmark.org/help", "",
+            plain!(<6, 29> "www.commonmark.org/help")
+        ),
+        plain!(<29, 30> ".")
+    );
+
+    par_check!(
+        https_with_parens,
+        ParserSettings::ExtendedAutolinks,
+        "(https://en.wikipedia.org/wiki/Rust_(language)))",
+        plain!(<0, 1> "("),
+        link!(
+            <1, 46> <1, 46> <1, 46> <46, 46> "https://en.wikipedia.org/wiki/Rust_(language)", "",
+            plain!(<1, 46> "https://en.wikipedia.org/wiki/Rust_(language)")
+        ),
+        plain!(<46, 48> "))")
+    );
+
+    par_check!(
+        entity_at_end,
+        ParserSettings::ExtendedAutolinks,
+        "www.google.com/search?q=commonmark&hl;",
+        link!(
+            <0, 34> <0, 34> <0, 34> <34, 34> "http://www.google.com/search?q=commonmark", "",
+            plain!(<0, 34> "www.google.com/search?q=commonmark")
+        ),
+        plain!(<34, 38> "&hl;")
+    );
+
+    par_check!(
+        email,
+        ParserSettings::ExtendedAutolinks,
+        "Mail foo.bar+baz@example.com.",
+        plain!(<0, 5> "Mail "),
+        link!(
+            <5, 28> <5, 28> <5, 28> <28, 28> "mailto:foo.bar+baz@example.com", "",
+            plain!(<5, 28> "foo.bar+baz@example.com")
+        ),
+        plain!(<28, 29> ".")
+    );
+
+    par_check!(
+        no_match,
+        ParserSettings::ExtendedAutolinks,
+        "awww.example.com www. a@b a@b.c_ https://",
+        plain!(<0, 41> "awww.example.com www. a@b a@b.c_ https://")
+    );
+
+    par_check!(
+        not_in_code_or_link,
+        ParserSettings::ExtendedAutolinks,
+        "`www.a.com` [www.b.com](/x)",
+        code!(<0, 11> "www.a.com"),
+        plain!(<11, 12> " "),
+        link!(<12, 27> <13, 22> <24, 26> <26, 26> "/x", "", plain!(<13, 22> "www.b.com"))
+    );
+
+    body_check!(
+        after_soft_break,
+        ParserSettings::ExtendedAutolinks,
+        ("text", "https://example.org/"),
+        paragraph!(
+            <0, 25>
+            plain!(<0, 4> "text"),
+            soft_break!(<4, 5>),
+            link!(
+                <5, 25> <5, 25> <5, 25> <25, 25> "https://example.org/", "", plain!(<5, 25> "https://example.org/")
+            )
+        )
+    );
+
+    par_check!(
+        inside_emph,
+        ParserSettings::ExtendedAutolinks,
+        "*www.a.com*",
+        emph!(<0, 11> link!(<1, 10> <1, 10> <1, 10> <10, 10> "http://www.a.com", "", plain!(<1, 10> "www.a.com")))
+    );
+}
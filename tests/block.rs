@@ -7,11 +7,10 @@ use common::*;
 cm_body_check!(
     /// Block structure takes precedence over inline structure
     /// <https://spec.commonmark.org/0.29/#example-61>
-    #[cfg_attr(feature = "location", ignore)]
     code_block_breaks_inline_code,
     "`*Foo*\n```\nnot inline code`\n",
-    paragraph!(<0, 7> plain!(<0, 1> "`"), emph!(plain!(<2, 5> "Foo"))),
-    code_block!(<7, 28> "", "not inline code`\n")
+    paragraph!(<0, 7> plain!(<0, 1> "`"), emph!(<1, 6> plain!(<2, 5> "Foo"))),
+    code_block!(<7, 28> <10, 10> "", "not inline code`\n")
 );
 
 cm_body_check!(
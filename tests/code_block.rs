@@ -6,5 +6,31 @@ use common::*;
 
 body_check!(
     trailing_space_in_fenced_code,
-    "~~~\nline 1   \n~~~", code_block!(<0, 17> "", "line 1   \n")
+    "~~~\nline 1   \n~~~", code_block!(<0, 17> <3, 3> "", "line 1   \n")
 );
+
+mod attributes {
+    use super::*;
+
+    body_check!(
+        info_string,
+        ParserSettings::Attributes,
+        ("``` rust {.numberLines startFrom=10}", "fn main() {}", "```"),
+        code_block!(
+            <0, 53> <4, 8> "rust", [attrs!(."numberLines" "startFrom" = "10")], "fn main() {}\n"
+        )
+    );
+
+    body_check!(
+        only_attributes,
+        ParserSettings::Attributes,
+        ("~~~ {#listing}", "~~~"),
+        code_block!(<0, 18> <4, 4> "", [attrs!(#"listing")], "")
+    );
+
+    body_check!(
+        disabled,
+        "``` rust {.numberLines}\n```",
+        code_block!(<0, 27> <4, 23> "rust {.numberLines}", "")
+    );
+}
@@ -57,7 +57,7 @@ pub macro par_check {
             init!($inp);
 
             match paragraph(&mut StringData::new($inp, ParserSettings::default()), false) {
-                Block::Paragraph(ct, _)
+                Block::Paragraph(ct, ..)
                     => assert_eq!(vec![ $( $val ),* ] as Vec<Inline>, ct),
 
                 x => assert_matches!(x, Block::Paragraph(..)),
@@ -87,7 +87,7 @@ pub macro par_check {
             );
 
             match par {
-                Block::Paragraph(ct, _)
+                Block::Paragraph(ct, ..)
                     => assert_eq!(vec![ $( $val ),* ] as Vec<Inline>, ct),
 
                 x => assert_matches!(x, Block::Paragraph(..)),
@@ -0,0 +1,94 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+body_check!(
+    disabled,
+    "::: warning\ntext\n:::",
+    paragraph!(
+        <0, 20>
+        plain!(<0, 11> "::: warning"), soft_break!(<11, 12>), plain!(<12, 16> "text"), soft_break!(<16, 17>),
+        plain!(<17, 20> ":::")
+    )
+);
+
+body_check!(
+    simple,
+    ParserSettings::Containers,
+    ("::: warning", "Some text", "", "# Heading", ":::", "after"),
+    container!(
+        <0, 37> "warning",
+        paragraph!(<12, 22> plain!(<12, 21> "Some text")),
+        heading!(<23, 33> 1, plain!(<25, 32> "Heading"))
+    ),
+    paragraph!(<37, 42> plain!(<37, 42> "after"))
+);
+
+body_check!(
+    attributes,
+    ParserSettings::Containers,
+    ("::::: {#main .columns} :::::", "text", ":::::"),
+    container!(
+        <0, 39> "", [attrs!(#"main" ."columns")],
+        paragraph!(<29, 34> plain!(<29, 33> "text"))
+    )
+);
+
+body_check!(
+    nested,
+    ParserSettings::Containers,
+    ("::::: tabs", "::: tab", "one", ":::", "::: tab", "two", ":::", ":::::"),
+    container!(
+        <0, 48> "tabs",
+        container!(<11, 27> "tab", paragraph!(<19, 23> plain!(<19, 22> "one"))),
+        container!(<27, 43> "tab", paragraph!(<35, 39> plain!(<35, 38> "two")))
+    )
+);
+
+body_check!(
+    nested_same_colons,
+    ParserSettings::Containers,
+    ("::: outer", "::: inner", "text", ":::", ":::"),
+    container!(
+        <0, 32> "outer",
+        container!(<10, 29> "inner", paragraph!(<20, 25> plain!(<20, 24> "text")))
+    )
+);
+
+body_check!(
+    short_fence_is_content,
+    ParserSettings::Containers,
+    ("::::: outer", ":::", ":::::"),
+    container!(<0, 21> "outer", paragraph!(<12, 16> plain!(<12, 15> ":::")))
+);
+
+body_check!(
+    fence_in_code_block,
+    ParserSettings::Containers,
+    ("::: note", "```", ":::", "```", ":::"),
+    container!(<0, 24> "note", code_block!(<9, 21> <12, 12> "", ":::\n"))
+);
+
+body_check!(
+    interrupts_paragraph,
+    ParserSettings::Containers,
+    ("text", "::: aside", "inner", ":::"),
+    paragraph!(<0, 5> plain!(<0, 4> "text")),
+    container!(<5, 24> "aside", paragraph!(<15, 21> plain!(<15, 20> "inner")))
+);
+
+body_check!(
+    unclosed,
+    ParserSettings::Containers,
+    ("::: note", "text"),
+    paragraph!(<0, 13> plain!(<0, 8> "::: note"), soft_break!(<8, 9>), plain!(<9, 13> "text"))
+);
+
+body_check!(
+    in_quote,
+    ParserSettings::Containers,
+    ("> ::: note", "> text", "> :::"),
+    quote!(<0, 23> container!(<2, 23> "note", paragraph!(<13, 20> plain!(<13, 17> "text"))))
+);
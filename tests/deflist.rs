@@ -0,0 +1,77 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+body_check!(
+    disabled,
+    "term\n: definition",
+    paragraph!(<0, 17> plain!(<0, 4> "term"), soft_break!(<4, 5>), plain!(<5, 17> ": definition"))
+);
+
+body_check!(
+    simple,
+    ParserSettings::DefinitionLists,
+    ("term", ": definition"),
+    definition_list!(<0, 17> 
+        (vec![plain!(<0, 4> "term")], vec![vec![paragraph!(<7, 17> plain!(<7, 17> "definition"))]])
+    )
+);
+
+body_check!(
+    multiple_definitions,
+    ParserSettings::DefinitionLists,
+    ("term", ": first", ": second"),
+    definition_list!(<0, 21> 
+        (
+            vec![plain!(<0, 4> "term")],
+            vec![
+                vec![paragraph!(<7, 13> plain!(<7, 12> "first"))],
+                vec![paragraph!(<15, 21> plain!(<15, 21> "second"))]
+            ]
+        )
+    )
+);
+
+body_check!(
+    multiple_terms,
+    ParserSettings::DefinitionLists,
+    ("`key`", ": first", "", "other", ": second"),
+    definition_list!(<0, 29> 
+        (
+            vec![code!(<0, 6> "key")],
+            vec![vec![paragraph!(<8, 14> plain!(<8, 13> "first"))]]
+        ),
+        (
+            vec![plain!(<15, 20> "other")],
+            vec![vec![paragraph!(<23, 29> plain!(<23, 29> "second"))]]
+        )
+    )
+);
+
+body_check!(
+    multiple_blocks,
+    ParserSettings::DefinitionLists,
+    ("term", ": first line", "  continued", "", "  second paragraph", "", "next"),
+    definition_list!(<0, 51> 
+        (
+            vec![plain!(<0, 4> "term")],
+            vec![vec![
+                paragraph!(
+                    <7, 30>
+                    plain!(<7, 17> "first line"), soft_break!(<17, 18>), plain!(<20, 29> "continued")
+                ),
+                paragraph!(<33, 50> plain!(<33, 49> "second paragraph"))
+            ]]
+        )
+    ),
+    paragraph!(<51, 55> plain!(<51, 55> "next"))
+);
+
+body_check!(
+    no_definition,
+    ParserSettings::DefinitionLists,
+    ("term", "no definition"),
+    paragraph!(<0, 18> plain!(<0, 4> "term"), soft_break!(<4, 5>), plain!(<5, 18> "no definition"))
+);
@@ -0,0 +1,88 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+/// The inputs start with an empty line to skip the head
+fn parse(inp: &str, settings: ParserSettings) -> Document {
+    init!(inp);
+    Document::parse("", &mut StringData::new(inp, settings))
+}
+
+fn codes(doc: &Document) -> Vec<(Severity, &'static str)> {
+    doc.diagnostics().iter().map(|d| (d.severity, d.code)).collect()
+}
+
+#[test]
+fn none_by_default() {
+    let doc = parse("\ntext  \ntext", ParserSettings::default());
+    assert_eq!(doc.diagnostics(), &[]);
+}
+
+#[test]
+fn trailing_space_break() {
+    let doc = parse("\ntext  \ntext", ParserSettings::TrailingSpaceLint);
+    assert_eq!(codes(&doc), [(Severity::Warning, "trailing-space-break")]);
+
+    #[cfg(feature = "location")]
+    assert_eq!(doc.diagnostics()[0].location, Location { begin: 5.into(), end: 7.into() });
+}
+
+#[test]
+fn trailing_space_end_of_input() {
+    let doc = parse("\ntext  ", ParserSettings::TrailingSpaceLint);
+    assert_eq!(doc.diagnostics(), &[]);
+}
+
+#[test]
+fn in_containers() {
+    for inp in [
+        "\n> text  \n> text",
+        "\n- text  \n  text",
+        "\n1. text  \n   text\n2. b",
+        "\nterm\n: text  \n  text",
+        "\na\n\n- b\n\n  > text  \n  > text\n\nc  \nd",
+    ] {
+        let doc = parse(
+            inp,
+            ParserSettings::TrailingSpaceLint | ParserSettings::DefinitionLists,
+        );
+        let expected = inp.matches("  \n").count();
+        assert_eq!(doc.diagnostics().len(), expected, "{:?}", inp);
+    }
+}
+
+#[test]
+fn display() {
+    let doc = parse("\ntext  \ntext", ParserSettings::TrailingSpaceLint);
+    assert_eq!(
+        doc.diagnostics()[0].to_string(),
+        "warning[trailing-space-break]: invisible hard line break by trailing spaces"
+    );
+}
+
+#[test]
+fn list_item_empty_start() {
+    let doc = parse("\n-\n  a\n-\n  b", ParserSettings::default());
+    assert_eq!(
+        codes(&doc),
+        [(Severity::Warning, "list-item-empty-start"), (Severity::Warning, "list-item-empty-start")]
+    );
+
+    #[cfg(feature = "location")]
+    {
+        assert_eq!(doc.diagnostics()[0].location, Location { begin: 1.into(), end: 2.into() });
+        assert_eq!(doc.diagnostics()[1].location, Location { begin: 7.into(), end: 8.into() });
+    }
+}
+
+#[test]
+fn list_item_extra_indent() {
+    let doc = parse("\n- a\n - b\n  - c", ParserSettings::default());
+    assert_eq!(codes(&doc), [(Severity::Warning, "list-item-extra-indent")]);
+    assert_eq!(
+        doc.diagnostics()[0].message,
+        "list item is indented by 2 columns more than the first one"
+    );
+}
@@ -0,0 +1,60 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+par_check!(
+    disabled_by_default, "Party :tada:",
+    plain!(<0, 12> "Party :tada:")
+);
+
+par_check!(
+    shortcode, ParserSettings::Emoji, "Party :tada: :+1:",
+    plain!(<0, 17> "Party 🎉 👍")
+);
+
+par_check!(
+    unknown_shortcode, ParserSettings::Emoji, "Time :no_such_emoji: 10:30:00",
+    plain!(<0, 29> "Time :no_such_emoji: 10:30:00")
+);
+
+par_check!(
+    inside_word, ParserSettings::Emoji, "foo:tada: :tada:bar",
+    plain!(<0, 19> "foo:tada: 🎉bar")
+);
+
+par_check!(
+    punctuation, ParserSettings::Emoji, "(:smile:)",
+    plain!(<0, 9> "(😄)")
+);
+
+par_check!(
+    code_span, ParserSettings::Emoji, "`:tada:`",
+    code!(<0, 8> ":tada:")
+);
+
+par_check!(
+    link_url, ParserSettings::Emoji, "[:tada:](/:tada:/)",
+    link!(<0, 18> <1, 7> <9, 17> <17, 17> "/:tada:/", "", plain!(<1, 7> "🎉"))
+);
+
+par_check!(
+    autolink, ParserSettings::Emoji | ParserSettings::Html, "<https://example.com/:tada:>",
+    link!(<0, 28> <1, 27> <1, 27> <27, 27> "https://example.com/:tada:", "", plain!(<1, 27> "https://example.com/:tada:"))
+);
+
+par_check!(
+    bare_url, ParserSettings::Emoji, "See https://example.com/:tada:/ and www.example.com/:tada:",
+    plain!(<0, 58> "See https://example.com/:tada:/ and www.example.com/:tada:")
+);
+
+par_check!(
+    extended_autolink, ParserSettings::Emoji | ParserSettings::ExtendedAutolinks,
+    "https://example.com/:tada:/ :tada:",
+    link!(
+        <0, 27> <0, 27> <0, 27> <27, 27> "https://example.com/:tada:/", "",
+        plain!(<0, 27> "https://example.com/:tada:/")
+    ),
+    plain!(<27, 34> " 🎉")
+);
@@ -7,34 +7,55 @@ use common::*;
 par_check!(
     end_between_content_punct,
     "*foo `x`*.",
-    emph!(plain!(<1, 5> "foo "), code!(<6, 7> "x")),
+    emph!(<0, 9> plain!(<1, 5> "foo "), code!(<5, 8> "x")),
     plain!(<9, 10> ".")
 );
 
 par_check!(
     across_link,
     "[*foo]()*",
-    link!(<0, 0> "", "", plain!(<1, 5> "*foo")),
+    link!(<0, 8> <1, 5> <7, 7> <7, 7> "", "", plain!(<1, 5> "*foo")),
     plain!(<8, 9> "*")
 );
 
 par_check!(
     xacross_ref_label,
     "[foo][*]*",
-    linkref!(<0, 0> "*", plain!(<1, 4> "foo")),
+    linkref!(<0, 8> <6, 7> "*", plain!(<1, 4> "foo")),
     plain!(<8, 9> "*")
 );
 
 cm_par_check!(
     cacross_ref_label,
     "[foo][*]*",
-    linkref!(<0, 0> "*", plain!(<1, 4> "foo")),
+    linkref!(<0, 8> <6, 7> "*", plain!(<1, 4> "foo")),
     plain!(<8, 9> "*")
 );
 
 par_check!(
     cleanup_open_emph_at_par_end,
     "*a **b [ c** d* x",
-    emph!(plain!(<1, 3> "a "), strong!(plain!(<5, 10> "b [ c")), plain!(<12, 14> " d")),
+    emph!(<0, 15> plain!(<1, 3> "a "), strong!(<3, 12> plain!(<5, 10> "b [ c")), plain!(<12, 14> " d")),
     plain!(<15, 17> " x")
 );
+
+par_check!(
+    cleanup_across_lines,
+    "*[\nä*",
+    emph!(<0, 6> plain!(<1, 2> "["), soft_break!(<2, 3>), plain!(<3, 5> "ä"))
+);
+
+par_check!(
+    cleanup_with_html,
+    ParserSettings::Html,
+    "*[<v>*",
+    emph!(<0, 6> plain!(<1, 2> "["), html!(<2, 5> "<v>"))
+);
+
+par_check!(
+    cleanup_end_inside_other_emph,
+    ParserSettings::Del | ParserSettings::Ins,
+    "~~[++~~++",
+    plain!(<0, 3> "~~["),
+    ins!(<3, 9> plain!(<5, 7> "~~"))
+);
@@ -0,0 +1,65 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+body_check!(
+    task_list, ParserSettings::Checkboxes, ("- [ ] a", "- [x] b"),
+    unordered_list!(
+        <0, 15> '-',
+        item!(<0, 8> paragraph!(<6, 8> checkbox!(<2, 5> false), plain!(<6, 7> "a"))),
+        item!(<8, 15> paragraph!(<14, 15> checkbox!(<10, 13> true), plain!(<14, 15> "b")))
+    )
+);
+
+body_check!(
+    task_list_ordered, ParserSettings::Checkboxes, "1. [X] a",
+    ordered_list!(<0, 8> item!(<0, 8> paragraph!(<7, 8> checkbox!(<3, 6> true), plain!(<7, 8> "a"))))
+);
+
+body_check!(
+    task_marker_needs_space, ParserSettings::Checkboxes, "- [x]a",
+    unordered_list!(
+        <0, 6> '-',
+        item!(<0, 6> paragraph!(
+            <2, 6> linkref!(<2, 5> <3, 4> "x", plain!(<3, 4> "x")), plain!(<5, 6> "a")
+        ))
+    )
+);
+
+body_check!(
+    checkboxes_disabled, ParserSettings::default(), "- [ ] a",
+    unordered_list!(
+        <0, 7> '-',
+        item!(<0, 7> paragraph!(
+            <2, 7> linkref!(<2, 5> <3, 4> " ", plain!(<3, 4> " ")), plain!(<5, 7> " a")
+        ))
+    )
+);
+
+body_check!(
+    tag_filter_block, ParserSettings::Html | ParserSettings::TagFilter, "<script>\nalert(1)\n</script>",
+    html_block!(<0, 27> "&lt;script>\nalert(1)\n&lt;/script>\n")
+);
+
+body_check!(
+    tag_filter_inline, ParserSettings::Html | ParserSettings::TagFilter, "a <title> <em> <xmp/>",
+    paragraph!(
+        <0, 21>
+        plain!(<0, 2> "a "), html!(<2, 9> "&lt;title>"), plain!(<9, 10> " "),
+        html!(<10, 14> "<em>"), plain!(<14, 15> " "), html!(<15, 21> "&lt;xmp/>")
+    )
+);
+
+body_check!(
+    gfm_preset, ParserSettings::gfm(), ("| a |", "| - |", "| ~~b~~ www.x.org |"),
+    table!(
+        <0, 31> [None],
+        [[plain!(<2, 3> "a")]],
+        [[
+            del!(<14, 19> plain!(<16, 17> "b")), plain!(<19, 20> " "),
+            link!(<20, 29> <20, 29> <20, 29> <29, 29> "http://www.x.org", "", plain!(<20, 29> "www.x.org"))
+        ]]
+    )
+);
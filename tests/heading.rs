@@ -6,7 +6,6 @@ use common::*;
 
 body_check!(
     /// https://spec.commonmark.org/0.29/#insecure-characters
-    #[cfg_attr(feature = "location", ignore)]
     headline_with_0, "# \0text", heading!(<0, 7> 1, plain!(<2, 7> "\u{fffd}text"))
 );
 
@@ -33,10 +32,9 @@ body_check!(
 cm_body_check!(
     /// Block structure takes precedence over inline structure
     /// <https://spec.commonmark.org/0.29/#example-61>
-    #[cfg_attr(feature = "location", ignore)]
     heading_breaks_inline_code,
     "`*Foo*\n# Heading\nnot inline code`",
-    paragraph!(<0, 7> plain!(<0, 1> "`"), emph!(plain!(<2, 5> "Foo"))),
+    paragraph!(<0, 7> plain!(<0, 1> "`"), emph!(<1, 6> plain!(<2, 5> "Foo"))),
     heading!(<7, 17> 1, plain!(<9, 16> "Heading")),
     paragraph!(<17, 33> plain!(<17, 33> "not inline code`"))
 );
@@ -44,10 +42,53 @@ cm_body_check!(
 cm_body_check!(
     /// Block structure takes precedence over inline structure
     /// <https://spec.commonmark.org/0.29/#example-61>
-    #[cfg_attr(feature = "location", ignore)]
     headline_breaks_link,
     "[*Foo*\n# 12\n](https://example.org/)",
-    paragraph!(<0, 7> plain!(<0, 1> "["), emph!(plain!(<2, 5> "Foo"))),
+    paragraph!(<0, 7> plain!(<0, 1> "["), emph!(<1, 6> plain!(<2, 5> "Foo"))),
     heading!(<7, 12> 1, plain!(<9, 11> "12")),
     paragraph!(<12, 35> plain!(<12, 35> "](https://example.org/)"))
 );
+
+mod attributes {
+    use super::*;
+
+    body_check!(
+        disabled,
+        "# Title {#intro}",
+        heading!(<0, 16> 1, plain!(<2, 16> "Title {#intro}"))
+    );
+
+    body_check!(
+        id_class_pair,
+        ParserSettings::Attributes,
+        ("## Title `here` {#intro .lead data-x=\"a b\"}", "text"),
+        heading!(
+            <0, 44> 2, [attrs!(#"intro" ."lead" "data-x" = "a b")],
+            plain!(<3, 9> "Title "), code!(<9, 15> "here")
+        ),
+        paragraph!(<44, 48> plain!(<44, 48> "text"))
+    );
+
+    body_check!(
+        only_attributes,
+        ParserSettings::Attributes,
+        ("# {.hidden}"),
+        heading!(<0, 11> 1, [attrs!(."hidden")])
+    );
+
+    body_check!(
+        invalid_stays_text,
+        ParserSettings::Attributes,
+        ("# Title {#intro", "# Title {}", "# Title{#intro}"),
+        heading!(<0, 16> 1, plain!(<2, 15> "Title {#intro")),
+        heading!(<16, 27> 1, plain!(<18, 26> "Title {}")),
+        heading!(<27, 42> 1, plain!(<29, 42> "Title{#intro}"))
+    );
+
+    body_check!(
+        escaped_brace,
+        ParserSettings::Attributes,
+        ("# Title \\{#intro}"),
+        heading!(<0, 17> 1, plain!(<2, 17> "Title {#intro}"))
+    );
+}
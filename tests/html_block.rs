@@ -74,6 +74,44 @@ body_check!(
     html_block!(<75, 88> "<h6>Text</h6>\n")
 );
 
+body_check!(
+    block_tag_continues_paragraph,
+    "Foo\n<div>\nbar",
+    paragraph!(<0, 13>
+        plain!(<0, 3> "Foo"), soft_break!(<3, 4>),
+        html!(<4, 9> "<div>"), soft_break!(<9, 10>), plain!(<10, 13> "bar")
+    )
+);
+
+body_check!(
+    block_tag_interrupts_paragraph,
+    ParserSettings::Html | ParserSettings::HtmlInterrupt,
+    "Foo\n<div>\nbar\n</div>",
+    paragraph!(<0, 4> plain!(<0, 3> "Foo")),
+    html_block!(<4, 20> "<div>\nbar\n</div>\n")
+);
+
+body_check!(
+    closing_block_tag_interrupts_paragraph,
+    ParserSettings::Html | ParserSettings::HtmlInterrupt,
+    "_world_.\n</pre>\n</td></tr></table>",
+    paragraph!(<0, 16>
+        emph!(<0, 7> plain!(<1, 6> "world")), plain!(<7, 8> "."), soft_break!(<8, 9>),
+        html!(<9, 15> "</pre>")
+    ),
+    html_block!(<16, 34> "</td></tr></table>\n")
+);
+
+body_check!(
+    inline_tag_continues_paragraph,
+    ParserSettings::Html | ParserSettings::HtmlInterrupt,
+    "Foo\n<span>bar</span>",
+    paragraph!(<0, 20>
+        plain!(<0, 3> "Foo"), soft_break!(<3, 4>),
+        html!(<4, 10> "<span>"), plain!(<10, 13> "bar"), html!(<13, 20> "</span>")
+    )
+);
+
 mod nohtml {
     use super::*;
 
@@ -81,8 +119,8 @@ mod nohtml {
         div, ParserSettings::None,
         "<div>\ntext\n</div>",
         paragraph!(<0, 17>
-            plain!(<0, 5> "<div>"), SoftBreak,
-            plain!(<6, 10> "text"), SoftBreak,
+            plain!(<0, 5> "<div>"), soft_break!(<5, 6>),
+            plain!(<6, 10> "text"), soft_break!(<10, 11>),
             plain!(<11, 17> "</div>")
         )
     );
@@ -91,8 +129,8 @@ mod nohtml {
         script, ParserSettings::None,
         "<script>\nwindow.alert('hello');\n</script>",
         paragraph!(<0, 41>
-            plain!(<0, 8> "<script>"), SoftBreak,
-            plain!(<9, 31> "window.alert('hello');"), SoftBreak,
+            plain!(<0, 8> "<script>"), soft_break!(<8, 9>),
+            plain!(<9, 31> "window.alert('hello');"), soft_break!(<31, 32>),
             plain!(<32, 41> "</script>")
         )
     );
@@ -101,10 +139,10 @@ mod nohtml {
         style, ParserSettings::None,
         "<style>\nbody {\n  color: #fff;\n}\n</style>",
         paragraph!(<0, 40>
-            plain!(<0, 7> "<style>"), SoftBreak,
-            plain!(<8, 14> "body {"), SoftBreak,
-            plain!(<17, 29> "color: #fff;"), SoftBreak,
-            plain!(<30, 31> "}"), SoftBreak,
+            plain!(<0, 7> "<style>"), soft_break!(<7, 8>),
+            plain!(<8, 14> "body {"), soft_break!(<14, 15>),
+            plain!(<17, 29> "color: #fff;"), soft_break!(<29, 30>),
+            plain!(<30, 31> "}"), soft_break!(<31, 32>),
             plain!(<32, 40> "</style>")
         )
     );
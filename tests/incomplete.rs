@@ -0,0 +1,97 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+body_check!(
+    emph, ParserSettings::IncompleteEnd, "text *text",
+    paragraph!(<0, 10> plain!(<0, 5> "text "), incomplete!(emph!(<5, 10> plain!(<6, 10> "text"))))
+);
+
+body_check!(
+    strong, ParserSettings::IncompleteEnd, "a **b",
+    paragraph!(<0, 5> plain!(<0, 2> "a "), incomplete!(strong!(<2, 5> plain!(<4, 5> "b"))))
+);
+
+body_check!(
+    nested_emph, ParserSettings::IncompleteEnd, "*a **b",
+    paragraph!(
+        <0, 6>
+        incomplete!(emph!(<0, 6> plain!(<1, 3> "a "), incomplete!(strong!(<3, 6> plain!(<5, 6> "b")))))
+    )
+);
+
+body_check!(
+    closed_emph, ParserSettings::IncompleteEnd, "*a* b",
+    paragraph!(<0, 5> emph!(<0, 3> plain!(<1, 2> "a")), plain!(<3, 5> " b"))
+);
+
+body_check!(
+    emph_in_second_line, ParserSettings::IncompleteEnd, ("*a*", "b _c"),
+    paragraph!(
+        <0, 8>
+        emph!(<0, 3> plain!(<1, 2> "a")), soft_break!(<3, 4>), plain!(<4, 6> "b "),
+        incomplete!(emph!(<6, 8> plain!(<7, 8> "c")))
+    )
+);
+
+body_check!(
+    link_text, ParserSettings::IncompleteEnd, "see [the docs",
+    paragraph!(
+        <0, 13>
+        plain!(<0, 4> "see "), incomplete!(linkref!(<4, 13> <5, 13> plain!(<5, 13> "the docs")))
+    )
+);
+
+body_check!(
+    link_with_emph, ParserSettings::IncompleteEnd, "[a *b",
+    paragraph!(
+        <0, 5>
+        incomplete!(linkref!(<0, 5> <1, 5> plain!(<1, 3> "a "), incomplete!(emph!(<3, 5> plain!(<4, 5> "b")))))
+    )
+);
+
+body_check!(
+    link_destination, ParserSettings::IncompleteEnd, "[a](http://x.org \"ti",
+    paragraph!(
+        <0, 20>
+        incomplete!(link!(<0, 20> <1, 2> <4, 16> <18, 20> "http://x.org", "ti", plain!(<1, 2> "a")))
+    )
+);
+
+body_check!(
+    image_destination, ParserSettings::IncompleteEnd, "![a](<x.png",
+    paragraph!(<0, 11> incomplete!(image!(<0, 11> <2, 3> <6, 11> <11, 11> "x.png", "", plain!(<2, 3> "a"))))
+);
+
+body_check!(
+    code_span, ParserSettings::IncompleteEnd, "a `b c",
+    paragraph!(<0, 6> plain!(<0, 2> "a "), incomplete!(code!(<2, 6> "b c")))
+);
+
+body_check!(
+    html_tag, ParserSettings::IncompleteEnd | ParserSettings::Html, "a <a href=\"",
+    paragraph!(<0, 11> plain!(<0, 2> "a "), incomplete!(html!(<2, 11> "<a href=\"")))
+);
+
+body_check!(
+    embedded_expr, ParserSettings::IncompleteEnd | ParserSettings::Embedded, "a @(x + ",
+    paragraph!(<0, 8> plain!(<0, 2> "a "), incomplete!(emb_expr!(<3, 8> "x + ")))
+);
+
+body_check!(
+    embedded_block, ParserSettings::IncompleteEnd | ParserSettings::Embedded, "a @{x",
+    paragraph!(<0, 5> plain!(<0, 2> "a "), incomplete!(emb_block!(<3, 5> "x")))
+);
+
+body_check!(
+    per_paragraph, ParserSettings::IncompleteEnd, ("*a", "", "b"),
+    paragraph!(<0, 3> incomplete!(emph!(<0, 3> plain!(<1, 2> "a")))),
+    paragraph!(<4, 5> plain!(<4, 5> "b"))
+);
+
+body_check!(
+    disabled, ParserSettings::default(), "text *text `code [link",
+    paragraph!(<0, 22> plain!(<0, 22> "text *text `code [link"))
+);
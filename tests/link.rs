@@ -9,38 +9,38 @@ mod footnote_inline {
 
     par_check!(
         empty, "[][^]",
-        linkref!(<0, 0> "^", )
+        linkref!(<0, 5> <3, 4> "^", )
     );
 
     par_check!(
         simple, "[Text][^]",
-        linkref!(<0, 0> "^", plain!(<1, 5> "Text"))
+        linkref!(<0, 9> <7, 8> "^", plain!(<1, 5> "Text"))
     );
 
     par_check!(
         with_image, "[Text and ![an image](img.jpg)][^]",
         linkref!(
-            <0, 0> "^",
-            plain!(<1, 5> "Text and "),
-            image!(<0, 0> "img.jpg", "", plain!(<0, 0> "an image"))
+            <0, 34> <32, 33> "^",
+            plain!(<1, 10> "Text and "),
+            image!(<10, 30> <12, 20> <22, 29> <29, 29> "img.jpg", "", plain!(<12, 20> "an image"))
         )
     );
 
     par_check!(
         with_link, "[Text and [a link](/url)][^]",
         linkref!(
-            <0, 0> "^",
-            plain!(<1, 5> "Text and "),
-            link!(<0, 0> "/url", "", plain!(<0, 0> "a link"))
+            <0, 28> <26, 27> "^",
+            plain!(<1, 10> "Text and "),
+            link!(<10, 24> <11, 17> <19, 23> <23, 23> "/url", "", plain!(<11, 17> "a link"))
         )
     );
 
     par_check!(
         with_footnote, "[A footnote[in a footnote][^]][^]",
         linkref!(
-            <0, 0> "^",
-            plain!(<1, 5> "A footnote"),
-            linkref!(<0, 0> "^", plain!(<0, 0> "in a footnote"))
+            <0, 33> <31, 32> "^",
+            plain!(<1, 11> "A footnote"),
+            linkref!(<11, 29> <27, 28> "^", plain!(<12, 25> "in a footnote"))
         )
     );
 }
@@ -50,7 +50,7 @@ mod footnote_ref {
 
     par_check!(
         without_def, "[^fn]",
-        linkref!(<0, 0> "^fn",)
+        linkref!(<0, 5> <1, 4> "^fn",)
     );
 
     body_check!(
@@ -58,8 +58,8 @@ mod footnote_ref {
         "Text[^fn]\n\n\
          [^fn]:",
 
-        paragraph!(<0, 0> plain!(<0, 4> "Text"), linkref!(<0, 0> "^fn",)),
-        linkdef!(<0, 0> "^fn", "")
+        paragraph!(<0, 10> plain!(<0, 4> "Text"), linkref!(<4, 9> <5, 8> "^fn",)),
+        linkdef!(<11, 17> <12, 15> <17, 17> <17, 17> "^fn", "")
     );
 
     body_check!(
@@ -67,10 +67,10 @@ mod footnote_ref {
         "Text[^fn]\n\n\
          [^fn]: Footnote content",
 
-        paragraph!(<0, 0> plain!(<0, 4> "Text"), linkref!(<0, 0> "^fn",)),
+        paragraph!(<0, 10> plain!(<0, 4> "Text"), linkref!(<4, 9> <5, 8> "^fn",)),
         linkdef!(
-            <0, 0> "^fn",
-            "" // paragraph!(<0, 0> plain!(<0, 0> "Footnote content"))
+            <11, 34> <12, 15> <18, 18> <18, 18> "^fn",
+            "" // paragraph!(<18, 34> plain!(<18, 34> "Footnote content"))
         )
     );
 }
@@ -81,14 +81,62 @@ mod image {
     par_check!(
         url_with_space,
         r#"![Text](<local file.txt>)"#,
-        image!(<0, 0> "local file.txt", "", plain!(<2, 6> "Text"))
+        image!(<0, 25> <2, 6> <9, 23> <24, 24> "local file.txt", "", plain!(<2, 6> "Text"))
     );
 
     par_check!(
         empty_text,
         r#"![](/url)"#,
-        image!(<0, 0> "/url", "")
+        image!(<0, 9> <2, 2> <4, 8> <8, 8> "/url", "")
     );
+
+    par_check!(
+        link_in_description,
+        "![a [b](/u) c](/i)",
+        image!(
+            <0, 18> <2, 13> <15, 17> <17, 17> "/i", "",
+            plain!(<2, 4> "a "),
+            link!(<4, 11> <5, 6> <8, 10> <10, 10> "/u", "", plain!(<5, 6> "b")),
+            plain!(<11, 13> " c")
+        )
+    );
+
+    par_check!(
+        image_in_description,
+        "![a ![b](/u)][ref]",
+        imageref!(
+            <0, 18> <14, 17> "ref",
+            plain!(<2, 4> "a "),
+            image!(<4, 12> <6, 7> <9, 11> <11, 11> "/u", "", plain!(<6, 7> "b"))
+        )
+    );
+
+    par_check!(
+        no_link_around_link_in_description,
+        "[a ![b [c](/u)](/i)](/l)",
+        plain!(<0, 3> "[a "),
+        image!(
+            <3, 19> <5, 14> <16, 18> <18, 18> "/i", "",
+            plain!(<5, 7> "b "),
+            link!(<7, 14> <8, 9> <11, 13> <13, 13> "/u", "", plain!(<8, 9> "c"))
+        ),
+        plain!(<19, 24> "](/l)")
+    );
+
+    #[test]
+    fn alt_text() {
+        let par = paragraph(
+            &mut StringData::new("![a *b* [c `d`](/u) ![e](/v)](/i) f", ParserSettings::default()),
+            false
+        );
+
+        let Block::Paragraph(list, ..) = par else {
+            panic!("no paragraph: {:?}", par);
+        };
+
+        assert_eq!(Some("a b c d e".to_string()), list[0].alt_text());
+        assert_eq!(None, list[1].alt_text());
+    }
 }
 
 mod imageref {
@@ -97,7 +145,7 @@ mod imageref {
     par_check!(
         empty_text,
         r#"![][ref]"#,
-        imageref!(<0, 0> "ref", )
+        imageref!(<0, 8> <4, 7> "ref", )
     );
 
     par_check!(
@@ -110,7 +158,7 @@ mod imageref {
         short_on_empty_label,
         r#"a ![text][] b"#,
         plain!(<0, 2> "a "),
-        imageref!(<0, 0> "", plain!(<4, 8> "text")),
+        imageref!(<2, 11> <4, 8> "text", plain!(<4, 8> "text")),
         plain!(<11, 13> " b")
     );
 
@@ -118,7 +166,7 @@ mod imageref {
         /// Derived from [Spec 542](https://spec.commonmark.org/0.29/#example-542)
         label_with_bracket,
         "![foo][ref[]",
-        imageref!(<0, 0> plain!(<2, 5> "foo")), plain!(<6, 12> "[ref[]")
+        imageref!(<0, 6> <2, 5> "foo", plain!(<2, 5> "foo")), plain!(<6, 12> "[ref[]")
 
     );
 
@@ -127,8 +175,8 @@ mod imageref {
         escaped_brackets,
         "![\\[foo\\]]\n\n\
          [\\[foo\\]]: /url \"title\"",
-        paragraph!(<0, 11> imageref!(<0, 0> plain!(<2, 9> "[foo]"))),
-        linkdef!(<0, 0> "[foo]", "/url", "title")
+        paragraph!(<0, 11> imageref!(<0, 10> <2, 9> "[foo]", plain!(<2, 9> "[foo]"))),
+        linkdef!(<12, 35> <13, 20> <23, 27> <29, 34> "[foo]", "/url", "title")
     );
 }
 
@@ -138,20 +186,20 @@ mod link {
     par_check!(
         url_with_space,
         r#"[Text](<local file.txt>)"#,
-        link!(<0, 0> "local file.txt", "", plain!(<1, 5> "Text"))
+        link!(<0, 24> <1, 5> <8, 22> <23, 23> "local file.txt", "", plain!(<1, 5> "Text"))
     );
 
     par_check!(
         empty_text,
         r#"[](/url)"#,
-        link!(<0, 0> "/url", "")
+        link!(<0, 8> <1, 1> <3, 7> <7, 7> "/url", "")
     );
 
     par_check!(
         /// Derived from https://spec.commonmark.org/0.29/#example-170
         no_space_between_url_title,
         "[foo](<bar>(baz))",
-        linkref!(<0, 0> plain!(<1, 4> "foo")),
+        linkref!(<0, 5> <1, 4> "foo", plain!(<1, 4> "foo")),
         plain!(<5, 6> "("), html!(<6, 11> "<bar>"), plain!(<11, 17> "(baz))")
     );
 
@@ -160,14 +208,14 @@ mod link {
         "[abc](/tes(t )\n\n[abc](/tes(t\n)",
         paragraph!(
             <0, 15>
-            linkref!(<0, 0> plain!(<1, 4> "abc")),
+            linkref!(<0, 5> <1, 4> "abc", plain!(<1, 4> "abc")),
             plain!(<5, 14> "(/tes(t )")
         ),
         paragraph!(
             <16, 30>
-            linkref!(<0, 0> plain!(<17, 20> "abc")),
+            linkref!(<16, 21> <17, 20> "abc", plain!(<17, 20> "abc")),
             plain!(<21, 28> "(/tes(t"),
-            SoftBreak,
+            soft_break!(<28, 29>),
             plain!(<29, 30> ")")
         )
     );
@@ -179,7 +227,7 @@ mod linkref {
     par_check!(
         empty_text,
         r#"[][ref]"#,
-        linkref!(<0, 0> "ref", )
+        linkref!(<0, 7> <3, 6> "ref", )
     );
 
     par_check!(
@@ -192,15 +240,15 @@ mod linkref {
         multiple_simple_linkdefs,
         "[foo]: /url1\n\
          [bar]: /url2",
-        linkdef!(<0, 0> "foo", "/url1"),
-        linkdef!(<0, 0> "bar", "/url2")
+        linkdef!(<0, 13> <1, 4> <7, 12> <12, 12> "foo", "/url1"),
+        linkdef!(<13, 25> <14, 17> <20, 25> <25, 25> "bar", "/url2")
     );
 
     par_check!(
         short_on_empty_label,
         r#"a [text][] b"#,
         plain!(<0, 2> "a "),
-        linkref!(<0, 0> "", plain!(<3, 7> "text")),
+        linkref!(<2, 10> <3, 7> "text", plain!(<3, 7> "text")),
         plain!(<10, 12> " b")
     );
 
@@ -210,6 +258,37 @@ mod linkref {
         /// definition of *reflabel*.
         linkref_non_link,
         "[text][reflabel](/url)",
-        linkref!(<0, 0> "reflabel", plain!(<1, 5> "text")), plain!(<16, 22> "(/url)")
+        linkref!(<0, 16> <7, 15> "reflabel", plain!(<1, 5> "text")), plain!(<16, 22> "(/url)")
+    );
+}
+
+mod attributes {
+    use super::*;
+
+    body_check!(
+        image,
+        ParserSettings::Attributes,
+        ("![alt](src.png){width=300 .photo}"),
+        paragraph!(<0, 33> image!(
+            <0, 33> <2, 5> <7, 14> <14, 14> "src.png", "", [attrs!(."photo" "width" = "300")], plain!(<2, 5> "alt")
+        ))
+    );
+
+    body_check!(
+        link,
+        ParserSettings::Attributes,
+        ("[text](/url \"title\"){#ref} after"),
+        paragraph!(
+            <0, 32>
+            link!(<0, 26> <1, 5> <7, 11> <13, 18> "/url", "title", [attrs!(#"ref")], plain!(<1, 5> "text")),
+            plain!(<26, 32> " after")
+        )
+    );
+
+    body_check!(
+        invalid_stays_text,
+        ParserSettings::Attributes,
+        ("[text](/url){}"),
+        paragraph!(<0, 14> link!(<0, 12> <1, 5> <7, 11> <11, 11> "/url", "", plain!(<1, 5> "text")), plain!(<12, 14> "{}"))
     );
 }
@@ -0,0 +1,120 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+const ALL: ParserSettings = ParserSettings::Del
+    .union(ParserSettings::Sup)
+    .union(ParserSettings::Sub)
+    .union(ParserSettings::Mark)
+    .union(ParserSettings::Ins);
+
+par_check!(
+    disabled_by_default, "^a^ ~b~ ~~c~~ ==d== ++e++",
+    plain!(<0, 25> "^a^ ~b~ ~~c~~ ==d== ++e++")
+);
+
+par_check!(
+    sup, ParserSettings::Sup, "x^2^ + y^10^",
+    plain!(<0, 1> "x"),
+    sup!(<1, 4> plain!(<2, 3> "2")),
+    plain!(<4, 8> " + y"),
+    sup!(<8, 12> plain!(<9, 11> "10"))
+);
+
+par_check!(
+    sub, ParserSettings::Sub, "H~2~O",
+    plain!(<0, 1> "H"),
+    sub!(<1, 4> plain!(<2, 3> "2")),
+    plain!(<4, 5> "O")
+);
+
+par_check!(
+    mark, ParserSettings::Mark, "a ==b c== d",
+    plain!(<0, 2> "a "),
+    mark!(<2, 9> plain!(<4, 7> "b c")),
+    plain!(<9, 11> " d")
+);
+
+par_check!(
+    ins, ParserSettings::Ins, "a ++b c++ d",
+    plain!(<0, 2> "a "),
+    ins!(<2, 9> plain!(<4, 7> "b c")),
+    plain!(<9, 11> " d")
+);
+
+par_check!(
+    del, ParserSettings::Del, "a ~~b c~~ d",
+    plain!(<0, 2> "a "),
+    del!(<2, 9> plain!(<4, 7> "b c")),
+    plain!(<9, 11> " d")
+);
+
+par_check!(
+    sub_and_del, ALL, "~a~ ~~b~~ ~~~c~~~",
+    sub!(<0, 3> plain!(<1, 2> "a")),
+    plain!(<3, 4> " "),
+    del!(<4, 9> plain!(<6, 7> "b")),
+    plain!(<9, 17> " ~~~c~~~")
+);
+
+par_check!(
+    del_without_sub, ParserSettings::Del, "~a~ ~~b~~",
+    plain!(<0, 4> "~a~ "),
+    del!(<4, 9> plain!(<6, 7> "b"))
+);
+
+par_check!(
+    sub_without_del, ParserSettings::Sub, "~a~ ~~b~~",
+    sub!(<0, 3> plain!(<1, 2> "a")),
+    plain!(<3, 9> " ~~b~~")
+);
+
+par_check!(
+    sub_in_del, ALL, "~~a~b~c~~",
+    del!(<0, 9> plain!(<2, 3> "a"), sub!(<3, 6> plain!(<4, 5> "b")), plain!(<6, 7> "c"))
+);
+
+par_check!(
+    del_in_sub, ALL, "~a ~~b~~ c~",
+    sub!(<0, 11> plain!(<1, 3> "a "), del!(<3, 8> plain!(<5, 6> "b")), plain!(<8, 10> " c"))
+);
+
+par_check!(
+    different_run_lengths, ALL, "~~a~ ==b= +c++",
+    plain!(<0, 14> "~~a~ ==b= +c++")
+);
+
+par_check!(
+    single_markers, ALL, "a = b + c ^ d ~ e",
+    plain!(<0, 17> "a = b + c ^ d ~ e")
+);
+
+par_check!(
+    not_left_flanking, ALL, "a ^ b^ == c==",
+    plain!(<0, 13> "a ^ b^ == c==")
+);
+
+par_check!(
+    overlapping_emph, ALL, "*a ==b* c==",
+    emph!(<0, 7> plain!(<1, 6> "a ==b")),
+    plain!(<7, 11> " c==")
+);
+
+par_check!(
+    emph_inside, ALL, "==*a* **b**==",
+    mark!(<0, 13> emph!(<2, 5> plain!(<3, 4> "a")), plain!(<5, 6> " "), strong!(<6, 11> plain!(<8, 9> "b")))
+);
+
+par_check!(
+    escaped, ALL, "\\^a^ \\~~b~~",
+    plain!(<0, 11> "^a^ ~~b~~")
+);
+
+par_check!(
+    across_link, ALL, "[^a](/)^ ^[b^](/)^",
+    link!(<0, 7> <1, 3> <5, 6> <6, 6> "/", "", plain!(<1, 3> "^a")),
+    plain!(<7, 9> "^ "),
+    sup!(<9, 18> link!(<10, 17> <11, 13> <15, 16> <16, 16> "/", "", plain!(<11, 13> "b^")))
+);
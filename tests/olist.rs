@@ -7,45 +7,105 @@ use common::*;
 body_check!(
     parenthesis_delimiter, "1) foo\n2) foo",
     ordered_list!(
-        vec![paragraph!(<3, 7> plain!(<3, 6> "foo"))],
-        vec![paragraph!(<10, 13> plain!(<10, 13> "foo"))]
+        <0, 13> "1)",
+        item!(<0, 7> paragraph!(<3, 7> plain!(<3, 6> "foo"))),
+        item!(<7, 13> paragraph!(<10, 13> plain!(<10, 13> "foo")))
     )
 );
 
 body_check!(
     mixed_delimiters, "1. text\n2) text\n3. text",
+    ordered_list!(<0, 8> item!(<0, 8> paragraph!(<3, 8> plain!(<3, 7> "text")))),
+    ordered_list!(<8, 16> "2)", item!(<8, 16> paragraph!(<11, 16> plain!(<11, 15> "text")))),
+    ordered_list!(<16, 23> "3", item!(<16, 23> paragraph!(<19, 23> plain!(<19, 23> "text"))))
+);
+
+body_check!(
+    leading_zeros, "003) text\n004) text",
     ordered_list!(
-        vec![paragraph!(<3, 8> plain!(<3, 7> "text"))],
-        vec![paragraph!(<11, 16> plain!(<11, 15> "text"))],
-        vec![paragraph!(<19, 23> plain!(<19, 23> "text"))]
+        <0, 19> "003)",
+        item!(<0, 10> paragraph!(<5, 10> plain!(<5, 9> "text"))),
+        item!(<10, 19> paragraph!(<15, 19> plain!(<15, 19> "text")))
     )
 );
 
+#[test]
+fn list_start() {
+    let start = "007)".parse::<ListStart>().unwrap();
+    assert_eq!(7, start.number);
+    assert_eq!(3, start.width);
+    assert_eq!(')', start.delimiter);
+    assert_eq!("007)", start.to_string());
+
+    assert_eq!(ListStart::default(), "1.".parse().unwrap());
+    assert_eq!("12.", "12".parse::<ListStart>().unwrap().to_string());
+    assert!("x.".parse::<ListStart>().is_err());
+}
+
+mod interrupt_paragraph {
+    use super::*;
+
+    body_check!(
+        one, "text\n1) item",
+        paragraph!(<0, 5> plain!(<0, 4> "text")),
+        ordered_list!(<5, 12> "1)", item!(<5, 12> paragraph!(<8, 12> plain!(<8, 12> "item"))))
+    );
+
+    body_check!(
+        other_number, "text\n2. item",
+        paragraph!(<0, 12> plain!(<0, 4> "text"), soft_break!(<4, 5>), plain!(<5, 12> "2. item"))
+    );
+
+    body_check!(
+        any_number, ParserSettings::ListInterruptAnyNumber, "text\n2. item",
+        paragraph!(<0, 5> plain!(<0, 4> "text")),
+        ordered_list!(<5, 12> "2", item!(<5, 12> paragraph!(<8, 12> plain!(<8, 12> "item"))))
+    );
+
+    body_check!(
+        never, ParserSettings::ListInterruptNever, "text\n1. item",
+        paragraph!(<0, 12> plain!(<0, 4> "text"), soft_break!(<4, 5>), plain!(<5, 12> "1. item"))
+    );
+
+    body_check!(
+        empty_item, ParserSettings::ListInterruptAnyNumber, "text\n2.\n",
+        paragraph!(<0, 8> plain!(<0, 4> "text"), soft_break!(<4, 5>), plain!(<5, 7> "2."))
+    );
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "exclude each other")]
+    fn any_number_and_never() {
+        let settings = ParserSettings::ListInterruptAnyNumber | ParserSettings::ListInterruptNever;
+        body(&mut StringData::new("text\n2. item", settings));
+    }
+}
+
 body_check!(
     big_numbers,
     "1. text\n22. text\n333. text\n4444. text\n1234567890. text",
     ordered_list!(
-        vec![paragraph!(<3, 8> plain!(<3, 7> "text"))],
-        vec![paragraph!(<12, 17> plain!(<12, 16> "text"))],
-        vec![paragraph!(<22, 27> plain!(<22, 26> "text"))],
-        vec![paragraph!(<33, 38> plain!(<33, 37> "text"))],
-        vec![paragraph!(<50, 54> plain!(<50, 54> "text"))]
+        <0, 54>
+        item!(<0, 8> paragraph!(<3, 8> plain!(<3, 7> "text"))),
+        item!(<8, 17> paragraph!(<12, 17> plain!(<12, 16> "text"))),
+        item!(<17, 27> paragraph!(<22, 27> plain!(<22, 26> "text"))),
+        item!(<27, 38> paragraph!(<33, 38> plain!(<33, 37> "text"))),
+        item!(<38, 54> paragraph!(<50, 54> plain!(<50, 54> "text")))
     )
 );
 
 body_check!(
-    #[ignore]
     number_at_line_start,
-    "1. paragraph with\n  3 lines\n  end\n2. next list item",
+    "1. paragraph with\n   3 lines\n   end\n2. next list item",
     ordered_list!(
-        "1",
-        vec![paragraph!(
-            <3, 31>
-            plain!(<3, 17> "paragraph with"), SoftBreak,
-            plain!(<18, 26> "3 lines"), SoftBreak,
-            plain!(<27, 30> "end"), SoftBreak
-        )],
-
-        vec![paragraph!(<3, 31> plain!(<3, 17> "next list item"))]
+        <0, 53> "1",
+        item!(<0, 36> paragraph!(
+            <3, 36>
+            plain!(<3, 17> "paragraph with"), soft_break!(<17, 18>),
+            plain!(<21, 28> "3 lines"), soft_break!(<28, 29>),
+            plain!(<32, 35> "end")
+        )),
+
+        item!(<36, 53> paragraph!(<39, 53> plain!(<39, 53> "next list item")))
     )
 );
@@ -14,38 +14,35 @@ par_check!(
 
 par_check!(
     /// https://spec.commonmark.org/0.29/#insecure-characters
-    #[cfg_attr(feature = "location", ignore)]
     text_with_0_begin, "\0x", plain!(<0, 2> "\u{fffd}x")
 );
 
 par_check!(
     /// https://spec.commonmark.org/0.29/#insecure-characters
-    #[cfg_attr(feature = "location", ignore)]
     text_with_0_middle, "x\0y", plain!(<0, 3> "x\u{fffd}y")
 );
 
 par_check!(
     /// https://spec.commonmark.org/0.29/#insecure-characters
-    #[cfg_attr(feature = "location", ignore)]
     text_with_0_end, "x\0", plain!(<0, 2> "x\u{fffd}")
 );
 
 par_check!(
     whitespace_at_line_begin_end,
     "x\n    \x0b\x0c    Foo  \t  \x0b\x0c   \ny",
-    plain!(<0, 1> "x"), SoftBreak,
-    plain!(<6, 22> "\x0b\x0c    Foo  \t  \x0b\x0c"), SoftBreak,
+    plain!(<0, 1> "x"), soft_break!(<1, 2>),
+    plain!(<6, 22> "\x0b\x0c    Foo  \t  \x0b\x0c"), soft_break!(<25, 26>),
     plain!(<26, 27> "y")
 );
 
 par_check!(
     whitespace_before_softbreak,
-    "x  \t \ny", plain!(<0, 1> "x"), SoftBreak, plain!(<6, 7> "y")
+    "x  \t \ny", plain!(<0, 1> "x"), soft_break!(<5, 6>), plain!(<6, 7> "y")
 );
 
 par_check!(
     whitespace_before_hardbreak,
-    "x  \t  \\\ny", plain!(<0, 6> "x  \t  "), HardBreak, plain!(<8, 9> "y")
+    "x  \t  \\\ny", plain!(<0, 6> "x  \t  "), hard_break!(<6, 8>), plain!(<8, 9> "y")
 );
 
 body_check!(
@@ -62,26 +59,56 @@ cm_par_check!(
 
 cm_par_check!(
     /// Derived from https://spec.commonmark.org/0.29/#example-333
-    t333b, "`\tb\t`", code!(<1, 4> "\tb\t")
+    t333b, "`\tb\t`", code!(<0, 5> "\tb\t")
 );
 
 cm_par_check!(
     /// Derived from https://spec.commonmark.org/0.29/#example-335
     t335b,
-    "``\r\nfoo\r\nbar  \r\nbaz\r\n``", code!(<2, 21> "foo bar   baz")
+    "``\r\nfoo\r\nbar  \r\nbaz\r\n``", code!(<0, 23> "foo bar   baz")
 );
 
 cm_par_check!(
     /// Derived from https://spec.commonmark.org/0.29/#example-336
-    t336b, "``\r\nfoo \r\n``", code!(<2, 10> "foo ")
+    t336b, "``\r\nfoo \r\n``", code!(<0, 12> "foo ")
 );
 
 cm_par_check!(
     /// Derived from https://spec.commonmark.org/0.29/#example-337
-    t337b, "`foo   bar \r\nbaz`", code!(<1, 16> "foo   bar  baz")
+    t337b, "`foo   bar \r\nbaz`", code!(<0, 17> "foo   bar  baz")
 );
 
 par_check!(
     no_html_in_paragraph, ParserSettings::None,
     "text <a> or <b/>", plain!(<0, 16> "text <a> or <b/>")
 );
+
+mod attributes {
+    use super::*;
+
+    body_check!(
+        line_after_paragraph,
+        ParserSettings::Attributes,
+        ("Some text", "{.lead #intro}", "", "next"),
+        paragraph!(<0, 25> [attrs!(#"intro" ."lead")], plain!(<0, 9> "Some text")),
+        paragraph!(<26, 30> plain!(<26, 30> "next"))
+    );
+
+    body_check!(
+        not_whole_line,
+        ParserSettings::Attributes,
+        ("Some text", "{.lead} more"),
+        paragraph!(
+            <0, 22> plain!(<0, 9> "Some text"), soft_break!(<9, 10>),
+            plain!(<10, 22> "{.lead} more")
+        )
+    );
+
+    body_check!(
+        disabled,
+        "Some text\n{.lead}",
+        paragraph!(
+            <0, 17> plain!(<0, 9> "Some text"), soft_break!(<9, 10>), plain!(<10, 17> "{.lead}")
+        )
+    );
+}
@@ -7,41 +7,87 @@ use common::*;
 par_check!(
     trim_space_bol,
     "text\n   text",
-    plain!(<0, 4> "text"), SoftBreak, plain!(<8, 12> "text")
+    plain!(<0, 4> "text"), soft_break!(<4, 5>), plain!(<8, 12> "text")
 );
 
 par_check!(
     trim_space_eol,
     "text   \ntext",
-    plain!(<0, 4> "text"), SoftBreak, plain!(<8, 12> "text")
+    plain!(<0, 4> "text"), soft_break!(<7, 8>), plain!(<8, 12> "text")
 );
 
 par_check!(
     hardbreak,
     "text\\\ntext",
-    plain!(<0, 4> "text"), HardBreak, plain!(<6, 10> "text")
+    plain!(<0, 4> "text"), hard_break!(<4, 6>), plain!(<6, 10> "text")
 );
 
 par_check!(
     keep_eol_escaped_backslash,
     "text\\\\\ntext",
-    plain!(<0, 6> "text\\"), SoftBreak, plain!(<7, 11> "text")
+    plain!(<0, 6> "text\\"), soft_break!(<6, 7>), plain!(<7, 11> "text")
 );
 
 par_check!(
     keep_eol_html_entity_backslash,
     "text&#92;\ntext",
-    plain!(<0, 9> "text\\"), SoftBreak, plain!(<10, 14> "text")
+    plain!(<0, 9> "text\\"), soft_break!(<9, 10>), plain!(<10, 14> "text")
 );
 
 par_check!(
     keep_eol_html_entity_space,
     "text  &#32;\ntext",
-    plain!(<0, 11> "text   "), SoftBreak, plain!(<12, 16> "text")
+    plain!(<0, 11> "text   "), soft_break!(<11, 12>), plain!(<12, 16> "text")
 );
 
 par_check!(
     whitespace_before_hardbreak,
     "text   \\\ntext",
-    plain!(<0, 7> "text   "), HardBreak, plain!(<9, 13> "text")
+    plain!(<0, 7> "text   "), hard_break!(<7, 9>), plain!(<9, 13> "text")
 );
+
+mod trailing_space_breaks {
+    use super::*;
+
+    par_check!(
+        two_spaces,
+        ParserSettings::TrailingSpaceBreaks,
+        "text  \ntext",
+        plain!(<0, 4> "text"), hard_break!(<4, 7>), plain!(<7, 11> "text")
+    );
+
+    par_check!(
+        indented_next_line,
+        ParserSettings::TrailingSpaceBreaks,
+        "text     \n   text",
+        plain!(<0, 4> "text"), hard_break!(<4, 10>), plain!(<13, 17> "text")
+    );
+
+    par_check!(
+        one_space,
+        ParserSettings::TrailingSpaceBreaks,
+        "text \ntext",
+        plain!(<0, 4> "text"), soft_break!(<5, 6>), plain!(<6, 10> "text")
+    );
+
+    par_check!(
+        tabs,
+        ParserSettings::TrailingSpaceBreaks,
+        "text\t\t\ntext",
+        plain!(<0, 4> "text"), soft_break!(<6, 7>), plain!(<7, 11> "text")
+    );
+
+    par_check!(
+        end_of_paragraph,
+        ParserSettings::TrailingSpaceBreaks,
+        "text  ",
+        plain!(<0, 4> "text")
+    );
+
+    par_check!(
+        lint_only,
+        ParserSettings::TrailingSpaceLint,
+        "text  \ntext",
+        plain!(<0, 4> "text"), soft_break!(<6, 7>), plain!(<7, 11> "text")
+    );
+}
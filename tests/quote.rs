@@ -23,7 +23,7 @@ body_check!(
     quote!(<0, 17> paragraph!(
         <2, 17>
         plain!(<2, 8> "line 1"),
-        SoftBreak,
+        soft_break!(<8, 9>),
         plain!(<11, 17> "line 2")
     ))
 );
@@ -34,18 +34,40 @@ body_check!(
     quote!(<0, 17> paragraph!(
         <1, 17>
         plain!(<1, 7> "line 1"),
-        SoftBreak,
+        soft_break!(<7, 8>),
         plain!(<11, 17> "line 2")
     ))
 );
 
+body_check!(
+    continued_crlf,
+    "> line 1\r\n> line 2",
+    quote!(<0, 18> paragraph!(
+        <2, 18>
+        plain!(<2, 8> "line 1"),
+        soft_break!(<8, 10>),
+        plain!(<12, 18> "line 2")
+    ))
+);
+
+body_check!(
+    hard_break,
+    "> line 1\\\n> line 2",
+    quote!(<0, 18> paragraph!(
+        <2, 18>
+        plain!(<2, 8> "line 1"),
+        hard_break!(<8, 10>),
+        plain!(<12, 18> "line 2")
+    ))
+);
+
 body_check!(
     continued_hanging,
     "> line 1\nline 2",
     quote!(<0, 15> paragraph!(
         <2, 15>
         plain!(<2, 8> "line 1"),
-        SoftBreak,
+        soft_break!(<8, 9>),
         plain!(<9, 15> "line 2")
     ))
 );
@@ -62,7 +84,7 @@ body_check!(
             paragraph!(
                 <14, 28>
                 plain!(<14, 20> "line 2"),
-                SoftBreak,
+                soft_break!(<20, 21>),
                 plain!(<21, 27> "inside")
             )
         )
@@ -74,7 +96,7 @@ body_check!(
 body_check!(
     inner_code_block,
     "> ```text\n>   bla\n>  ```",
-    quote!(<0, 24> code_block!(<2, 24> "text", "  bla\n"))
+    quote!(<0, 24> code_block!(<2, 24> <5, 9> "text", "  bla\n"))
 );
 
 body_check!(
@@ -89,3 +111,74 @@ body_check!(
     quote!(<0, 8> paragraph!(<2, 8> plain!(<2, 7> "quote"))),
     heading!(<8, 18> 1, plain!(<10, 18> "headline"))
 );
+
+mod admonitions {
+    use super::*;
+
+    body_check!(
+        disabled,
+        "> [!NOTE]\n> text",
+        quote!(<0, 16> paragraph!(
+            <2, 16>
+            linkref!(<2, 9> <3, 8> "!NOTE", plain!(<3, 8> "!NOTE")),
+            soft_break!(<9, 10>), plain!(<12, 16> "text")
+        ))
+    );
+
+    body_check!(
+        note,
+        ParserSettings::Admonitions,
+        ("> [!NOTE]", "> Useful information."),
+        admonition!(
+            <0, 31> "note", [],
+            paragraph!(<12, 31> plain!(<12, 31> "Useful information."))
+        )
+    );
+
+    body_check!(
+        custom_title,
+        ParserSettings::Admonitions,
+        ("> [!warning] Be `careful` now", "> text"),
+        admonition!(
+            <0, 36> "warning",
+            [plain!(<13, 16> "Be "), code!(<16, 25> "careful"), plain!(<25, 29> " now")],
+            paragraph!(<32, 36> plain!(<32, 36> "text"))
+        )
+    );
+
+    body_check!(
+        unknown_type,
+        ParserSettings::Admonitions,
+        ("> [!FOO]", "> text"),
+        quote!(<0, 15> paragraph!(
+            <2, 15>
+            linkref!(<2, 8> <3, 7> "!FOO", plain!(<3, 7> "!FOO")),
+            soft_break!(<8, 9>), plain!(<11, 15> "text")
+        ))
+    );
+
+    body_check!(
+        not_first_line,
+        ParserSettings::Admonitions,
+        ("> text", "> [!TIP]"),
+        quote!(<0, 15> paragraph!(
+            <2, 15>
+            plain!(<2, 6> "text"), soft_break!(<6, 7>),
+            linkref!(<9, 15> <10, 14> "!TIP", plain!(<10, 14> "!TIP"))
+        ))
+    );
+
+    #[test]
+    fn custom_types() {
+        let types = ["info"];
+        let inp = "> [!INFO]\n> text\n\n> [!NOTE]\n";
+
+        let mut data = StringData::new(inp, ParserSettings::Admonitions)
+            .with_admonition_types(&types);
+
+        assert!(matches!(
+            body(&mut data).as_slice(),
+            [Block::Admonition(kind, _, _, _), Block::Quote(..)] if kind == "info"
+        ));
+    }
+}
@@ -0,0 +1,101 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+fn parse(inp: &str) -> Document {
+    init!(inp);
+    Document::parse("", &mut StringData::new(inp, ParserSettings::default()))
+}
+
+/// Text, URL and title of the links and images of the last paragraph
+fn links(doc: &Document) -> Vec<(String, String, String)> {
+    let Some(Block::Paragraph(ct, ..)) = doc.body().last() else {
+        panic!("no paragraph at the end of {:?}", doc.body());
+    };
+
+    ct.iter()
+        .filter_map(|el| match el {
+            Inline::Link(txt, url, title, ..) | Inline::Image(txt, url, title, ..) => {
+                Some((inline_text(txt), url.clone(), title.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn labels(unresolved: Vec<(String, Location)>) -> Vec<String> {
+    unresolved.into_iter().map(|(label, _)| label).collect()
+}
+
+#[test]
+fn resolve() {
+    let mut doc = parse(
+        "[foo bar]: /url \"title\"\n[FOO BAR]: /other\n\n[Foo Bar], [x][foo  bar], ![img][FOO\nbar]"
+    );
+
+    assert!(doc.resolve_references().is_empty());
+    assert_eq!(
+        vec![
+            ("Foo Bar".into(), "/url".into(), "title".into()),
+            ("x".into(), "/url".into(), "title".into()),
+            ("img".into(), "/url".into(), "title".into()),
+        ],
+        links(&doc)
+    );
+    assert_matches!(doc.body()[0], Block::LinkDef(..));
+}
+
+#[test]
+fn nested_definitions() {
+    let mut doc = parse("> [a]: /a\n\n- [b]: /b\n\n[a] *[b]*");
+
+    assert!(doc.resolve_references().is_empty());
+    assert_eq!(vec![("a".into(), "/a".into(), String::new())], links(&doc));
+    assert_matches!(
+        doc.body().last(),
+        Some(Block::Paragraph(ct, ..))
+            if matches!(&ct[2], Inline::Emph(e, _) if matches!(e[0], Inline::Link(..)))
+    );
+}
+
+#[test]
+fn case_fold() {
+    let mut doc = parse("[ẞ]\n\n[SS]: /url");
+
+    assert!(doc.resolve_references().is_empty());
+    assert_matches!(doc.body()[0], Block::Paragraph(ref ct, ..) if matches!(ct[0], Inline::Link(..)));
+}
+
+#[test]
+fn unresolved() {
+    let mut doc = parse("[a]: /a\n\n[*b*] [c][missing] ![d][a]");
+    let unresolved = doc.resolve_references();
+
+    assert_eq!(vec!["*b*".to_string(), "missing".to_string()], labels(unresolved));
+    assert_eq!(vec![("d".into(), "/a".into(), String::new())], links(&doc));
+}
+
+/// Like the definitions, `[text]` and `[text][]` use the raw text as label
+#[test]
+fn raw_label() {
+    for inp in [
+        "[*foo* bar]: /url\n\n[*foo* bar]\n",
+        "[*foo* bar]: /url\n\n[*foo* bar][]\n",
+        "[`a`]: /url\n\n[`a`]\n",
+        "[`a`]: /url\n\n![`a`]\n",
+    ] {
+        let mut doc = parse(inp);
+        assert_eq!(doc.resolve_references(), [], "{:?}", inp);
+        assert_eq!(links(&doc).len(), 1, "{:?}", inp);
+    }
+}
+
+#[test]
+fn markup_not_in_label() {
+    let mut doc = parse("[foo bar]: /url\n\n[*foo* bar]\n");
+
+    assert_eq!(vec!["*foo* bar".to_string()], labels(doc.resolve_references()));
+    assert_eq!(links(&doc), []);
+}
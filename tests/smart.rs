@@ -0,0 +1,71 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+fn smart(inp: &str) -> Vec<Block> {
+    init!(inp);
+    let mut doc = Document::parse("", &mut StringData::new(inp, ParserSettings::default()));
+    doc.smart_punctuation();
+    std::mem::take(doc.body_mut())
+}
+
+#[test]
+fn english_by_default() {
+    assert_eq!(
+        vec![paragraph!(<0, 32> plain!(<0, 32> "“It’s ‘ok’” – really… — yes"))],
+        smart(r#""It's 'ok'" -- really... --- yes"#)
+    );
+}
+
+#[test]
+fn german() {
+    assert_eq!(
+        vec![paragraph!(<10, 41> plain!(<10, 41> "„Das ist’s“, sagt ‚er‘ – ja…"))],
+        smart("lang: de\n\n\"Das ist's\", sagt 'er' -- ja...")
+    );
+}
+
+#[test]
+fn across_markup() {
+    assert_eq!(
+        vec![paragraph!(
+            <0, 19>
+            plain!(<0, 1> "“"),
+            link!(<1, 7> <2, 3> <5, 6> <6, 6> "/", "", plain!(<2, 3> "a")),
+            plain!(<7, 10> "” ‘"),
+            code!(<10, 13> "b"),
+            plain!(<13, 19> "’ – c")
+        )],
+        smart(r#""[a](/)" '`b`' -- c"#)
+    );
+}
+
+#[test]
+fn code_and_urls_unchanged() {
+    assert_eq!(
+        vec![paragraph!(
+            <0, 41>
+            code!(<0, 7> "\"a\"--"),
+            plain!(<7, 8> " "),
+            link!(<8, 27> <9, 26> <9, 26> <26, 26> "http://x.org/a--b", "", plain!(<9, 26> "http://x.org/a--b")),
+            plain!(<27, 28> " "),
+            html!(<28, 41> "<a title='x'>")
+        )],
+        smart("`\"a\"--` <http://x.org/a--b> <a title='x'>")
+    );
+}
+
+#[test]
+fn nested_blocks() {
+    assert_eq!(
+        vec![
+            heading!(<0, 10> 1, plain!(<2, 9> "“Title”")),
+            quote!(<10, 19> paragraph!(<12, 19> plain!(<12, 18> "a – b"))),
+            unordered_list!(<20, 32> '-', item!(<20, 32> paragraph!(<22, 32> plain!(<22, 31> "‘c’ — d")))),
+            code_block!(<32, 44> <35, 35> "", "\"e\"\n")
+        ],
+        smart("# \"Title\"\n> a -- b\n\n- 'c' --- d\n```\n\"e\"\n```\n")
+    );
+}
@@ -3,36 +3,12 @@
 #![cfg(not(feature = "location"))]
 //
 // This file was generated with
-//   target/debug/build-spec-tests -i 9,30,118,154,168,207,208,219,246,260,262,263,267,272,282,283 commonmark-spec.txt commonmark-deviations.md
+//   target/debug/build-spec-tests -i 30,118,154,207,208,219,246,260,262,263,267,282,283 commonmark-spec.txt commonmark-deviations.md commonmark-ast.md
 //
 
 mod common;
 use common::*;
 
-fn space2tab(mut body: Vec<Block>) -> Vec<Block> {
-    body.iter_mut().for_each(|e| {
-        match e {
-            Block::Code(_, ref mut txt, _) | Block::Html(ref mut txt, _)
-                | Block::LinkDef(ref mut txt, ..) =>
-            {
-                *txt = txt.replace(' ', "\t");
-            }
-
-            Block::Paragraph(par, _) => {
-                par.iter_mut().for_each(|e| {
-                    match e {
-                        Inline::Code(txt, _) => *txt = txt.replace(' ', "\t"),
-                        _ => (),
-                    }
-                })
-            }
-
-            _ => (),
-        }
-    });
-
-    body
-}
 
 #[test]
 /// Test case generated from Commonmark Spec line 352
@@ -172,7 +148,7 @@ fn t3() {
     assert_eq!(
         // from spec: <p>a→a\nὐ→a</p>
         [
-            paragraph!(plain!("a\ta"), SoftBreak, plain!("ὐ\ta"))
+            paragraph!(plain!("a\ta"), soft_break!(), plain!("ὐ\ta"))
         ].as_slice(),
         body(&mut StringData::new("    a\ta\n    \u{1f50}\ta\n", ParserSettings::Html)),
     );
@@ -188,7 +164,7 @@ fn t3_cr() {
     assert_eq!(
         // from spec: <p>a→a\nὐ→a</p>
         [
-            paragraph!(plain!("a\ta"), SoftBreak, plain!("ὐ\ta"))
+            paragraph!(plain!("a\ta"), soft_break!(), plain!("ὐ\ta"))
         ].as_slice(),
         body(&mut StringData::new("    a\ta\r    \u{1f50}\ta\r", ParserSettings::Html)),
     );
@@ -204,7 +180,7 @@ fn t3_cr_nl() {
     assert_eq!(
         // from spec: <p>a→a\nὐ→a</p>
         [
-            paragraph!(plain!("a\ta"), SoftBreak, plain!("ὐ\ta"))
+            paragraph!(plain!("a\ta"), soft_break!(), plain!("ὐ\ta"))
         ].as_slice(),
         body(&mut StringData::new("    a\ta\r\n    \u{1f50}\ta\r\n", ParserSettings::Html)),
     );
@@ -220,7 +196,7 @@ fn t3_no_nl() {
     assert_eq!(
         // from spec: <p>a→a\nὐ→a</p>
         [
-            paragraph!(plain!("a\ta"), SoftBreak, plain!("ὐ\ta"))
+            paragraph!(plain!("a\ta"), soft_break!(), plain!("ὐ\ta"))
         ].as_slice(),
         body(&mut StringData::new("    a\ta\n    \u{1f50}\ta", ParserSettings::Html)),
     );
@@ -236,7 +212,7 @@ fn t4() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  - foo\n\n\tbar\n", ParserSettings::Html)),
     );
@@ -252,7 +228,7 @@ fn t4_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  - foo\r\r\tbar\r", ParserSettings::Html)),
     );
@@ -268,7 +244,7 @@ fn t4_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  - foo\r\n\r\n\tbar\r\n", ParserSettings::Html)),
     );
@@ -284,28 +260,12 @@ fn t4_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  - foo\n\n\tbar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 379
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L379>
-/// <https://spec.commonmark.org/0.29/#example-4>
-fn t4_tab() {
-    init!("  -\tfoo\n\n→bar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  -\tfoo\n\n\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 392
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L392>
@@ -316,7 +276,7 @@ fn t5() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n\n\t\tbar\n", ParserSettings::Html)),
     );
@@ -332,7 +292,7 @@ fn t5_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\r\t\tbar\r", ParserSettings::Html)),
     );
@@ -348,7 +308,7 @@ fn t5_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n\r\n\t\tbar\r\n", ParserSettings::Html)),
     );
@@ -364,28 +324,12 @@ fn t5_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n\n\t\tbar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 392
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L392>
-/// <https://spec.commonmark.org/0.29/#example-5>
-fn t5_tab() {
-    init!("-\tfoo\n\n→→bar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n\n\t\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 415
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L415>
@@ -460,7 +404,7 @@ fn t7() {
     assert_eq!(
         // from spec: <ul><li>foo</li></ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("-\t\tfoo\n", ParserSettings::Html)),
     );
@@ -476,7 +420,7 @@ fn t7_cr() {
     assert_eq!(
         // from spec: <ul><li>foo</li></ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("-\t\tfoo\r", ParserSettings::Html)),
     );
@@ -492,7 +436,7 @@ fn t7_cr_nl() {
     assert_eq!(
         // from spec: <ul><li>foo</li></ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("-\t\tfoo\r\n", ParserSettings::Html)),
     );
@@ -508,7 +452,7 @@ fn t7_no_nl() {
     assert_eq!(
         // from spec: <ul><li>foo</li></ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("-\t\tfoo", ParserSettings::Html)),
     );
@@ -524,7 +468,7 @@ fn t8() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("    foo\n\tbar\n", ParserSettings::Html)),
     );
@@ -540,7 +484,7 @@ fn t8_cr() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("    foo\r\tbar\r", ParserSettings::Html)),
     );
@@ -556,7 +500,7 @@ fn t8_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("    foo\r\n\tbar\r\n", ParserSettings::Html)),
     );
@@ -572,14 +516,13 @@ fn t8_no_nl() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("    foo\n\tbar", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 445
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L445>
 /// <https://spec.commonmark.org/0.29/#example-9>
@@ -589,14 +532,13 @@ fn t9() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz"))])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', item!(paragraph!(plain!("baz"))))))))
         ].as_slice(),
         body(&mut StringData::new(" - foo\n   - bar\n\t - baz\n", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 445
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L445>
 /// <https://spec.commonmark.org/0.29/#example-9>
@@ -606,14 +548,13 @@ fn t9_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz"))])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', item!(paragraph!(plain!("baz"))))))))
         ].as_slice(),
         body(&mut StringData::new(" - foo\r   - bar\r\t - baz\r", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 445
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L445>
 /// <https://spec.commonmark.org/0.29/#example-9>
@@ -623,14 +564,13 @@ fn t9_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz"))])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', item!(paragraph!(plain!("baz"))))))))
         ].as_slice(),
         body(&mut StringData::new(" - foo\r\n   - bar\r\n\t - baz\r\n", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 445
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L445>
 /// <https://spec.commonmark.org/0.29/#example-9>
@@ -640,29 +580,12 @@ fn t9_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz"))])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', item!(paragraph!(plain!("baz"))))))))
         ].as_slice(),
         body(&mut StringData::new(" - foo\n   - bar\n\t - baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 445
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L445>
-/// <https://spec.commonmark.org/0.29/#example-9>
-fn t9_tab() {
-    init!(" -\tfoo\n   -\tbar\n→\t-\tbaz\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz"))])])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(" -\tfoo\n   -\tbar\n\t\t-\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 463
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L463>
@@ -737,7 +660,7 @@ fn t11() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("*\t*\t*\t\n", ParserSettings::Html)),
     );
@@ -753,7 +676,7 @@ fn t11_cr() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("*\t*\t*\t\r", ParserSettings::Html)),
     );
@@ -769,7 +692,7 @@ fn t11_cr_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("*\t*\t*\t\r\n", ParserSettings::Html)),
     );
@@ -785,7 +708,7 @@ fn t11_no_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("*\t*\t*\t", ParserSettings::Html)),
     );
@@ -801,7 +724,7 @@ fn t12() {
     assert_eq!(
         // from spec: <ul>\n<li>`one</li>\n<li>two`</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("`one"))], vec![paragraph!(plain!("two`"))])
+            unordered_list!('-', item!(paragraph!(plain!("`one"))), item!(paragraph!(plain!("two`"))))
         ].as_slice(),
         body(&mut StringData::new("- `one\n- two`\n", ParserSettings::Html)),
     );
@@ -817,7 +740,7 @@ fn t12_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>`one</li>\n<li>two`</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("`one"))], vec![paragraph!(plain!("two`"))])
+            unordered_list!('-', item!(paragraph!(plain!("`one"))), item!(paragraph!(plain!("two`"))))
         ].as_slice(),
         body(&mut StringData::new("- `one\r- two`\r", ParserSettings::Html)),
     );
@@ -833,7 +756,7 @@ fn t12_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>`one</li>\n<li>two`</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("`one"))], vec![paragraph!(plain!("two`"))])
+            unordered_list!('-', item!(paragraph!(plain!("`one"))), item!(paragraph!(plain!("two`"))))
         ].as_slice(),
         body(&mut StringData::new("- `one\r\n- two`\r\n", ParserSettings::Html)),
     );
@@ -849,28 +772,12 @@ fn t12_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>`one</li>\n<li>two`</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("`one"))], vec![paragraph!(plain!("two`"))])
+            unordered_list!('-', item!(paragraph!(plain!("`one"))), item!(paragraph!(plain!("two`"))))
         ].as_slice(),
         body(&mut StringData::new("- `one\n- two`", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 496
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L496>
-/// <https://spec.commonmark.org/0.29/#example-12>
-fn t12_tab() {
-    init!("-\t`one\n-\ttwo`\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>`one</li>\n<li>two`</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("`one"))], vec![paragraph!(plain!("two`"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t`one\n-\ttwo`\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 535
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L535>
@@ -881,7 +788,7 @@ fn t13() {
     assert_eq!(
         // from spec: <hr />\n<hr />\n<hr />
         [
-            Break, Break, Break
+            thematic_break!(), thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("***\n---\n___\n", ParserSettings::Html)),
     );
@@ -897,7 +804,7 @@ fn t13_cr() {
     assert_eq!(
         // from spec: <hr />\n<hr />\n<hr />
         [
-            Break, Break, Break
+            thematic_break!(), thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("***\r---\r___\r", ParserSettings::Html)),
     );
@@ -913,7 +820,7 @@ fn t13_cr_nl() {
     assert_eq!(
         // from spec: <hr />\n<hr />\n<hr />
         [
-            Break, Break, Break
+            thematic_break!(), thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("***\r\n---\r\n___\r\n", ParserSettings::Html)),
     );
@@ -929,7 +836,7 @@ fn t13_no_nl() {
     assert_eq!(
         // from spec: <hr />\n<hr />\n<hr />
         [
-            Break, Break, Break
+            thematic_break!(), thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("***\n---\n___", ParserSettings::Html)),
     );
@@ -1073,7 +980,7 @@ fn t16() {
     assert_eq!(
         // from spec: <p>--\n**\n__</p>
         [
-            paragraph!(plain!("--"), SoftBreak, plain!("**"), SoftBreak, plain!("__"))
+            paragraph!(plain!("--"), soft_break!(), plain!("**"), soft_break!(), plain!("__"))
         ].as_slice(),
         body(&mut StringData::new("--\n**\n__\n", ParserSettings::Html)),
     );
@@ -1089,7 +996,7 @@ fn t16_cr() {
     assert_eq!(
         // from spec: <p>--\n**\n__</p>
         [
-            paragraph!(plain!("--"), SoftBreak, plain!("**"), SoftBreak, plain!("__"))
+            paragraph!(plain!("--"), soft_break!(), plain!("**"), soft_break!(), plain!("__"))
         ].as_slice(),
         body(&mut StringData::new("--\r**\r__\r", ParserSettings::Html)),
     );
@@ -1105,7 +1012,7 @@ fn t16_cr_nl() {
     assert_eq!(
         // from spec: <p>--\n**\n__</p>
         [
-            paragraph!(plain!("--"), SoftBreak, plain!("**"), SoftBreak, plain!("__"))
+            paragraph!(plain!("--"), soft_break!(), plain!("**"), soft_break!(), plain!("__"))
         ].as_slice(),
         body(&mut StringData::new("--\r\n**\r\n__\r\n", ParserSettings::Html)),
     );
@@ -1121,7 +1028,7 @@ fn t16_no_nl() {
     assert_eq!(
         // from spec: <p>--\n**\n__</p>
         [
-            paragraph!(plain!("--"), SoftBreak, plain!("**"), SoftBreak, plain!("__"))
+            paragraph!(plain!("--"), soft_break!(), plain!("**"), soft_break!(), plain!("__"))
         ].as_slice(),
         body(&mut StringData::new("--\n**\n__", ParserSettings::Html)),
     );
@@ -1137,7 +1044,7 @@ fn t17() {
     assert_eq!(
         // from spec: <hr />\n<hr />\n<hr />
         [
-            Break, Break, Break
+            thematic_break!(), thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" ***\n  ***\n   ***\n", ParserSettings::Html)),
     );
@@ -1153,7 +1060,7 @@ fn t17_cr() {
     assert_eq!(
         // from spec: <hr />\n<hr />\n<hr />
         [
-            Break, Break, Break
+            thematic_break!(), thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" ***\r  ***\r   ***\r", ParserSettings::Html)),
     );
@@ -1169,7 +1076,7 @@ fn t17_cr_nl() {
     assert_eq!(
         // from spec: <hr />\n<hr />\n<hr />
         [
-            Break, Break, Break
+            thematic_break!(), thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" ***\r\n  ***\r\n   ***\r\n", ParserSettings::Html)),
     );
@@ -1185,7 +1092,7 @@ fn t17_no_nl() {
     assert_eq!(
         // from spec: <hr />\n<hr />\n<hr />
         [
-            Break, Break, Break
+            thematic_break!(), thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" ***\n  ***\n   ***", ParserSettings::Html)),
     );
@@ -1201,7 +1108,7 @@ fn t18() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    ***\n", ParserSettings::Html)),
     );
@@ -1217,7 +1124,7 @@ fn t18_cr() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    ***\r", ParserSettings::Html)),
     );
@@ -1233,7 +1140,7 @@ fn t18_cr_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    ***\r\n", ParserSettings::Html)),
     );
@@ -1249,7 +1156,7 @@ fn t18_no_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    ***", ParserSettings::Html)),
     );
@@ -1265,7 +1172,7 @@ fn t19() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\n    ***\n", ParserSettings::Html)),
     );
@@ -1281,7 +1188,7 @@ fn t19_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r    ***\r", ParserSettings::Html)),
     );
@@ -1297,7 +1204,7 @@ fn t19_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n    ***\r\n", ParserSettings::Html)),
     );
@@ -1313,7 +1220,7 @@ fn t19_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\n    ***", ParserSettings::Html)),
     );
@@ -1329,7 +1236,7 @@ fn t20() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("_____________________________________\n", ParserSettings::Html)),
     );
@@ -1345,7 +1252,7 @@ fn t20_cr() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("_____________________________________\r", ParserSettings::Html)),
     );
@@ -1361,7 +1268,7 @@ fn t20_cr_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("_____________________________________\r\n", ParserSettings::Html)),
     );
@@ -1377,7 +1284,7 @@ fn t20_no_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("_____________________________________", ParserSettings::Html)),
     );
@@ -1393,7 +1300,7 @@ fn t21() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" - - -\n", ParserSettings::Html)),
     );
@@ -1409,7 +1316,7 @@ fn t21_cr() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" - - -\r", ParserSettings::Html)),
     );
@@ -1425,7 +1332,7 @@ fn t21_cr_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" - - -\r\n", ParserSettings::Html)),
     );
@@ -1441,28 +1348,12 @@ fn t21_no_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" - - -", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 618
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L618>
-/// <https://spec.commonmark.org/0.29/#example-21>
-fn t21_tab() {
-    init!(" -\t-\t-\n");
-
-    assert_eq!(
-        // from spec: <hr />
-        [
-            Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(" -\t-\t-\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 625
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L625>
@@ -1473,7 +1364,7 @@ fn t22() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" **  * ** * ** * **\n", ParserSettings::Html)),
     );
@@ -1489,7 +1380,7 @@ fn t22_cr() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" **  * ** * ** * **\r", ParserSettings::Html)),
     );
@@ -1505,7 +1396,7 @@ fn t22_cr_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" **  * ** * ** * **\r\n", ParserSettings::Html)),
     );
@@ -1521,28 +1412,12 @@ fn t22_no_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new(" **  * ** * ** * **", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 625
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L625>
-/// <https://spec.commonmark.org/0.29/#example-22>
-fn t22_tab() {
-    init!(" **\t\t*\t**\t*\t**\t*\t**\n");
-
-    assert_eq!(
-        // from spec: <hr />
-        [
-            Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(" **\t\t*\t**\t*\t**\t*\t**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 632
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L632>
@@ -1553,7 +1428,7 @@ fn t23() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("-     -      -      -\n", ParserSettings::Html)),
     );
@@ -1569,7 +1444,7 @@ fn t23_cr() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("-     -      -      -\r", ParserSettings::Html)),
     );
@@ -1585,7 +1460,7 @@ fn t23_cr_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("-     -      -      -\r\n", ParserSettings::Html)),
     );
@@ -1601,28 +1476,12 @@ fn t23_no_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("-     -      -      -", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 632
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L632>
-/// <https://spec.commonmark.org/0.29/#example-23>
-fn t23_tab() {
-    init!("-\t\t\t\t\t-\t\t\t\t\t\t-\t\t\t\t\t\t-\n");
-
-    assert_eq!(
-        // from spec: <hr />
-        [
-            Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t\t\t\t\t-\t\t\t\t\t\t-\t\t\t\t\t\t-\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 641
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L641>
@@ -1633,7 +1492,7 @@ fn t24() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- - - -    \n", ParserSettings::Html)),
     );
@@ -1649,7 +1508,7 @@ fn t24_cr() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- - - -    \r", ParserSettings::Html)),
     );
@@ -1665,7 +1524,7 @@ fn t24_cr_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- - - -    \r\n", ParserSettings::Html)),
     );
@@ -1681,28 +1540,12 @@ fn t24_no_nl() {
     assert_eq!(
         // from spec: <hr />
         [
-            Break
+            thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- - - -    ", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 641
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L641>
-/// <https://spec.commonmark.org/0.29/#example-24>
-fn t24_tab() {
-    init!("-\t-\t-\t-\t\t  \n");
-
-    assert_eq!(
-        // from spec: <hr />
-        [
-            Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t-\t-\t-\t\t  \n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 650
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L650>
@@ -1767,22 +1610,6 @@ fn t25_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 650
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L650>
-/// <https://spec.commonmark.org/0.29/#example-25>
-fn t25_tab() {
-    init!("_\t_\t_\t_\ta\n\na------\n\n---a---\n");
-
-    assert_eq!(
-        // from spec: <p>_ _ _ _ a</p>\n<p>a------</p>\n<p>---a---</p>
-        [
-            paragraph!(plain!("_	_	_	_	a")), paragraph!(plain!("a------")), paragraph!(plain!("---a---"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("_\t_\t_\t_\ta\n\na------\n\n---a---\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 666
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L666>
@@ -1857,7 +1684,7 @@ fn t27() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />\n<ul>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break, unordered_list!(vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), thematic_break!(), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n***\n- bar\n", ParserSettings::Html)),
     );
@@ -1873,7 +1700,7 @@ fn t27_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />\n<ul>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break, unordered_list!(vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), thematic_break!(), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r***\r- bar\r", ParserSettings::Html)),
     );
@@ -1889,7 +1716,7 @@ fn t27_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />\n<ul>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break, unordered_list!(vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), thematic_break!(), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n***\r\n- bar\r\n", ParserSettings::Html)),
     );
@@ -1905,28 +1732,12 @@ fn t27_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />\n<ul>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break, unordered_list!(vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), thematic_break!(), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n***\n- bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 675
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L675>
-/// <https://spec.commonmark.org/0.29/#example-27>
-fn t27_tab() {
-    init!("-\tfoo\n***\n-\tbar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />\n<ul>\n<li>bar</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break, unordered_list!(vec![paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n***\n-\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 692
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L692>
@@ -1937,7 +1748,7 @@ fn t28() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>bar</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("bar"))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\n***\nbar\n", ParserSettings::Html)),
     );
@@ -1953,7 +1764,7 @@ fn t28_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>bar</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("bar"))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r***\rbar\r", ParserSettings::Html)),
     );
@@ -1969,7 +1780,7 @@ fn t28_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>bar</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("bar"))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n***\r\nbar\r\n", ParserSettings::Html)),
     );
@@ -1985,7 +1796,7 @@ fn t28_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>bar</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("bar"))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\n***\nbar", ParserSettings::Html)),
     );
@@ -2001,7 +1812,7 @@ fn t29() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>bar</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("bar"))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\n---\nbar\n", ParserSettings::Html)),
     );
@@ -2017,7 +1828,7 @@ fn t29_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>bar</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("bar"))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r---\rbar\r", ParserSettings::Html)),
     );
@@ -2033,7 +1844,7 @@ fn t29_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>bar</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("bar"))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n---\r\nbar\r\n", ParserSettings::Html)),
     );
@@ -2049,7 +1860,7 @@ fn t29_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>bar</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("bar"))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\n---\nbar", ParserSettings::Html)),
     );
@@ -2066,7 +1877,7 @@ fn t30() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />\n<ul>\n<li>Bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break, unordered_list!(vec![paragraph!(plain!("Bar"))])
+            unordered_list!('*', item!(paragraph!(plain!("Foo")))), thematic_break!(), unordered_list!('*', item!(paragraph!(plain!("Bar"))))
         ].as_slice(),
         body(&mut StringData::new("* Foo\n* * *\n* Bar\n", ParserSettings::Html)),
     );
@@ -2083,7 +1894,7 @@ fn t30_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />\n<ul>\n<li>Bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break, unordered_list!(vec![paragraph!(plain!("Bar"))])
+            unordered_list!('*', item!(paragraph!(plain!("Foo")))), thematic_break!(), unordered_list!('*', item!(paragraph!(plain!("Bar"))))
         ].as_slice(),
         body(&mut StringData::new("* Foo\r* * *\r* Bar\r", ParserSettings::Html)),
     );
@@ -2100,7 +1911,7 @@ fn t30_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />\n<ul>\n<li>Bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break, unordered_list!(vec![paragraph!(plain!("Bar"))])
+            unordered_list!('*', item!(paragraph!(plain!("Foo")))), thematic_break!(), unordered_list!('*', item!(paragraph!(plain!("Bar"))))
         ].as_slice(),
         body(&mut StringData::new("* Foo\r\n* * *\r\n* Bar\r\n", ParserSettings::Html)),
     );
@@ -2117,29 +1928,12 @@ fn t30_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />\n<ul>\n<li>Bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break, unordered_list!(vec![paragraph!(plain!("Bar"))])
+            unordered_list!('*', item!(paragraph!(plain!("Foo")))), thematic_break!(), unordered_list!('*', item!(paragraph!(plain!("Bar"))))
         ].as_slice(),
         body(&mut StringData::new("* Foo\n* * *\n* Bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 722
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L722>
-/// <https://spec.commonmark.org/0.29/#example-30>
-fn t30_tab() {
-    init!("*\tFoo\n*\t*\t*\n*\tBar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />\n<ul>\n<li>Bar</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break, unordered_list!(vec![paragraph!(plain!("Bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*\tFoo\n*\t*\t*\n*\tBar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 739
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L739>
@@ -2150,7 +1944,7 @@ fn t31() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n<li>\n<hr />\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))], vec![Break])
+            unordered_list!('-', item!(paragraph!(plain!("Foo"))), item!(thematic_break!()))
         ].as_slice(),
         body(&mut StringData::new("- Foo\n- * * *\n", ParserSettings::Html)),
     );
@@ -2166,7 +1960,7 @@ fn t31_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n<li>\n<hr />\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))], vec![Break])
+            unordered_list!('-', item!(paragraph!(plain!("Foo"))), item!(thematic_break!()))
         ].as_slice(),
         body(&mut StringData::new("- Foo\r- * * *\r", ParserSettings::Html)),
     );
@@ -2182,7 +1976,7 @@ fn t31_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n<li>\n<hr />\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))], vec![Break])
+            unordered_list!('-', item!(paragraph!(plain!("Foo"))), item!(thematic_break!()))
         ].as_slice(),
         body(&mut StringData::new("- Foo\r\n- * * *\r\n", ParserSettings::Html)),
     );
@@ -2198,28 +1992,12 @@ fn t31_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n<li>\n<hr />\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))], vec![Break])
+            unordered_list!('-', item!(paragraph!(plain!("Foo"))), item!(thematic_break!()))
         ].as_slice(),
         body(&mut StringData::new("- Foo\n- * * *", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 739
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L739>
-/// <https://spec.commonmark.org/0.29/#example-31>
-fn t31_tab() {
-    init!("-\tFoo\n-\t*\t*\t*\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>Foo</li>\n<li>\n<hr />\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("Foo"))], vec![Break])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tFoo\n-\t*\t*\t*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 768
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L768>
@@ -2284,22 +2062,6 @@ fn t32_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 768
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L768>
-/// <https://spec.commonmark.org/0.29/#example-32>
-fn t32_tab() {
-    init!("#\tfoo\n##\tfoo\n###\tfoo\n####\tfoo\n#####\tfoo\n######\tfoo\n");
-
-    assert_eq!(
-        // from spec: <h1>foo</h1>\n<h2>foo</h2>\n<h3>foo</h3>\n<h4>foo</h4>\n<h5>foo</h5>\n<h6>foo</h6>
-        [
-            heading!(1, plain!("foo")), heading!(2, plain!("foo")), heading!(3, plain!("foo")), heading!(4, plain!("foo")), heading!(5, plain!("foo")), heading!(6, plain!("foo"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#\tfoo\n##\tfoo\n###\tfoo\n####\tfoo\n#####\tfoo\n######\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 787
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L787>
@@ -2364,22 +2126,6 @@ fn t33_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 787
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L787>
-/// <https://spec.commonmark.org/0.29/#example-33>
-fn t33_tab() {
-    init!("#######\tfoo\n");
-
-    assert_eq!(
-        // from spec: <p>####### foo</p>
-        [
-            paragraph!(plain!("#######	foo"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#######\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 802
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L802>
@@ -2444,22 +2190,6 @@ fn t34_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 802
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L802>
-/// <https://spec.commonmark.org/0.29/#example-34>
-fn t34_tab() {
-    init!("#5\tbolt\n\n#hashtag\n");
-
-    assert_eq!(
-        // from spec: <p>#5 bolt</p>\n<p>#hashtag</p>
-        [
-            paragraph!(plain!("#5	bolt")), paragraph!(plain!("#hashtag"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#5\tbolt\n\n#hashtag\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 814
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L814>
@@ -2524,22 +2254,6 @@ fn t35_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 814
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L814>
-/// <https://spec.commonmark.org/0.29/#example-35>
-fn t35_tab() {
-    init!("\\##\tfoo\n");
-
-    assert_eq!(
-        // from spec: <p>## foo</p>
-        [
-            paragraph!(plain!("##	foo"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("\\##\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 823
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L823>
@@ -2604,22 +2318,6 @@ fn t36_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 823
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L823>
-/// <https://spec.commonmark.org/0.29/#example-36>
-fn t36_tab() {
-    init!("#\tfoo\t*bar*\t\\*baz\\*\n");
-
-    assert_eq!(
-        // from spec: <h1>foo <em>bar</em> *baz*</h1>
-        [
-            heading!(1, plain!("foo	"), emph!(plain!("bar")), plain!("	*baz*"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#\tfoo\t*bar*\t\\*baz\\*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 832
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L832>
@@ -2684,22 +2382,6 @@ fn t37_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 832
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L832>
-/// <https://spec.commonmark.org/0.29/#example-37>
-fn t37_tab() {
-    init!("#\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\tfoo\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t  \n");
-
-    assert_eq!(
-        // from spec: <h1>foo</h1>
-        [
-            heading!(1, plain!("foo"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\tfoo\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t  \n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 841
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L841>
@@ -2764,22 +2446,6 @@ fn t38_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 841
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L841>
-/// <https://spec.commonmark.org/0.29/#example-38>
-fn t38_tab() {
-    init!(" ###\tfoo\n  ##\tfoo\n   #\tfoo\n");
-
-    assert_eq!(
-        // from spec: <h3>foo</h3>\n<h2>foo</h2>\n<h1>foo</h1>
-        [
-            heading!(3, plain!("foo")), heading!(2, plain!("foo")), heading!(1, plain!("foo"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(" ###\tfoo\n  ##\tfoo\n   #\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 854
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L854>
@@ -2844,22 +2510,6 @@ fn t39_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 854
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L854>
-/// <https://spec.commonmark.org/0.29/#example-39>
-fn t39_tab() {
-    init!("    #\tfoo\n");
-
-    assert_eq!(
-        // from spec: <h1>foo</h1>
-        [
-            heading!(1, plain!("foo"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    #\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 862
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L862>
@@ -2924,22 +2574,6 @@ fn t40_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 862
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L862>
-/// <https://spec.commonmark.org/0.29/#example-40>
-fn t40_tab() {
-    init!("foo\n    #\tbar\n");
-
-    assert_eq!(
-        // from spec: <p>foo</p>\n<h1>bar</h1>
-        [
-            paragraph!(plain!("foo")), heading!(1, plain!("bar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\n    #\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 873
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L873>
@@ -3004,22 +2638,6 @@ fn t41_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 873
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L873>
-/// <https://spec.commonmark.org/0.29/#example-41>
-fn t41_tab() {
-    init!("##\tfoo\t##\n  ###\t\t\tbar\t\t\t\t###\n");
-
-    assert_eq!(
-        // from spec: <h2>foo ##</h2>\n<h3>bar    ###</h3>
-        [
-            heading!(2, plain!("foo	##")), heading!(3, plain!("bar				###"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("##\tfoo\t##\n  ###\t\t\tbar\t\t\t\t###\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 884
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L884>
@@ -3084,22 +2702,6 @@ fn t42_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 884
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L884>
-/// <https://spec.commonmark.org/0.29/#example-42>
-fn t42_tab() {
-    init!("#\tfoo\t##################################\n#####\tfoo\t##\n");
-
-    assert_eq!(
-        // from spec: <h1>foo ##################################</h1>\n<h5>foo ##</h5>
-        [
-            heading!(1, plain!("foo	##################################")), heading!(5, plain!("foo	##"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#\tfoo\t##################################\n#####\tfoo\t##\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 895
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L895>
@@ -3164,22 +2766,6 @@ fn t43_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 895
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L895>
-/// <https://spec.commonmark.org/0.29/#example-43>
-fn t43_tab() {
-    init!("###\tfoo\t###\t\t\t  \n");
-
-    assert_eq!(
-        // from spec: <h3>foo ###</h3>
-        [
-            heading!(3, plain!("foo	###"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("###\tfoo\t###\t\t\t  \n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 906
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L906>
@@ -3244,22 +2830,6 @@ fn t44_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 906
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L906>
-/// <https://spec.commonmark.org/0.29/#example-44>
-fn t44_tab() {
-    init!("###\tfoo\t###\tb\n");
-
-    assert_eq!(
-        // from spec: <h3>foo ### b</h3>
-        [
-            heading!(3, plain!("foo	###	b"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("###\tfoo\t###\tb\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 915
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L915>
@@ -3324,22 +2894,6 @@ fn t45_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 915
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L915>
-/// <https://spec.commonmark.org/0.29/#example-45>
-fn t45_tab() {
-    init!("#\tfoo#\n");
-
-    assert_eq!(
-        // from spec: <h1>foo#</h1>
-        [
-            heading!(1, plain!("foo#"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#\tfoo#\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 925
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L925>
@@ -3404,22 +2958,6 @@ fn t46_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 925
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L925>
-/// <https://spec.commonmark.org/0.29/#example-46>
-fn t46_tab() {
-    init!("###\tfoo\t\\###\n##\tfoo\t#\\##\n#\tfoo\t\\#\n");
-
-    assert_eq!(
-        // from spec: <h3>foo ###</h3>\n<h2>foo ###</h2>\n<h1>foo #</h1>
-        [
-            heading!(3, plain!("foo	###")), heading!(2, plain!("foo	###")), heading!(1, plain!("foo	#"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("###\tfoo\t\\###\n##\tfoo\t#\\##\n#\tfoo\t\\#\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 939
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L939>
@@ -3430,7 +2968,7 @@ fn t47() {
     assert_eq!(
         // from spec: <hr />\n<h2>foo</h2>\n<hr />
         [
-            Break, heading!(2, plain!("foo")), Break
+            thematic_break!(), heading!(2, plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("****\n## foo\n****\n", ParserSettings::Html)),
     );
@@ -3446,7 +2984,7 @@ fn t47_cr() {
     assert_eq!(
         // from spec: <hr />\n<h2>foo</h2>\n<hr />
         [
-            Break, heading!(2, plain!("foo")), Break
+            thematic_break!(), heading!(2, plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("****\r## foo\r****\r", ParserSettings::Html)),
     );
@@ -3462,7 +3000,7 @@ fn t47_cr_nl() {
     assert_eq!(
         // from spec: <hr />\n<h2>foo</h2>\n<hr />
         [
-            Break, heading!(2, plain!("foo")), Break
+            thematic_break!(), heading!(2, plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("****\r\n## foo\r\n****\r\n", ParserSettings::Html)),
     );
@@ -3478,28 +3016,12 @@ fn t47_no_nl() {
     assert_eq!(
         // from spec: <hr />\n<h2>foo</h2>\n<hr />
         [
-            Break, heading!(2, plain!("foo")), Break
+            thematic_break!(), heading!(2, plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("****\n## foo\n****", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 939
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L939>
-/// <https://spec.commonmark.org/0.29/#example-47>
-fn t47_tab() {
-    init!("****\n##\tfoo\n****\n");
-
-    assert_eq!(
-        // from spec: <hr />\n<h2>foo</h2>\n<hr />
-        [
-            Break, heading!(2, plain!("foo")), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("****\n##\tfoo\n****\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 950
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L950>
@@ -3564,22 +3086,6 @@ fn t48_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 950
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L950>
-/// <https://spec.commonmark.org/0.29/#example-48>
-fn t48_tab() {
-    init!("Foo\tbar\n#\tbaz\nBar\tfoo\n");
-
-    assert_eq!(
-        // from spec: <p>Foo bar</p>\n<h1>baz</h1>\n<p>Bar foo</p>
-        [
-            paragraph!(plain!("Foo	bar")), heading!(1, plain!("baz")), paragraph!(plain!("Bar	foo"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\tbar\n#\tbaz\nBar\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 963
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L963>
@@ -3644,22 +3150,6 @@ fn t49_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 963
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L963>
-/// <https://spec.commonmark.org/0.29/#example-49>
-fn t49_tab() {
-    init!("##\t\n#\n###\t###\n");
-
-    assert_eq!(
-        // from spec: <h2></h2>\n<h1></h1>\n<h3>###</h3>
-        [
-            heading!(2), heading!(1), heading!(3, plain!("###"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("##\t\n#\n###\t###\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1006
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1006>
@@ -3670,7 +3160,7 @@ fn t50() {
     assert_eq!(
         // from spec: <p>Foo <em>bar</em>\n=========</p>\n<p>Foo <em>bar</em></p>\n<hr />
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar")), SoftBreak, plain!("=========")), paragraph!(plain!("Foo "), emph!(plain!("bar"))), Break
+            paragraph!(plain!("Foo "), emph!(plain!("bar")), soft_break!(), plain!("=========")), paragraph!(plain!("Foo "), emph!(plain!("bar"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo *bar*\n=========\n\nFoo *bar*\n---------\n", ParserSettings::Html)),
     );
@@ -3686,7 +3176,7 @@ fn t50_cr() {
     assert_eq!(
         // from spec: <p>Foo <em>bar</em>\n=========</p>\n<p>Foo <em>bar</em></p>\n<hr />
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar")), SoftBreak, plain!("=========")), paragraph!(plain!("Foo "), emph!(plain!("bar"))), Break
+            paragraph!(plain!("Foo "), emph!(plain!("bar")), soft_break!(), plain!("=========")), paragraph!(plain!("Foo "), emph!(plain!("bar"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo *bar*\r=========\r\rFoo *bar*\r---------\r", ParserSettings::Html)),
     );
@@ -3702,7 +3192,7 @@ fn t50_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo <em>bar</em>\n=========</p>\n<p>Foo <em>bar</em></p>\n<hr />
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar")), SoftBreak, plain!("=========")), paragraph!(plain!("Foo "), emph!(plain!("bar"))), Break
+            paragraph!(plain!("Foo "), emph!(plain!("bar")), soft_break!(), plain!("=========")), paragraph!(plain!("Foo "), emph!(plain!("bar"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo *bar*\r\n=========\r\n\r\nFoo *bar*\r\n---------\r\n", ParserSettings::Html)),
     );
@@ -3718,28 +3208,12 @@ fn t50_no_nl() {
     assert_eq!(
         // from spec: <p>Foo <em>bar</em>\n=========</p>\n<p>Foo <em>bar</em></p>\n<hr />
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar")), SoftBreak, plain!("=========")), paragraph!(plain!("Foo "), emph!(plain!("bar"))), Break
+            paragraph!(plain!("Foo "), emph!(plain!("bar")), soft_break!(), plain!("=========")), paragraph!(plain!("Foo "), emph!(plain!("bar"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo *bar*\n=========\n\nFoo *bar*\n---------", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1006
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1006>
-/// <https://spec.commonmark.org/0.29/#example-50>
-fn t50_tab() {
-    init!("Foo\t*bar*\n=========\n\nFoo\t*bar*\n---------\n");
-
-    assert_eq!(
-        // from spec: <p>Foo <em>bar</em>\n=========</p>\n<p>Foo <em>bar</em></p>\n<hr />
-        [
-            paragraph!(plain!("Foo	"), emph!(plain!("bar")), SoftBreak, plain!("=========")), paragraph!(plain!("Foo	"), emph!(plain!("bar"))), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\t*bar*\n=========\n\nFoo\t*bar*\n---------\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1020
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1020>
@@ -3750,7 +3224,7 @@ fn t51() {
     assert_eq!(
         // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
+            paragraph!(plain!("Foo "), emph!(plain!("bar"), soft_break!(), plain!("baz")), soft_break!(), plain!("===="))
         ].as_slice(),
         body(&mut StringData::new("Foo *bar\nbaz*\n====\n", ParserSettings::Html)),
     );
@@ -3766,7 +3240,7 @@ fn t51_cr() {
     assert_eq!(
         // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
+            paragraph!(plain!("Foo "), emph!(plain!("bar"), soft_break!(), plain!("baz")), soft_break!(), plain!("===="))
         ].as_slice(),
         body(&mut StringData::new("Foo *bar\rbaz*\r====\r", ParserSettings::Html)),
     );
@@ -3782,7 +3256,7 @@ fn t51_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
+            paragraph!(plain!("Foo "), emph!(plain!("bar"), soft_break!(), plain!("baz")), soft_break!(), plain!("===="))
         ].as_slice(),
         body(&mut StringData::new("Foo *bar\r\nbaz*\r\n====\r\n", ParserSettings::Html)),
     );
@@ -3798,28 +3272,12 @@ fn t51_no_nl() {
     assert_eq!(
         // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
+            paragraph!(plain!("Foo "), emph!(plain!("bar"), soft_break!(), plain!("baz")), soft_break!(), plain!("===="))
         ].as_slice(),
         body(&mut StringData::new("Foo *bar\nbaz*\n====", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1020
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1020>
-/// <https://spec.commonmark.org/0.29/#example-51>
-fn t51_tab() {
-    init!("Foo\t*bar\nbaz*\n====\n");
-
-    assert_eq!(
-        // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
-        [
-            paragraph!(plain!("Foo	"), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\t*bar\nbaz*\n====\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1034
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1034>
@@ -3830,7 +3288,7 @@ fn t52() {
     assert_eq!(
         // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
+            paragraph!(plain!("Foo "), emph!(plain!("bar"), soft_break!(), plain!("baz")), soft_break!(), plain!("===="))
         ].as_slice(),
         body(&mut StringData::new("  Foo *bar\nbaz*\t\n====\n", ParserSettings::Html)),
     );
@@ -3846,7 +3304,7 @@ fn t52_cr() {
     assert_eq!(
         // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
+            paragraph!(plain!("Foo "), emph!(plain!("bar"), soft_break!(), plain!("baz")), soft_break!(), plain!("===="))
         ].as_slice(),
         body(&mut StringData::new("  Foo *bar\rbaz*\t\r====\r", ParserSettings::Html)),
     );
@@ -3862,7 +3320,7 @@ fn t52_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
+            paragraph!(plain!("Foo "), emph!(plain!("bar"), soft_break!(), plain!("baz")), soft_break!(), plain!("===="))
         ].as_slice(),
         body(&mut StringData::new("  Foo *bar\r\nbaz*\t\r\n====\r\n", ParserSettings::Html)),
     );
@@ -3878,28 +3336,12 @@ fn t52_no_nl() {
     assert_eq!(
         // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
         [
-            paragraph!(plain!("Foo "), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
+            paragraph!(plain!("Foo "), emph!(plain!("bar"), soft_break!(), plain!("baz")), soft_break!(), plain!("===="))
         ].as_slice(),
         body(&mut StringData::new("  Foo *bar\nbaz*\t\n====", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1034
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1034>
-/// <https://spec.commonmark.org/0.29/#example-52>
-fn t52_tab() {
-    init!("  Foo\t*bar\nbaz*→\n====\n");
-
-    assert_eq!(
-        // from spec: <p>Foo <em>bar\nbaz</em>\n====</p>
-        [
-            paragraph!(plain!("Foo	"), emph!(plain!("bar"), SoftBreak, plain!("baz")), SoftBreak, plain!("===="))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  Foo\t*bar\nbaz*\t\n====\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1046
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1046>
@@ -3910,7 +3352,7 @@ fn t53() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo\n=</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo"), SoftBreak, plain!("="))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo"), soft_break!(), plain!("="))
         ].as_slice(),
         body(&mut StringData::new("Foo\n-------------------------\n\nFoo\n=\n", ParserSettings::Html)),
     );
@@ -3926,7 +3368,7 @@ fn t53_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo\n=</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo"), SoftBreak, plain!("="))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo"), soft_break!(), plain!("="))
         ].as_slice(),
         body(&mut StringData::new("Foo\r-------------------------\r\rFoo\r=\r", ParserSettings::Html)),
     );
@@ -3942,7 +3384,7 @@ fn t53_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo\n=</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo"), SoftBreak, plain!("="))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo"), soft_break!(), plain!("="))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n-------------------------\r\n\r\nFoo\r\n=\r\n", ParserSettings::Html)),
     );
@@ -3958,7 +3400,7 @@ fn t53_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo\n=</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo"), SoftBreak, plain!("="))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo"), soft_break!(), plain!("="))
         ].as_slice(),
         body(&mut StringData::new("Foo\n-------------------------\n\nFoo\n=", ParserSettings::Html)),
     );
@@ -3974,7 +3416,7 @@ fn t54() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo</p>\n<hr />\n<p>Foo\n===</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo"), SoftBreak, plain!("==="))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo"), soft_break!(), plain!("==="))
         ].as_slice(),
         body(&mut StringData::new("   Foo\n---\n\n  Foo\n-----\n\n  Foo\n  ===\n", ParserSettings::Html)),
     );
@@ -3990,7 +3432,7 @@ fn t54_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo</p>\n<hr />\n<p>Foo\n===</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo"), SoftBreak, plain!("==="))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo"), soft_break!(), plain!("==="))
         ].as_slice(),
         body(&mut StringData::new("   Foo\r---\r\r  Foo\r-----\r\r  Foo\r  ===\r", ParserSettings::Html)),
     );
@@ -4006,7 +3448,7 @@ fn t54_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo</p>\n<hr />\n<p>Foo\n===</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo"), SoftBreak, plain!("==="))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo"), soft_break!(), plain!("==="))
         ].as_slice(),
         body(&mut StringData::new("   Foo\r\n---\r\n\r\n  Foo\r\n-----\r\n\r\n  Foo\r\n  ===\r\n", ParserSettings::Html)),
     );
@@ -4022,7 +3464,7 @@ fn t54_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo</p>\n<hr />\n<p>Foo\n===</p>
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo"), SoftBreak, plain!("==="))
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo"), soft_break!(), plain!("==="))
         ].as_slice(),
         body(&mut StringData::new("   Foo\n---\n\n  Foo\n-----\n\n  Foo\n  ===", ParserSettings::Html)),
     );
@@ -4038,7 +3480,7 @@ fn t55() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    Foo\n    ---\n\n    Foo\n---\n", ParserSettings::Html)),
     );
@@ -4054,7 +3496,7 @@ fn t55_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    Foo\r    ---\r\r    Foo\r---\r", ParserSettings::Html)),
     );
@@ -4070,7 +3512,7 @@ fn t55_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    Foo\r\n    ---\r\n\r\n    Foo\r\n---\r\n", ParserSettings::Html)),
     );
@@ -4086,7 +3528,7 @@ fn t55_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />\n<p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break, paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    Foo\n    ---\n\n    Foo\n---", ParserSettings::Html)),
     );
@@ -4102,7 +3544,7 @@ fn t56() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\n   ----      \n", ParserSettings::Html)),
     );
@@ -4118,7 +3560,7 @@ fn t56_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r   ----      \r", ParserSettings::Html)),
     );
@@ -4134,7 +3576,7 @@ fn t56_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n   ----      \r\n", ParserSettings::Html)),
     );
@@ -4150,28 +3592,12 @@ fn t56_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\n   ----      ", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1098
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1098>
-/// <https://spec.commonmark.org/0.29/#example-56>
-fn t56_tab() {
-    init!("Foo\n   ----\t\t\t\t  \n");
-
-    assert_eq!(
-        // from spec: <p>Foo</p>\n<hr />
-        [
-            paragraph!(plain!("Foo")), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\n   ----\t\t\t\t  \n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1108
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1108>
@@ -4182,7 +3608,7 @@ fn t57() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\n    ---\n", ParserSettings::Html)),
     );
@@ -4198,7 +3624,7 @@ fn t57_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r    ---\r", ParserSettings::Html)),
     );
@@ -4214,7 +3640,7 @@ fn t57_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n    ---\r\n", ParserSettings::Html)),
     );
@@ -4230,7 +3656,7 @@ fn t57_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\n    ---", ParserSettings::Html)),
     );
@@ -4246,7 +3672,7 @@ fn t58() {
     assert_eq!(
         // from spec: <p>Foo\n= =</p>\n<p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("= =")), paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo"), soft_break!(), plain!("= =")), paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\n= =\n\nFoo\n--- -\n", ParserSettings::Html)),
     );
@@ -4262,7 +3688,7 @@ fn t58_cr() {
     assert_eq!(
         // from spec: <p>Foo\n= =</p>\n<p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("= =")), paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo"), soft_break!(), plain!("= =")), paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r= =\r\rFoo\r--- -\r", ParserSettings::Html)),
     );
@@ -4278,7 +3704,7 @@ fn t58_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo\n= =</p>\n<p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("= =")), paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo"), soft_break!(), plain!("= =")), paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n= =\r\n\r\nFoo\r\n--- -\r\n", ParserSettings::Html)),
     );
@@ -4294,28 +3720,12 @@ fn t58_no_nl() {
     assert_eq!(
         // from spec: <p>Foo\n= =</p>\n<p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("= =")), paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo"), soft_break!(), plain!("= =")), paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\n= =\n\nFoo\n--- -", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1119
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1119>
-/// <https://spec.commonmark.org/0.29/#example-58>
-fn t58_tab() {
-    init!("Foo\n=\t=\n\nFoo\n---\t-\n");
-
-    assert_eq!(
-        // from spec: <p>Foo\n= =</p>\n<p>Foo</p>\n<hr />
-        [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("=	=")), paragraph!(plain!("Foo")), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\n=\t=\n\nFoo\n---\t-\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1135
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1135>
@@ -4326,7 +3736,7 @@ fn t59() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo  \n-----\n", ParserSettings::Html)),
     );
@@ -4342,7 +3752,7 @@ fn t59_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo  \r-----\r", ParserSettings::Html)),
     );
@@ -4358,7 +3768,7 @@ fn t59_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo  \r\n-----\r\n", ParserSettings::Html)),
     );
@@ -4374,7 +3784,7 @@ fn t59_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<hr />
         [
-            paragraph!(plain!("Foo")), Break
+            paragraph!(plain!("Foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo  \n-----", ParserSettings::Html)),
     );
@@ -4390,7 +3800,7 @@ fn t60() {
     assert_eq!(
         // from spec: <p>Foo\\</p>\n<hr />
         [
-            paragraph!(plain!("Foo\\")), Break
+            paragraph!(plain!("Foo\\")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\\\n----\n", ParserSettings::Html)),
     );
@@ -4406,7 +3816,7 @@ fn t60_cr() {
     assert_eq!(
         // from spec: <p>Foo\\</p>\n<hr />
         [
-            paragraph!(plain!("Foo\\")), Break
+            paragraph!(plain!("Foo\\")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\\\r----\r", ParserSettings::Html)),
     );
@@ -4422,7 +3832,7 @@ fn t60_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo\\</p>\n<hr />
         [
-            paragraph!(plain!("Foo\\")), Break
+            paragraph!(plain!("Foo\\")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\\\r\n----\r\n", ParserSettings::Html)),
     );
@@ -4438,7 +3848,7 @@ fn t60_no_nl() {
     assert_eq!(
         // from spec: <p>Foo\\</p>\n<hr />
         [
-            paragraph!(plain!("Foo\\")), Break
+            paragraph!(plain!("Foo\\")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\\\n----", ParserSettings::Html)),
     );
@@ -4454,7 +3864,7 @@ fn t61() {
     assert_eq!(
         // from spec: <p>`Foo</p>\n<hr />\n<p>`</p>\n<p>&lt;a title=&quot;a lot</p>\n<hr />\n<p>of dashes&quot;/&gt;</p>
         [
-            paragraph!(plain!("`Foo")), Break, paragraph!(plain!("`")), paragraph!(plain!("<a title=\"a lot")), Break, paragraph!(plain!("of dashes\"/>"))
+            paragraph!(plain!("`Foo")), thematic_break!(), paragraph!(plain!("`")), paragraph!(plain!("<a title=\"a lot")), thematic_break!(), paragraph!(plain!("of dashes\"/>"))
         ].as_slice(),
         body(&mut StringData::new("`Foo\n----\n`\n\n<a title=\"a lot\n---\nof dashes\"/>\n", ParserSettings::Html)),
     );
@@ -4470,7 +3880,7 @@ fn t61_cr() {
     assert_eq!(
         // from spec: <p>`Foo</p>\n<hr />\n<p>`</p>\n<p>&lt;a title=&quot;a lot</p>\n<hr />\n<p>of dashes&quot;/&gt;</p>
         [
-            paragraph!(plain!("`Foo")), Break, paragraph!(plain!("`")), paragraph!(plain!("<a title=\"a lot")), Break, paragraph!(plain!("of dashes\"/>"))
+            paragraph!(plain!("`Foo")), thematic_break!(), paragraph!(plain!("`")), paragraph!(plain!("<a title=\"a lot")), thematic_break!(), paragraph!(plain!("of dashes\"/>"))
         ].as_slice(),
         body(&mut StringData::new("`Foo\r----\r`\r\r<a title=\"a lot\r---\rof dashes\"/>\r", ParserSettings::Html)),
     );
@@ -4486,7 +3896,7 @@ fn t61_cr_nl() {
     assert_eq!(
         // from spec: <p>`Foo</p>\n<hr />\n<p>`</p>\n<p>&lt;a title=&quot;a lot</p>\n<hr />\n<p>of dashes&quot;/&gt;</p>
         [
-            paragraph!(plain!("`Foo")), Break, paragraph!(plain!("`")), paragraph!(plain!("<a title=\"a lot")), Break, paragraph!(plain!("of dashes\"/>"))
+            paragraph!(plain!("`Foo")), thematic_break!(), paragraph!(plain!("`")), paragraph!(plain!("<a title=\"a lot")), thematic_break!(), paragraph!(plain!("of dashes\"/>"))
         ].as_slice(),
         body(&mut StringData::new("`Foo\r\n----\r\n`\r\n\r\n<a title=\"a lot\r\n---\r\nof dashes\"/>\r\n", ParserSettings::Html)),
     );
@@ -4502,28 +3912,12 @@ fn t61_no_nl() {
     assert_eq!(
         // from spec: <p>`Foo</p>\n<hr />\n<p>`</p>\n<p>&lt;a title=&quot;a lot</p>\n<hr />\n<p>of dashes&quot;/&gt;</p>
         [
-            paragraph!(plain!("`Foo")), Break, paragraph!(plain!("`")), paragraph!(plain!("<a title=\"a lot")), Break, paragraph!(plain!("of dashes\"/>"))
+            paragraph!(plain!("`Foo")), thematic_break!(), paragraph!(plain!("`")), paragraph!(plain!("<a title=\"a lot")), thematic_break!(), paragraph!(plain!("of dashes\"/>"))
         ].as_slice(),
         body(&mut StringData::new("`Foo\n----\n`\n\n<a title=\"a lot\n---\nof dashes\"/>", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1156
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1156>
-/// <https://spec.commonmark.org/0.29/#example-61>
-fn t61_tab() {
-    init!("`Foo\n----\n`\n\n<a\ttitle=\"a\tlot\n---\nof\tdashes\"/>\n");
-
-    assert_eq!(
-        // from spec: <p>`Foo</p>\n<hr />\n<p>`</p>\n<p>&lt;a title=&quot;a lot</p>\n<hr />\n<p>of dashes&quot;/&gt;</p>
-        [
-            paragraph!(plain!("`Foo")), Break, paragraph!(plain!("`")), paragraph!(plain!("<a	title=\"a	lot")), Break, paragraph!(plain!("of	dashes\"/>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("`Foo\n----\n`\n\n<a\ttitle=\"a\tlot\n---\nof\tdashes\"/>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1175
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1175>
@@ -4534,7 +3928,7 @@ fn t62() {
     assert_eq!(
         // from spec: <blockquote>\n<p>Foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("Foo"))), Break
+            quote!(paragraph!(plain!("Foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> Foo\n---\n", ParserSettings::Html)),
     );
@@ -4550,7 +3944,7 @@ fn t62_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>Foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("Foo"))), Break
+            quote!(paragraph!(plain!("Foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> Foo\r---\r", ParserSettings::Html)),
     );
@@ -4566,7 +3960,7 @@ fn t62_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>Foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("Foo"))), Break
+            quote!(paragraph!(plain!("Foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> Foo\r\n---\r\n", ParserSettings::Html)),
     );
@@ -4582,28 +3976,12 @@ fn t62_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>Foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("Foo"))), Break
+            quote!(paragraph!(plain!("Foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> Foo\n---", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1175
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1175>
-/// <https://spec.commonmark.org/0.29/#example-62>
-fn t62_tab() {
-    init!(">\tFoo\n---\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>Foo</p>\n</blockquote>\n<hr />
-        [
-            quote!(paragraph!(plain!("Foo"))), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tFoo\n---\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1186
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1186>
@@ -4614,7 +3992,7 @@ fn t63() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar\n===</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("===")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("===")))
         ].as_slice(),
         body(&mut StringData::new("> foo\nbar\n===\n", ParserSettings::Html)),
     );
@@ -4630,7 +4008,7 @@ fn t63_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar\n===</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("===")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("===")))
         ].as_slice(),
         body(&mut StringData::new("> foo\rbar\r===\r", ParserSettings::Html)),
     );
@@ -4646,7 +4024,7 @@ fn t63_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar\n===</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("===")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("===")))
         ].as_slice(),
         body(&mut StringData::new("> foo\r\nbar\r\n===\r\n", ParserSettings::Html)),
     );
@@ -4662,28 +4040,12 @@ fn t63_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar\n===</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("===")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("===")))
         ].as_slice(),
         body(&mut StringData::new("> foo\nbar\n===", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1186
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1186>
-/// <https://spec.commonmark.org/0.29/#example-63>
-fn t63_tab() {
-    init!(">\tfoo\nbar\n===\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo\nbar\n===</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("===")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tfoo\nbar\n===\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1199
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1199>
@@ -4694,7 +4056,7 @@ fn t64() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break
+            unordered_list!('-', item!(paragraph!(plain!("Foo")))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- Foo\n---\n", ParserSettings::Html)),
     );
@@ -4710,7 +4072,7 @@ fn t64_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break
+            unordered_list!('-', item!(paragraph!(plain!("Foo")))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- Foo\r---\r", ParserSettings::Html)),
     );
@@ -4726,7 +4088,7 @@ fn t64_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break
+            unordered_list!('-', item!(paragraph!(plain!("Foo")))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- Foo\r\n---\r\n", ParserSettings::Html)),
     );
@@ -4742,28 +4104,12 @@ fn t64_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />
         [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break
+            unordered_list!('-', item!(paragraph!(plain!("Foo")))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- Foo\n---", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1199
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1199>
-/// <https://spec.commonmark.org/0.29/#example-64>
-fn t64_tab() {
-    init!("-\tFoo\n---\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>Foo</li>\n</ul>\n<hr />
-        [
-            unordered_list!(vec![paragraph!(plain!("Foo"))]), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tFoo\n---\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1214
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1214>
@@ -4774,7 +4120,7 @@ fn t65() {
     assert_eq!(
         // from spec: <p>Foo\nBar</p>\n<hr />
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("Bar")), Break
+            paragraph!(plain!("Foo"), soft_break!(), plain!("Bar")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\nBar\n---\n", ParserSettings::Html)),
     );
@@ -4790,7 +4136,7 @@ fn t65_cr() {
     assert_eq!(
         // from spec: <p>Foo\nBar</p>\n<hr />
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("Bar")), Break
+            paragraph!(plain!("Foo"), soft_break!(), plain!("Bar")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\rBar\r---\r", ParserSettings::Html)),
     );
@@ -4806,7 +4152,7 @@ fn t65_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo\nBar</p>\n<hr />
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("Bar")), Break
+            paragraph!(plain!("Foo"), soft_break!(), plain!("Bar")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\r\nBar\r\n---\r\n", ParserSettings::Html)),
     );
@@ -4822,7 +4168,7 @@ fn t65_no_nl() {
     assert_eq!(
         // from spec: <p>Foo\nBar</p>\n<hr />
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("Bar")), Break
+            paragraph!(plain!("Foo"), soft_break!(), plain!("Bar")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("Foo\nBar\n---", ParserSettings::Html)),
     );
@@ -4838,7 +4184,7 @@ fn t66() {
     assert_eq!(
         // from spec: <hr />\n<p>Foo</p>\n<hr />\n<p>Bar</p>\n<hr />\n<p>Baz</p>
         [
-            Break, paragraph!(plain!("Foo")), Break, paragraph!(plain!("Bar")), Break, paragraph!(plain!("Baz"))
+            thematic_break!(), paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Bar")), thematic_break!(), paragraph!(plain!("Baz"))
         ].as_slice(),
         body(&mut StringData::new("---\nFoo\n---\nBar\n---\nBaz\n", ParserSettings::Html)),
     );
@@ -4854,7 +4200,7 @@ fn t66_cr() {
     assert_eq!(
         // from spec: <hr />\n<p>Foo</p>\n<hr />\n<p>Bar</p>\n<hr />\n<p>Baz</p>
         [
-            Break, paragraph!(plain!("Foo")), Break, paragraph!(plain!("Bar")), Break, paragraph!(plain!("Baz"))
+            thematic_break!(), paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Bar")), thematic_break!(), paragraph!(plain!("Baz"))
         ].as_slice(),
         body(&mut StringData::new("---\rFoo\r---\rBar\r---\rBaz\r", ParserSettings::Html)),
     );
@@ -4870,7 +4216,7 @@ fn t66_cr_nl() {
     assert_eq!(
         // from spec: <hr />\n<p>Foo</p>\n<hr />\n<p>Bar</p>\n<hr />\n<p>Baz</p>
         [
-            Break, paragraph!(plain!("Foo")), Break, paragraph!(plain!("Bar")), Break, paragraph!(plain!("Baz"))
+            thematic_break!(), paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Bar")), thematic_break!(), paragraph!(plain!("Baz"))
         ].as_slice(),
         body(&mut StringData::new("---\r\nFoo\r\n---\r\nBar\r\n---\r\nBaz\r\n", ParserSettings::Html)),
     );
@@ -4886,7 +4232,7 @@ fn t66_no_nl() {
     assert_eq!(
         // from spec: <hr />\n<p>Foo</p>\n<hr />\n<p>Bar</p>\n<hr />\n<p>Baz</p>
         [
-            Break, paragraph!(plain!("Foo")), Break, paragraph!(plain!("Bar")), Break, paragraph!(plain!("Baz"))
+            thematic_break!(), paragraph!(plain!("Foo")), thematic_break!(), paragraph!(plain!("Bar")), thematic_break!(), paragraph!(plain!("Baz"))
         ].as_slice(),
         body(&mut StringData::new("---\nFoo\n---\nBar\n---\nBaz", ParserSettings::Html)),
     );
@@ -4966,7 +4312,7 @@ fn t68() {
     assert_eq!(
         // from spec: <hr />\n<hr />
         [
-            Break, Break
+            thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("---\n---\n", ParserSettings::Html)),
     );
@@ -4982,7 +4328,7 @@ fn t68_cr() {
     assert_eq!(
         // from spec: <hr />\n<hr />
         [
-            Break, Break
+            thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("---\r---\r", ParserSettings::Html)),
     );
@@ -4998,7 +4344,7 @@ fn t68_cr_nl() {
     assert_eq!(
         // from spec: <hr />\n<hr />
         [
-            Break, Break
+            thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("---\r\n---\r\n", ParserSettings::Html)),
     );
@@ -5014,7 +4360,7 @@ fn t68_no_nl() {
     assert_eq!(
         // from spec: <hr />\n<hr />
         [
-            Break, Break
+            thematic_break!(), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("---\n---", ParserSettings::Html)),
     );
@@ -5030,7 +4376,7 @@ fn t69() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- foo\n-----\n", ParserSettings::Html)),
     );
@@ -5046,7 +4392,7 @@ fn t69_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- foo\r-----\r", ParserSettings::Html)),
     );
@@ -5062,7 +4408,7 @@ fn t69_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n-----\r\n", ParserSettings::Html)),
     );
@@ -5078,28 +4424,12 @@ fn t69_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("- foo\n-----", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1265
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1265>
-/// <https://spec.commonmark.org/0.29/#example-69>
-fn t69_tab() {
-    init!("-\tfoo\n-----\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n</ul>\n<hr />
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n-----\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1276
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1276>
@@ -5110,7 +4440,7 @@ fn t70() {
     assert_eq!(
         // from spec: <p>foo</p>\n<hr />
         [
-            paragraph!(plain!("foo")), Break
+            paragraph!(plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    foo\n---\n", ParserSettings::Html)),
     );
@@ -5126,7 +4456,7 @@ fn t70_cr() {
     assert_eq!(
         // from spec: <p>foo</p>\n<hr />
         [
-            paragraph!(plain!("foo")), Break
+            paragraph!(plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    foo\r---\r", ParserSettings::Html)),
     );
@@ -5142,7 +4472,7 @@ fn t70_cr_nl() {
     assert_eq!(
         // from spec: <p>foo</p>\n<hr />
         [
-            paragraph!(plain!("foo")), Break
+            paragraph!(plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    foo\r\n---\r\n", ParserSettings::Html)),
     );
@@ -5158,7 +4488,7 @@ fn t70_no_nl() {
     assert_eq!(
         // from spec: <p>foo</p>\n<hr />
         [
-            paragraph!(plain!("foo")), Break
+            paragraph!(plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("    foo\n---", ParserSettings::Html)),
     );
@@ -5174,7 +4504,7 @@ fn t71() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("foo"))), Break
+            quote!(paragraph!(plain!("foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> foo\n-----\n", ParserSettings::Html)),
     );
@@ -5190,7 +4520,7 @@ fn t71_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("foo"))), Break
+            quote!(paragraph!(plain!("foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> foo\r-----\r", ParserSettings::Html)),
     );
@@ -5206,7 +4536,7 @@ fn t71_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("foo"))), Break
+            quote!(paragraph!(plain!("foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> foo\r\n-----\r\n", ParserSettings::Html)),
     );
@@ -5222,28 +4552,12 @@ fn t71_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("foo"))), Break
+            quote!(paragraph!(plain!("foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> foo\n-----", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1286
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1286>
-/// <https://spec.commonmark.org/0.29/#example-71>
-fn t71_tab() {
-    init!(">\tfoo\n-----\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
-        [
-            quote!(paragraph!(plain!("foo"))), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tfoo\n-----\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1300
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1300>
@@ -5254,7 +4568,7 @@ fn t72() {
     assert_eq!(
         // from spec: <p>&gt; foo</p>\n<hr />
         [
-            paragraph!(plain!("> foo")), Break
+            paragraph!(plain!("> foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("\\> foo\n------\n", ParserSettings::Html)),
     );
@@ -5270,7 +4584,7 @@ fn t72_cr() {
     assert_eq!(
         // from spec: <p>&gt; foo</p>\n<hr />
         [
-            paragraph!(plain!("> foo")), Break
+            paragraph!(plain!("> foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("\\> foo\r------\r", ParserSettings::Html)),
     );
@@ -5286,7 +4600,7 @@ fn t72_cr_nl() {
     assert_eq!(
         // from spec: <p>&gt; foo</p>\n<hr />
         [
-            paragraph!(plain!("> foo")), Break
+            paragraph!(plain!("> foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("\\> foo\r\n------\r\n", ParserSettings::Html)),
     );
@@ -5302,28 +4616,12 @@ fn t72_no_nl() {
     assert_eq!(
         // from spec: <p>&gt; foo</p>\n<hr />
         [
-            paragraph!(plain!("> foo")), Break
+            paragraph!(plain!("> foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("\\> foo\n------", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1300
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1300>
-/// <https://spec.commonmark.org/0.29/#example-72>
-fn t72_tab() {
-    init!("\\>\tfoo\n------\n");
-
-    assert_eq!(
-        // from spec: <p>&gt; foo</p>\n<hr />
-        [
-            paragraph!(plain!(">	foo")), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("\\>\tfoo\n------\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1331
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1331>
@@ -5334,7 +4632,7 @@ fn t73() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<p>bar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo")), paragraph!(plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo")), paragraph!(plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\n\nbar\n---\nbaz\n", ParserSettings::Html)),
     );
@@ -5350,7 +4648,7 @@ fn t73_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<p>bar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo")), paragraph!(plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo")), paragraph!(plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\rbar\r---\rbaz\r", ParserSettings::Html)),
     );
@@ -5366,7 +4664,7 @@ fn t73_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<p>bar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo")), paragraph!(plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo")), paragraph!(plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n\r\nbar\r\n---\r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -5382,7 +4680,7 @@ fn t73_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<p>bar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo")), paragraph!(plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo")), paragraph!(plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\n\nbar\n---\nbaz", ParserSettings::Html)),
     );
@@ -5398,7 +4696,7 @@ fn t74() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\nbar\n\n---\n\nbaz\n", ParserSettings::Html)),
     );
@@ -5414,7 +4712,7 @@ fn t74_cr() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\rbar\r\r---\r\rbaz\r", ParserSettings::Html)),
     );
@@ -5430,7 +4728,7 @@ fn t74_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\nbar\r\n\r\n---\r\n\r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -5446,7 +4744,7 @@ fn t74_no_nl() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\nbar\n\n---\n\nbaz", ParserSettings::Html)),
     );
@@ -5462,7 +4760,7 @@ fn t75() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\nbar\n* * *\nbaz\n", ParserSettings::Html)),
     );
@@ -5478,7 +4776,7 @@ fn t75_cr() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\rbar\r* * *\rbaz\r", ParserSettings::Html)),
     );
@@ -5494,7 +4792,7 @@ fn t75_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\nbar\r\n* * *\r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -5510,28 +4808,12 @@ fn t75_no_nl() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar")), thematic_break!(), paragraph!(plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\nbar\n* * *\nbaz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1365
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1365>
-/// <https://spec.commonmark.org/0.29/#example-75>
-fn t75_tab() {
-    init!("Foo\nbar\n*\t*\t*\nbaz\n");
-
-    assert_eq!(
-        // from spec: <p>Foo\nbar</p>\n<hr />\n<p>baz</p>
-        [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar")), Break, paragraph!(plain!("baz"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\nbar\n*\t*\t*\nbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1380
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1380>
@@ -5542,7 +4824,7 @@ fn t76() {
     assert_eq!(
         // from spec: <p>Foo\nbar\n---\nbaz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("---"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("---"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\nbar\n\\---\nbaz\n", ParserSettings::Html)),
     );
@@ -5558,7 +4840,7 @@ fn t76_cr() {
     assert_eq!(
         // from spec: <p>Foo\nbar\n---\nbaz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("---"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("---"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\rbar\r\\---\rbaz\r", ParserSettings::Html)),
     );
@@ -5574,7 +4856,7 @@ fn t76_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo\nbar\n---\nbaz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("---"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("---"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\nbar\r\n\\---\r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -5590,7 +4872,7 @@ fn t76_no_nl() {
     assert_eq!(
         // from spec: <p>Foo\nbar\n---\nbaz</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("---"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("---"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("Foo\nbar\n\\---\nbaz", ParserSettings::Html)),
     );
@@ -5606,7 +4888,7 @@ fn t77() {
     assert_eq!(
         // from spec: <p>a simple\nindented code block</p>
         [
-            paragraph!(plain!("a simple"), SoftBreak, plain!("indented code block"))
+            paragraph!(plain!("a simple"), soft_break!(), plain!("indented code block"))
         ].as_slice(),
         body(&mut StringData::new("    a simple\n      indented code block\n", ParserSettings::Html)),
     );
@@ -5622,7 +4904,7 @@ fn t77_cr() {
     assert_eq!(
         // from spec: <p>a simple\nindented code block</p>
         [
-            paragraph!(plain!("a simple"), SoftBreak, plain!("indented code block"))
+            paragraph!(plain!("a simple"), soft_break!(), plain!("indented code block"))
         ].as_slice(),
         body(&mut StringData::new("    a simple\r      indented code block\r", ParserSettings::Html)),
     );
@@ -5638,7 +4920,7 @@ fn t77_cr_nl() {
     assert_eq!(
         // from spec: <p>a simple\nindented code block</p>
         [
-            paragraph!(plain!("a simple"), SoftBreak, plain!("indented code block"))
+            paragraph!(plain!("a simple"), soft_break!(), plain!("indented code block"))
         ].as_slice(),
         body(&mut StringData::new("    a simple\r\n      indented code block\r\n", ParserSettings::Html)),
     );
@@ -5654,28 +4936,12 @@ fn t77_no_nl() {
     assert_eq!(
         // from spec: <p>a simple\nindented code block</p>
         [
-            paragraph!(plain!("a simple"), SoftBreak, plain!("indented code block"))
+            paragraph!(plain!("a simple"), soft_break!(), plain!("indented code block"))
         ].as_slice(),
         body(&mut StringData::new("    a simple\n      indented code block", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1408
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1408>
-/// <https://spec.commonmark.org/0.29/#example-77>
-fn t77_tab() {
-    init!("    a\tsimple\n    \t\tindented\tcode\tblock\n");
-
-    assert_eq!(
-        // from spec: <p>a simple\nindented code block</p>
-        [
-            paragraph!(plain!("a	simple"), SoftBreak, plain!("indented	code	block"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    a\tsimple\n    \t\tindented\tcode\tblock\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1422
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1422>
@@ -5686,7 +4952,7 @@ fn t78() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  - foo\n\n    bar\n", ParserSettings::Html)),
     );
@@ -5702,7 +4968,7 @@ fn t78_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  - foo\r\r    bar\r", ParserSettings::Html)),
     );
@@ -5718,7 +4984,7 @@ fn t78_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  - foo\r\n\r\n    bar\r\n", ParserSettings::Html)),
     );
@@ -5734,28 +5000,12 @@ fn t78_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  - foo\n\n    bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1422
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1422>
-/// <https://spec.commonmark.org/0.29/#example-78>
-fn t78_tab() {
-    init!("  -\tfoo\n\n    bar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  -\tfoo\n\n    bar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1436
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1436>
@@ -5766,7 +5016,7 @@ fn t79() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
+            ordered_list!(loose, item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))))))
         ].as_slice(),
         body(&mut StringData::new("1.  foo\n\n    - bar\n", ParserSettings::Html)),
     );
@@ -5782,7 +5032,7 @@ fn t79_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
+            ordered_list!(loose, item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))))))
         ].as_slice(),
         body(&mut StringData::new("1.  foo\r\r    - bar\r", ParserSettings::Html)),
     );
@@ -5798,7 +5048,7 @@ fn t79_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
+            ordered_list!(loose, item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))))))
         ].as_slice(),
         body(&mut StringData::new("1.  foo\r\n\r\n    - bar\r\n", ParserSettings::Html)),
     );
@@ -5814,28 +5064,12 @@ fn t79_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
+            ordered_list!(loose, item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))))))
         ].as_slice(),
         body(&mut StringData::new("1.  foo\n\n    - bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1436
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1436>
-/// <https://spec.commonmark.org/0.29/#example-79>
-fn t79_tab() {
-    init!("1.\t\tfoo\n\n    -\tbar\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\t\tfoo\n\n    -\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1456
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1456>
@@ -5847,7 +5081,7 @@ fn t80() {
         // from spec: <pre><code>&lt;a/&gt;\n*hi*\n\n- one\n</code></pre>
         [
             html_block!("    <a/>\n    *hi*\n"),
-            unordered_list!(vec![ paragraph!(plain!("one")) ])
+            unordered_list!('-', item!( paragraph!(plain!("one")) ))
         ].as_slice(),
         body(&mut StringData::new("    <a/>\n    *hi*\n\n    - one\n", ParserSettings::Html)),
     );
@@ -5864,7 +5098,7 @@ fn t80_cr() {
         // from spec: <pre><code>&lt;a/&gt;\n*hi*\n\n- one\n</code></pre>
         [
             html_block!("    <a/>\r    *hi*\r"),
-            unordered_list!(vec![ paragraph!(plain!("one")) ])
+            unordered_list!('-', item!( paragraph!(plain!("one")) ))
         ].as_slice(),
         body(&mut StringData::new("    <a/>\r    *hi*\r\r    - one\r", ParserSettings::Html)),
     );
@@ -5881,7 +5115,7 @@ fn t80_cr_nl() {
         // from spec: <pre><code>&lt;a/&gt;\n*hi*\n\n- one\n</code></pre>
         [
             html_block!("    <a/>\r\n    *hi*\r\n"),
-            unordered_list!(vec![ paragraph!(plain!("one")) ])
+            unordered_list!('-', item!( paragraph!(plain!("one")) ))
         ].as_slice(),
         body(&mut StringData::new("    <a/>\r\n    *hi*\r\n\r\n    - one\r\n", ParserSettings::Html)),
     );
@@ -5898,29 +5132,12 @@ fn t80_no_nl() {
         // from spec: <pre><code>&lt;a/&gt;\n*hi*\n\n- one\n</code></pre>
         [
             html_block!("    <a/>\n    *hi*\n"),
-            unordered_list!(vec![ paragraph!(plain!("one")) ])
+            unordered_list!('-', item!( paragraph!(plain!("one")) ))
         ].as_slice(),
         body(&mut StringData::new("    <a/>\n    *hi*\n\n    - one", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1456
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1456>
-/// <https://spec.commonmark.org/0.29/#example-80>
-fn t80_tab() {
-    init!("    <a/>\n    *hi*\n\n    -\tone\n");
-
-    assert_eq!(
-        // from spec: <pre><code>&lt;a/&gt;\n*hi*\n\n- one\n</code></pre>
-        [
-            html_block!("				<a/>\n				*hi*\n"),
-            unordered_list!(vec![	paragraph!(plain!("one"))	])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    <a/>\n    *hi*\n\n    -\tone\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1472
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1472>
@@ -6049,22 +5266,6 @@ fn t82_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1495
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1495>
-/// <https://spec.commonmark.org/0.29/#example-82>
-fn t82_tab() {
-    init!("    chunk1\n      \n    \t\tchunk2\n");
-
-    assert_eq!(
-        // from spec: <p>chunk1</p>\n<p>chunk2</p>
-        [
-            paragraph!(plain!("chunk1")), paragraph!(plain!("chunk2"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    chunk1\n      \n    \t\tchunk2\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1510
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1510>
@@ -6075,7 +5276,7 @@ fn t83() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\n    bar\n\n", ParserSettings::Html)),
     );
@@ -6091,7 +5292,7 @@ fn t83_cr() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r    bar\r\r", ParserSettings::Html)),
     );
@@ -6107,7 +5308,7 @@ fn t83_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n    bar\r\n\r\n", ParserSettings::Html)),
     );
@@ -6123,7 +5324,7 @@ fn t83_no_nl() {
     assert_eq!(
         // from spec: <p>Foo\nbar</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("Foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("Foo\n    bar", ParserSettings::Html)),
     );
@@ -6139,7 +5340,7 @@ fn t84() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("    foo\nbar\n", ParserSettings::Html)),
     );
@@ -6155,7 +5356,7 @@ fn t84_cr() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("    foo\rbar\r", ParserSettings::Html)),
     );
@@ -6171,7 +5372,7 @@ fn t84_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("    foo\r\nbar\r\n", ParserSettings::Html)),
     );
@@ -6187,7 +5388,7 @@ fn t84_no_nl() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("    foo\nbar", ParserSettings::Html)),
     );
@@ -6203,7 +5404,7 @@ fn t85() {
     assert_eq!(
         // from spec: <h1>Heading</h1>\n<p>foo\nHeading</p>\n<hr />\n<p>foo</p>\n<hr />
         [
-            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), SoftBreak, plain!("Heading")), Break, paragraph!(plain!("foo")), Break
+            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), soft_break!(), plain!("Heading")), thematic_break!(), paragraph!(plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("# Heading\n    foo\nHeading\n------\n    foo\n----\n", ParserSettings::Html)),
     );
@@ -6219,7 +5420,7 @@ fn t85_cr() {
     assert_eq!(
         // from spec: <h1>Heading</h1>\n<p>foo\nHeading</p>\n<hr />\n<p>foo</p>\n<hr />
         [
-            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), SoftBreak, plain!("Heading")), Break, paragraph!(plain!("foo")), Break
+            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), soft_break!(), plain!("Heading")), thematic_break!(), paragraph!(plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("# Heading\r    foo\rHeading\r------\r    foo\r----\r", ParserSettings::Html)),
     );
@@ -6235,7 +5436,7 @@ fn t85_cr_nl() {
     assert_eq!(
         // from spec: <h1>Heading</h1>\n<p>foo\nHeading</p>\n<hr />\n<p>foo</p>\n<hr />
         [
-            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), SoftBreak, plain!("Heading")), Break, paragraph!(plain!("foo")), Break
+            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), soft_break!(), plain!("Heading")), thematic_break!(), paragraph!(plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("# Heading\r\n    foo\r\nHeading\r\n------\r\n    foo\r\n----\r\n", ParserSettings::Html)),
     );
@@ -6251,28 +5452,12 @@ fn t85_no_nl() {
     assert_eq!(
         // from spec: <h1>Heading</h1>\n<p>foo\nHeading</p>\n<hr />\n<p>foo</p>\n<hr />
         [
-            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), SoftBreak, plain!("Heading")), Break, paragraph!(plain!("foo")), Break
+            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), soft_break!(), plain!("Heading")), thematic_break!(), paragraph!(plain!("foo")), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("# Heading\n    foo\nHeading\n------\n    foo\n----", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1537
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1537>
-/// <https://spec.commonmark.org/0.29/#example-85>
-fn t85_tab() {
-    init!("#\tHeading\n    foo\nHeading\n------\n    foo\n----\n");
-
-    assert_eq!(
-        // from spec: <h1>Heading</h1>\n<p>foo\nHeading</p>\n<hr />\n<p>foo</p>\n<hr />
-        [
-            heading!(1, plain!("Heading")), paragraph!(plain!("foo"), SoftBreak, plain!("Heading")), Break, paragraph!(plain!("foo")), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#\tHeading\n    foo\nHeading\n------\n    foo\n----\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1557
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1557>
@@ -6283,7 +5468,7 @@ fn t86() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("        foo\n    bar\n", ParserSettings::Html)),
     );
@@ -6299,7 +5484,7 @@ fn t86_cr() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("        foo\r    bar\r", ParserSettings::Html)),
     );
@@ -6315,7 +5500,7 @@ fn t86_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("        foo\r\n    bar\r\n", ParserSettings::Html)),
     );
@@ -6331,28 +5516,12 @@ fn t86_no_nl() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("        foo\n    bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1557
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1557>
-/// <https://spec.commonmark.org/0.29/#example-86>
-fn t86_tab() {
-    init!("    \t\t\t\tfoo\n    bar\n");
-
-    assert_eq!(
-        // from spec: <p>foo\nbar</p>
-        [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    \t\t\t\tfoo\n    bar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1570
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1570>
@@ -7121,22 +6290,6 @@ fn t98_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1750
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1750>
-/// <https://spec.commonmark.org/0.29/#example-98>
-fn t98_tab() {
-    init!(">\t```\n>\taaa\n\nbbb\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<pre><code>aaa\n</code></pre>\n</blockquote>\n<p>bbb</p>
-        [
-            quote!(code_block!("", "aaa\n")), paragraph!(plain!("bbb"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t```\n>\taaa\n\nbbb\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1766
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1766>
@@ -7723,7 +6876,7 @@ fn t108() {
     assert_eq!(
         // from spec: <p><code> </code>\naaa</p>
         [
-            paragraph!(code!(" "), SoftBreak, plain!("aaa"))
+            paragraph!(code!(" "), soft_break!(), plain!("aaa"))
         ].as_slice(),
         body(&mut StringData::new("``` ```\naaa\n", ParserSettings::Html)),
     );
@@ -7739,7 +6892,7 @@ fn t108_cr() {
     assert_eq!(
         // from spec: <p><code> </code>\naaa</p>
         [
-            paragraph!(code!(" "), SoftBreak, plain!("aaa"))
+            paragraph!(code!(" "), soft_break!(), plain!("aaa"))
         ].as_slice(),
         body(&mut StringData::new("``` ```\raaa\r", ParserSettings::Html)),
     );
@@ -7755,7 +6908,7 @@ fn t108_cr_nl() {
     assert_eq!(
         // from spec: <p><code> </code>\naaa</p>
         [
-            paragraph!(code!(" "), SoftBreak, plain!("aaa"))
+            paragraph!(code!(" "), soft_break!(), plain!("aaa"))
         ].as_slice(),
         body(&mut StringData::new("``` ```\r\naaa\r\n", ParserSettings::Html)),
     );
@@ -7771,28 +6924,12 @@ fn t108_no_nl() {
     assert_eq!(
         // from spec: <p><code> </code>\naaa</p>
         [
-            paragraph!(code!(" "), SoftBreak, plain!("aaa"))
+            paragraph!(code!(" "), soft_break!(), plain!("aaa"))
         ].as_slice(),
         body(&mut StringData::new("``` ```\naaa", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1885
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1885>
-/// <https://spec.commonmark.org/0.29/#example-108>
-fn t108_tab() {
-    init!("```\t```\naaa\n");
-
-    assert_eq!(
-        // from spec: <p><code> </code>\naaa</p>
-        [
-            paragraph!(code!("\t"), SoftBreak, plain!("aaa"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("```\t```\naaa\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1894
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1894>
@@ -7857,22 +6994,6 @@ fn t109_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1894
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1894>
-/// <https://spec.commonmark.org/0.29/#example-109>
-fn t109_tab() {
-    init!("~~~~~~\naaa\n~~~\t~~\n");
-
-    assert_eq!(
-        // from spec: <pre><code>aaa\n~~~ ~~\n</code></pre>
-        [
-            code_block!("", "aaa\n~~~\t~~\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("~~~~~~\naaa\n~~~\t~~\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1908
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1908>
@@ -7947,7 +7068,7 @@ fn t111() {
     assert_eq!(
         // from spec: <p>foo</p>\n<hr />\n<pre><code>bar\n</code></pre>\n<h1>baz</h1>
         [
-            paragraph!(plain!("foo")), Break, code_block!("", "bar\n"), heading!(1, plain!("baz"))
+            paragraph!(plain!("foo")), thematic_break!(), code_block!("", "bar\n"), heading!(1, plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\n---\n~~~\nbar\n~~~\n# baz\n", ParserSettings::Html)),
     );
@@ -7963,7 +7084,7 @@ fn t111_cr() {
     assert_eq!(
         // from spec: <p>foo</p>\n<hr />\n<pre><code>bar\n</code></pre>\n<h1>baz</h1>
         [
-            paragraph!(plain!("foo")), Break, code_block!("", "bar\r"), heading!(1, plain!("baz"))
+            paragraph!(plain!("foo")), thematic_break!(), code_block!("", "bar\r"), heading!(1, plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\r---\r~~~\rbar\r~~~\r# baz\r", ParserSettings::Html)),
     );
@@ -7979,7 +7100,7 @@ fn t111_cr_nl() {
     assert_eq!(
         // from spec: <p>foo</p>\n<hr />\n<pre><code>bar\n</code></pre>\n<h1>baz</h1>
         [
-            paragraph!(plain!("foo")), Break, code_block!("", "bar\r\n"), heading!(1, plain!("baz"))
+            paragraph!(plain!("foo")), thematic_break!(), code_block!("", "bar\r\n"), heading!(1, plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\r\n---\r\n~~~\r\nbar\r\n~~~\r\n# baz\r\n", ParserSettings::Html)),
     );
@@ -7995,28 +7116,12 @@ fn t111_no_nl() {
     assert_eq!(
         // from spec: <p>foo</p>\n<hr />\n<pre><code>bar\n</code></pre>\n<h1>baz</h1>
         [
-            paragraph!(plain!("foo")), Break, code_block!("", "bar\n"), heading!(1, plain!("baz"))
+            paragraph!(plain!("foo")), thematic_break!(), code_block!("", "bar\n"), heading!(1, plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\n---\n~~~\nbar\n~~~\n# baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1925
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1925>
-/// <https://spec.commonmark.org/0.29/#example-111>
-fn t111_tab() {
-    init!("foo\n---\n~~~\nbar\n~~~\n#\tbaz\n");
-
-    assert_eq!(
-        // from spec: <p>foo</p>\n<hr />\n<pre><code>bar\n</code></pre>\n<h1>baz</h1>
-        [
-            paragraph!(plain!("foo")), Break, code_block!("", "bar\n"), heading!(1, plain!("baz"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\n---\n~~~\nbar\n~~~\n#\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1947
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1947>
@@ -8081,22 +7186,6 @@ fn t112_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1947
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1947>
-/// <https://spec.commonmark.org/0.29/#example-112>
-fn t112_tab() {
-    init!("```ruby\ndef\tfoo(x)\n  return\t3\nend\n```\n");
-
-    assert_eq!(
-        // from spec: <pre><code class=\"language-ruby\">def foo(x)\n  return 3\nend\n</code></pre>
-        [
-            code_block!("ruby", "def\tfoo(x)\n\t\treturn\t3\nend\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("```ruby\ndef\tfoo(x)\n  return\t3\nend\n```\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1961
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1961>
@@ -8161,22 +7250,6 @@ fn t113_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 1961
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1961>
-/// <https://spec.commonmark.org/0.29/#example-113>
-fn t113_tab() {
-    init!("~~~~\t\t\t\truby\tstartline=3\t$%@#$\ndef\tfoo(x)\n  return\t3\nend\n~~~~~~~\n");
-
-    assert_eq!(
-        // from spec: <pre><code class=\"language-ruby\">def foo(x)\n  return 3\nend\n</code></pre>
-        [
-            code_block!("ruby	startline=3	$%@#$",	"def	foo(x)\n		return	3\nend\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("~~~~\t\t\t\truby\tstartline=3\t$%@#$\ndef\tfoo(x)\n  return\t3\nend\n~~~~~~~\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 1975
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L1975>
@@ -8251,7 +7324,7 @@ fn t115() {
     assert_eq!(
         // from spec: <p><code>aa</code>\nfoo</p>
         [
-            paragraph!(code!("aa"), SoftBreak, plain!("foo"))
+            paragraph!(code!("aa"), soft_break!(), plain!("foo"))
         ].as_slice(),
         body(&mut StringData::new("``` aa ```\nfoo\n", ParserSettings::Html)),
     );
@@ -8267,7 +7340,7 @@ fn t115_cr() {
     assert_eq!(
         // from spec: <p><code>aa</code>\nfoo</p>
         [
-            paragraph!(code!("aa"), SoftBreak, plain!("foo"))
+            paragraph!(code!("aa"), soft_break!(), plain!("foo"))
         ].as_slice(),
         body(&mut StringData::new("``` aa ```\rfoo\r", ParserSettings::Html)),
     );
@@ -8283,7 +7356,7 @@ fn t115_cr_nl() {
     assert_eq!(
         // from spec: <p><code>aa</code>\nfoo</p>
         [
-            paragraph!(code!("aa"), SoftBreak, plain!("foo"))
+            paragraph!(code!("aa"), soft_break!(), plain!("foo"))
         ].as_slice(),
         body(&mut StringData::new("``` aa ```\r\nfoo\r\n", ParserSettings::Html)),
     );
@@ -8299,7 +7372,7 @@ fn t115_no_nl() {
     assert_eq!(
         // from spec: <p><code>aa</code>\nfoo</p>
         [
-            paragraph!(code!("aa"), SoftBreak, plain!("foo"))
+            paragraph!(code!("aa"), soft_break!(), plain!("foo"))
         ].as_slice(),
         body(&mut StringData::new("``` aa ```\nfoo", ParserSettings::Html)),
     );
@@ -8433,22 +7506,6 @@ fn t117_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2008
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2008>
-/// <https://spec.commonmark.org/0.29/#example-117>
-fn t117_tab() {
-    init!("```\n```\taaa\n```\n");
-
-    assert_eq!(
-        // from spec: <pre><code>``` aaa\n</code></pre>
-        [
-            code_block!("", "```\taaa\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("```\n```\taaa\n```\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 2087
@@ -8462,7 +7519,7 @@ fn t118() {
         [
             html_block!("<table><tr><td>\n<pre>\n**Hello**,\n"),
             paragraph!(
-              emph!(plain!("world")), plain!("."), SoftBreak,
+              emph!(plain!("world")), plain!("."), soft_break!(),
               html!("</pre>")
             ),
             html_block!("</td></tr></table>\n")
@@ -8484,7 +7541,7 @@ fn t118_cr() {
         [
             html_block!("<table><tr><td>\r<pre>\r**Hello**,\r"),
             paragraph!(
-              emph!(plain!("world")), plain!("."), SoftBreak,
+              emph!(plain!("world")), plain!("."), soft_break!(),
               html!("</pre>")
             ),
             html_block!("</td></tr></table>\r")
@@ -8506,7 +7563,7 @@ fn t118_cr_nl() {
         [
             html_block!("<table><tr><td>\r\n<pre>\r\n**Hello**,\r\n"),
             paragraph!(
-              emph!(plain!("world")), plain!("."), SoftBreak,
+              emph!(plain!("world")), plain!("."), soft_break!(),
               html!("</pre>")
             ),
             html_block!("</td></tr></table>\r\n")
@@ -8528,7 +7585,7 @@ fn t118_no_nl() {
         [
             html_block!("<table><tr><td>\n<pre>\n**Hello**,\n"),
             paragraph!(
-              emph!(plain!("world")), plain!("."), SoftBreak,
+              emph!(plain!("world")), plain!("."), soft_break!(),
               html!("</pre>")
             ),
             html_block!("</td></tr></table>\n")
@@ -8605,23 +7662,6 @@ fn t119_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2116
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2116>
-/// <https://spec.commonmark.org/0.29/#example-119>
-fn t119_tab() {
-    init!("<table>\n  <tr>\n    <td>\n    \t\t\t\t\t\t\thi\n    </td>\n  </tr>\n</table>\n\nokay.\n");
-
-    assert_eq!(
-        // from spec: <table>\n  <tr>\n    <td>\n           hi\n    </td>\n  </tr>\n</table>\n<p>okay.</p>
-        [
-            html_block!("<table>\n		<tr>\n				<td>\n											hi\n				</td>\n		</tr>\n</table>\n"),
-            paragraph!(plain!("okay."))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<table>\n  <tr>\n    <td>\n    \t\t\t\t\t\t\thi\n    </td>\n  </tr>\n</table>\n\nokay.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2138
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2138>
@@ -8686,22 +7726,6 @@ fn t120_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2138
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2138>
-/// <https://spec.commonmark.org/0.29/#example-120>
-fn t120_tab() {
-    init!(" <div>\n  *hello*\n    \t\t\t\t\t<foo><a>\n");
-
-    assert_eq!(
-        // from spec:  <div>\n  *hello*\n         <foo><a>
-        [
-            html_block!("	<div>\n		*hello*\n									<foo><a>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(" <div>\n  *hello*\n    \t\t\t\t\t<foo><a>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2151
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2151>
@@ -8838,24 +7862,6 @@ fn t122_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2162
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2162>
-/// <https://spec.commonmark.org/0.29/#example-122>
-fn t122_tab() {
-    init!("<DIV\tCLASS=\"foo\">\n\n*Markdown*\n\n</DIV>\n");
-
-    assert_eq!(
-        // from spec: <DIV CLASS=\"foo\">\n<p><em>Markdown</em></p>\n</DIV>
-        [
-            html_block!("<DIV	CLASS=\"foo\">\n"),
-            paragraph!(emph!(plain!("Markdown"))),
-            html_block!("</DIV>\n"),
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<DIV\tCLASS=\"foo\">\n\n*Markdown*\n\n</DIV>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2178
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2178>
@@ -8920,22 +7926,6 @@ fn t123_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2178
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2178>
-/// <https://spec.commonmark.org/0.29/#example-123>
-fn t123_tab() {
-    init!("<div\tid=\"foo\"\n  class=\"bar\">\n</div>\n");
-
-    assert_eq!(
-        // from spec: <div id=\"foo\"\n  class=\"bar\">\n</div>
-        [
-            html_block!("<div	id=\"foo\"\n		class=\"bar\">\n</div>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div\tid=\"foo\"\n  class=\"bar\">\n</div>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2189
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2189>
@@ -9000,22 +7990,6 @@ fn t124_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2189
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2189>
-/// <https://spec.commonmark.org/0.29/#example-124>
-fn t124_tab() {
-    init!("<div\tid=\"foo\"\tclass=\"bar\n  baz\">\n</div>\n");
-
-    assert_eq!(
-        // from spec: <div id=\"foo\" class=\"bar\n  baz\">\n</div>
-        [
-            html_block!("<div	id=\"foo\"	class=\"bar\n		baz\">\n</div>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div\tid=\"foo\"\tclass=\"bar\n  baz\">\n</div>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2201
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2201>
@@ -9144,22 +8118,6 @@ fn t126_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2217
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2217>
-/// <https://spec.commonmark.org/0.29/#example-126>
-fn t126_tab() {
-    init!("<div\tid=\"foo\"\n*hi*\n");
-
-    assert_eq!(
-        // from spec: <div id=\"foo\"\n*hi*
-        [
-            html_block!("<div	id=\"foo\"\n*hi*\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div\tid=\"foo\"\n*hi*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2226
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2226>
@@ -9224,22 +8182,6 @@ fn t127_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2226
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2226>
-/// <https://spec.commonmark.org/0.29/#example-127>
-fn t127_tab() {
-    init!("<div\tclass\nfoo\n");
-
-    assert_eq!(
-        // from spec: <div class\nfoo
-        [
-            html_block!("<div	class\nfoo\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div\tclass\nfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2238
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2238>
@@ -9304,22 +8246,6 @@ fn t128_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2238
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2238>
-/// <https://spec.commonmark.org/0.29/#example-128>
-fn t128_tab() {
-    init!("<div\t*???-&&&-<---\n*foo*\n");
-
-    assert_eq!(
-        // from spec: <div *???-&&&-<---\n*foo*
-        [
-            html_block!("<div	*???-&&&-<---\n*foo*\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div\t*???-&&&-<---\n*foo*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2250
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2250>
@@ -9384,22 +8310,6 @@ fn t129_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2250
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2250>
-/// <https://spec.commonmark.org/0.29/#example-129>
-fn t129_tab() {
-    init!("<div><a\thref=\"bar\">*foo*</a></div>\n");
-
-    assert_eq!(
-        // from spec: <div><a href=\"bar\">*foo*</a></div>
-        [
-            html_block!("<div><a	href=\"bar\">*foo*</a></div>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div><a\thref=\"bar\">*foo*</a></div>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2257
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2257>
@@ -9528,22 +8438,6 @@ fn t131_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2274
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2274>
-/// <https://spec.commonmark.org/0.29/#example-131>
-fn t131_tab() {
-    init!("<div></div>\n```\tc\nint\tx\t=\t33;\n```\n");
-
-    assert_eq!(
-        // from spec: <div></div>\n``` c\nint x = 33;\n```
-        [
-            html_block!("<div></div>\n```	c\nint	x	=	33;\n```\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div></div>\n```\tc\nint\tx\t=\t33;\n```\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2291
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2291>
@@ -9608,22 +8502,6 @@ fn t132_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2291
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2291>
-/// <https://spec.commonmark.org/0.29/#example-132>
-fn t132_tab() {
-    init!("<a\thref=\"foo\">\n*bar*\n</a>\n");
-
-    assert_eq!(
-        // from spec: <a href=\"foo\">\n*bar*\n</a>
-        [
-            html_block!("<a	href=\"foo\">\n*bar*\n</a>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\thref=\"foo\">\n*bar*\n</a>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2304
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2304>
@@ -9752,22 +8630,6 @@ fn t134_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2315
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2315>
-/// <https://spec.commonmark.org/0.29/#example-134>
-fn t134_tab() {
-    init!("<i\tclass=\"foo\">\n*bar*\n</i>\n");
-
-    assert_eq!(
-        // from spec: <i class=\"foo\">\n*bar*\n</i>
-        [
-            html_block!("<i	class=\"foo\">\n*bar*\n</i>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<i\tclass=\"foo\">\n*bar*\n</i>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2326
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2326>
@@ -10092,23 +8954,6 @@ fn t139_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2390
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2390>
-/// <https://spec.commonmark.org/0.29/#example-139>
-fn t139_tab() {
-    init!("<pre\tlanguage=\"haskell\"><code>\nimport\tText.HTML.TagSoup\n\nmain\t::\tIO\t()\nmain\t=\tprint\t$\tparseTags\ttags\n</code></pre>\nokay\n");
-
-    assert_eq!(
-        // from spec: <pre language=\"haskell\"><code>\nimport Text.HTML.TagSoup\n\nmain :: IO ()\nmain = print $ parseTags tags\n</code></pre>\n<p>okay</p>
-        [
-            html_block!("<pre	language=\"haskell\"><code>\nimport	Text.HTML.TagSoup\n\nmain	::	IO	()\nmain	=	print	$	parseTags	tags\n</code></pre>\n"),
-            paragraph!(plain!("okay"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<pre\tlanguage=\"haskell\"><code>\nimport\tText.HTML.TagSoup\n\nmain\t::\tIO\t()\nmain\t=\tprint\t$\tparseTags\ttags\n</code></pre>\nokay\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2411
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2411>
@@ -10177,23 +9022,6 @@ fn t140_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2411
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2411>
-/// <https://spec.commonmark.org/0.29/#example-140>
-fn t140_tab() {
-    init!("<script\ttype=\"text/javascript\">\n//\tJavaScript\texample\n\ndocument.getElementById(\"demo\").innerHTML\t=\t\"Hello\tJavaScript!\";\n</script>\nokay\n");
-
-    assert_eq!(
-        // from spec: <script type=\"text/javascript\">\n// JavaScript example\n\ndocument.getElementById(\"demo\").innerHTML = \"Hello JavaScript!\";\n</script>\n<p>okay</p>
-        [
-            html_block!("<script	type=\"text/javascript\">\n//	JavaScript	example\n\ndocument.getElementById(\"demo\").innerHTML	=	\"Hello	JavaScript!\";\n</script>\n"),
-            paragraph!(plain!("okay"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<script\ttype=\"text/javascript\">\n//\tJavaScript\texample\n\ndocument.getElementById(\"demo\").innerHTML\t=\t\"Hello\tJavaScript!\";\n</script>\nokay\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2430
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2430>
@@ -10262,23 +9090,6 @@ fn t141_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2430
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2430>
-/// <https://spec.commonmark.org/0.29/#example-141>
-fn t141_tab() {
-    init!("<style\n  type=\"text/css\">\nh1\t{color:red;}\n\np\t{color:blue;}\n</style>\nokay\n");
-
-    assert_eq!(
-        // from spec: <style\n  type=\"text/css\">\nh1 {color:red;}\n\np {color:blue;}\n</style>\n<p>okay</p>
-        [
-            html_block!("<style\n		type=\"text/css\">\nh1	{color:red;}\n\np	{color:blue;}\n</style>\n"),
-            paragraph!(plain!("okay"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<style\n  type=\"text/css\">\nh1\t{color:red;}\n\np\t{color:blue;}\n</style>\nokay\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2453
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2453>
@@ -10411,23 +9222,6 @@ fn t143_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2466
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2466>
-/// <https://spec.commonmark.org/0.29/#example-143>
-fn t143_tab() {
-    init!(">\t<div>\n>\tfoo\n\nbar\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<div>\nfoo\n</blockquote>\n<p>bar</p>
-        [
-            quote!(html_block!("<div>\nfoo\n")),
-            paragraph!(plain!("bar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t<div>\n>\tfoo\n\nbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2480
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2480>
@@ -10439,8 +9233,9 @@ fn t144() {
         // from spec: <ul>\n<li>\n<div>\n</li>\n<li>foo</li>\n</ul>
         [
             unordered_list!(
-              vec![ html_block!("<div>\n") ],
-              vec![ paragraph!(plain!("foo")) ]
+              '-',
+              item!( html_block!("<div>\n") ),
+              item!( paragraph!(plain!("foo")) )
             )
         ].as_slice(),
         body(&mut StringData::new("- <div>\n- foo\n", ParserSettings::Html)),
@@ -10458,8 +9253,9 @@ fn t144_cr() {
         // from spec: <ul>\n<li>\n<div>\n</li>\n<li>foo</li>\n</ul>
         [
             unordered_list!(
-              vec![ html_block!("<div>\r") ],
-              vec![ paragraph!(plain!("foo")) ]
+              '-',
+              item!( html_block!("<div>\r") ),
+              item!( paragraph!(plain!("foo")) )
             )
         ].as_slice(),
         body(&mut StringData::new("- <div>\r- foo\r", ParserSettings::Html)),
@@ -10477,8 +9273,9 @@ fn t144_cr_nl() {
         // from spec: <ul>\n<li>\n<div>\n</li>\n<li>foo</li>\n</ul>
         [
             unordered_list!(
-              vec![ html_block!("<div>\r\n") ],
-              vec![ paragraph!(plain!("foo")) ]
+              '-',
+              item!( html_block!("<div>\r\n") ),
+              item!( paragraph!(plain!("foo")) )
             )
         ].as_slice(),
         body(&mut StringData::new("- <div>\r\n- foo\r\n", ParserSettings::Html)),
@@ -10496,33 +9293,15 @@ fn t144_no_nl() {
         // from spec: <ul>\n<li>\n<div>\n</li>\n<li>foo</li>\n</ul>
         [
             unordered_list!(
-              vec![ html_block!("<div>\n") ],
-              vec![ paragraph!(plain!("foo")) ]
+              '-',
+              item!( html_block!("<div>\n") ),
+              item!( paragraph!(plain!("foo")) )
             )
         ].as_slice(),
         body(&mut StringData::new("- <div>\n- foo", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2480
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2480>
-/// <https://spec.commonmark.org/0.29/#example-144>
-fn t144_tab() {
-    init!("-\t<div>\n-\tfoo\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<div>\n</li>\n<li>foo</li>\n</ul>
-        [
-            unordered_list!(
-            		vec![	html_block!("<div>\n")	],
-            		vec![	paragraph!(plain!("foo"))	]
-            )
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t<div>\n-\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2495
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2495>
@@ -10655,23 +9434,6 @@ fn t146_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2504
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2504>
-/// <https://spec.commonmark.org/0.29/#example-146>
-fn t146_tab() {
-    init!("<!--\tfoo\t-->*bar*\n*baz*\n");
-
-    assert_eq!(
-        // from spec: <!-- foo -->*bar*\n<p><em>baz</em></p>
-        [
-            html_block!("<!--	foo	-->*bar*\n"),
-            paragraph!(emph!(plain!("baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<!--\tfoo\t-->*bar*\n*baz*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2516
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2516>
@@ -10736,22 +9498,6 @@ fn t147_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2516
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2516>
-/// <https://spec.commonmark.org/0.29/#example-147>
-fn t147_tab() {
-    init!("<script>\nfoo\n</script>1.\t*bar*\n");
-
-    assert_eq!(
-        // from spec: <script>\nfoo\n</script>1. *bar*
-        [
-            html_block!("<script>\nfoo\n</script>1.	*bar*\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<script>\nfoo\n</script>1.\t*bar*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2529
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2529>
@@ -10816,22 +9562,6 @@ fn t148_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2529
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2529>
-/// <https://spec.commonmark.org/0.29/#example-148>
-fn t148_tab() {
-    init!("<!--\tFoo\n\nbar\n   baz\t-->\nokay\n");
-
-    assert_eq!(
-        // from spec: <!-- Foo\n\nbar\n   baz -->\n<p>okay</p>
-        [
-            html_block!("<!--\tFoo\n\nbar\n\t\t\tbaz\t-->\n"), paragraph!(plain!("okay"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<!--\tFoo\n\nbar\n   baz\t-->\nokay\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2547
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2547>
@@ -10900,23 +9630,6 @@ fn t149_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2547
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2547>
-/// <https://spec.commonmark.org/0.29/#example-149>
-fn t149_tab() {
-    init!("<?php\n\n  echo\t\'>\';\n\n?>\nokay\n");
-
-    assert_eq!(
-        // from spec: <?php\n\n  echo \'>\';\n\n?>\n<p>okay</p>
-        [
-            html_block!("<?php\n\n		echo	'>';\n\n?>\n"),
-            paragraph!(plain!("okay"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<?php\n\n  echo\t\'>\';\n\n?>\nokay\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2566
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2566>
@@ -10981,22 +9694,6 @@ fn t150_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2566
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2566>
-/// <https://spec.commonmark.org/0.29/#example-150>
-fn t150_tab() {
-    init!("<!DOCTYPE\thtml>\n");
-
-    assert_eq!(
-        // from spec: <!DOCTYPE html>
-        [
-            html_block!("<!DOCTYPE	html>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<!DOCTYPE\thtml>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2575
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2575>
@@ -11065,23 +9762,6 @@ fn t151_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2575
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2575>
-/// <https://spec.commonmark.org/0.29/#example-151>
-fn t151_tab() {
-    init!("<![CDATA[\nfunction\tmatchwo(a,b)\n{\n  if\t(a\t<\tb\t&&\ta\t<\t0)\tthen\t{\n    return\t1;\n\n  }\telse\t{\n\n    return\t0;\n  }\n}\n]]>\nokay\n");
-
-    assert_eq!(
-        // from spec: <![CDATA[\nfunction matchwo(a,b)\n{\n  if (a < b && a < 0) then {\n    return 1;\n\n  } else {\n\n    return 0;\n  }\n}\n]]>\n<p>okay</p>
-        [
-            html_block!("<![CDATA[\nfunction	matchwo(a,b)\n{\n		if	(a	<	b	&&	a	<	0)	then	{\n				return	1;\n\n		}	else	{\n\n				return	0;\n		}\n}\n]]>\n"),
-            paragraph!(plain!("okay"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<![CDATA[\nfunction\tmatchwo(a,b)\n{\n  if\t(a\t<\tb\t&&\ta\t<\t0)\tthen\t{\n    return\t1;\n\n  }\telse\t{\n\n    return\t0;\n  }\n}\n]]>\nokay\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2608
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2608>
@@ -11150,23 +9830,6 @@ fn t152_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2608
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2608>
-/// <https://spec.commonmark.org/0.29/#example-152>
-fn t152_tab() {
-    init!("  <!--\tfoo\t-->\n\n    <!--\tfoo\t-->\n");
-
-    assert_eq!(
-        // from spec:   <!-- foo -->\n<pre><code>&lt;!-- foo --&gt;\n</code></pre>
-        [
-            html_block!("		<!--	foo	-->\n"),
-            html_block!("				<!--	foo	-->\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  <!--\tfoo\t-->\n\n    <!--\tfoo\t-->\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2619
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2619>
@@ -11383,9 +10046,9 @@ fn t156() {
         [
             paragraph!(
               plain!("Foo"),
-              SoftBreak,
+              soft_break!(),
               html!("<a href=\"bar\">"),
-              SoftBreak,
+              soft_break!(),
               plain!("baz")
             )
         ].as_slice(),
@@ -11405,9 +10068,9 @@ fn t156_cr() {
         [
             paragraph!(
               plain!("Foo"),
-              SoftBreak,
+              soft_break!(),
               html!("<a href=\"bar\">"),
-              SoftBreak,
+              soft_break!(),
               plain!("baz")
             )
         ].as_slice(),
@@ -11427,9 +10090,9 @@ fn t156_cr_nl() {
         [
             paragraph!(
               plain!("Foo"),
-              SoftBreak,
+              soft_break!(),
               html!("<a href=\"bar\">"),
-              SoftBreak,
+              soft_break!(),
               plain!("baz")
             )
         ].as_slice(),
@@ -11449,9 +10112,9 @@ fn t156_no_nl() {
         [
             paragraph!(
               plain!("Foo"),
-              SoftBreak,
+              soft_break!(),
               html!("<a href=\"bar\">"),
-              SoftBreak,
+              soft_break!(),
               plain!("baz")
             )
         ].as_slice(),
@@ -11459,28 +10122,6 @@ fn t156_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2665
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2665>
-/// <https://spec.commonmark.org/0.29/#example-156>
-fn t156_tab() {
-    init!("Foo\n<a\thref=\"bar\">\nbaz\n");
-
-    assert_eq!(
-        // from spec: <p>Foo\n<a href=\"bar\">\nbaz</p>
-        [
-            paragraph!(
-            		plain!("Foo"),
-            		SoftBreak,
-            		html!("<a	href=\"bar\">"),
-            		SoftBreak,
-            		plain!("baz")
-            )
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\n<a\thref=\"bar\">\nbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2706
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2706>
@@ -11553,24 +10194,6 @@ fn t157_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2706
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2706>
-/// <https://spec.commonmark.org/0.29/#example-157>
-fn t157_tab() {
-    init!("<div>\n\n*Emphasized*\ttext.\n\n</div>\n");
-
-    assert_eq!(
-        // from spec: <div>\n<p><em>Emphasized</em> text.</p>\n</div>
-        [
-            html_block!("<div>\n"),
-            paragraph!(emph!(plain!("Emphasized")),	plain!("	text.")),
-            html_block!("</div>\n"),
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div>\n\n*Emphasized*\ttext.\n\n</div>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2719
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2719>
@@ -11635,22 +10258,6 @@ fn t158_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2719
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2719>
-/// <https://spec.commonmark.org/0.29/#example-158>
-fn t158_tab() {
-    init!("<div>\n*Emphasized*\ttext.\n</div>\n");
-
-    assert_eq!(
-        // from spec: <div>\n*Emphasized* text.\n</div>
-        [
-            html_block!("<div>\n*Emphasized*	text.\n</div>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<div>\n*Emphasized*\ttext.\n</div>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2741
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2741>
@@ -11811,26 +10418,6 @@ fn t160_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2768
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2768>
-/// <https://spec.commonmark.org/0.29/#example-160>
-fn t160_tab() {
-    init!("<table>\n\n  <tr>\n\n    <td>\n    \t\tHi\n    </td>\n\n  </tr>\n\n</table>\n");
-
-    assert_eq!(
-        // from spec: <table>\n  <tr>\n<pre><code>&lt;td&gt;\n  Hi\n&lt;/td&gt;\n</code></pre>\n  </tr>\n</table>
-        [
-            html_block!("<table>\n"),
-            html_block!("		<tr>\n"),
-            html_block!("				<td>\n						Hi\n				</td>\n"),
-            html_block!("		</tr>\n"),
-            html_block!("</table>\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<table>\n\n  <tr>\n\n    <td>\n    \t\tHi\n    </td>\n\n  </tr>\n\n</table>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2816
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2816>
@@ -11842,7 +10429,7 @@ fn t161() {
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
             linkdef!("foo", "/url", "title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \"title\"\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -11859,7 +10446,7 @@ fn t161_cr() {
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
             linkdef!("foo", "/url", "title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \"title\"\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -11876,7 +10463,7 @@ fn t161_cr_nl() {
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
             linkdef!("foo", "/url", "title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \"title\"\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -11893,29 +10480,12 @@ fn t161_no_nl() {
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
             linkdef!("foo", "/url", "title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \"title\"\n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2816
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2816>
-/// <https://spec.commonmark.org/0.29/#example-161>
-fn t161_tab() {
-    init!("[foo]:\t/url\t\"title\"\n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
-        [
-            linkdef!("foo",	"/url",	"title"),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\t\"title\"\n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2825
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2825>
@@ -11927,7 +10497,7 @@ fn t162() {
         // from spec: <p><a href=\"/url\" title=\"the title\">foo</a></p>
         [
             linkdef!("foo", "/url", "the title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("   [foo]: \n      /url  \n           \'the title\'  \n\n[foo]\n", ParserSettings::Html)),
     );
@@ -11944,7 +10514,7 @@ fn t162_cr() {
         // from spec: <p><a href=\"/url\" title=\"the title\">foo</a></p>
         [
             linkdef!("foo", "/url", "the title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("   [foo]: \r      /url  \r           \'the title\'  \r\r[foo]\r", ParserSettings::Html)),
     );
@@ -11961,7 +10531,7 @@ fn t162_cr_nl() {
         // from spec: <p><a href=\"/url\" title=\"the title\">foo</a></p>
         [
             linkdef!("foo", "/url", "the title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("   [foo]: \r\n      /url  \r\n           \'the title\'  \r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -11978,29 +10548,12 @@ fn t162_no_nl() {
         // from spec: <p><a href=\"/url\" title=\"the title\">foo</a></p>
         [
             linkdef!("foo", "/url", "the title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("   [foo]: \n      /url  \n           \'the title\'  \n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2825
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2825>
-/// <https://spec.commonmark.org/0.29/#example-162>
-fn t162_tab() {
-    init!("   [foo]:\t\n    \t\t/url  \n    \t\t\t\t\t\t\t\'the\ttitle\'  \n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"the title\">foo</a></p>
-        [
-            linkdef!("foo",	"/url",	"the	title"),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("   [foo]:\t\n    \t\t/url  \n    \t\t\t\t\t\t\t\'the\ttitle\'  \n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2836
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2836>
@@ -12069,23 +10622,6 @@ fn t163_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2836
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2836>
-/// <https://spec.commonmark.org/0.29/#example-163>
-fn t163_tab() {
-    init!("[Foo*bar\\]]:my_(url)\t\'title\t(with\tparens)\'\n\n[Foo*bar\\]]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"my_(url)\" title=\"title (with parens)\">Foo*bar]</a></p>
-        [
-            linkdef!("Foo*bar]",	"my_(url)",	"title	(with	parens)"),
-            paragraph!(plain!("[Foo*bar]]"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[Foo*bar\\]]:my_(url)\t\'title\t(with\tparens)\'\n\n[Foo*bar\\]]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2845
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2845>
@@ -12097,7 +10633,7 @@ fn t164() {
         // from spec: <p><a href=\"my%20url\" title=\"title\">Foo bar</a></p>
         [
             linkdef!("Foo bar", "my url", "title"),
-            paragraph!(linkref!(plain!("Foo bar")))
+            paragraph!(linkref!("Foo bar", plain!("Foo bar")))
         ].as_slice(),
         body(&mut StringData::new("[Foo bar]:\n<my url>\n\'title\'\n\n[Foo bar]\n", ParserSettings::Html)),
     );
@@ -12114,7 +10650,7 @@ fn t164_cr() {
         // from spec: <p><a href=\"my%20url\" title=\"title\">Foo bar</a></p>
         [
             linkdef!("Foo bar", "my url", "title"),
-            paragraph!(linkref!(plain!("Foo bar")))
+            paragraph!(linkref!("Foo bar", plain!("Foo bar")))
         ].as_slice(),
         body(&mut StringData::new("[Foo bar]:\r<my url>\r\'title\'\r\r[Foo bar]\r", ParserSettings::Html)),
     );
@@ -12131,7 +10667,7 @@ fn t164_cr_nl() {
         // from spec: <p><a href=\"my%20url\" title=\"title\">Foo bar</a></p>
         [
             linkdef!("Foo bar", "my url", "title"),
-            paragraph!(linkref!(plain!("Foo bar")))
+            paragraph!(linkref!("Foo bar", plain!("Foo bar")))
         ].as_slice(),
         body(&mut StringData::new("[Foo bar]:\r\n<my url>\r\n\'title\'\r\n\r\n[Foo bar]\r\n", ParserSettings::Html)),
     );
@@ -12148,29 +10684,12 @@ fn t164_no_nl() {
         // from spec: <p><a href=\"my%20url\" title=\"title\">Foo bar</a></p>
         [
             linkdef!("Foo bar", "my url", "title"),
-            paragraph!(linkref!(plain!("Foo bar")))
+            paragraph!(linkref!("Foo bar", plain!("Foo bar")))
         ].as_slice(),
         body(&mut StringData::new("[Foo bar]:\n<my url>\n\'title\'\n\n[Foo bar]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2845
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2845>
-/// <https://spec.commonmark.org/0.29/#example-164>
-fn t164_tab() {
-    init!("[Foo\tbar]:\n<my\turl>\n\'title\'\n\n[Foo\tbar]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"my%20url\" title=\"title\">Foo bar</a></p>
-        [
-            linkdef!("Foo	bar",	"my	url",	"title"),
-            paragraph!(linkref!(plain!("Foo	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[Foo\tbar]:\n<my\turl>\n\'title\'\n\n[Foo\tbar]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2858
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2858>
@@ -12182,7 +10701,7 @@ fn t165() {
         // from spec: <p><a href=\"/url\" title=\"\ntitle\nline1\nline2\n\">foo</a></p>
         [
             linkdef!("foo", "/url", "\ntitle\nline1\nline2\n"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \'\ntitle\nline1\nline2\n\'\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -12199,7 +10718,7 @@ fn t165_cr() {
         // from spec: <p><a href=\"/url\" title=\"\ntitle\nline1\nline2\n\">foo</a></p>
         [
             linkdef!("foo", "/url", "\rtitle\rline1\rline2\r"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \'\rtitle\rline1\rline2\r\'\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -12216,7 +10735,7 @@ fn t165_cr_nl() {
         // from spec: <p><a href=\"/url\" title=\"\ntitle\nline1\nline2\n\">foo</a></p>
         [
             linkdef!("foo", "/url", "\r\ntitle\r\nline1\r\nline2\r\n"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \'\r\ntitle\r\nline1\r\nline2\r\n\'\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -12233,29 +10752,12 @@ fn t165_no_nl() {
         // from spec: <p><a href=\"/url\" title=\"\ntitle\nline1\nline2\n\">foo</a></p>
         [
             linkdef!("foo", "/url", "\ntitle\nline1\nline2\n"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \'\ntitle\nline1\nline2\n\'\n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2858
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2858>
-/// <https://spec.commonmark.org/0.29/#example-165>
-fn t165_tab() {
-    init!("[foo]:\t/url\t\'\ntitle\nline1\nline2\n\'\n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"\ntitle\nline1\nline2\n\">foo</a></p>
-        [
-            linkdef!("foo",	"/url",	"\ntitle\nline1\nline2\n"),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\t\'\ntitle\nline1\nline2\n\'\n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2877
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2877>
@@ -12266,9 +10768,9 @@ fn t166() {
     assert_eq!(
         // from spec: <p>[foo]: /url \'title</p>\n<p>with blank line\'</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": /url 'title")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": /url 'title")),
             paragraph!(plain!("with blank line'")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \'title\n\nwith blank line\'\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -12284,9 +10786,9 @@ fn t166_cr() {
     assert_eq!(
         // from spec: <p>[foo]: /url \'title</p>\n<p>with blank line\'</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": /url 'title")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": /url 'title")),
             paragraph!(plain!("with blank line'")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \'title\r\rwith blank line\'\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -12302,9 +10804,9 @@ fn t166_cr_nl() {
     assert_eq!(
         // from spec: <p>[foo]: /url \'title</p>\n<p>with blank line\'</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": /url 'title")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": /url 'title")),
             paragraph!(plain!("with blank line'")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \'title\r\n\r\nwith blank line\'\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -12320,32 +10822,14 @@ fn t166_no_nl() {
     assert_eq!(
         // from spec: <p>[foo]: /url \'title</p>\n<p>with blank line\'</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": /url 'title")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": /url 'title")),
             paragraph!(plain!("with blank line'")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \'title\n\nwith blank line\'\n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2877
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2877>
-/// <https://spec.commonmark.org/0.29/#example-166>
-fn t166_tab() {
-    init!("[foo]:\t/url\t\'title\n\nwith\tblank\tline\'\n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p>[foo]: /url \'title</p>\n<p>with blank line\'</p>\n<p>[foo]</p>
-        [
-            paragraph!(linkref!(plain!("foo")),	plain!(":	/url	'title")),
-            paragraph!(plain!("with	blank	line'")),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\t\'title\n\nwith\tblank\tline\'\n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2892
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2892>
@@ -12357,7 +10841,7 @@ fn t167() {
         // from spec: <p><a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]:\n/url\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -12374,7 +10858,7 @@ fn t167_cr() {
         // from spec: <p><a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]:\r/url\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -12391,7 +10875,7 @@ fn t167_cr_nl() {
         // from spec: <p><a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]:\r\n/url\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -12408,14 +10892,13 @@ fn t167_no_nl() {
         // from spec: <p><a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]:\n/url\n\n[foo]", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 2904
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2904>
 /// <https://spec.commonmark.org/0.29/#example-168>
@@ -12425,15 +10908,14 @@ fn t168() {
     assert_eq!(
         // from spec: <p>[foo]:</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(":")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(":")),
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]:\n\n[foo]\n", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 2904
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2904>
 /// <https://spec.commonmark.org/0.29/#example-168>
@@ -12443,15 +10925,14 @@ fn t168_cr() {
     assert_eq!(
         // from spec: <p>[foo]:</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(":")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(":")),
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]:\r\r[foo]\r", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 2904
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2904>
 /// <https://spec.commonmark.org/0.29/#example-168>
@@ -12461,15 +10942,14 @@ fn t168_cr_nl() {
     assert_eq!(
         // from spec: <p>[foo]:</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(":")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(":")),
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]:\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 2904
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2904>
 /// <https://spec.commonmark.org/0.29/#example-168>
@@ -12479,8 +10959,8 @@ fn t168_no_nl() {
     assert_eq!(
         // from spec: <p>[foo]:</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(":")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(":")),
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]:\n\n[foo]", ParserSettings::Html)),
     );
@@ -12497,7 +10977,7 @@ fn t169() {
         // from spec: <p><a href=\"\">foo</a></p>
         [
             linkdef!("foo", ""),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: <>\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -12514,7 +10994,7 @@ fn t169_cr() {
         // from spec: <p><a href=\"\">foo</a></p>
         [
             linkdef!("foo", ""),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: <>\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -12531,7 +11011,7 @@ fn t169_cr_nl() {
         // from spec: <p><a href=\"\">foo</a></p>
         [
             linkdef!("foo", ""),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: <>\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -12548,29 +11028,12 @@ fn t169_no_nl() {
         // from spec: <p><a href=\"\">foo</a></p>
         [
             linkdef!("foo", ""),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: <>\n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2916
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2916>
-/// <https://spec.commonmark.org/0.29/#example-169>
-fn t169_tab() {
-    init!("[foo]:\t<>\n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"\">foo</a></p>
-        [
-            linkdef!("foo",	""),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t<>\n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2927
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2927>
@@ -12581,8 +11044,8 @@ fn t170() {
     assert_eq!(
         // from spec: <p>[foo]: <bar>(baz)</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": "), html!("<bar>"), plain!("(baz)")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": "), html!("<bar>"), plain!("(baz)")),
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: <bar>(baz)\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -12598,8 +11061,8 @@ fn t170_cr() {
     assert_eq!(
         // from spec: <p>[foo]: <bar>(baz)</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": "), html!("<bar>"), plain!("(baz)")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": "), html!("<bar>"), plain!("(baz)")),
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: <bar>(baz)\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -12615,8 +11078,8 @@ fn t170_cr_nl() {
     assert_eq!(
         // from spec: <p>[foo]: <bar>(baz)</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": "), html!("<bar>"), plain!("(baz)")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": "), html!("<bar>"), plain!("(baz)")),
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: <bar>(baz)\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -12632,30 +11095,13 @@ fn t170_no_nl() {
     assert_eq!(
         // from spec: <p>[foo]: <bar>(baz)</p>\n<p>[foo]</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": "), html!("<bar>"), plain!("(baz)")),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": "), html!("<bar>"), plain!("(baz)")),
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: <bar>(baz)\n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2927
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2927>
-/// <https://spec.commonmark.org/0.29/#example-170>
-fn t170_tab() {
-    init!("[foo]:\t<bar>(baz)\n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p>[foo]: <bar>(baz)</p>\n<p>[foo]</p>
-        [
-            paragraph!(linkref!(plain!("foo")),	plain!(":	"),	html!("<bar>"),	plain!("(baz)")),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t<bar>(baz)\n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2940
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2940>
@@ -12667,7 +11113,7 @@ fn t171() {
         // from spec: <p><a href=\"/url%5Cbar*baz\" title=\"foo&quot;bar\\baz\">foo</a></p>
         [
             linkdef!("foo", "/url\\bar*baz", "foo\"bar\\baz"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\\bar\\*baz \"foo\\\"bar\\baz\"\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -12684,7 +11130,7 @@ fn t171_cr() {
         // from spec: <p><a href=\"/url%5Cbar*baz\" title=\"foo&quot;bar\\baz\">foo</a></p>
         [
             linkdef!("foo", "/url\\bar*baz", "foo\"bar\\baz"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\\bar\\*baz \"foo\\\"bar\\baz\"\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -12701,7 +11147,7 @@ fn t171_cr_nl() {
         // from spec: <p><a href=\"/url%5Cbar*baz\" title=\"foo&quot;bar\\baz\">foo</a></p>
         [
             linkdef!("foo", "/url\\bar*baz", "foo\"bar\\baz"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\\bar\\*baz \"foo\\\"bar\\baz\"\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -12718,29 +11164,12 @@ fn t171_no_nl() {
         // from spec: <p><a href=\"/url%5Cbar*baz\" title=\"foo&quot;bar\\baz\">foo</a></p>
         [
             linkdef!("foo", "/url\\bar*baz", "foo\"bar\\baz"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\\bar\\*baz \"foo\\\"bar\\baz\"\n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2940
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2940>
-/// <https://spec.commonmark.org/0.29/#example-171>
-fn t171_tab() {
-    init!("[foo]:\t/url\\bar\\*baz\t\"foo\\\"bar\\baz\"\n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url%5Cbar*baz\" title=\"foo&quot;bar\\baz\">foo</a></p>
-        [
-            linkdef!("foo",	"/url\\bar*baz",	"foo\"bar\\baz"),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\\bar\\*baz\t\"foo\\\"bar\\baz\"\n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2951
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2951>
@@ -12751,7 +11180,7 @@ fn t172() {
     assert_eq!(
         // from spec: <p><a href=\"url\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "url")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n[foo]: url\n", ParserSettings::Html)),
@@ -12768,7 +11197,7 @@ fn t172_cr() {
     assert_eq!(
         // from spec: <p><a href=\"url\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "url")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\r[foo]: url\r", ParserSettings::Html)),
@@ -12785,7 +11214,7 @@ fn t172_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"url\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "url")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\n\r\n[foo]: url\r\n", ParserSettings::Html)),
@@ -12802,30 +11231,13 @@ fn t172_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"url\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "url")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n[foo]: url", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2951
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2951>
-/// <https://spec.commonmark.org/0.29/#example-172>
-fn t172_tab() {
-    init!("[foo]\n\n[foo]:\turl\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"url\">foo</a></p>
-        [
-            paragraph!(linkref!(plain!("foo"))),
-            linkdef!("foo",	"url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\n\n[foo]:\turl\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2963
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2963>
@@ -12836,7 +11248,7 @@ fn t173() {
     assert_eq!(
         // from spec: <p><a href=\"first\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "first"),
             linkdef!("foo", "second")
         ].as_slice(),
@@ -12854,7 +11266,7 @@ fn t173_cr() {
     assert_eq!(
         // from spec: <p><a href=\"first\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "first"),
             linkdef!("foo", "second")
         ].as_slice(),
@@ -12872,7 +11284,7 @@ fn t173_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"first\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "first"),
             linkdef!("foo", "second")
         ].as_slice(),
@@ -12890,7 +11302,7 @@ fn t173_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"first\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "first"),
             linkdef!("foo", "second")
         ].as_slice(),
@@ -12898,24 +11310,6 @@ fn t173_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2963
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2963>
-/// <https://spec.commonmark.org/0.29/#example-173>
-fn t173_tab() {
-    init!("[foo]\n\n[foo]:\tfirst\n[foo]:\tsecond\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"first\">foo</a></p>
-        [
-            paragraph!(linkref!(plain!("foo"))),
-            linkdef!("foo",	"first"),
-            linkdef!("foo",	"second")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\n\n[foo]:\tfirst\n[foo]:\tsecond\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2976
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2976>
@@ -12927,7 +11321,7 @@ fn t174() {
         // from spec: <p><a href=\"/url\">Foo</a></p>
         [
             linkdef!("FOO", "/url"),
-            paragraph!(linkref!(plain!("Foo")))
+            paragraph!(linkref!("Foo", plain!("Foo")))
         ].as_slice(),
         body(&mut StringData::new("[FOO]: /url\n\n[Foo]\n", ParserSettings::Html)),
     );
@@ -12944,7 +11338,7 @@ fn t174_cr() {
         // from spec: <p><a href=\"/url\">Foo</a></p>
         [
             linkdef!("FOO", "/url"),
-            paragraph!(linkref!(plain!("Foo")))
+            paragraph!(linkref!("Foo", plain!("Foo")))
         ].as_slice(),
         body(&mut StringData::new("[FOO]: /url\r\r[Foo]\r", ParserSettings::Html)),
     );
@@ -12961,7 +11355,7 @@ fn t174_cr_nl() {
         // from spec: <p><a href=\"/url\">Foo</a></p>
         [
             linkdef!("FOO", "/url"),
-            paragraph!(linkref!(plain!("Foo")))
+            paragraph!(linkref!("Foo", plain!("Foo")))
         ].as_slice(),
         body(&mut StringData::new("[FOO]: /url\r\n\r\n[Foo]\r\n", ParserSettings::Html)),
     );
@@ -12978,29 +11372,12 @@ fn t174_no_nl() {
         // from spec: <p><a href=\"/url\">Foo</a></p>
         [
             linkdef!("FOO", "/url"),
-            paragraph!(linkref!(plain!("Foo")))
+            paragraph!(linkref!("Foo", plain!("Foo")))
         ].as_slice(),
         body(&mut StringData::new("[FOO]: /url\n\n[Foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2976
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2976>
-/// <https://spec.commonmark.org/0.29/#example-174>
-fn t174_tab() {
-    init!("[FOO]:\t/url\n\n[Foo]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\">Foo</a></p>
-        [
-            linkdef!("FOO",	"/url"),
-            paragraph!(linkref!(plain!("Foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[FOO]:\t/url\n\n[Foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2985
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2985>
@@ -13012,7 +11389,7 @@ fn t175() {
         // from spec: <p><a href=\"/%CF%86%CE%BF%CF%85\">αγω</a></p>
         [
             linkdef!("ΑΓΩ", "/\u{3c6}\u{3bf}\u{3c5}"),
-            paragraph!(linkref!(plain!("αγω")))
+            paragraph!(linkref!("αγω", plain!("αγω")))
         ].as_slice(),
         body(&mut StringData::new("[\u{391}\u{393}\u{3a9}]: /\u{3c6}\u{3bf}\u{3c5}\n\n[\u{3b1}\u{3b3}\u{3c9}]\n", ParserSettings::Html)),
     );
@@ -13029,7 +11406,7 @@ fn t175_cr() {
         // from spec: <p><a href=\"/%CF%86%CE%BF%CF%85\">αγω</a></p>
         [
             linkdef!("ΑΓΩ", "/\u{3c6}\u{3bf}\u{3c5}"),
-            paragraph!(linkref!(plain!("αγω")))
+            paragraph!(linkref!("αγω", plain!("αγω")))
         ].as_slice(),
         body(&mut StringData::new("[\u{391}\u{393}\u{3a9}]: /\u{3c6}\u{3bf}\u{3c5}\r\r[\u{3b1}\u{3b3}\u{3c9}]\r", ParserSettings::Html)),
     );
@@ -13046,7 +11423,7 @@ fn t175_cr_nl() {
         // from spec: <p><a href=\"/%CF%86%CE%BF%CF%85\">αγω</a></p>
         [
             linkdef!("ΑΓΩ", "/\u{3c6}\u{3bf}\u{3c5}"),
-            paragraph!(linkref!(plain!("αγω")))
+            paragraph!(linkref!("αγω", plain!("αγω")))
         ].as_slice(),
         body(&mut StringData::new("[\u{391}\u{393}\u{3a9}]: /\u{3c6}\u{3bf}\u{3c5}\r\n\r\n[\u{3b1}\u{3b3}\u{3c9}]\r\n", ParserSettings::Html)),
     );
@@ -13063,29 +11440,12 @@ fn t175_no_nl() {
         // from spec: <p><a href=\"/%CF%86%CE%BF%CF%85\">αγω</a></p>
         [
             linkdef!("ΑΓΩ", "/\u{3c6}\u{3bf}\u{3c5}"),
-            paragraph!(linkref!(plain!("αγω")))
+            paragraph!(linkref!("αγω", plain!("αγω")))
         ].as_slice(),
         body(&mut StringData::new("[\u{391}\u{393}\u{3a9}]: /\u{3c6}\u{3bf}\u{3c5}\n\n[\u{3b1}\u{3b3}\u{3c9}]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2985
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2985>
-/// <https://spec.commonmark.org/0.29/#example-175>
-fn t175_tab() {
-    init!("[ΑΓΩ]:\t/φου\n\n[αγω]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/%CF%86%CE%BF%CF%85\">αγω</a></p>
-        [
-            linkdef!("ΑΓΩ",	"/\u{3c6}\u{3bf}\u{3c5}"),
-            paragraph!(linkref!(plain!("αγω")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[\u{391}\u{393}\u{3a9}]:\t/\u{3c6}\u{3bf}\u{3c5}\n\n[\u{3b1}\u{3b3}\u{3c9}]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 2997
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2997>
@@ -13150,22 +11510,6 @@ fn t176_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 2997
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L2997>
-/// <https://spec.commonmark.org/0.29/#example-176>
-fn t176_tab() {
-    init!("[foo]:\t/url\n");
-
-    assert_eq!(
-        // from spec: 
-        [
-            linkdef!("foo",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3005
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3005>
@@ -13234,23 +11578,6 @@ fn t177_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3005
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3005>
-/// <https://spec.commonmark.org/0.29/#example-177>
-fn t177_tab() {
-    init!("[\nfoo\n]:\t/url\nbar\n");
-
-    assert_eq!(
-        // from spec: <p>bar</p>
-        [
-            linkdef!("\nfoo\n",	"/url"),
-            paragraph!(plain!("bar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[\nfoo\n]:\t/url\nbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3018
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3018>
@@ -13261,7 +11588,7 @@ fn t178() {
     assert_eq!(
         // from spec: <p>[foo]: /url &quot;title&quot; ok</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": /url \"title\" ok"))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": /url \"title\" ok"))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \"title\" ok\n", ParserSettings::Html)),
     );
@@ -13277,7 +11604,7 @@ fn t178_cr() {
     assert_eq!(
         // from spec: <p>[foo]: /url &quot;title&quot; ok</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": /url \"title\" ok"))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": /url \"title\" ok"))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \"title\" ok\r", ParserSettings::Html)),
     );
@@ -13293,7 +11620,7 @@ fn t178_cr_nl() {
     assert_eq!(
         // from spec: <p>[foo]: /url &quot;title&quot; ok</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": /url \"title\" ok"))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": /url \"title\" ok"))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \"title\" ok\r\n", ParserSettings::Html)),
     );
@@ -13309,28 +11636,12 @@ fn t178_no_nl() {
     assert_eq!(
         // from spec: <p>[foo]: /url &quot;title&quot; ok</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(": /url \"title\" ok"))
+            paragraph!(linkref!("foo", plain!("foo")), plain!(": /url \"title\" ok"))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url \"title\" ok", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3018
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3018>
-/// <https://spec.commonmark.org/0.29/#example-178>
-fn t178_tab() {
-    init!("[foo]:\t/url\t\"title\"\tok\n");
-
-    assert_eq!(
-        // from spec: <p>[foo]: /url &quot;title&quot; ok</p>
-        [
-            paragraph!(linkref!(plain!("foo")),	plain!(":	/url	\"title\"	ok"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\t\"title\"\tok\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3027
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3027>
@@ -13399,23 +11710,6 @@ fn t179_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3027
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3027>
-/// <https://spec.commonmark.org/0.29/#example-179>
-fn t179_tab() {
-    init!("[foo]:\t/url\n\"title\"\tok\n");
-
-    assert_eq!(
-        // from spec: <p>&quot;title&quot; ok</p>
-        [
-            linkdef!("foo",	"/url"),
-            paragraph!(plain!("\"title\"	ok"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\n\"title\"\tok\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3038
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3038>
@@ -13427,7 +11721,7 @@ fn t180() {
         // from spec: <pre><code>[foo]: /url &quot;title&quot;\n</code></pre>\n<p>[foo]</p>
         [
             linkdef!("foo", "/url", "title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("    [foo]: /url \"title\"\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -13444,7 +11738,7 @@ fn t180_cr() {
         // from spec: <pre><code>[foo]: /url &quot;title&quot;\n</code></pre>\n<p>[foo]</p>
         [
             linkdef!("foo", "/url", "title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("    [foo]: /url \"title\"\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -13461,7 +11755,7 @@ fn t180_cr_nl() {
         // from spec: <pre><code>[foo]: /url &quot;title&quot;\n</code></pre>\n<p>[foo]</p>
         [
             linkdef!("foo", "/url", "title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("    [foo]: /url \"title\"\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -13478,29 +11772,12 @@ fn t180_no_nl() {
         // from spec: <pre><code>[foo]: /url &quot;title&quot;\n</code></pre>\n<p>[foo]</p>
         [
             linkdef!("foo", "/url", "title"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("    [foo]: /url \"title\"\n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3038
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3038>
-/// <https://spec.commonmark.org/0.29/#example-180>
-fn t180_tab() {
-    init!("    [foo]:\t/url\t\"title\"\n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <pre><code>[foo]: /url &quot;title&quot;\n</code></pre>\n<p>[foo]</p>
-        [
-            linkdef!("foo",	"/url",	"title"),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    [foo]:\t/url\t\"title\"\n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3052
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3052>
@@ -13512,7 +11789,7 @@ fn t181() {
         // from spec: <pre><code>[foo]: /url\n</code></pre>\n<p>[foo]</p>
         [
             code_block!("", "[foo]: /url\n"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("```\n[foo]: /url\n```\n\n[foo]\n", ParserSettings::Html)),
     );
@@ -13529,7 +11806,7 @@ fn t181_cr() {
         // from spec: <pre><code>[foo]: /url\n</code></pre>\n<p>[foo]</p>
         [
             code_block!("", "[foo]: /url\r"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("```\r[foo]: /url\r```\r\r[foo]\r", ParserSettings::Html)),
     );
@@ -13546,7 +11823,7 @@ fn t181_cr_nl() {
         // from spec: <pre><code>[foo]: /url\n</code></pre>\n<p>[foo]</p>
         [
             code_block!("", "[foo]: /url\r\n"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("```\r\n[foo]: /url\r\n```\r\n\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -13563,29 +11840,12 @@ fn t181_no_nl() {
         // from spec: <pre><code>[foo]: /url\n</code></pre>\n<p>[foo]</p>
         [
             code_block!("", "[foo]: /url\n"),
-            paragraph!(linkref!(plain!("foo")))
+            paragraph!(linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("```\n[foo]: /url\n```\n\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3052
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3052>
-/// <https://spec.commonmark.org/0.29/#example-181>
-fn t181_tab() {
-    init!("```\n[foo]:\t/url\n```\n\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <pre><code>[foo]: /url\n</code></pre>\n<p>[foo]</p>
-        [
-            code_block!("",	"[foo]:	/url\n"),
-            paragraph!(linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("```\n[foo]:\t/url\n```\n\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3067
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3067>
@@ -13596,8 +11856,8 @@ fn t182() {
     assert_eq!(
         // from spec: <p>Foo\n[bar]: /baz</p>\n<p>[bar]</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, linkref!(plain!("bar")), plain!(": /baz")),
-            paragraph!(linkref!(plain!("bar")))
+            paragraph!(plain!("Foo"), soft_break!(), linkref!("bar", plain!("bar")), plain!(": /baz")),
+            paragraph!(linkref!("bar", plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("Foo\n[bar]: /baz\n\n[bar]\n", ParserSettings::Html)),
     );
@@ -13613,8 +11873,8 @@ fn t182_cr() {
     assert_eq!(
         // from spec: <p>Foo\n[bar]: /baz</p>\n<p>[bar]</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, linkref!(plain!("bar")), plain!(": /baz")),
-            paragraph!(linkref!(plain!("bar")))
+            paragraph!(plain!("Foo"), soft_break!(), linkref!("bar", plain!("bar")), plain!(": /baz")),
+            paragraph!(linkref!("bar", plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("Foo\r[bar]: /baz\r\r[bar]\r", ParserSettings::Html)),
     );
@@ -13630,8 +11890,8 @@ fn t182_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo\n[bar]: /baz</p>\n<p>[bar]</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, linkref!(plain!("bar")), plain!(": /baz")),
-            paragraph!(linkref!(plain!("bar")))
+            paragraph!(plain!("Foo"), soft_break!(), linkref!("bar", plain!("bar")), plain!(": /baz")),
+            paragraph!(linkref!("bar", plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n[bar]: /baz\r\n\r\n[bar]\r\n", ParserSettings::Html)),
     );
@@ -13647,30 +11907,13 @@ fn t182_no_nl() {
     assert_eq!(
         // from spec: <p>Foo\n[bar]: /baz</p>\n<p>[bar]</p>
         [
-            paragraph!(plain!("Foo"), SoftBreak, linkref!(plain!("bar")), plain!(": /baz")),
-            paragraph!(linkref!(plain!("bar")))
+            paragraph!(plain!("Foo"), soft_break!(), linkref!("bar", plain!("bar")), plain!(": /baz")),
+            paragraph!(linkref!("bar", plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("Foo\n[bar]: /baz\n\n[bar]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3067
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3067>
-/// <https://spec.commonmark.org/0.29/#example-182>
-fn t182_tab() {
-    init!("Foo\n[bar]:\t/baz\n\n[bar]\n");
-
-    assert_eq!(
-        // from spec: <p>Foo\n[bar]: /baz</p>\n<p>[bar]</p>
-        [
-            paragraph!(plain!("Foo"),	SoftBreak,	linkref!(plain!("bar")),	plain!(":	/baz")),
-            paragraph!(linkref!(plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\n[bar]:\t/baz\n\n[bar]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3082
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3082>
@@ -13681,7 +11924,7 @@ fn t183() {
     assert_eq!(
         // from spec: <h1><a href=\"/url\">Foo</a></h1>\n<blockquote>\n<p>bar</p>\n</blockquote>
         [
-            heading!(1, linkref!(plain!("Foo"))),
+            heading!(1, linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url"),
             quote!(paragraph!(plain!("bar")))
         ].as_slice(),
@@ -13699,7 +11942,7 @@ fn t183_cr() {
     assert_eq!(
         // from spec: <h1><a href=\"/url\">Foo</a></h1>\n<blockquote>\n<p>bar</p>\n</blockquote>
         [
-            heading!(1, linkref!(plain!("Foo"))),
+            heading!(1, linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url"),
             quote!(paragraph!(plain!("bar")))
         ].as_slice(),
@@ -13717,7 +11960,7 @@ fn t183_cr_nl() {
     assert_eq!(
         // from spec: <h1><a href=\"/url\">Foo</a></h1>\n<blockquote>\n<p>bar</p>\n</blockquote>
         [
-            heading!(1, linkref!(plain!("Foo"))),
+            heading!(1, linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url"),
             quote!(paragraph!(plain!("bar")))
         ].as_slice(),
@@ -13735,7 +11978,7 @@ fn t183_no_nl() {
     assert_eq!(
         // from spec: <h1><a href=\"/url\">Foo</a></h1>\n<blockquote>\n<p>bar</p>\n</blockquote>
         [
-            heading!(1, linkref!(plain!("Foo"))),
+            heading!(1, linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url"),
             quote!(paragraph!(plain!("bar")))
         ].as_slice(),
@@ -13743,24 +11986,6 @@ fn t183_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3082
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3082>
-/// <https://spec.commonmark.org/0.29/#example-183>
-fn t183_tab() {
-    init!("#\t[Foo]\n[foo]:\t/url\n>\tbar\n");
-
-    assert_eq!(
-        // from spec: <h1><a href=\"/url\">Foo</a></h1>\n<blockquote>\n<p>bar</p>\n</blockquote>
-        [
-            heading!(1,	linkref!(plain!("Foo"))),
-            linkdef!("foo",	"/url"),
-            quote!(paragraph!(plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("#\t[Foo]\n[foo]:\t/url\n>\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3093
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3093>
@@ -13772,7 +11997,7 @@ fn t184() {
         // from spec: <p>bar\n===\n<a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(plain!("bar"), SoftBreak, plain!("==="), SoftBreak, linkref!(plain!("foo")))
+            paragraph!(plain!("bar"), soft_break!(), plain!("==="), soft_break!(), linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\nbar\n===\n[foo]\n", ParserSettings::Html)),
     );
@@ -13789,7 +12014,7 @@ fn t184_cr() {
         // from spec: <p>bar\n===\n<a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(plain!("bar"), SoftBreak, plain!("==="), SoftBreak, linkref!(plain!("foo")))
+            paragraph!(plain!("bar"), soft_break!(), plain!("==="), soft_break!(), linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\rbar\r===\r[foo]\r", ParserSettings::Html)),
     );
@@ -13806,7 +12031,7 @@ fn t184_cr_nl() {
         // from spec: <p>bar\n===\n<a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(plain!("bar"), SoftBreak, plain!("==="), SoftBreak, linkref!(plain!("foo")))
+            paragraph!(plain!("bar"), soft_break!(), plain!("==="), soft_break!(), linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\r\nbar\r\n===\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -13823,29 +12048,12 @@ fn t184_no_nl() {
         // from spec: <p>bar\n===\n<a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(plain!("bar"), SoftBreak, plain!("==="), SoftBreak, linkref!(plain!("foo")))
+            paragraph!(plain!("bar"), soft_break!(), plain!("==="), soft_break!(), linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\nbar\n===\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3093
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3093>
-/// <https://spec.commonmark.org/0.29/#example-184>
-fn t184_tab() {
-    init!("[foo]:\t/url\nbar\n===\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p>bar\n===\n<a href=\"/url\">foo</a></p>
-        [
-            linkdef!("foo",	"/url"),
-            paragraph!(plain!("bar"),	SoftBreak,	plain!("==="),	SoftBreak,	linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\nbar\n===\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3103
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3103>
@@ -13857,7 +12065,7 @@ fn t185() {
         // from spec: <p>===\n<a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(plain!("==="), SoftBreak, linkref!(plain!("foo")))
+            paragraph!(plain!("==="), soft_break!(), linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\n===\n[foo]\n", ParserSettings::Html)),
     );
@@ -13874,7 +12082,7 @@ fn t185_cr() {
         // from spec: <p>===\n<a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(plain!("==="), SoftBreak, linkref!(plain!("foo")))
+            paragraph!(plain!("==="), soft_break!(), linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\r===\r[foo]\r", ParserSettings::Html)),
     );
@@ -13891,7 +12099,7 @@ fn t185_cr_nl() {
         // from spec: <p>===\n<a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(plain!("==="), SoftBreak, linkref!(plain!("foo")))
+            paragraph!(plain!("==="), soft_break!(), linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\r\n===\r\n[foo]\r\n", ParserSettings::Html)),
     );
@@ -13908,29 +12116,12 @@ fn t185_no_nl() {
         // from spec: <p>===\n<a href=\"/url\">foo</a></p>
         [
             linkdef!("foo", "/url"),
-            paragraph!(plain!("==="), SoftBreak, linkref!(plain!("foo")))
+            paragraph!(plain!("==="), soft_break!(), linkref!("foo", plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("[foo]: /url\n===\n[foo]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3103
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3103>
-/// <https://spec.commonmark.org/0.29/#example-185>
-fn t185_tab() {
-    init!("[foo]:\t/url\n===\n[foo]\n");
-
-    assert_eq!(
-        // from spec: <p>===\n<a href=\"/url\">foo</a></p>
-        [
-            linkdef!("foo",	"/url"),
-            paragraph!(plain!("==="),	SoftBreak,	linkref!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\n===\n[foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3116
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3116>
@@ -13945,11 +12136,11 @@ fn t186() {
             linkdef!("bar", "/bar-url", "bar"),
             linkdef!("baz", "/baz-url"),
             paragraph!(
-                linkref!(plain!("foo")),
-                plain!(","), SoftBreak,
-                linkref!(plain!("bar")),
-                plain!(","), SoftBreak,
-                linkref!(plain!("baz"))
+                linkref!("foo", plain!("foo")),
+                plain!(","), soft_break!(),
+                linkref!("bar", plain!("bar")),
+                plain!(","), soft_break!(),
+                linkref!("baz", plain!("baz"))
             )
         ].as_slice(),
         body(&mut StringData::new("[foo]: /foo-url \"foo\"\n[bar]: /bar-url\n  \"bar\"\n[baz]: /baz-url\n\n[foo],\n[bar],\n[baz]\n", ParserSettings::Html)),
@@ -13970,11 +12161,11 @@ fn t186_cr() {
             linkdef!("bar", "/bar-url", "bar"),
             linkdef!("baz", "/baz-url"),
             paragraph!(
-                linkref!(plain!("foo")),
-                plain!(","), SoftBreak,
-                linkref!(plain!("bar")),
-                plain!(","), SoftBreak,
-                linkref!(plain!("baz"))
+                linkref!("foo", plain!("foo")),
+                plain!(","), soft_break!(),
+                linkref!("bar", plain!("bar")),
+                plain!(","), soft_break!(),
+                linkref!("baz", plain!("baz"))
             )
         ].as_slice(),
         body(&mut StringData::new("[foo]: /foo-url \"foo\"\r[bar]: /bar-url\r  \"bar\"\r[baz]: /baz-url\r\r[foo],\r[bar],\r[baz]\r", ParserSettings::Html)),
@@ -13995,11 +12186,11 @@ fn t186_cr_nl() {
             linkdef!("bar", "/bar-url", "bar"),
             linkdef!("baz", "/baz-url"),
             paragraph!(
-                linkref!(plain!("foo")),
-                plain!(","), SoftBreak,
-                linkref!(plain!("bar")),
-                plain!(","), SoftBreak,
-                linkref!(plain!("baz"))
+                linkref!("foo", plain!("foo")),
+                plain!(","), soft_break!(),
+                linkref!("bar", plain!("bar")),
+                plain!(","), soft_break!(),
+                linkref!("baz", plain!("baz"))
             )
         ].as_slice(),
         body(&mut StringData::new("[foo]: /foo-url \"foo\"\r\n[bar]: /bar-url\r\n  \"bar\"\r\n[baz]: /baz-url\r\n\r\n[foo],\r\n[bar],\r\n[baz]\r\n", ParserSettings::Html)),
@@ -14020,42 +12211,17 @@ fn t186_no_nl() {
             linkdef!("bar", "/bar-url", "bar"),
             linkdef!("baz", "/baz-url"),
             paragraph!(
-                linkref!(plain!("foo")),
-                plain!(","), SoftBreak,
-                linkref!(plain!("bar")),
-                plain!(","), SoftBreak,
-                linkref!(plain!("baz"))
+                linkref!("foo", plain!("foo")),
+                plain!(","), soft_break!(),
+                linkref!("bar", plain!("bar")),
+                plain!(","), soft_break!(),
+                linkref!("baz", plain!("baz"))
             )
         ].as_slice(),
         body(&mut StringData::new("[foo]: /foo-url \"foo\"\n[bar]: /bar-url\n  \"bar\"\n[baz]: /baz-url\n\n[foo],\n[bar],\n[baz]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3116
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3116>
-/// <https://spec.commonmark.org/0.29/#example-186>
-fn t186_tab() {
-    init!("[foo]:\t/foo-url\t\"foo\"\n[bar]:\t/bar-url\n  \"bar\"\n[baz]:\t/baz-url\n\n[foo],\n[bar],\n[baz]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/foo-url\" title=\"foo\">foo</a>,\n<a href=\"/bar-url\" title=\"bar\">bar</a>,\n<a href=\"/baz-url\">baz</a></p>
-        [
-            linkdef!("foo",	"/foo-url",	"foo"),
-            linkdef!("bar",	"/bar-url",	"bar"),
-            linkdef!("baz",	"/baz-url"),
-            paragraph!(
-            				linkref!(plain!("foo")),
-            				plain!(","),	SoftBreak,
-            				linkref!(plain!("bar")),
-            				plain!(","),	SoftBreak,
-            				linkref!(plain!("baz"))
-            )
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/foo-url\t\"foo\"\n[bar]:\t/bar-url\n  \"bar\"\n[baz]:\t/baz-url\n\n[foo],\n[bar],\n[baz]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3137
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3137>
@@ -14066,7 +12232,7 @@ fn t187() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">foo</a></p>\n<blockquote>\n</blockquote>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             quote!(linkdef!("foo", "/url"))
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n> [foo]: /url\n", ParserSettings::Html)),
@@ -14083,7 +12249,7 @@ fn t187_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">foo</a></p>\n<blockquote>\n</blockquote>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             quote!(linkdef!("foo", "/url"))
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\r> [foo]: /url\r", ParserSettings::Html)),
@@ -14100,7 +12266,7 @@ fn t187_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">foo</a></p>\n<blockquote>\n</blockquote>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             quote!(linkdef!("foo", "/url"))
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\n\r\n> [foo]: /url\r\n", ParserSettings::Html)),
@@ -14117,30 +12283,13 @@ fn t187_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">foo</a></p>\n<blockquote>\n</blockquote>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             quote!(linkdef!("foo", "/url"))
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n> [foo]: /url", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3137
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3137>
-/// <https://spec.commonmark.org/0.29/#example-187>
-fn t187_tab() {
-    init!("[foo]\n\n>\t[foo]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\">foo</a></p>\n<blockquote>\n</blockquote>
-        [
-            paragraph!(linkref!(plain!("foo"))),
-            quote!(linkdef!("foo",	"/url"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\n\n>\t[foo]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3154
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3154>
@@ -14205,22 +12354,6 @@ fn t188_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3154
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3154>
-/// <https://spec.commonmark.org/0.29/#example-188>
-fn t188_tab() {
-    init!("[foo]:\t/url\n");
-
-    assert_eq!(
-        // from spec: 
-        [
-            linkdef!("foo",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3171
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3171>
@@ -14295,7 +12428,7 @@ fn t190() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>\n<p>ccc\nddd</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb")), paragraph!(plain!("ccc"), SoftBreak, plain!("ddd"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb")), paragraph!(plain!("ccc"), soft_break!(), plain!("ddd"))
         ].as_slice(),
         body(&mut StringData::new("aaa\nbbb\n\nccc\nddd\n", ParserSettings::Html)),
     );
@@ -14311,7 +12444,7 @@ fn t190_cr() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>\n<p>ccc\nddd</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb")), paragraph!(plain!("ccc"), SoftBreak, plain!("ddd"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb")), paragraph!(plain!("ccc"), soft_break!(), plain!("ddd"))
         ].as_slice(),
         body(&mut StringData::new("aaa\rbbb\r\rccc\rddd\r", ParserSettings::Html)),
     );
@@ -14327,7 +12460,7 @@ fn t190_cr_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>\n<p>ccc\nddd</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb")), paragraph!(plain!("ccc"), SoftBreak, plain!("ddd"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb")), paragraph!(plain!("ccc"), soft_break!(), plain!("ddd"))
         ].as_slice(),
         body(&mut StringData::new("aaa\r\nbbb\r\n\r\nccc\r\nddd\r\n", ParserSettings::Html)),
     );
@@ -14343,7 +12476,7 @@ fn t190_no_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>\n<p>ccc\nddd</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb")), paragraph!(plain!("ccc"), SoftBreak, plain!("ddd"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb")), paragraph!(plain!("ccc"), soft_break!(), plain!("ddd"))
         ].as_slice(),
         body(&mut StringData::new("aaa\nbbb\n\nccc\nddd", ParserSettings::Html)),
     );
@@ -14423,7 +12556,7 @@ fn t192() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("  aaa\n bbb\n", ParserSettings::Html)),
     );
@@ -14439,7 +12572,7 @@ fn t192_cr() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("  aaa\r bbb\r", ParserSettings::Html)),
     );
@@ -14455,7 +12588,7 @@ fn t192_cr_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("  aaa\r\n bbb\r\n", ParserSettings::Html)),
     );
@@ -14471,7 +12604,7 @@ fn t192_no_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("  aaa\n bbb", ParserSettings::Html)),
     );
@@ -14487,7 +12620,7 @@ fn t193() {
     assert_eq!(
         // from spec: <p>aaa\nbbb\nccc</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"), SoftBreak, plain!("ccc"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"), soft_break!(), plain!("ccc"))
         ].as_slice(),
         body(&mut StringData::new("aaa\n             bbb\n                                       ccc\n", ParserSettings::Html)),
     );
@@ -14503,7 +12636,7 @@ fn t193_cr() {
     assert_eq!(
         // from spec: <p>aaa\nbbb\nccc</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"), SoftBreak, plain!("ccc"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"), soft_break!(), plain!("ccc"))
         ].as_slice(),
         body(&mut StringData::new("aaa\r             bbb\r                                       ccc\r", ParserSettings::Html)),
     );
@@ -14519,7 +12652,7 @@ fn t193_cr_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb\nccc</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"), SoftBreak, plain!("ccc"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"), soft_break!(), plain!("ccc"))
         ].as_slice(),
         body(&mut StringData::new("aaa\r\n             bbb\r\n                                       ccc\r\n", ParserSettings::Html)),
     );
@@ -14535,28 +12668,12 @@ fn t193_no_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb\nccc</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"), SoftBreak, plain!("ccc"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"), soft_break!(), plain!("ccc"))
         ].as_slice(),
         body(&mut StringData::new("aaa\n             bbb\n                                       ccc", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3224
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3224>
-/// <https://spec.commonmark.org/0.29/#example-193>
-fn t193_tab() {
-    init!("aaa\n    \t\t\t\t\t\t\t\t\tbbb\n    \t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\tccc\n");
-
-    assert_eq!(
-        // from spec: <p>aaa\nbbb\nccc</p>
-        [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"), SoftBreak, plain!("ccc"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("aaa\n    \t\t\t\t\t\t\t\t\tbbb\n    \t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\tccc\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3238
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3238>
@@ -14567,7 +12684,7 @@ fn t194() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("   aaa\nbbb\n", ParserSettings::Html)),
     );
@@ -14583,7 +12700,7 @@ fn t194_cr() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("   aaa\rbbb\r", ParserSettings::Html)),
     );
@@ -14599,7 +12716,7 @@ fn t194_cr_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("   aaa\r\nbbb\r\n", ParserSettings::Html)),
     );
@@ -14615,7 +12732,7 @@ fn t194_no_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("   aaa\nbbb", ParserSettings::Html)),
     );
@@ -14631,7 +12748,7 @@ fn t195() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("    aaa\nbbb\n", ParserSettings::Html)),
     );
@@ -14647,7 +12764,7 @@ fn t195_cr() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("    aaa\rbbb\r", ParserSettings::Html)),
     );
@@ -14663,7 +12780,7 @@ fn t195_cr_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("    aaa\r\nbbb\r\n", ParserSettings::Html)),
     );
@@ -14679,7 +12796,7 @@ fn t195_no_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("    aaa\nbbb", ParserSettings::Html)),
     );
@@ -14695,7 +12812,7 @@ fn t196() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("aaa     \nbbb     \n", ParserSettings::Html)),
     );
@@ -14711,7 +12828,7 @@ fn t196_cr() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("aaa     \rbbb     \r", ParserSettings::Html)),
     );
@@ -14727,7 +12844,7 @@ fn t196_cr_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("aaa     \r\nbbb     \r\n", ParserSettings::Html)),
     );
@@ -14743,7 +12860,7 @@ fn t196_no_nl() {
     assert_eq!(
         // from spec: <p>aaa\nbbb</p>
         [
-            paragraph!(plain!("aaa"), SoftBreak, plain!("bbb"))
+            paragraph!(plain!("aaa"), soft_break!(), plain!("bbb"))
         ].as_slice(),
         body(&mut StringData::new("aaa     \nbbb     ", ParserSettings::Html)),
     );
@@ -14813,22 +12930,6 @@ fn t197_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3278
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3278>
-/// <https://spec.commonmark.org/0.29/#example-197>
-fn t197_tab() {
-    init!("  \n\naaa\n  \n\n#\taaa\n\n  \n");
-
-    assert_eq!(
-        // from spec: <p>aaa</p>\n<h1>aaa</h1>
-        [
-            paragraph!(plain!("aaa")), heading!(1, plain!("aaa"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  \n\naaa\n  \n\n#\taaa\n\n  \n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3344
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3344>
@@ -14839,7 +12940,7 @@ fn t198() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> # Foo\n> bar\n> baz\n", ParserSettings::Html)),
     );
@@ -14855,7 +12956,7 @@ fn t198_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> # Foo\r> bar\r> baz\r", ParserSettings::Html)),
     );
@@ -14871,7 +12972,7 @@ fn t198_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> # Foo\r\n> bar\r\n> baz\r\n", ParserSettings::Html)),
     );
@@ -14887,28 +12988,12 @@ fn t198_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> # Foo\n> bar\n> baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3344
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3344>
-/// <https://spec.commonmark.org/0.29/#example-198>
-fn t198_tab() {
-    init!(">\t#\tFoo\n>\tbar\n>\tbaz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
-        [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t#\tFoo\n>\tbar\n>\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3359
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3359>
@@ -14919,7 +13004,7 @@ fn t199() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("># Foo\n>bar\n> baz\n", ParserSettings::Html)),
     );
@@ -14935,7 +13020,7 @@ fn t199_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("># Foo\r>bar\r> baz\r", ParserSettings::Html)),
     );
@@ -14951,7 +13036,7 @@ fn t199_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("># Foo\r\n>bar\r\n> baz\r\n", ParserSettings::Html)),
     );
@@ -14967,28 +13052,12 @@ fn t199_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("># Foo\n>bar\n> baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3359
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3359>
-/// <https://spec.commonmark.org/0.29/#example-199>
-fn t199_tab() {
-    init!(">#\tFoo\n>bar\n>\tbaz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
-        [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">#\tFoo\n>bar\n>\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3374
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3374>
@@ -14999,7 +13068,7 @@ fn t200() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("   > # Foo\n   > bar\n > baz\n", ParserSettings::Html)),
     );
@@ -15015,7 +13084,7 @@ fn t200_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("   > # Foo\r   > bar\r > baz\r", ParserSettings::Html)),
     );
@@ -15031,7 +13100,7 @@ fn t200_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("   > # Foo\r\n   > bar\r\n > baz\r\n", ParserSettings::Html)),
     );
@@ -15047,28 +13116,12 @@ fn t200_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("   > # Foo\n   > bar\n > baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3374
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3374>
-/// <https://spec.commonmark.org/0.29/#example-200>
-fn t200_tab() {
-    init!("   >\t#\tFoo\n   >\tbar\n >\tbaz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
-        [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("   >\t#\tFoo\n   >\tbar\n >\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3389
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3389>
@@ -15079,7 +13132,7 @@ fn t201() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("    > # Foo\n    > bar\n    > baz\n", ParserSettings::Html)),
     );
@@ -15095,7 +13148,7 @@ fn t201_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("    > # Foo\r    > bar\r    > baz\r", ParserSettings::Html)),
     );
@@ -15111,7 +13164,7 @@ fn t201_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("    > # Foo\r\n    > bar\r\n    > baz\r\n", ParserSettings::Html)),
     );
@@ -15127,28 +13180,12 @@ fn t201_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("    > # Foo\n    > bar\n    > baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3389
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3389>
-/// <https://spec.commonmark.org/0.29/#example-201>
-fn t201_tab() {
-    init!("    >\t#\tFoo\n    >\tbar\n    >\tbaz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
-        [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    >\t#\tFoo\n    >\tbar\n    >\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3404
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3404>
@@ -15159,7 +13196,7 @@ fn t202() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> # Foo\n> bar\nbaz\n", ParserSettings::Html)),
     );
@@ -15175,7 +13212,7 @@ fn t202_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> # Foo\r> bar\rbaz\r", ParserSettings::Html)),
     );
@@ -15191,7 +13228,7 @@ fn t202_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> # Foo\r\n> bar\r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -15207,28 +13244,12 @@ fn t202_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> # Foo\n> bar\nbaz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3404
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3404>
-/// <https://spec.commonmark.org/0.29/#example-202>
-fn t202_tab() {
-    init!(">\t#\tFoo\n>\tbar\nbaz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<h1>Foo</h1>\n<p>bar\nbaz</p>\n</blockquote>
-        [
-            quote!(heading!(1, plain!("Foo")), paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t#\tFoo\n>\tbar\nbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3420
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3420>
@@ -15239,7 +13260,7 @@ fn t203() {
     assert_eq!(
         // from spec: <blockquote>\n<p>bar\nbaz\nfoo</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz"), SoftBreak, plain!("foo")))
+            quote!(paragraph!(plain!("bar"), soft_break!(), plain!("baz"), soft_break!(), plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("> bar\nbaz\n> foo\n", ParserSettings::Html)),
     );
@@ -15255,7 +13276,7 @@ fn t203_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>bar\nbaz\nfoo</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz"), SoftBreak, plain!("foo")))
+            quote!(paragraph!(plain!("bar"), soft_break!(), plain!("baz"), soft_break!(), plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("> bar\rbaz\r> foo\r", ParserSettings::Html)),
     );
@@ -15271,7 +13292,7 @@ fn t203_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>bar\nbaz\nfoo</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz"), SoftBreak, plain!("foo")))
+            quote!(paragraph!(plain!("bar"), soft_break!(), plain!("baz"), soft_break!(), plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("> bar\r\nbaz\r\n> foo\r\n", ParserSettings::Html)),
     );
@@ -15287,28 +13308,12 @@ fn t203_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>bar\nbaz\nfoo</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz"), SoftBreak, plain!("foo")))
+            quote!(paragraph!(plain!("bar"), soft_break!(), plain!("baz"), soft_break!(), plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("> bar\nbaz\n> foo", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3420
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3420>
-/// <https://spec.commonmark.org/0.29/#example-203>
-fn t203_tab() {
-    init!(">\tbar\nbaz\n>\tfoo\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>bar\nbaz\nfoo</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz"), SoftBreak, plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tbar\nbaz\n>\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3444
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3444>
@@ -15319,7 +13324,7 @@ fn t204() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("foo"))), Break
+            quote!(paragraph!(plain!("foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> foo\n---\n", ParserSettings::Html)),
     );
@@ -15335,7 +13340,7 @@ fn t204_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("foo"))), Break
+            quote!(paragraph!(plain!("foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> foo\r---\r", ParserSettings::Html)),
     );
@@ -15351,7 +13356,7 @@ fn t204_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("foo"))), Break
+            quote!(paragraph!(plain!("foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> foo\r\n---\r\n", ParserSettings::Html)),
     );
@@ -15367,28 +13372,12 @@ fn t204_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
         [
-            quote!(paragraph!(plain!("foo"))), Break
+            quote!(paragraph!(plain!("foo"))), thematic_break!()
         ].as_slice(),
         body(&mut StringData::new("> foo\n---", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3444
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3444>
-/// <https://spec.commonmark.org/0.29/#example-204>
-fn t204_tab() {
-    init!(">\tfoo\n---\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<hr />
-        [
-            quote!(paragraph!(plain!("foo"))), Break
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tfoo\n---\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3464
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3464>
@@ -15399,7 +13388,7 @@ fn t205() {
     assert_eq!(
         // from spec: <blockquote>\n<ul>\n<li>foo</li>\n</ul>\n</blockquote>\n<ul>\n<li>bar</li>\n</ul>
         [
-            quote!(unordered_list!(vec![paragraph!(plain!("foo"))])), unordered_list!(vec![paragraph!(plain!("bar"))])
+            quote!(unordered_list!('-', item!(paragraph!(plain!("foo"))))), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("> - foo\n- bar\n", ParserSettings::Html)),
     );
@@ -15415,7 +13404,7 @@ fn t205_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<ul>\n<li>foo</li>\n</ul>\n</blockquote>\n<ul>\n<li>bar</li>\n</ul>
         [
-            quote!(unordered_list!(vec![paragraph!(plain!("foo"))])), unordered_list!(vec![paragraph!(plain!("bar"))])
+            quote!(unordered_list!('-', item!(paragraph!(plain!("foo"))))), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("> - foo\r- bar\r", ParserSettings::Html)),
     );
@@ -15431,7 +13420,7 @@ fn t205_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<ul>\n<li>foo</li>\n</ul>\n</blockquote>\n<ul>\n<li>bar</li>\n</ul>
         [
-            quote!(unordered_list!(vec![paragraph!(plain!("foo"))])), unordered_list!(vec![paragraph!(plain!("bar"))])
+            quote!(unordered_list!('-', item!(paragraph!(plain!("foo"))))), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("> - foo\r\n- bar\r\n", ParserSettings::Html)),
     );
@@ -15447,28 +13436,12 @@ fn t205_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<ul>\n<li>foo</li>\n</ul>\n</blockquote>\n<ul>\n<li>bar</li>\n</ul>
         [
-            quote!(unordered_list!(vec![paragraph!(plain!("foo"))])), unordered_list!(vec![paragraph!(plain!("bar"))])
+            quote!(unordered_list!('-', item!(paragraph!(plain!("foo"))))), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("> - foo\n- bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3464
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3464>
-/// <https://spec.commonmark.org/0.29/#example-205>
-fn t205_tab() {
-    init!(">\t-\tfoo\n-\tbar\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<ul>\n<li>foo</li>\n</ul>\n</blockquote>\n<ul>\n<li>bar</li>\n</ul>
-        [
-            quote!(unordered_list!(vec![paragraph!(plain!("foo"))])), unordered_list!(vec![paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t-\tfoo\n-\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3482
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3482>
@@ -15479,7 +13452,7 @@ fn t206() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new(">     foo\n    bar\n", ParserSettings::Html)),
     );
@@ -15495,7 +13468,7 @@ fn t206_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new(">     foo\r    bar\r", ParserSettings::Html)),
     );
@@ -15511,7 +13484,7 @@ fn t206_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new(">     foo\r\n    bar\r\n", ParserSettings::Html)),
     );
@@ -15527,28 +13500,12 @@ fn t206_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new(">     foo\n    bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3482
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3482>
-/// <https://spec.commonmark.org/0.29/#example-206>
-fn t206_tab() {
-    init!(">\t\t\t\t\tfoo\n    bar\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t\t\t\t\tfoo\n    bar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 3495
@@ -15617,23 +13574,6 @@ fn t207_no_nl() {
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 3495
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3495>
-/// <https://spec.commonmark.org/0.29/#example-207>
-fn t207_tab() {
-    init!(">\t```\nfoo\n```\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<pre><code></code></pre>\n</blockquote>\n<p>foo</p>\n<pre><code></code></pre>
-        [
-            quote!(code_block!("")), paragraph!(plain!("foo")), code_block!("")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t```\nfoo\n```\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 3511
@@ -15645,7 +13585,7 @@ fn t208() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\n- bar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("- bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("- bar")))
         ].as_slice(),
         body(&mut StringData::new("> foo\n    - bar\n", ParserSettings::Html)),
     );
@@ -15662,7 +13602,7 @@ fn t208_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\n- bar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("- bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("- bar")))
         ].as_slice(),
         body(&mut StringData::new("> foo\r    - bar\r", ParserSettings::Html)),
     );
@@ -15679,7 +13619,7 @@ fn t208_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\n- bar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("- bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("- bar")))
         ].as_slice(),
         body(&mut StringData::new("> foo\r\n    - bar\r\n", ParserSettings::Html)),
     );
@@ -15696,29 +13636,12 @@ fn t208_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\n- bar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("- bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("- bar")))
         ].as_slice(),
         body(&mut StringData::new("> foo\n    - bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 3511
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3511>
-/// <https://spec.commonmark.org/0.29/#example-208>
-fn t208_tab() {
-    init!(">\tfoo\n    -\tbar\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo\n- bar</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("-	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tfoo\n    -\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3535
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3535>
@@ -15847,22 +13770,6 @@ fn t210_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3543
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3543>
-/// <https://spec.commonmark.org/0.29/#example-210>
-fn t210_tab() {
-    init!(">\n>  \n>\t\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n</blockquote>
-        [
-            quote!()
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\n>  \n>\t\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3555
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3555>
@@ -15927,22 +13834,6 @@ fn t211_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3555
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3555>
-/// <https://spec.commonmark.org/0.29/#example-211>
-fn t211_tab() {
-    init!(">\n>\tfoo\n>  \n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\n>\tfoo\n>  \n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3568
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3568>
@@ -16007,22 +13898,6 @@ fn t212_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3568
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3568>
-/// <https://spec.commonmark.org/0.29/#example-212>
-fn t212_tab() {
-    init!(">\tfoo\n\n>\tbar\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo</p>\n</blockquote>\n<blockquote>\n<p>bar</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("foo"))), quote!(paragraph!(plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tfoo\n\n>\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3590
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3590>
@@ -16033,7 +13908,7 @@ fn t213() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("> foo\n> bar\n", ParserSettings::Html)),
     );
@@ -16049,7 +13924,7 @@ fn t213_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("> foo\r> bar\r", ParserSettings::Html)),
     );
@@ -16065,7 +13940,7 @@ fn t213_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("> foo\r\n> bar\r\n", ParserSettings::Html)),
     );
@@ -16081,28 +13956,12 @@ fn t213_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
+            quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("> foo\n> bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3590
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3590>
-/// <https://spec.commonmark.org/0.29/#example-213>
-fn t213_tab() {
-    init!(">\tfoo\n>\tbar\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo\nbar</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tfoo\n>\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3603
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3603>
@@ -16167,22 +14026,6 @@ fn t214_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3603
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3603>
-/// <https://spec.commonmark.org/0.29/#example-214>
-fn t214_tab() {
-    init!(">\tfoo\n>\n>\tbar\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>foo</p>\n<p>bar</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("foo")), paragraph!(plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tfoo\n>\n>\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3617
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3617>
@@ -16247,22 +14090,6 @@ fn t215_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3617
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3617>
-/// <https://spec.commonmark.org/0.29/#example-215>
-fn t215_tab() {
-    init!("foo\n>\tbar\n");
-
-    assert_eq!(
-        // from spec: <p>foo</p>\n<blockquote>\n<p>bar</p>\n</blockquote>
-        [
-            paragraph!(plain!("foo")), quote!(paragraph!(plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\n>\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3631
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3631>
@@ -16273,7 +14100,7 @@ fn t216() {
     assert_eq!(
         // from spec: <blockquote>\n<p>aaa</p>\n</blockquote>\n<hr />\n<blockquote>\n<p>bbb</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("aaa"))), Break, quote!(paragraph!(plain!("bbb")))
+            quote!(paragraph!(plain!("aaa"))), thematic_break!(), quote!(paragraph!(plain!("bbb")))
         ].as_slice(),
         body(&mut StringData::new("> aaa\n***\n> bbb\n", ParserSettings::Html)),
     );
@@ -16289,7 +14116,7 @@ fn t216_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>aaa</p>\n</blockquote>\n<hr />\n<blockquote>\n<p>bbb</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("aaa"))), Break, quote!(paragraph!(plain!("bbb")))
+            quote!(paragraph!(plain!("aaa"))), thematic_break!(), quote!(paragraph!(plain!("bbb")))
         ].as_slice(),
         body(&mut StringData::new("> aaa\r***\r> bbb\r", ParserSettings::Html)),
     );
@@ -16305,7 +14132,7 @@ fn t216_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>aaa</p>\n</blockquote>\n<hr />\n<blockquote>\n<p>bbb</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("aaa"))), Break, quote!(paragraph!(plain!("bbb")))
+            quote!(paragraph!(plain!("aaa"))), thematic_break!(), quote!(paragraph!(plain!("bbb")))
         ].as_slice(),
         body(&mut StringData::new("> aaa\r\n***\r\n> bbb\r\n", ParserSettings::Html)),
     );
@@ -16321,28 +14148,12 @@ fn t216_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>aaa</p>\n</blockquote>\n<hr />\n<blockquote>\n<p>bbb</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("aaa"))), Break, quote!(paragraph!(plain!("bbb")))
+            quote!(paragraph!(plain!("aaa"))), thematic_break!(), quote!(paragraph!(plain!("bbb")))
         ].as_slice(),
         body(&mut StringData::new("> aaa\n***\n> bbb", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3631
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3631>
-/// <https://spec.commonmark.org/0.29/#example-216>
-fn t216_tab() {
-    init!(">\taaa\n***\n>\tbbb\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>aaa</p>\n</blockquote>\n<hr />\n<blockquote>\n<p>bbb</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("aaa"))), Break, quote!(paragraph!(plain!("bbb")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\taaa\n***\n>\tbbb\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3649
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3649>
@@ -16353,7 +14164,7 @@ fn t217() {
     assert_eq!(
         // from spec: <blockquote>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> bar\nbaz\n", ParserSettings::Html)),
     );
@@ -16369,7 +14180,7 @@ fn t217_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> bar\rbaz\r", ParserSettings::Html)),
     );
@@ -16385,7 +14196,7 @@ fn t217_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> bar\r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -16401,28 +14212,12 @@ fn t217_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<p>bar\nbaz</p>\n</blockquote>
         [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
+            quote!(paragraph!(plain!("bar"), soft_break!(), plain!("baz")))
         ].as_slice(),
         body(&mut StringData::new("> bar\nbaz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3649
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3649>
-/// <https://spec.commonmark.org/0.29/#example-217>
-fn t217_tab() {
-    init!(">\tbar\nbaz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>bar\nbaz</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("bar"), SoftBreak, plain!("baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tbar\nbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3660
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3660>
@@ -16487,22 +14282,6 @@ fn t218_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3660
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3660>
-/// <https://spec.commonmark.org/0.29/#example-218>
-fn t218_tab() {
-    init!(">\tbar\n\nbaz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>bar</p>\n</blockquote>\n<p>baz</p>
-        [
-            quote!(paragraph!(plain!("bar"))), paragraph!(plain!("baz"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tbar\n\nbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 3672
@@ -16571,23 +14350,6 @@ fn t219_no_nl() {
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 3672
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3672>
-/// <https://spec.commonmark.org/0.29/#example-219>
-fn t219_tab() {
-    init!(">\tbar\n>\nbaz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>bar</p>\n</blockquote>\n<p>baz</p>
-        [
-            quote!(paragraph!(plain!("bar"))), paragraph!(plain!("baz"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\tbar\n>\nbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3688
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3688>
@@ -16598,7 +14360,7 @@ fn t220() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar</p>\n</blockquote>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))))
+            quote!(quote!(quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))))
         ].as_slice(),
         body(&mut StringData::new("> > > foo\nbar\n", ParserSettings::Html)),
     );
@@ -16614,7 +14376,7 @@ fn t220_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar</p>\n</blockquote>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))))
+            quote!(quote!(quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))))
         ].as_slice(),
         body(&mut StringData::new("> > > foo\rbar\r", ParserSettings::Html)),
     );
@@ -16630,7 +14392,7 @@ fn t220_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar</p>\n</blockquote>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))))
+            quote!(quote!(quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))))
         ].as_slice(),
         body(&mut StringData::new("> > > foo\r\nbar\r\n", ParserSettings::Html)),
     );
@@ -16646,28 +14408,12 @@ fn t220_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar</p>\n</blockquote>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))))
+            quote!(quote!(quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar")))))
         ].as_slice(),
         body(&mut StringData::new("> > > foo\nbar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3688
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3688>
-/// <https://spec.commonmark.org/0.29/#example-220>
-fn t220_tab() {
-    init!(">\t>\t>\tfoo\nbar\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar</p>\n</blockquote>\n</blockquote>\n</blockquote>
-        [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar")))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t>\t>\tfoo\nbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3703
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3703>
@@ -16678,7 +14424,7 @@ fn t221() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar\nbaz</p>\n</blockquote>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("baz")))))
+            quote!(quote!(quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("baz")))))
         ].as_slice(),
         body(&mut StringData::new(">>> foo\n> bar\n>>baz\n", ParserSettings::Html)),
     );
@@ -16694,7 +14440,7 @@ fn t221_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar\nbaz</p>\n</blockquote>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("baz")))))
+            quote!(quote!(quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("baz")))))
         ].as_slice(),
         body(&mut StringData::new(">>> foo\r> bar\r>>baz\r", ParserSettings::Html)),
     );
@@ -16710,7 +14456,7 @@ fn t221_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar\nbaz</p>\n</blockquote>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("baz")))))
+            quote!(quote!(quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("baz")))))
         ].as_slice(),
         body(&mut StringData::new(">>> foo\r\n> bar\r\n>>baz\r\n", ParserSettings::Html)),
     );
@@ -16726,28 +14472,12 @@ fn t221_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar\nbaz</p>\n</blockquote>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("baz")))))
+            quote!(quote!(quote!(paragraph!(plain!("foo"), soft_break!(), plain!("bar"), soft_break!(), plain!("baz")))))
         ].as_slice(),
         body(&mut StringData::new(">>> foo\n> bar\n>>baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3703
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3703>
-/// <https://spec.commonmark.org/0.29/#example-221>
-fn t221_tab() {
-    init!(">>>\tfoo\n>\tbar\n>>baz\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<blockquote>\n<blockquote>\n<p>foo\nbar\nbaz</p>\n</blockquote>\n</blockquote>\n</blockquote>
-        [
-            quote!(quote!(quote!(paragraph!(plain!("foo"), SoftBreak, plain!("bar"), SoftBreak, plain!("baz")))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">>>\tfoo\n>\tbar\n>>baz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3725
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3725>
@@ -16812,22 +14542,6 @@ fn t222_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3725
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3725>
-/// <https://spec.commonmark.org/0.29/#example-222>
-fn t222_tab() {
-    init!(">\t\t\t\t\tcode\n\n>\t\t\t\tnot\tcode\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<p>code</p>\n</blockquote>\n<blockquote>\n<p>not code</p>\n</blockquote>
-        [
-            quote!(paragraph!(plain!("code"))), quote!(paragraph!(plain!("not	code")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t\t\t\t\tcode\n\n>\t\t\t\tnot\tcode\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3779
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3779>
@@ -16838,7 +14552,7 @@ fn t223() {
     assert_eq!(
         // from spec: <p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>
         [
-            paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))
+            paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))
         ].as_slice(),
         body(&mut StringData::new("A paragraph\nwith two lines.\n\n    indented code\n\n> A block quote.\n", ParserSettings::Html)),
     );
@@ -16854,7 +14568,7 @@ fn t223_cr() {
     assert_eq!(
         // from spec: <p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>
         [
-            paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))
+            paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))
         ].as_slice(),
         body(&mut StringData::new("A paragraph\rwith two lines.\r\r    indented code\r\r> A block quote.\r", ParserSettings::Html)),
     );
@@ -16870,7 +14584,7 @@ fn t223_cr_nl() {
     assert_eq!(
         // from spec: <p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>
         [
-            paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))
+            paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))
         ].as_slice(),
         body(&mut StringData::new("A paragraph\r\nwith two lines.\r\n\r\n    indented code\r\n\r\n> A block quote.\r\n", ParserSettings::Html)),
     );
@@ -16886,28 +14600,12 @@ fn t223_no_nl() {
     assert_eq!(
         // from spec: <p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>
         [
-            paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))
+            paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))
         ].as_slice(),
         body(&mut StringData::new("A paragraph\nwith two lines.\n\n    indented code\n\n> A block quote.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3779
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3779>
-/// <https://spec.commonmark.org/0.29/#example-223>
-fn t223_tab() {
-    init!("A\tparagraph\nwith\ttwo\tlines.\n\n    indented\tcode\n\n>\tA\tblock\tquote.\n");
-
-    assert_eq!(
-        // from spec: <p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>
-        [
-            paragraph!(plain!("A	paragraph"), SoftBreak, plain!("with	two	lines.")), paragraph!(plain!("indented	code")), quote!(paragraph!(plain!("A	block	quote.")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("A\tparagraph\nwith\ttwo\tlines.\n\n    indented\tcode\n\n>\tA\tblock\tquote.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3801
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3801>
@@ -16918,7 +14616,7 @@ fn t224() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("1.  A paragraph\n    with two lines.\n\n        indented code\n\n    > A block quote.\n", ParserSettings::Html)),
     );
@@ -16934,7 +14632,7 @@ fn t224_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("1.  A paragraph\r    with two lines.\r\r        indented code\r\r    > A block quote.\r", ParserSettings::Html)),
     );
@@ -16950,7 +14648,7 @@ fn t224_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("1.  A paragraph\r\n    with two lines.\r\n\r\n        indented code\r\n\r\n    > A block quote.\r\n", ParserSettings::Html)),
     );
@@ -16966,28 +14664,12 @@ fn t224_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("1.  A paragraph\n    with two lines.\n\n        indented code\n\n    > A block quote.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3801
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3801>
-/// <https://spec.commonmark.org/0.29/#example-224>
-fn t224_tab() {
-    init!("1.\t\tA\tparagraph\n    with\ttwo\tlines.\n\n    \t\t\t\tindented\tcode\n\n    >\tA\tblock\tquote.\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("A	paragraph"), SoftBreak, plain!("with	two	lines.")), paragraph!(plain!("indented	code")), quote!(paragraph!(plain!("A	block	quote.")))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\t\tA\tparagraph\n    with\ttwo\tlines.\n\n    \t\t\t\tindented\tcode\n\n    >\tA\tblock\tquote.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3834
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3834>
@@ -16998,7 +14680,7 @@ fn t225() {
     assert_eq!(
         // from spec: <ul>\n<li>one</li>\n</ul>\n<p>two</p>
         [
-            unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))
+            unordered_list!('-', item!(paragraph!(plain!("one")))), paragraph!(plain!("two"))
         ].as_slice(),
         body(&mut StringData::new("- one\n\n two\n", ParserSettings::Html)),
     );
@@ -17014,7 +14696,7 @@ fn t225_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>one</li>\n</ul>\n<p>two</p>
         [
-            unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))
+            unordered_list!('-', item!(paragraph!(plain!("one")))), paragraph!(plain!("two"))
         ].as_slice(),
         body(&mut StringData::new("- one\r\r two\r", ParserSettings::Html)),
     );
@@ -17030,7 +14712,7 @@ fn t225_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>one</li>\n</ul>\n<p>two</p>
         [
-            unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))
+            unordered_list!('-', item!(paragraph!(plain!("one")))), paragraph!(plain!("two"))
         ].as_slice(),
         body(&mut StringData::new("- one\r\n\r\n two\r\n", ParserSettings::Html)),
     );
@@ -17046,28 +14728,12 @@ fn t225_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>one</li>\n</ul>\n<p>two</p>
         [
-            unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))
+            unordered_list!('-', item!(paragraph!(plain!("one")))), paragraph!(plain!("two"))
         ].as_slice(),
         body(&mut StringData::new("- one\n\n two", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3834
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3834>
-/// <https://spec.commonmark.org/0.29/#example-225>
-fn t225_tab() {
-    init!("-\tone\n\n two\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>one</li>\n</ul>\n<p>two</p>
-        [
-            unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tone\n\n two\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3846
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3846>
@@ -17078,7 +14744,7 @@ fn t226() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new("- one\n\n  two\n", ParserSettings::Html)),
     );
@@ -17094,7 +14760,7 @@ fn t226_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new("- one\r\r  two\r", ParserSettings::Html)),
     );
@@ -17110,7 +14776,7 @@ fn t226_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new("- one\r\n\r\n  two\r\n", ParserSettings::Html)),
     );
@@ -17126,28 +14792,12 @@ fn t226_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new("- one\n\n  two", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3846
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3846>
-/// <https://spec.commonmark.org/0.29/#example-226>
-fn t226_tab() {
-    init!("-\tone\n\n  two\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tone\n\n  two\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3860
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3860>
@@ -17158,7 +14808,7 @@ fn t227() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(" -    one\n\n     two\n", ParserSettings::Html)),
     );
@@ -17174,7 +14824,7 @@ fn t227_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(" -    one\r\r     two\r", ParserSettings::Html)),
     );
@@ -17190,7 +14840,7 @@ fn t227_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(" -    one\r\n\r\n     two\r\n", ParserSettings::Html)),
     );
@@ -17206,28 +14856,12 @@ fn t227_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(" -    one\n\n     two", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3860
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3860>
-/// <https://spec.commonmark.org/0.29/#example-227>
-fn t227_tab() {
-    init!(" -\t\t\t\tone\n\n    \ttwo\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(" -\t\t\t\tone\n\n    \ttwo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3873
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3873>
@@ -17238,7 +14872,7 @@ fn t228() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(" -    one\n\n      two\n", ParserSettings::Html)),
     );
@@ -17254,7 +14888,7 @@ fn t228_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(" -    one\r\r      two\r", ParserSettings::Html)),
     );
@@ -17270,7 +14904,7 @@ fn t228_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(" -    one\r\n\r\n      two\r\n", ParserSettings::Html)),
     );
@@ -17286,28 +14920,12 @@ fn t228_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(" -    one\n\n      two", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3873
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3873>
-/// <https://spec.commonmark.org/0.29/#example-228>
-fn t228_tab() {
-    init!(" -\t\t\t\tone\n\n    \t\ttwo\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(" -\t\t\t\tone\n\n    \t\ttwo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3895
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3895>
@@ -17318,7 +14936,7 @@ fn t229() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<ol>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ol>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(ordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])))
+            quote!(quote!(ordered_list!(loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))))
         ].as_slice(),
         body(&mut StringData::new("   > > 1.  one\n>>\n>>     two\n", ParserSettings::Html)),
     );
@@ -17334,7 +14952,7 @@ fn t229_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<ol>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ol>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(ordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])))
+            quote!(quote!(ordered_list!(loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))))
         ].as_slice(),
         body(&mut StringData::new("   > > 1.  one\r>>\r>>     two\r", ParserSettings::Html)),
     );
@@ -17350,7 +14968,7 @@ fn t229_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<ol>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ol>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(ordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])))
+            quote!(quote!(ordered_list!(loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))))
         ].as_slice(),
         body(&mut StringData::new("   > > 1.  one\r\n>>\r\n>>     two\r\n", ParserSettings::Html)),
     );
@@ -17366,28 +14984,12 @@ fn t229_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<ol>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ol>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(ordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])))
+            quote!(quote!(ordered_list!(loose, item!(paragraph!(plain!("one")), paragraph!(plain!("two"))))))
         ].as_slice(),
         body(&mut StringData::new("   > > 1.  one\n>>\n>>     two", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3895
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3895>
-/// <https://spec.commonmark.org/0.29/#example-229>
-fn t229_tab() {
-    init!("   >\t>\t1.\t\tone\n>>\n>>\t\t\t\t\ttwo\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<blockquote>\n<ol>\n<li>\n<p>one</p>\n<p>two</p>\n</li>\n</ol>\n</blockquote>\n</blockquote>
-        [
-            quote!(quote!(ordered_list!(vec![paragraph!(plain!("one")), paragraph!(plain!("two"))])))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("   >\t>\t1.\t\tone\n>>\n>>\t\t\t\t\ttwo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3922
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3922>
@@ -17398,7 +15000,7 @@ fn t230() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<ul>\n<li>one</li>\n</ul>\n<p>two</p>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))))
+            quote!(quote!(unordered_list!('-', item!(paragraph!(plain!("one")))), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(">>- one\n>>\n  >  > two\n", ParserSettings::Html)),
     );
@@ -17414,7 +15016,7 @@ fn t230_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<ul>\n<li>one</li>\n</ul>\n<p>two</p>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))))
+            quote!(quote!(unordered_list!('-', item!(paragraph!(plain!("one")))), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(">>- one\r>>\r  >  > two\r", ParserSettings::Html)),
     );
@@ -17430,7 +15032,7 @@ fn t230_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<ul>\n<li>one</li>\n</ul>\n<p>two</p>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))))
+            quote!(quote!(unordered_list!('-', item!(paragraph!(plain!("one")))), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(">>- one\r\n>>\r\n  >  > two\r\n", ParserSettings::Html)),
     );
@@ -17446,28 +15048,12 @@ fn t230_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<blockquote>\n<ul>\n<li>one</li>\n</ul>\n<p>two</p>\n</blockquote>\n</blockquote>
         [
-            quote!(quote!(unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))))
+            quote!(quote!(unordered_list!('-', item!(paragraph!(plain!("one")))), paragraph!(plain!("two"))))
         ].as_slice(),
         body(&mut StringData::new(">>- one\n>>\n  >  > two", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3922
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3922>
-/// <https://spec.commonmark.org/0.29/#example-230>
-fn t230_tab() {
-    init!(">>-\tone\n>>\n  >\t\t>\ttwo\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<blockquote>\n<ul>\n<li>one</li>\n</ul>\n<p>two</p>\n</blockquote>\n</blockquote>
-        [
-            quote!(quote!(unordered_list!(vec![paragraph!(plain!("one"))]), paragraph!(plain!("two"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">>-\tone\n>>\n  >\t\t>\ttwo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3941
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3941>
@@ -17542,7 +15128,7 @@ fn t232() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n\n\n  bar\n", ParserSettings::Html)),
     );
@@ -17558,7 +15144,7 @@ fn t232_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\r\r  bar\r", ParserSettings::Html)),
     );
@@ -17574,7 +15160,7 @@ fn t232_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n\r\n\r\n  bar\r\n", ParserSettings::Html)),
     );
@@ -17590,28 +15176,12 @@ fn t232_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n\n\n  bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3954
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3954>
-/// <https://spec.commonmark.org/0.29/#example-232>
-fn t232_tab() {
-    init!("-\tfoo\n\n\n  bar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n\n\n  bar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3971
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3971>
@@ -17622,7 +15192,7 @@ fn t233() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n<p>baz</p>\n<blockquote>\n<p>bam</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo")), code_block!("", "bar\n"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))])
+            ordered_list!(loose, item!(paragraph!(plain!("foo")), code_block!("", "bar\n"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))))
         ].as_slice(),
         body(&mut StringData::new("1.  foo\n\n    ```\n    bar\n    ```\n\n    baz\n\n    > bam\n", ParserSettings::Html)),
     );
@@ -17638,7 +15208,7 @@ fn t233_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n<p>baz</p>\n<blockquote>\n<p>bam</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo")), code_block!("", "bar\r"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))])
+            ordered_list!(loose, item!(paragraph!(plain!("foo")), code_block!("", "bar\r"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))))
         ].as_slice(),
         body(&mut StringData::new("1.  foo\r\r    ```\r    bar\r    ```\r\r    baz\r\r    > bam\r", ParserSettings::Html)),
     );
@@ -17654,7 +15224,7 @@ fn t233_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n<p>baz</p>\n<blockquote>\n<p>bam</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo")), code_block!("", "bar\r\n"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))])
+            ordered_list!(loose, item!(paragraph!(plain!("foo")), code_block!("", "bar\r\n"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))))
         ].as_slice(),
         body(&mut StringData::new("1.  foo\r\n\r\n    ```\r\n    bar\r\n    ```\r\n\r\n    baz\r\n\r\n    > bam\r\n", ParserSettings::Html)),
     );
@@ -17670,28 +15240,12 @@ fn t233_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n<p>baz</p>\n<blockquote>\n<p>bam</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo")), code_block!("", "bar\n"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))])
+            ordered_list!(loose, item!(paragraph!(plain!("foo")), code_block!("", "bar\n"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))))
         ].as_slice(),
         body(&mut StringData::new("1.  foo\n\n    ```\n    bar\n    ```\n\n    baz\n\n    > bam", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3971
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3971>
-/// <https://spec.commonmark.org/0.29/#example-233>
-fn t233_tab() {
-    init!("1.\t\tfoo\n\n    ```\n    bar\n    ```\n\n    baz\n\n    >\tbam\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>foo</p>\n<pre><code>bar\n</code></pre>\n<p>baz</p>\n<blockquote>\n<p>bam</p>\n</blockquote>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("foo")), code_block!("", "bar\n"), paragraph!(plain!("baz")), quote!(paragraph!(plain!("bam")))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\t\tfoo\n\n    ```\n    bar\n    ```\n\n    baz\n\n    >\tbam\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 3999
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3999>
@@ -17702,7 +15256,7 @@ fn t234() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>Foo</p>\n<p>bar</p>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- Foo\n\n      bar\n\n\n      baz\n", ParserSettings::Html)),
     );
@@ -17718,7 +15272,7 @@ fn t234_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>Foo</p>\n<p>bar</p>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- Foo\r\r      bar\r\r\r      baz\r", ParserSettings::Html)),
     );
@@ -17734,7 +15288,7 @@ fn t234_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>Foo</p>\n<p>bar</p>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- Foo\r\n\r\n      bar\r\n\r\n\r\n      baz\r\n", ParserSettings::Html)),
     );
@@ -17750,28 +15304,12 @@ fn t234_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>Foo</p>\n<p>bar</p>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- Foo\n\n      bar\n\n\n      baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 3999
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L3999>
-/// <https://spec.commonmark.org/0.29/#example-234>
-fn t234_tab() {
-    init!("-\tFoo\n\n    \t\tbar\n\n\n    \t\tbaz\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>Foo</p>\n<p>bar</p>\n<p>baz</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("Foo")), paragraph!(plain!("bar")), paragraph!(plain!("baz"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tFoo\n\n    \t\tbar\n\n\n    \t\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4021
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4021>
@@ -17782,7 +15320,7 @@ fn t235() {
     assert_eq!(
         // from spec: <ol start=\"123456789\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("123456789", vec![paragraph!(plain!("ok"))])
+            ordered_list!("123456789", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("123456789. ok\n", ParserSettings::Html)),
     );
@@ -17798,7 +15336,7 @@ fn t235_cr() {
     assert_eq!(
         // from spec: <ol start=\"123456789\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("123456789", vec![paragraph!(plain!("ok"))])
+            ordered_list!("123456789", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("123456789. ok\r", ParserSettings::Html)),
     );
@@ -17814,7 +15352,7 @@ fn t235_cr_nl() {
     assert_eq!(
         // from spec: <ol start=\"123456789\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("123456789", vec![paragraph!(plain!("ok"))])
+            ordered_list!("123456789", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("123456789. ok\r\n", ParserSettings::Html)),
     );
@@ -17830,28 +15368,12 @@ fn t235_no_nl() {
     assert_eq!(
         // from spec: <ol start=\"123456789\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("123456789", vec![paragraph!(plain!("ok"))])
+            ordered_list!("123456789", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("123456789. ok", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4021
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4021>
-/// <https://spec.commonmark.org/0.29/#example-235>
-fn t235_tab() {
-    init!("123456789.\tok\n");
-
-    assert_eq!(
-        // from spec: <ol start=\"123456789\">\n<li>ok</li>\n</ol>
-        [
-            ordered_list!("123456789", vec![paragraph!(plain!("ok"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("123456789.\tok\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4030
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4030>
@@ -17916,22 +15438,6 @@ fn t236_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4030
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4030>
-/// <https://spec.commonmark.org/0.29/#example-236>
-fn t236_tab() {
-    init!("1234567890.\tnot\tok\n");
-
-    assert_eq!(
-        // from spec: <p>1234567890. not ok</p>
-        [
-            paragraph!(plain!("1234567890.	not	ok"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1234567890.\tnot\tok\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4039
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4039>
@@ -17942,7 +15448,7 @@ fn t237() {
     assert_eq!(
         // from spec: <ol start=\"0\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("0", vec![paragraph!(plain!("ok"))])
+            ordered_list!("0", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("0. ok\n", ParserSettings::Html)),
     );
@@ -17958,7 +15464,7 @@ fn t237_cr() {
     assert_eq!(
         // from spec: <ol start=\"0\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("0", vec![paragraph!(plain!("ok"))])
+            ordered_list!("0", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("0. ok\r", ParserSettings::Html)),
     );
@@ -17974,7 +15480,7 @@ fn t237_cr_nl() {
     assert_eq!(
         // from spec: <ol start=\"0\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("0", vec![paragraph!(plain!("ok"))])
+            ordered_list!("0", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("0. ok\r\n", ParserSettings::Html)),
     );
@@ -17990,28 +15496,12 @@ fn t237_no_nl() {
     assert_eq!(
         // from spec: <ol start=\"0\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("0", vec![paragraph!(plain!("ok"))])
+            ordered_list!("0", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("0. ok", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4039
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4039>
-/// <https://spec.commonmark.org/0.29/#example-237>
-fn t237_tab() {
-    init!("0.\tok\n");
-
-    assert_eq!(
-        // from spec: <ol start=\"0\">\n<li>ok</li>\n</ol>
-        [
-            ordered_list!("0", vec![paragraph!(plain!("ok"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("0.\tok\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4048
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4048>
@@ -18022,7 +15512,7 @@ fn t238() {
     assert_eq!(
         // from spec: <ol start=\"003\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("003", vec![paragraph!(plain!("ok"))])
+            ordered_list!("003", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("003. ok\n", ParserSettings::Html)),
     );
@@ -18038,7 +15528,7 @@ fn t238_cr() {
     assert_eq!(
         // from spec: <ol start=\"003\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("003", vec![paragraph!(plain!("ok"))])
+            ordered_list!("003", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("003. ok\r", ParserSettings::Html)),
     );
@@ -18054,7 +15544,7 @@ fn t238_cr_nl() {
     assert_eq!(
         // from spec: <ol start=\"003\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("003", vec![paragraph!(plain!("ok"))])
+            ordered_list!("003", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("003. ok\r\n", ParserSettings::Html)),
     );
@@ -18070,28 +15560,12 @@ fn t238_no_nl() {
     assert_eq!(
         // from spec: <ol start=\"003\">\n<li>ok</li>\n</ol>
         [
-            ordered_list!("003", vec![paragraph!(plain!("ok"))])
+            ordered_list!("003", item!(paragraph!(plain!("ok"))))
         ].as_slice(),
         body(&mut StringData::new("003. ok", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4048
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4048>
-/// <https://spec.commonmark.org/0.29/#example-238>
-fn t238_tab() {
-    init!("003.\tok\n");
-
-    assert_eq!(
-        // from spec: <ol start=\"003\">\n<li>ok</li>\n</ol>
-        [
-            ordered_list!("003", vec![paragraph!(plain!("ok"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("003.\tok\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4059
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4059>
@@ -18156,22 +15630,6 @@ fn t239_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4059
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4059>
-/// <https://spec.commonmark.org/0.29/#example-239>
-fn t239_tab() {
-    init!("-1.\tnot\tok\n");
-
-    assert_eq!(
-        // from spec: <p>-1. not ok</p>
-        [
-            paragraph!(plain!("-1.	not	ok"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-1.\tnot\tok\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4082
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4082>
@@ -18182,7 +15640,7 @@ fn t240() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n\n      bar\n", ParserSettings::Html)),
     );
@@ -18198,7 +15656,7 @@ fn t240_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\r      bar\r", ParserSettings::Html)),
     );
@@ -18214,7 +15672,7 @@ fn t240_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n\r\n      bar\r\n", ParserSettings::Html)),
     );
@@ -18230,28 +15688,12 @@ fn t240_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n\n      bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4082
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4082>
-/// <https://spec.commonmark.org/0.29/#example-240>
-fn t240_tab() {
-    init!("-\tfoo\n\n    \t\tbar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n\n    \t\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4099
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4099>
@@ -18262,7 +15704,7 @@ fn t241() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ol>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            ordered_list!("10", loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  10.  foo\n\n           bar\n", ParserSettings::Html)),
     );
@@ -18278,7 +15720,7 @@ fn t241_cr() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ol>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            ordered_list!("10", loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  10.  foo\r\r           bar\r", ParserSettings::Html)),
     );
@@ -18294,7 +15736,7 @@ fn t241_cr_nl() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ol>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            ordered_list!("10", loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  10.  foo\r\n\r\n           bar\r\n", ParserSettings::Html)),
     );
@@ -18310,28 +15752,12 @@ fn t241_no_nl() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ol>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            ordered_list!("10", loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("  10.  foo\n\n           bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4099
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4099>
-/// <https://spec.commonmark.org/0.29/#example-241>
-fn t241_tab() {
-    init!("  10.\t\tfoo\n\n    \t\t\t\t\t\t\tbar\n");
-
-    assert_eq!(
-        // from spec: <ol start=\"10\">\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ol>
-        [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  10.\t\tfoo\n\n    \t\t\t\t\t\t\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4118
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4118>
@@ -18396,22 +15822,6 @@ fn t242_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4118
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4118>
-/// <https://spec.commonmark.org/0.29/#example-242>
-fn t242_tab() {
-    init!("    indented\tcode\n\nparagraph\n\n    more\tcode\n");
-
-    assert_eq!(
-        // from spec: <p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>
-        [
-            paragraph!(plain!("indented	code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more	code"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    indented\tcode\n\nparagraph\n\n    more\tcode\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4133
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4133>
@@ -18422,7 +15832,7 @@ fn t243() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))])
+            ordered_list!(loose, item!(paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))))
         ].as_slice(),
         body(&mut StringData::new("1.     indented code\n\n   paragraph\n\n       more code\n", ParserSettings::Html)),
     );
@@ -18438,7 +15848,7 @@ fn t243_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))])
+            ordered_list!(loose, item!(paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))))
         ].as_slice(),
         body(&mut StringData::new("1.     indented code\r\r   paragraph\r\r       more code\r", ParserSettings::Html)),
     );
@@ -18454,7 +15864,7 @@ fn t243_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))])
+            ordered_list!(loose, item!(paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))))
         ].as_slice(),
         body(&mut StringData::new("1.     indented code\r\n\r\n   paragraph\r\n\r\n       more code\r\n", ParserSettings::Html)),
     );
@@ -18470,28 +15880,12 @@ fn t243_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))])
+            ordered_list!(loose, item!(paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))))
         ].as_slice(),
         body(&mut StringData::new("1.     indented code\n\n   paragraph\n\n       more code", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4133
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4133>
-/// <https://spec.commonmark.org/0.29/#example-243>
-fn t243_tab() {
-    init!("1.\t\t\t\t\tindented\tcode\n\n   paragraph\n\n    \t\t\tmore\tcode\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("indented	code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more	code"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\t\t\t\t\tindented\tcode\n\n   paragraph\n\n    \t\t\tmore\tcode\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4155
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4155>
@@ -18502,7 +15896,7 @@ fn t244() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))])
+            ordered_list!(loose, item!(paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))))
         ].as_slice(),
         body(&mut StringData::new("1.      indented code\n\n   paragraph\n\n       more code\n", ParserSettings::Html)),
     );
@@ -18518,7 +15912,7 @@ fn t244_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))])
+            ordered_list!(loose, item!(paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))))
         ].as_slice(),
         body(&mut StringData::new("1.      indented code\r\r   paragraph\r\r       more code\r", ParserSettings::Html)),
     );
@@ -18534,7 +15928,7 @@ fn t244_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))])
+            ordered_list!(loose, item!(paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))))
         ].as_slice(),
         body(&mut StringData::new("1.      indented code\r\n\r\n   paragraph\r\n\r\n       more code\r\n", ParserSettings::Html)),
     );
@@ -18550,28 +15944,12 @@ fn t244_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))])
+            ordered_list!(loose, item!(paragraph!(plain!("indented code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more code"))))
         ].as_slice(),
         body(&mut StringData::new("1.      indented code\n\n   paragraph\n\n       more code", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4155
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4155>
-/// <https://spec.commonmark.org/0.29/#example-244>
-fn t244_tab() {
-    init!("1.\t\t\t\t\t\tindented\tcode\n\n   paragraph\n\n    \t\t\tmore\tcode\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>indented code</p>\n<p>paragraph</p>\n<p>more code</p>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("indented	code")), paragraph!(plain!("paragraph")), paragraph!(plain!("more	code"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\t\t\t\t\t\tindented\tcode\n\n   paragraph\n\n    \t\t\tmore\tcode\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4182
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4182>
@@ -18647,7 +16025,7 @@ fn t246() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<p>bar</p>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), paragraph!(plain!("bar"))
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("-    foo\n\n  bar\n", ParserSettings::Html)),
     );
@@ -18664,7 +16042,7 @@ fn t246_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<p>bar</p>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), paragraph!(plain!("bar"))
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("-    foo\r\r  bar\r", ParserSettings::Html)),
     );
@@ -18681,7 +16059,7 @@ fn t246_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<p>bar</p>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), paragraph!(plain!("bar"))
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("-    foo\r\n\r\n  bar\r\n", ParserSettings::Html)),
     );
@@ -18698,29 +16076,12 @@ fn t246_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>\n<p>bar</p>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), paragraph!(plain!("bar"))
+            unordered_list!('-', item!(paragraph!(plain!("foo")))), paragraph!(plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("-    foo\n\n  bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 4192
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4192>
-/// <https://spec.commonmark.org/0.29/#example-246>
-fn t246_tab() {
-    init!("-\t\t\t\tfoo\n\n  bar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n</ul>\n<p>bar</p>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))]), paragraph!(plain!("bar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t\t\t\tfoo\n\n  bar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4209
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4209>
@@ -18731,7 +16092,7 @@ fn t247() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("-  foo\n\n   bar\n", ParserSettings::Html)),
     );
@@ -18747,7 +16108,7 @@ fn t247_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("-  foo\r\r   bar\r", ParserSettings::Html)),
     );
@@ -18763,7 +16124,7 @@ fn t247_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("-  foo\r\n\r\n   bar\r\n", ParserSettings::Html)),
     );
@@ -18779,28 +16140,12 @@ fn t247_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo")), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("-  foo\n\n   bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4209
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4209>
-/// <https://spec.commonmark.org/0.29/#example-247>
-fn t247_tab() {
-    init!("-\t\tfoo\n\n   bar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t\tfoo\n\n   bar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4237
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4237>
@@ -18809,9 +16154,9 @@ fn t248() {
     init!("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz\n");
 
     assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
+        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>baz</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![code_block!("", "bar\n")], vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(code_block!("", "bar\n")), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz\n", ParserSettings::Html)),
     );
@@ -18825,9 +16170,9 @@ fn t248_cr() {
     init!("-\r  foo\r-\r  ```\r  bar\r  ```\r-\r      baz\r");
 
     assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
+        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>baz</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![code_block!("", "bar\r")], vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(code_block!("", "bar\r")), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("-\r  foo\r-\r  ```\r  bar\r  ```\r-\r      baz\r", ParserSettings::Html)),
     );
@@ -18841,9 +16186,9 @@ fn t248_cr_nl() {
     init!("-\r\n  foo\r\n-\r\n  ```\r\n  bar\r\n  ```\r\n-\r\n      baz\r\n");
 
     assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
+        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>baz</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![code_block!("", "bar\r\n")], vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(code_block!("", "bar\r\n")), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("-\r\n  foo\r\n-\r\n  ```\r\n  bar\r\n  ```\r\n-\r\n      baz\r\n", ParserSettings::Html)),
     );
@@ -18857,30 +16202,14 @@ fn t248_no_nl() {
     init!("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz");
 
     assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
+        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>baz</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![code_block!("", "bar\n")], vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(code_block!("", "bar\n")), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4237
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4237>
-/// <https://spec.commonmark.org/0.29/#example-248>
-fn t248_tab() {
-    init!("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n    \t\tbaz\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![code_block!("", "bar\n")], vec![paragraph!(plain!("baz"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n    \t\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4263
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4263>
@@ -18891,7 +16220,7 @@ fn t249() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("-   \n  foo\n", ParserSettings::Html)),
     );
@@ -18907,7 +16236,7 @@ fn t249_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("-   \r  foo\r", ParserSettings::Html)),
     );
@@ -18923,7 +16252,7 @@ fn t249_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("-   \r\n  foo\r\n", ParserSettings::Html)),
     );
@@ -18939,28 +16268,12 @@ fn t249_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("-   \n  foo", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4263
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4263>
-/// <https://spec.commonmark.org/0.29/#example-249>
-fn t249_tab() {
-    init!("-\t  \n  foo\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t  \n  foo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4277
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4277>
@@ -18971,7 +16284,7 @@ fn t250() {
     assert_eq!(
         // from spec: <ul>\n<li></li>\n</ul>\n<p>foo</p>
         [
-            unordered_list!(vec![]), paragraph!(plain!("foo"))
+            unordered_list!('-', item!()), paragraph!(plain!("foo"))
         ].as_slice(),
         body(&mut StringData::new("-\n\n  foo\n", ParserSettings::Html)),
     );
@@ -18987,7 +16300,7 @@ fn t250_cr() {
     assert_eq!(
         // from spec: <ul>\n<li></li>\n</ul>\n<p>foo</p>
         [
-            unordered_list!(vec![]), paragraph!(plain!("foo"))
+            unordered_list!('-', item!()), paragraph!(plain!("foo"))
         ].as_slice(),
         body(&mut StringData::new("-\r\r  foo\r", ParserSettings::Html)),
     );
@@ -19003,7 +16316,7 @@ fn t250_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li></li>\n</ul>\n<p>foo</p>
         [
-            unordered_list!(vec![]), paragraph!(plain!("foo"))
+            unordered_list!('-', item!()), paragraph!(plain!("foo"))
         ].as_slice(),
         body(&mut StringData::new("-\r\n\r\n  foo\r\n", ParserSettings::Html)),
     );
@@ -19019,7 +16332,7 @@ fn t250_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li></li>\n</ul>\n<p>foo</p>
         [
-            unordered_list!(vec![]), paragraph!(plain!("foo"))
+            unordered_list!('-', item!()), paragraph!(plain!("foo"))
         ].as_slice(),
         body(&mut StringData::new("-\n\n  foo", ParserSettings::Html)),
     );
@@ -19035,7 +16348,7 @@ fn t251() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n-\n- bar\n", ParserSettings::Html)),
     );
@@ -19051,7 +16364,7 @@ fn t251_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r-\r- bar\r", ParserSettings::Html)),
     );
@@ -19067,7 +16380,7 @@ fn t251_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n-\r\n- bar\r\n", ParserSettings::Html)),
     );
@@ -19083,28 +16396,12 @@ fn t251_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n-\n- bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4291
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4291>
-/// <https://spec.commonmark.org/0.29/#example-251>
-fn t251_tab() {
-    init!("-\tfoo\n-\n-\tbar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n-\n-\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4306
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4306>
@@ -19115,7 +16412,7 @@ fn t252() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n-   \n- bar\n", ParserSettings::Html)),
     );
@@ -19131,7 +16428,7 @@ fn t252_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r-   \r- bar\r", ParserSettings::Html)),
     );
@@ -19147,7 +16444,7 @@ fn t252_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n-   \r\n- bar\r\n", ParserSettings::Html)),
     );
@@ -19163,28 +16460,12 @@ fn t252_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n-   \n- bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4306
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4306>
-/// <https://spec.commonmark.org/0.29/#example-252>
-fn t252_tab() {
-    init!("-\tfoo\n-\t  \n-\tbar\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n-\t  \n-\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4321
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4321>
@@ -19195,7 +16476,7 @@ fn t253() {
     assert_eq!(
         // from spec: <ol>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            ordered_list!(item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("1. foo\n2.\n3. bar\n", ParserSettings::Html)),
     );
@@ -19211,7 +16492,7 @@ fn t253_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            ordered_list!(item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("1. foo\r2.\r3. bar\r", ParserSettings::Html)),
     );
@@ -19227,7 +16508,7 @@ fn t253_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            ordered_list!(item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("1. foo\r\n2.\r\n3. bar\r\n", ParserSettings::Html)),
     );
@@ -19243,28 +16524,12 @@ fn t253_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
+            ordered_list!(item!(paragraph!(plain!("foo"))), item!(), item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("1. foo\n2.\n3. bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4321
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4321>
-/// <https://spec.commonmark.org/0.29/#example-253>
-fn t253_tab() {
-    init!("1.\tfoo\n2.\n3.\tbar\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![], vec![paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\tfoo\n2.\n3.\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4336
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4336>
@@ -19275,7 +16540,7 @@ fn t254() {
     assert_eq!(
         // from spec: <ul>\n<li></li>\n</ul>
         [
-            unordered_list!(vec![])
+            unordered_list!('*', item!())
         ].as_slice(),
         body(&mut StringData::new("*\n", ParserSettings::Html)),
     );
@@ -19291,7 +16556,7 @@ fn t254_cr() {
     assert_eq!(
         // from spec: <ul>\n<li></li>\n</ul>
         [
-            unordered_list!(vec![])
+            unordered_list!('*', item!())
         ].as_slice(),
         body(&mut StringData::new("*\r", ParserSettings::Html)),
     );
@@ -19307,7 +16572,7 @@ fn t254_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li></li>\n</ul>
         [
-            unordered_list!(vec![])
+            unordered_list!('*', item!())
         ].as_slice(),
         body(&mut StringData::new("*\r\n", ParserSettings::Html)),
     );
@@ -19323,7 +16588,7 @@ fn t254_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li></li>\n</ul>
         [
-            unordered_list!(vec![])
+            unordered_list!('*', item!())
         ].as_slice(),
         body(&mut StringData::new("*", ParserSettings::Html)),
     );
@@ -19339,7 +16604,7 @@ fn t255() {
     assert_eq!(
         // from spec: <p>foo\n*</p>\n<p>foo\n1.</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("*")), paragraph!(plain!("foo"), SoftBreak, plain!("1."))
+            paragraph!(plain!("foo"), soft_break!(), plain!("*")), paragraph!(plain!("foo"), soft_break!(), plain!("1."))
         ].as_slice(),
         body(&mut StringData::new("foo\n*\n\nfoo\n1.\n", ParserSettings::Html)),
     );
@@ -19355,7 +16620,7 @@ fn t255_cr() {
     assert_eq!(
         // from spec: <p>foo\n*</p>\n<p>foo\n1.</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("*")), paragraph!(plain!("foo"), SoftBreak, plain!("1."))
+            paragraph!(plain!("foo"), soft_break!(), plain!("*")), paragraph!(plain!("foo"), soft_break!(), plain!("1."))
         ].as_slice(),
         body(&mut StringData::new("foo\r*\r\rfoo\r1.\r", ParserSettings::Html)),
     );
@@ -19371,7 +16636,7 @@ fn t255_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\n*</p>\n<p>foo\n1.</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("*")), paragraph!(plain!("foo"), SoftBreak, plain!("1."))
+            paragraph!(plain!("foo"), soft_break!(), plain!("*")), paragraph!(plain!("foo"), soft_break!(), plain!("1."))
         ].as_slice(),
         body(&mut StringData::new("foo\r\n*\r\n\r\nfoo\r\n1.\r\n", ParserSettings::Html)),
     );
@@ -19387,7 +16652,7 @@ fn t255_no_nl() {
     assert_eq!(
         // from spec: <p>foo\n*</p>\n<p>foo\n1.</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("*")), paragraph!(plain!("foo"), SoftBreak, plain!("1."))
+            paragraph!(plain!("foo"), soft_break!(), plain!("*")), paragraph!(plain!("foo"), soft_break!(), plain!("1."))
         ].as_slice(),
         body(&mut StringData::new("foo\n*\n\nfoo\n1.", ParserSettings::Html)),
     );
@@ -19403,7 +16668,7 @@ fn t256() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new(" 1.  A paragraph\n     with two lines.\n\n         indented code\n\n     > A block quote.\n", ParserSettings::Html)),
     );
@@ -19419,7 +16684,7 @@ fn t256_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new(" 1.  A paragraph\r     with two lines.\r\r         indented code\r\r     > A block quote.\r", ParserSettings::Html)),
     );
@@ -19435,7 +16700,7 @@ fn t256_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new(" 1.  A paragraph\r\n     with two lines.\r\n\r\n         indented code\r\n\r\n     > A block quote.\r\n", ParserSettings::Html)),
     );
@@ -19451,28 +16716,12 @@ fn t256_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new(" 1.  A paragraph\n     with two lines.\n\n         indented code\n\n     > A block quote.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4368
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4368>
-/// <https://spec.commonmark.org/0.29/#example-256>
-fn t256_tab() {
-    init!(" 1.\t\tA\tparagraph\n    \twith\ttwo\tlines.\n\n    \t\t\t\t\tindented\tcode\n\n    \t>\tA\tblock\tquote.\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("A	paragraph"), SoftBreak, plain!("with	two	lines.")), paragraph!(plain!("indented	code")), quote!(paragraph!(plain!("A	block	quote.")))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(" 1.\t\tA\tparagraph\n    \twith\ttwo\tlines.\n\n    \t\t\t\t\tindented\tcode\n\n    \t>\tA\tblock\tquote.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4392
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4392>
@@ -19483,7 +16732,7 @@ fn t257() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\n      with two lines.\n\n          indented code\n\n      > A block quote.\n", ParserSettings::Html)),
     );
@@ -19499,7 +16748,7 @@ fn t257_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\r      with two lines.\r\r          indented code\r\r      > A block quote.\r", ParserSettings::Html)),
     );
@@ -19515,7 +16764,7 @@ fn t257_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\r\n      with two lines.\r\n\r\n          indented code\r\n\r\n      > A block quote.\r\n", ParserSettings::Html)),
     );
@@ -19531,28 +16780,12 @@ fn t257_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\n      with two lines.\n\n          indented code\n\n      > A block quote.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4392
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4392>
-/// <https://spec.commonmark.org/0.29/#example-257>
-fn t257_tab() {
-    init!("  1.\t\tA\tparagraph\n    \t\twith\ttwo\tlines.\n\n    \t\t\t\t\t\tindented\tcode\n\n    \t\t>\tA\tblock\tquote.\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("A	paragraph"), SoftBreak, plain!("with	two	lines.")), paragraph!(plain!("indented	code")), quote!(paragraph!(plain!("A	block	quote.")))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  1.\t\tA\tparagraph\n    \t\twith\ttwo\tlines.\n\n    \t\t\t\t\t\tindented\tcode\n\n    \t\t>\tA\tblock\tquote.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4416
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4416>
@@ -19563,7 +16796,7 @@ fn t258() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("   1.  A paragraph\n       with two lines.\n\n           indented code\n\n       > A block quote.\n", ParserSettings::Html)),
     );
@@ -19579,7 +16812,7 @@ fn t258_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("   1.  A paragraph\r       with two lines.\r\r           indented code\r\r       > A block quote.\r", ParserSettings::Html)),
     );
@@ -19595,7 +16828,7 @@ fn t258_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("   1.  A paragraph\r\n       with two lines.\r\n\r\n           indented code\r\n\r\n       > A block quote.\r\n", ParserSettings::Html)),
     );
@@ -19611,28 +16844,12 @@ fn t258_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("   1.  A paragraph\n       with two lines.\n\n           indented code\n\n       > A block quote.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4416
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4416>
-/// <https://spec.commonmark.org/0.29/#example-258>
-fn t258_tab() {
-    init!("   1.\t\tA\tparagraph\n    \t\t\twith\ttwo\tlines.\n\n    \t\t\t\t\t\t\tindented\tcode\n\n    \t\t\t>\tA\tblock\tquote.\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("A	paragraph"), SoftBreak, plain!("with	two	lines.")), paragraph!(plain!("indented	code")), quote!(paragraph!(plain!("A	block	quote.")))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("   1.\t\tA\tparagraph\n    \t\t\twith\ttwo\tlines.\n\n    \t\t\t\t\t\t\tindented\tcode\n\n    \t\t\t>\tA\tblock\tquote.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4440
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4440>
@@ -19643,7 +16860,7 @@ fn t259() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("    1.  A paragraph\n        with two lines.\n\n            indented code\n\n        > A block quote.\n", ParserSettings::Html)),
     );
@@ -19659,7 +16876,7 @@ fn t259_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("    1.  A paragraph\r        with two lines.\r\r            indented code\r\r        > A block quote.\r", ParserSettings::Html)),
     );
@@ -19675,7 +16892,7 @@ fn t259_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("    1.  A paragraph\r\n        with two lines.\r\n\r\n            indented code\r\n\r\n        > A block quote.\r\n", ParserSettings::Html)),
     );
@@ -19691,28 +16908,12 @@ fn t259_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), paragraph!(plain!("indented code")), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("    1.  A paragraph\n        with two lines.\n\n            indented code\n\n        > A block quote.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4440
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4440>
-/// <https://spec.commonmark.org/0.29/#example-259>
-fn t259_tab() {
-    init!("    1.\t\tA\tparagraph\n    \t\t\t\twith\ttwo\tlines.\n\n    \t\t\t\t\t\t\t\tindented\tcode\n\n    \t\t\t\t>\tA\tblock\tquote.\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<p>indented code</p>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("A	paragraph"), SoftBreak, plain!("with	two	lines.")), paragraph!(plain!("indented	code")), quote!(paragraph!(plain!("A	block	quote.")))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("    1.\t\tA\tparagraph\n    \t\t\t\twith\ttwo\tlines.\n\n    \t\t\t\t\t\t\t\tindented\tcode\n\n    \t\t\t\t>\tA\tblock\tquote.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 4470
@@ -19724,7 +16925,7 @@ fn t260() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), code_block!("", "indented code\n"), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), code_block!("", "indented code\n"), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\nwith two lines.\n\n          indented code\n\n      > A block quote.\n", ParserSettings::Html)),
     );
@@ -19741,7 +16942,7 @@ fn t260_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), code_block!("", "indented code\r"), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), code_block!("", "indented code\r"), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\rwith two lines.\r\r          indented code\r\r      > A block quote.\r", ParserSettings::Html)),
     );
@@ -19758,7 +16959,7 @@ fn t260_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), code_block!("", "indented code\r\n"), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), code_block!("", "indented code\r\n"), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\r\nwith two lines.\r\n\r\n          indented code\r\n\r\n      > A block quote.\r\n", ParserSettings::Html)),
     );
@@ -19775,29 +16976,12 @@ fn t260_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines.")), code_block!("", "indented code\n"), quote!(paragraph!(plain!("A block quote.")))])
+            ordered_list!(loose, item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines.")), code_block!("", "indented code\n"), quote!(paragraph!(plain!("A block quote.")))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\nwith two lines.\n\n          indented code\n\n      > A block quote.", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 4470
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4470>
-/// <https://spec.commonmark.org/0.29/#example-260>
-fn t260_tab() {
-    init!("  1.\t\tA\tparagraph\nwith\ttwo\tlines.\n\n    \t\t\t\t\t\tindented\tcode\n\n    \t\t>\tA\tblock\tquote.\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>A paragraph\nwith two lines.</p>\n<pre><code>indented code\n</code></pre>\n<blockquote>\n<p>A block quote.</p>\n</blockquote>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("A	paragraph"), SoftBreak, plain!("with	two	lines.")), code_block!("", "indented\tcode\n"), quote!(paragraph!(plain!("A	block	quote.")))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  1.\t\tA\tparagraph\nwith\ttwo\tlines.\n\n    \t\t\t\t\t\tindented\tcode\n\n    \t\t>\tA\tblock\tquote.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4494
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4494>
@@ -19808,7 +16992,7 @@ fn t261() {
     assert_eq!(
         // from spec: <ol>\n<li>A paragraph\nwith two lines.</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines."))])
+            ordered_list!(item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines."))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\n    with two lines.\n", ParserSettings::Html)),
     );
@@ -19824,7 +17008,7 @@ fn t261_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>A paragraph\nwith two lines.</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines."))])
+            ordered_list!(item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines."))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\r    with two lines.\r", ParserSettings::Html)),
     );
@@ -19840,7 +17024,7 @@ fn t261_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>A paragraph\nwith two lines.</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines."))])
+            ordered_list!(item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines."))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\r\n    with two lines.\r\n", ParserSettings::Html)),
     );
@@ -19856,28 +17040,12 @@ fn t261_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>A paragraph\nwith two lines.</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("A paragraph"), SoftBreak, plain!("with two lines."))])
+            ordered_list!(item!(paragraph!(plain!("A paragraph"), soft_break!(), plain!("with two lines."))))
         ].as_slice(),
         body(&mut StringData::new("  1.  A paragraph\n    with two lines.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4494
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4494>
-/// <https://spec.commonmark.org/0.29/#example-261>
-fn t261_tab() {
-    init!("  1.\t\tA\tparagraph\n    with\ttwo\tlines.\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>A paragraph\nwith two lines.</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("A	paragraph"), SoftBreak, plain!("with	two	lines."))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("  1.\t\tA\tparagraph\n    with\ttwo\tlines.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 4507
@@ -19889,7 +17057,7 @@ fn t262() {
     assert_eq!(
         // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
         [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued here.")))]))
+            quote!(ordered_list!(item!(quote!(paragraph!(plain!("Blockquote"), soft_break!(), plain!("continued here."))))))
         ].as_slice(),
         body(&mut StringData::new("> 1. > Blockquote\ncontinued here.\n", ParserSettings::Html)),
     );
@@ -19906,7 +17074,7 @@ fn t262_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
         [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued here.")))]))
+            quote!(ordered_list!(item!(quote!(paragraph!(plain!("Blockquote"), soft_break!(), plain!("continued here."))))))
         ].as_slice(),
         body(&mut StringData::new("> 1. > Blockquote\rcontinued here.\r", ParserSettings::Html)),
     );
@@ -19923,7 +17091,7 @@ fn t262_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
         [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued here.")))]))
+            quote!(ordered_list!(item!(quote!(paragraph!(plain!("Blockquote"), soft_break!(), plain!("continued here."))))))
         ].as_slice(),
         body(&mut StringData::new("> 1. > Blockquote\r\ncontinued here.\r\n", ParserSettings::Html)),
     );
@@ -19940,29 +17108,12 @@ fn t262_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
         [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued here.")))]))
+            quote!(ordered_list!(item!(quote!(paragraph!(plain!("Blockquote"), soft_break!(), plain!("continued here."))))))
         ].as_slice(),
         body(&mut StringData::new("> 1. > Blockquote\ncontinued here.", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 4507
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4507>
-/// <https://spec.commonmark.org/0.29/#example-262>
-fn t262_tab() {
-    init!(">\t1.\t>\tBlockquote\ncontinued\there.\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
-        [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued	here.")))]))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t1.\t>\tBlockquote\ncontinued\there.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 4524
@@ -19974,7 +17125,7 @@ fn t263() {
     assert_eq!(
         // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
         [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued here.")))]))
+            quote!(ordered_list!(item!(quote!(paragraph!(plain!("Blockquote"), soft_break!(), plain!("continued here."))))))
         ].as_slice(),
         body(&mut StringData::new("> 1. > Blockquote\n> continued here.\n", ParserSettings::Html)),
     );
@@ -19991,7 +17142,7 @@ fn t263_cr() {
     assert_eq!(
         // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
         [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued here.")))]))
+            quote!(ordered_list!(item!(quote!(paragraph!(plain!("Blockquote"), soft_break!(), plain!("continued here."))))))
         ].as_slice(),
         body(&mut StringData::new("> 1. > Blockquote\r> continued here.\r", ParserSettings::Html)),
     );
@@ -20008,7 +17159,7 @@ fn t263_cr_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
         [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued here.")))]))
+            quote!(ordered_list!(item!(quote!(paragraph!(plain!("Blockquote"), soft_break!(), plain!("continued here."))))))
         ].as_slice(),
         body(&mut StringData::new("> 1. > Blockquote\r\n> continued here.\r\n", ParserSettings::Html)),
     );
@@ -20025,29 +17176,12 @@ fn t263_no_nl() {
     assert_eq!(
         // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
         [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued here.")))]))
+            quote!(ordered_list!(item!(quote!(paragraph!(plain!("Blockquote"), soft_break!(), plain!("continued here."))))))
         ].as_slice(),
         body(&mut StringData::new("> 1. > Blockquote\n> continued here.", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 4524
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4524>
-/// <https://spec.commonmark.org/0.29/#example-263>
-fn t263_tab() {
-    init!(">\t1.\t>\tBlockquote\n>\tcontinued\there.\n");
-
-    assert_eq!(
-        // from spec: <blockquote>\n<ol>\n<li>\n<blockquote>\n<p>Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>
-        [
-            quote!(ordered_list!(vec![quote!(paragraph!(plain!("Blockquote"), SoftBreak, plain!("continued	here.")))]))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new(">\t1.\t>\tBlockquote\n>\tcontinued\there.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4552
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4552>
@@ -20058,7 +17192,7 @@ fn t264() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), unordered_list!(vec![paragraph!(plain!("boo"))])])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', item!(paragraph!(plain!("baz")), unordered_list!('-', item!(paragraph!(plain!("boo"))))))))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n  - bar\n    - baz\n      - boo\n", ParserSettings::Html)),
     );
@@ -20074,7 +17208,7 @@ fn t264_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), unordered_list!(vec![paragraph!(plain!("boo"))])])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', item!(paragraph!(plain!("baz")), unordered_list!('-', item!(paragraph!(plain!("boo"))))))))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r  - bar\r    - baz\r      - boo\r", ParserSettings::Html)),
     );
@@ -20090,7 +17224,7 @@ fn t264_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), unordered_list!(vec![paragraph!(plain!("boo"))])])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', item!(paragraph!(plain!("baz")), unordered_list!('-', item!(paragraph!(plain!("boo"))))))))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n  - bar\r\n    - baz\r\n      - boo\r\n", ParserSettings::Html)),
     );
@@ -20106,28 +17240,12 @@ fn t264_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), unordered_list!(vec![paragraph!(plain!("boo"))])])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', item!(paragraph!(plain!("baz")), unordered_list!('-', item!(paragraph!(plain!("boo"))))))))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n  - bar\n    - baz\n      - boo", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4552
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4552>
-/// <https://spec.commonmark.org/0.29/#example-264>
-fn t264_tab() {
-    init!("-\tfoo\n  -\tbar\n    -\tbaz\n    \t\t-\tboo\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>baz\n<ul>\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), unordered_list!(vec![paragraph!(plain!("boo"))])])])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n  -\tbar\n    -\tbaz\n    \t\t-\tboo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4578
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4578>
@@ -20138,7 +17256,7 @@ fn t265() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n<li>boo</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("boo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))), item!(paragraph!(plain!("boo"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n - bar\n  - baz\n   - boo\n", ParserSettings::Html)),
     );
@@ -20154,7 +17272,7 @@ fn t265_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n<li>boo</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("boo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))), item!(paragraph!(plain!("boo"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r - bar\r  - baz\r   - boo\r", ParserSettings::Html)),
     );
@@ -20170,7 +17288,7 @@ fn t265_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n<li>boo</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("boo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))), item!(paragraph!(plain!("boo"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n - bar\r\n  - baz\r\n   - boo\r\n", ParserSettings::Html)),
     );
@@ -20186,28 +17304,12 @@ fn t265_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n<li>boo</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("boo"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))), item!(paragraph!(plain!("boo"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n - bar\n  - baz\n   - boo", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4578
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4578>
-/// <https://spec.commonmark.org/0.29/#example-265>
-fn t265_tab() {
-    init!("-\tfoo\n -\tbar\n  -\tbaz\n   -\tboo\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n<li>boo</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("boo"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n -\tbar\n  -\tbaz\n   -\tboo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4595
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4595>
@@ -20218,7 +17320,7 @@ fn t266() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>foo\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
+            ordered_list!("10)", item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))))))
         ].as_slice(),
         body(&mut StringData::new("10) foo\n    - bar\n", ParserSettings::Html)),
     );
@@ -20234,7 +17336,7 @@ fn t266_cr() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>foo\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
+            ordered_list!("10)", item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))))))
         ].as_slice(),
         body(&mut StringData::new("10) foo\r    - bar\r", ParserSettings::Html)),
     );
@@ -20250,7 +17352,7 @@ fn t266_cr_nl() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>foo\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
+            ordered_list!("10)", item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))))))
         ].as_slice(),
         body(&mut StringData::new("10) foo\r\n    - bar\r\n", ParserSettings::Html)),
     );
@@ -20266,28 +17368,12 @@ fn t266_no_nl() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>foo\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
+            ordered_list!("10)", item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))))))
         ].as_slice(),
         body(&mut StringData::new("10) foo\n    - bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4595
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4595>
-/// <https://spec.commonmark.org/0.29/#example-266>
-fn t266_tab() {
-    init!("10)\tfoo\n    -\tbar\n");
-
-    assert_eq!(
-        // from spec: <ol start=\"10\">\n<li>foo\n<ul>\n<li>bar</li>\n</ul>\n</li>\n</ol>
-        [
-            ordered_list!("10", vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("10)\tfoo\n    -\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 4611
@@ -20299,7 +17385,7 @@ fn t267() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>foo</li>\n</ol>\n<ul>\n<li>bar</li>\n</ul>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo"))]), unordered_list!(vec![paragraph!(plain!("bar"))])
+            ordered_list!("10)", item!(paragraph!(plain!("foo")))), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("10) foo\n   - bar\n", ParserSettings::Html)),
     );
@@ -20316,7 +17402,7 @@ fn t267_cr() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>foo</li>\n</ol>\n<ul>\n<li>bar</li>\n</ul>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo"))]), unordered_list!(vec![paragraph!(plain!("bar"))])
+            ordered_list!("10)", item!(paragraph!(plain!("foo")))), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("10) foo\r   - bar\r", ParserSettings::Html)),
     );
@@ -20333,7 +17419,7 @@ fn t267_cr_nl() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>foo</li>\n</ol>\n<ul>\n<li>bar</li>\n</ul>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo"))]), unordered_list!(vec![paragraph!(plain!("bar"))])
+            ordered_list!("10)", item!(paragraph!(plain!("foo")))), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("10) foo\r\n   - bar\r\n", ParserSettings::Html)),
     );
@@ -20350,29 +17436,12 @@ fn t267_no_nl() {
     assert_eq!(
         // from spec: <ol start=\"10\">\n<li>foo</li>\n</ol>\n<ul>\n<li>bar</li>\n</ul>
         [
-            ordered_list!("10", vec![paragraph!(plain!("foo"))]), unordered_list!(vec![paragraph!(plain!("bar"))])
+            ordered_list!("10)", item!(paragraph!(plain!("foo")))), unordered_list!('-', item!(paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("10) foo\n   - bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 4611
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4611>
-/// <https://spec.commonmark.org/0.29/#example-267>
-fn t267_tab() {
-    init!("10)\tfoo\n   -\tbar\n");
-
-    assert_eq!(
-        // from spec: <ol start=\"10\">\n<li>foo</li>\n</ol>\n<ul>\n<li>bar</li>\n</ul>
-        [
-            ordered_list!("10", vec![paragraph!(plain!("foo"))]), unordered_list!(vec![paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("10)\tfoo\n   -\tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4626
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4626>
@@ -20383,7 +17452,7 @@ fn t268() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<ul>\n<li>foo</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![unordered_list!(vec![paragraph!(plain!("foo"))])])
+            unordered_list!('-', item!(unordered_list!('-', item!(paragraph!(plain!("foo"))))))
         ].as_slice(),
         body(&mut StringData::new("- - foo\n", ParserSettings::Html)),
     );
@@ -20399,7 +17468,7 @@ fn t268_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<ul>\n<li>foo</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![unordered_list!(vec![paragraph!(plain!("foo"))])])
+            unordered_list!('-', item!(unordered_list!('-', item!(paragraph!(plain!("foo"))))))
         ].as_slice(),
         body(&mut StringData::new("- - foo\r", ParserSettings::Html)),
     );
@@ -20415,7 +17484,7 @@ fn t268_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<ul>\n<li>foo</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![unordered_list!(vec![paragraph!(plain!("foo"))])])
+            unordered_list!('-', item!(unordered_list!('-', item!(paragraph!(plain!("foo"))))))
         ].as_slice(),
         body(&mut StringData::new("- - foo\r\n", ParserSettings::Html)),
     );
@@ -20431,28 +17500,12 @@ fn t268_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<ul>\n<li>foo</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![unordered_list!(vec![paragraph!(plain!("foo"))])])
+            unordered_list!('-', item!(unordered_list!('-', item!(paragraph!(plain!("foo"))))))
         ].as_slice(),
         body(&mut StringData::new("- - foo", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4626
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4626>
-/// <https://spec.commonmark.org/0.29/#example-268>
-fn t268_tab() {
-    init!("-\t-\tfoo\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<ul>\n<li>foo</li>\n</ul>\n</li>\n</ul>
-        [
-            unordered_list!(vec![unordered_list!(vec![paragraph!(plain!("foo"))])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t-\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4639
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4639>
@@ -20463,7 +17516,7 @@ fn t269() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<ul>\n<li>\n<ol start=\"2\">\n<li>foo</li>\n</ol>\n</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!(vec![unordered_list!(vec![ordered_list!("2", vec![paragraph!(plain!("foo"))])])])
+            ordered_list!(item!(unordered_list!('-', item!(ordered_list!("2", item!(paragraph!(plain!("foo"))))))))
         ].as_slice(),
         body(&mut StringData::new("1. - 2. foo\n", ParserSettings::Html)),
     );
@@ -20479,7 +17532,7 @@ fn t269_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<ul>\n<li>\n<ol start=\"2\">\n<li>foo</li>\n</ol>\n</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!(vec![unordered_list!(vec![ordered_list!("2", vec![paragraph!(plain!("foo"))])])])
+            ordered_list!(item!(unordered_list!('-', item!(ordered_list!("2", item!(paragraph!(plain!("foo"))))))))
         ].as_slice(),
         body(&mut StringData::new("1. - 2. foo\r", ParserSettings::Html)),
     );
@@ -20495,7 +17548,7 @@ fn t269_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<ul>\n<li>\n<ol start=\"2\">\n<li>foo</li>\n</ol>\n</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!(vec![unordered_list!(vec![ordered_list!("2", vec![paragraph!(plain!("foo"))])])])
+            ordered_list!(item!(unordered_list!('-', item!(ordered_list!("2", item!(paragraph!(plain!("foo"))))))))
         ].as_slice(),
         body(&mut StringData::new("1. - 2. foo\r\n", ParserSettings::Html)),
     );
@@ -20511,28 +17564,12 @@ fn t269_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<ul>\n<li>\n<ol start=\"2\">\n<li>foo</li>\n</ol>\n</li>\n</ul>\n</li>\n</ol>
         [
-            ordered_list!(vec![unordered_list!(vec![ordered_list!("2", vec![paragraph!(plain!("foo"))])])])
+            ordered_list!(item!(unordered_list!('-', item!(ordered_list!("2", item!(paragraph!(plain!("foo"))))))))
         ].as_slice(),
         body(&mut StringData::new("1. - 2. foo", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4639
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4639>
-/// <https://spec.commonmark.org/0.29/#example-269>
-fn t269_tab() {
-    init!("1.\t-\t2.\tfoo\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<ul>\n<li>\n<ol start=\"2\">\n<li>foo</li>\n</ol>\n</li>\n</ul>\n</li>\n</ol>
-        [
-            ordered_list!(vec![unordered_list!(vec![ordered_list!("2", vec![paragraph!(plain!("foo"))])])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\t-\t2.\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4658
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4658>
@@ -20541,9 +17578,13 @@ fn t270() {
     init!("- # Foo\n- Bar\n  ---\n  baz\n");
 
     assert_eq!(
-        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<p>Bar</p>\n<hr />\n<p>baz</p>\n</li>\n</ul>
+        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<h2>Bar</h2>\nbaz</li>\n</ul>
         [
-            unordered_list!(vec![heading!(1, plain!("Foo"))], vec![paragraph!(plain!("Bar")), Break, paragraph!(plain!("baz"))])
+            unordered_list!(
+              '-',
+              item!(heading!(1, plain!("Foo"))),
+              item!(paragraph!(plain!("Bar")), thematic_break!(), paragraph!(plain!("baz")))
+            )
         ].as_slice(),
         body(&mut StringData::new("- # Foo\n- Bar\n  ---\n  baz\n", ParserSettings::Html)),
     );
@@ -20557,9 +17598,13 @@ fn t270_cr() {
     init!("- # Foo\r- Bar\r  ---\r  baz\r");
 
     assert_eq!(
-        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<p>Bar</p>\n<hr />\n<p>baz</p>\n</li>\n</ul>
+        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<h2>Bar</h2>\nbaz</li>\n</ul>
         [
-            unordered_list!(vec![heading!(1, plain!("Foo"))], vec![paragraph!(plain!("Bar")), Break, paragraph!(plain!("baz"))])
+            unordered_list!(
+              '-',
+              item!(heading!(1, plain!("Foo"))),
+              item!(paragraph!(plain!("Bar")), thematic_break!(), paragraph!(plain!("baz")))
+            )
         ].as_slice(),
         body(&mut StringData::new("- # Foo\r- Bar\r  ---\r  baz\r", ParserSettings::Html)),
     );
@@ -20573,9 +17618,13 @@ fn t270_cr_nl() {
     init!("- # Foo\r\n- Bar\r\n  ---\r\n  baz\r\n");
 
     assert_eq!(
-        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<p>Bar</p>\n<hr />\n<p>baz</p>\n</li>\n</ul>
+        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<h2>Bar</h2>\nbaz</li>\n</ul>
         [
-            unordered_list!(vec![heading!(1, plain!("Foo"))], vec![paragraph!(plain!("Bar")), Break, paragraph!(plain!("baz"))])
+            unordered_list!(
+              '-',
+              item!(heading!(1, plain!("Foo"))),
+              item!(paragraph!(plain!("Bar")), thematic_break!(), paragraph!(plain!("baz")))
+            )
         ].as_slice(),
         body(&mut StringData::new("- # Foo\r\n- Bar\r\n  ---\r\n  baz\r\n", ParserSettings::Html)),
     );
@@ -20589,30 +17638,18 @@ fn t270_no_nl() {
     init!("- # Foo\n- Bar\n  ---\n  baz");
 
     assert_eq!(
-        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<p>Bar</p>\n<hr />\n<p>baz</p>\n</li>\n</ul>
+        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<h2>Bar</h2>\nbaz</li>\n</ul>
         [
-            unordered_list!(vec![heading!(1, plain!("Foo"))], vec![paragraph!(plain!("Bar")), Break, paragraph!(plain!("baz"))])
+            unordered_list!(
+              '-',
+              item!(heading!(1, plain!("Foo"))),
+              item!(paragraph!(plain!("Bar")), thematic_break!(), paragraph!(plain!("baz")))
+            )
         ].as_slice(),
         body(&mut StringData::new("- # Foo\n- Bar\n  ---\n  baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4658
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4658>
-/// <https://spec.commonmark.org/0.29/#example-270>
-fn t270_tab() {
-    init!("-\t#\tFoo\n-\tBar\n  ---\n  baz\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<h1>Foo</h1>\n</li>\n<li>\n<p>Bar</p>\n<hr />\n<p>baz</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![heading!(1, plain!("Foo"))], vec![paragraph!(plain!("Bar")), Break, paragraph!(plain!("baz"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t#\tFoo\n-\tBar\n  ---\n  baz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4894
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4894>
@@ -20623,7 +17660,7 @@ fn t271() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul>\n<li>baz</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), unordered_list!(vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), unordered_list!('+', item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n- bar\n+ baz\n", ParserSettings::Html)),
     );
@@ -20639,7 +17676,7 @@ fn t271_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul>\n<li>baz</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), unordered_list!(vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), unordered_list!('+', item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r- bar\r+ baz\r", ParserSettings::Html)),
     );
@@ -20655,7 +17692,7 @@ fn t271_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul>\n<li>baz</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), unordered_list!(vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), unordered_list!('+', item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n- bar\r\n+ baz\r\n", ParserSettings::Html)),
     );
@@ -20671,30 +17708,13 @@ fn t271_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul>\n<li>baz</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), unordered_list!(vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), unordered_list!('+', item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n- bar\n+ baz", ParserSettings::Html)),
     );
 }
 
 #[test]
-/// Test case generated from Commonmark Spec line 4894
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4894>
-/// <https://spec.commonmark.org/0.29/#example-271>
-fn t271_tab() {
-    init!("-\tfoo\n-\tbar\n+\tbaz\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul>\n<li>baz</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), unordered_list!(vec![paragraph!(plain!("baz"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n-\tbar\n+\tbaz\n", ParserSettings::Html))),
-    );
-}
-
-#[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 4909
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4909>
 /// <https://spec.commonmark.org/0.29/#example-272>
@@ -20704,14 +17724,13 @@ fn t272() {
     assert_eq!(
         // from spec: <ol>\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol start=\"3\">\n<li>baz</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), ordered_list!("3", vec![paragraph!(plain!("baz"))])
+            ordered_list!(item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), ordered_list!("3)", item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("1. foo\n2. bar\n3) baz\n", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 4909
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4909>
 /// <https://spec.commonmark.org/0.29/#example-272>
@@ -20721,14 +17740,13 @@ fn t272_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol start=\"3\">\n<li>baz</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), ordered_list!("3", vec![paragraph!(plain!("baz"))])
+            ordered_list!(item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), ordered_list!("3)", item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("1. foo\r2. bar\r3) baz\r", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 4909
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4909>
 /// <https://spec.commonmark.org/0.29/#example-272>
@@ -20738,14 +17756,13 @@ fn t272_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol start=\"3\">\n<li>baz</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), ordered_list!("3", vec![paragraph!(plain!("baz"))])
+            ordered_list!(item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), ordered_list!("3)", item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("1. foo\r\n2. bar\r\n3) baz\r\n", ParserSettings::Html)),
     );
 }
 
 #[test]
-#[ignore]
 /// Test case generated from Commonmark Spec line 4909
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4909>
 /// <https://spec.commonmark.org/0.29/#example-272>
@@ -20755,29 +17772,12 @@ fn t272_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol start=\"3\">\n<li>baz</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), ordered_list!("3", vec![paragraph!(plain!("baz"))])
+            ordered_list!(item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), ordered_list!("3)", item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("1. foo\n2. bar\n3) baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 4909
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4909>
-/// <https://spec.commonmark.org/0.29/#example-272>
-fn t272_tab() {
-    init!("1.\tfoo\n2.\tbar\n3)\tbaz\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol start=\"3\">\n<li>baz</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), ordered_list!("3", vec![paragraph!(plain!("baz"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\tfoo\n2.\tbar\n3)\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 4928
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4928>
@@ -20788,7 +17788,7 @@ fn t273() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>
         [
-            paragraph!(plain!("Foo")), unordered_list!(vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
+            paragraph!(plain!("Foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("Foo\n- bar\n- baz\n", ParserSettings::Html)),
     );
@@ -20804,7 +17804,7 @@ fn t273_cr() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>
         [
-            paragraph!(plain!("Foo")), unordered_list!(vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
+            paragraph!(plain!("Foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("Foo\r- bar\r- baz\r", ParserSettings::Html)),
     );
@@ -20820,7 +17820,7 @@ fn t273_cr_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>
         [
-            paragraph!(plain!("Foo")), unordered_list!(vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
+            paragraph!(plain!("Foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("Foo\r\n- bar\r\n- baz\r\n", ParserSettings::Html)),
     );
@@ -20836,28 +17836,12 @@ fn t273_no_nl() {
     assert_eq!(
         // from spec: <p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>
         [
-            paragraph!(plain!("Foo")), unordered_list!(vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
+            paragraph!(plain!("Foo")), unordered_list!('-', item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("Foo\n- bar\n- baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 4928
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L4928>
-/// <https://spec.commonmark.org/0.29/#example-273>
-fn t273_tab() {
-    init!("Foo\n-\tbar\n-\tbaz\n");
-
-    assert_eq!(
-        // from spec: <p>Foo</p>\n<ul>\n<li>bar</li>\n<li>baz</li>\n</ul>
-        [
-            paragraph!(plain!("Foo")), unordered_list!(vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\n-\tbar\n-\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5005
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5005>
@@ -20868,7 +17852,7 @@ fn t274() {
     assert_eq!(
         // from spec: <p>The number of windows in my house is\n14.  The number of doors is 6.</p>
         [
-            paragraph!(plain!("The number of windows in my house is"), SoftBreak, plain!("14.  The number of doors is 6."))
+            paragraph!(plain!("The number of windows in my house is"), soft_break!(), plain!("14.  The number of doors is 6."))
         ].as_slice(),
         body(&mut StringData::new("The number of windows in my house is\n14.  The number of doors is 6.\n", ParserSettings::Html)),
     );
@@ -20884,7 +17868,7 @@ fn t274_cr() {
     assert_eq!(
         // from spec: <p>The number of windows in my house is\n14.  The number of doors is 6.</p>
         [
-            paragraph!(plain!("The number of windows in my house is"), SoftBreak, plain!("14.  The number of doors is 6."))
+            paragraph!(plain!("The number of windows in my house is"), soft_break!(), plain!("14.  The number of doors is 6."))
         ].as_slice(),
         body(&mut StringData::new("The number of windows in my house is\r14.  The number of doors is 6.\r", ParserSettings::Html)),
     );
@@ -20900,7 +17884,7 @@ fn t274_cr_nl() {
     assert_eq!(
         // from spec: <p>The number of windows in my house is\n14.  The number of doors is 6.</p>
         [
-            paragraph!(plain!("The number of windows in my house is"), SoftBreak, plain!("14.  The number of doors is 6."))
+            paragraph!(plain!("The number of windows in my house is"), soft_break!(), plain!("14.  The number of doors is 6."))
         ].as_slice(),
         body(&mut StringData::new("The number of windows in my house is\r\n14.  The number of doors is 6.\r\n", ParserSettings::Html)),
     );
@@ -20916,28 +17900,12 @@ fn t274_no_nl() {
     assert_eq!(
         // from spec: <p>The number of windows in my house is\n14.  The number of doors is 6.</p>
         [
-            paragraph!(plain!("The number of windows in my house is"), SoftBreak, plain!("14.  The number of doors is 6."))
+            paragraph!(plain!("The number of windows in my house is"), soft_break!(), plain!("14.  The number of doors is 6."))
         ].as_slice(),
         body(&mut StringData::new("The number of windows in my house is\n14.  The number of doors is 6.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5005
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5005>
-/// <https://spec.commonmark.org/0.29/#example-274>
-fn t274_tab() {
-    init!("The\tnumber\tof\twindows\tin\tmy\thouse\tis\n14.\t\tThe\tnumber\tof\tdoors\tis\t6.\n");
-
-    assert_eq!(
-        // from spec: <p>The number of windows in my house is\n14.  The number of doors is 6.</p>
-        [
-            paragraph!(plain!("The	number	of	windows	in	my	house	is"), SoftBreak, plain!("14.		The	number	of	doors	is	6."))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("The\tnumber\tof\twindows\tin\tmy\thouse\tis\n14.\t\tThe\tnumber\tof\tdoors\tis\t6.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5015
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5015>
@@ -20948,7 +17916,7 @@ fn t275() {
     assert_eq!(
         // from spec: <p>The number of windows in my house is</p>\n<ol>\n<li>The number of doors is 6.</li>\n</ol>
         [
-            paragraph!(plain!("The number of windows in my house is")), ordered_list!(vec![paragraph!(plain!("The number of doors is 6."))])
+            paragraph!(plain!("The number of windows in my house is")), ordered_list!(item!(paragraph!(plain!("The number of doors is 6."))))
         ].as_slice(),
         body(&mut StringData::new("The number of windows in my house is\n1.  The number of doors is 6.\n", ParserSettings::Html)),
     );
@@ -20964,7 +17932,7 @@ fn t275_cr() {
     assert_eq!(
         // from spec: <p>The number of windows in my house is</p>\n<ol>\n<li>The number of doors is 6.</li>\n</ol>
         [
-            paragraph!(plain!("The number of windows in my house is")), ordered_list!(vec![paragraph!(plain!("The number of doors is 6."))])
+            paragraph!(plain!("The number of windows in my house is")), ordered_list!(item!(paragraph!(plain!("The number of doors is 6."))))
         ].as_slice(),
         body(&mut StringData::new("The number of windows in my house is\r1.  The number of doors is 6.\r", ParserSettings::Html)),
     );
@@ -20980,7 +17948,7 @@ fn t275_cr_nl() {
     assert_eq!(
         // from spec: <p>The number of windows in my house is</p>\n<ol>\n<li>The number of doors is 6.</li>\n</ol>
         [
-            paragraph!(plain!("The number of windows in my house is")), ordered_list!(vec![paragraph!(plain!("The number of doors is 6."))])
+            paragraph!(plain!("The number of windows in my house is")), ordered_list!(item!(paragraph!(plain!("The number of doors is 6."))))
         ].as_slice(),
         body(&mut StringData::new("The number of windows in my house is\r\n1.  The number of doors is 6.\r\n", ParserSettings::Html)),
     );
@@ -20996,28 +17964,12 @@ fn t275_no_nl() {
     assert_eq!(
         // from spec: <p>The number of windows in my house is</p>\n<ol>\n<li>The number of doors is 6.</li>\n</ol>
         [
-            paragraph!(plain!("The number of windows in my house is")), ordered_list!(vec![paragraph!(plain!("The number of doors is 6."))])
+            paragraph!(plain!("The number of windows in my house is")), ordered_list!(item!(paragraph!(plain!("The number of doors is 6."))))
         ].as_slice(),
         body(&mut StringData::new("The number of windows in my house is\n1.  The number of doors is 6.", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5015
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5015>
-/// <https://spec.commonmark.org/0.29/#example-275>
-fn t275_tab() {
-    init!("The\tnumber\tof\twindows\tin\tmy\thouse\tis\n1.\t\tThe\tnumber\tof\tdoors\tis\t6.\n");
-
-    assert_eq!(
-        // from spec: <p>The number of windows in my house is</p>\n<ol>\n<li>The number of doors is 6.</li>\n</ol>
-        [
-            paragraph!(plain!("The	number	of	windows	in	my	house	is")), ordered_list!(vec![paragraph!(plain!("The	number	of	doors	is	6."))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("The\tnumber\tof\twindows\tin\tmy\thouse\tis\n1.\t\tThe\tnumber\tof\tdoors\tis\t6.\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5029
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5029>
@@ -21028,7 +17980,7 @@ fn t276() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n\n- bar\n\n\n- baz\n", ParserSettings::Html)),
     );
@@ -21044,7 +17996,7 @@ fn t276_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\r- bar\r\r\r- baz\r", ParserSettings::Html)),
     );
@@ -21060,7 +18012,7 @@ fn t276_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n\r\n- bar\r\n\r\n\r\n- baz\r\n", ParserSettings::Html)),
     );
@@ -21076,28 +18028,12 @@ fn t276_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar"))), item!(paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n\n- bar\n\n\n- baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5029
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5029>
-/// <https://spec.commonmark.org/0.29/#example-276>
-fn t276_tab() {
-    init!("-\tfoo\n\n-\tbar\n\n\n-\tbaz\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n</li>\n<li>\n<p>bar</p>\n</li>\n<li>\n<p>baz</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))], vec![paragraph!(plain!("baz"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n\n-\tbar\n\n\n-\tbaz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5050
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5050>
@@ -21108,7 +18044,7 @@ fn t277() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>\n<p>baz</p>\n<p>bim</p>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), paragraph!(plain!("bim"))])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', loose, item!(paragraph!(plain!("baz")), paragraph!(plain!("bim"))))))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n  - bar\n    - baz\n\n\n      bim\n", ParserSettings::Html)),
     );
@@ -21124,7 +18060,7 @@ fn t277_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>\n<p>baz</p>\n<p>bim</p>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), paragraph!(plain!("bim"))])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', loose, item!(paragraph!(plain!("baz")), paragraph!(plain!("bim"))))))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r  - bar\r    - baz\r\r\r      bim\r", ParserSettings::Html)),
     );
@@ -21140,7 +18076,7 @@ fn t277_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>\n<p>baz</p>\n<p>bim</p>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), paragraph!(plain!("bim"))])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', loose, item!(paragraph!(plain!("baz")), paragraph!(plain!("bim"))))))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n  - bar\r\n    - baz\r\n\r\n\r\n      bim\r\n", ParserSettings::Html)),
     );
@@ -21156,28 +18092,12 @@ fn t277_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>\n<p>baz</p>\n<p>bim</p>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), paragraph!(plain!("bim"))])])])
+            unordered_list!('-', item!(paragraph!(plain!("foo")), unordered_list!('-', item!(paragraph!(plain!("bar")), unordered_list!('-', loose, item!(paragraph!(plain!("baz")), paragraph!(plain!("bim"))))))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n  - bar\n    - baz\n\n\n      bim", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5050
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5050>
-/// <https://spec.commonmark.org/0.29/#example-277>
-fn t277_tab() {
-    init!("-\tfoo\n  -\tbar\n    -\tbaz\n\n\n    \t\tbim\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo\n<ul>\n<li>bar\n<ul>\n<li>\n<p>baz</p>\n<p>bim</p>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar")), unordered_list!(vec![paragraph!(plain!("baz")), paragraph!(plain!("bim"))])])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n  -\tbar\n    -\tbaz\n\n\n    \t\tbim\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5080
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5080>
@@ -21188,7 +18108,7 @@ fn t278() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<!-- -->\n<ul>\n<li>baz</li>\n<li>bim</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), html_block!("<!-- -->\n"), unordered_list!(vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("bim"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), html_block!("<!-- -->\n"), unordered_list!('-', item!(paragraph!(plain!("baz"))), item!(paragraph!(plain!("bim"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n- bar\n\n<!-- -->\n\n- baz\n- bim\n", ParserSettings::Html)),
     );
@@ -21204,7 +18124,7 @@ fn t278_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<!-- -->\n<ul>\n<li>baz</li>\n<li>bim</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), html_block!("<!-- -->\r"), unordered_list!(vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("bim"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), html_block!("<!-- -->\r"), unordered_list!('-', item!(paragraph!(plain!("baz"))), item!(paragraph!(plain!("bim"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r- bar\r\r<!-- -->\r\r- baz\r- bim\r", ParserSettings::Html)),
     );
@@ -21220,7 +18140,7 @@ fn t278_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<!-- -->\n<ul>\n<li>baz</li>\n<li>bim</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), html_block!("<!-- -->\r\n"), unordered_list!(vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("bim"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), html_block!("<!-- -->\r\n"), unordered_list!('-', item!(paragraph!(plain!("baz"))), item!(paragraph!(plain!("bim"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\r\n- bar\r\n\r\n<!-- -->\r\n\r\n- baz\r\n- bim\r\n", ParserSettings::Html)),
     );
@@ -21236,28 +18156,12 @@ fn t278_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<!-- -->\n<ul>\n<li>baz</li>\n<li>bim</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), html_block!("<!-- -->\n"), unordered_list!(vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("bim"))])
+            unordered_list!('-', item!(paragraph!(plain!("foo"))), item!(paragraph!(plain!("bar")))), html_block!("<!-- -->\n"), unordered_list!('-', item!(paragraph!(plain!("baz"))), item!(paragraph!(plain!("bim"))))
         ].as_slice(),
         body(&mut StringData::new("- foo\n- bar\n\n<!-- -->\n\n- baz\n- bim", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5080
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5080>
-/// <https://spec.commonmark.org/0.29/#example-278>
-fn t278_tab() {
-    init!("-\tfoo\n-\tbar\n\n<!--\t-->\n\n-\tbaz\n-\tbim\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>foo</li>\n<li>bar</li>\n</ul>\n<!-- -->\n<ul>\n<li>baz</li>\n<li>bim</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo"))], vec![paragraph!(plain!("bar"))]), html_block!("<!--\t-->\n"), unordered_list!(vec![paragraph!(plain!("baz"))], vec![paragraph!(plain!("bim"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\tfoo\n-\tbar\n\n<!--\t-->\n\n-\tbaz\n-\tbim\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5101
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5101>
@@ -21269,11 +18173,12 @@ fn t279() {
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>notcode</p>\n</li>\n<li>\n<p>foo</p>\n</li>\n</ul>\n<!-- -->\n<pre><code>code\n</code></pre>
         [
             unordered_list!(
-              vec![
+              '-', loose,
+              item!(
                 paragraph!(plain!("foo")),
                 paragraph!(plain!("notcode"))
-              ],
-              vec![paragraph!(plain!("foo"))]
+              ),
+              item!(paragraph!(plain!("foo")))
             ),
             html_block!("<!-- -->\n"),
             paragraph!(plain!("code"))
@@ -21293,11 +18198,12 @@ fn t279_cr() {
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>notcode</p>\n</li>\n<li>\n<p>foo</p>\n</li>\n</ul>\n<!-- -->\n<pre><code>code\n</code></pre>
         [
             unordered_list!(
-              vec![
+              '-', loose,
+              item!(
                 paragraph!(plain!("foo")),
                 paragraph!(plain!("notcode"))
-              ],
-              vec![paragraph!(plain!("foo"))]
+              ),
+              item!(paragraph!(plain!("foo")))
             ),
             html_block!("<!-- -->\r"),
             paragraph!(plain!("code"))
@@ -21317,11 +18223,12 @@ fn t279_cr_nl() {
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>notcode</p>\n</li>\n<li>\n<p>foo</p>\n</li>\n</ul>\n<!-- -->\n<pre><code>code\n</code></pre>
         [
             unordered_list!(
-              vec![
+              '-', loose,
+              item!(
                 paragraph!(plain!("foo")),
                 paragraph!(plain!("notcode"))
-              ],
-              vec![paragraph!(plain!("foo"))]
+              ),
+              item!(paragraph!(plain!("foo")))
             ),
             html_block!("<!-- -->\r\n"),
             paragraph!(plain!("code"))
@@ -21341,11 +18248,12 @@ fn t279_no_nl() {
         // from spec: <ul>\n<li>\n<p>foo</p>\n<p>notcode</p>\n</li>\n<li>\n<p>foo</p>\n</li>\n</ul>\n<!-- -->\n<pre><code>code\n</code></pre>
         [
             unordered_list!(
-              vec![
+              '-', loose,
+              item!(
                 paragraph!(plain!("foo")),
                 paragraph!(plain!("notcode"))
-              ],
-              vec![paragraph!(plain!("foo"))]
+              ),
+              item!(paragraph!(plain!("foo")))
             ),
             html_block!("<!-- -->\n"),
             paragraph!(plain!("code"))
@@ -21354,30 +18262,6 @@ fn t279_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5101
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5101>
-/// <https://spec.commonmark.org/0.29/#example-279>
-fn t279_tab() {
-    init!("-\t\t\tfoo\n\n    notcode\n\n-\t\t\tfoo\n\n<!--\t-->\n\n    code\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n<p>notcode</p>\n</li>\n<li>\n<p>foo</p>\n</li>\n</ul>\n<!-- -->\n<pre><code>code\n</code></pre>
-        [
-            unordered_list!(
-            		vec![
-            				paragraph!(plain!("foo")),
-            				paragraph!(plain!("notcode"))
-            		],
-            		vec![paragraph!(plain!("foo"))]
-            ),
-            html_block!("<!--	-->\n"),
-            paragraph!(plain!("code"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\t\t\tfoo\n\n    notcode\n\n-\t\t\tfoo\n\n<!--\t-->\n\n    code\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5132
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5132>
@@ -21388,7 +18272,7 @@ fn t280() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d</li>\n<li>e</li>\n<li>f</li>\n<li>g</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))], vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))], vec![paragraph!(plain!("g"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))), item!(paragraph!(plain!("d"))), item!(paragraph!(plain!("e"))), item!(paragraph!(plain!("f"))), item!(paragraph!(plain!("g"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n - b\n  - c\n   - d\n  - e\n - f\n- g\n", ParserSettings::Html)),
     );
@@ -21404,7 +18288,7 @@ fn t280_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d</li>\n<li>e</li>\n<li>f</li>\n<li>g</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))], vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))], vec![paragraph!(plain!("g"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))), item!(paragraph!(plain!("d"))), item!(paragraph!(plain!("e"))), item!(paragraph!(plain!("f"))), item!(paragraph!(plain!("g"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r - b\r  - c\r   - d\r  - e\r - f\r- g\r", ParserSettings::Html)),
     );
@@ -21420,7 +18304,7 @@ fn t280_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d</li>\n<li>e</li>\n<li>f</li>\n<li>g</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))], vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))], vec![paragraph!(plain!("g"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))), item!(paragraph!(plain!("d"))), item!(paragraph!(plain!("e"))), item!(paragraph!(plain!("f"))), item!(paragraph!(plain!("g"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n - b\r\n  - c\r\n   - d\r\n  - e\r\n - f\r\n- g\r\n", ParserSettings::Html)),
     );
@@ -21436,28 +18320,12 @@ fn t280_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d</li>\n<li>e</li>\n<li>f</li>\n<li>g</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))], vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))], vec![paragraph!(plain!("g"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))), item!(paragraph!(plain!("d"))), item!(paragraph!(plain!("e"))), item!(paragraph!(plain!("f"))), item!(paragraph!(plain!("g"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n - b\n  - c\n   - d\n  - e\n - f\n- g", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5132
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5132>
-/// <https://spec.commonmark.org/0.29/#example-280>
-fn t280_tab() {
-    init!("-\ta\n -\tb\n  -\tc\n   -\td\n  -\te\n -\tf\n-\tg\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d</li>\n<li>e</li>\n<li>f</li>\n<li>g</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))], vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))], vec![paragraph!(plain!("g"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n -\tb\n  -\tc\n   -\td\n  -\te\n -\tf\n-\tg\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5153
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5153>
@@ -21468,7 +18336,7 @@ fn t281() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
+            ordered_list!(loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("1. a\n\n  2. b\n\n   3. c\n", ParserSettings::Html)),
     );
@@ -21484,7 +18352,7 @@ fn t281_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
+            ordered_list!(loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("1. a\r\r  2. b\r\r   3. c\r", ParserSettings::Html)),
     );
@@ -21500,7 +18368,7 @@ fn t281_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
+            ordered_list!(loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("1. a\r\n\r\n  2. b\r\n\r\n   3. c\r\n", ParserSettings::Html)),
     );
@@ -21516,28 +18384,12 @@ fn t281_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
+            ordered_list!(loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("1. a\n\n  2. b\n\n   3. c", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5153
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5153>
-/// <https://spec.commonmark.org/0.29/#example-281>
-fn t281_tab() {
-    init!("1.\ta\n\n  2.\tb\n\n   3.\tc\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ol>
-        [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\ta\n\n  2.\tb\n\n   3.\tc\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 5177
@@ -21549,7 +18401,7 @@ fn t282() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d\n- e</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"), SoftBreak, plain!("- e"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))), item!(paragraph!(plain!("d"), soft_break!(), plain!("- e"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n - b\n  - c\n   - d\n    - e\n", ParserSettings::Html)),
     );
@@ -21566,7 +18418,7 @@ fn t282_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d\n- e</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"), SoftBreak, plain!("- e"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))), item!(paragraph!(plain!("d"), soft_break!(), plain!("- e"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r - b\r  - c\r   - d\r    - e\r", ParserSettings::Html)),
     );
@@ -21583,7 +18435,7 @@ fn t282_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d\n- e</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"), SoftBreak, plain!("- e"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))), item!(paragraph!(plain!("d"), soft_break!(), plain!("- e"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n - b\r\n  - c\r\n   - d\r\n    - e\r\n", ParserSettings::Html)),
     );
@@ -21600,29 +18452,12 @@ fn t282_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d\n- e</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"), SoftBreak, plain!("- e"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))), item!(paragraph!(plain!("d"), soft_break!(), plain!("- e"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n - b\n  - c\n   - d\n    - e", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 5177
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5177>
-/// <https://spec.commonmark.org/0.29/#example-282>
-fn t282_tab() {
-    init!("-\ta\n -\tb\n  -\tc\n   -\td\n    -\te\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d\n- e</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))], vec![paragraph!(plain!("d"), SoftBreak, plain!("-	e"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n -\tb\n  -\tc\n   -\td\n    -\te\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 #[ignore]
 /// Test case generated from Commonmark Spec line 5197
@@ -21634,7 +18469,7 @@ fn t283() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ol>\n<pre><code>3. c\n</code></pre>
         [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))]), code_block!("", "3. c\n")
+            ordered_list!(loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b")))), code_block!("", "3. c\n")
         ].as_slice(),
         body(&mut StringData::new("1. a\n\n  2. b\n\n    3. c\n", ParserSettings::Html)),
     );
@@ -21651,7 +18486,7 @@ fn t283_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ol>\n<pre><code>3. c\n</code></pre>
         [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))]), code_block!("", "3. c\r")
+            ordered_list!(loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b")))), code_block!("", "3. c\r")
         ].as_slice(),
         body(&mut StringData::new("1. a\r\r  2. b\r\r    3. c\r", ParserSettings::Html)),
     );
@@ -21668,7 +18503,7 @@ fn t283_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ol>\n<pre><code>3. c\n</code></pre>
         [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))]), code_block!("", "3. c\r\n")
+            ordered_list!(loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b")))), code_block!("", "3. c\r\n")
         ].as_slice(),
         body(&mut StringData::new("1. a\r\n\r\n  2. b\r\n\r\n    3. c\r\n", ParserSettings::Html)),
     );
@@ -21685,29 +18520,12 @@ fn t283_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ol>\n<pre><code>3. c\n</code></pre>
         [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))]), code_block!("", "3. c\n")
+            ordered_list!(loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b")))), code_block!("", "3. c\n")
         ].as_slice(),
         body(&mut StringData::new("1. a\n\n  2. b\n\n    3. c", ParserSettings::Html)),
     );
 }
 
-#[test]
-#[ignore]
-/// Test case generated from Commonmark Spec line 5197
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5197>
-/// <https://spec.commonmark.org/0.29/#example-283>
-fn t283_tab() {
-    init!("1.\ta\n\n  2.\tb\n\n    3.\tc\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ol>\n<pre><code>3. c\n</code></pre>
-        [
-            ordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))]), code_block!("", "3.\tc\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\ta\n\n  2.\tb\n\n    3.\tc\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5220
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5220>
@@ -21718,7 +18536,7 @@ fn t284() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n- b\n\n- c\n", ParserSettings::Html)),
     );
@@ -21734,7 +18552,7 @@ fn t284_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r- b\r\r- c\r", ParserSettings::Html)),
     );
@@ -21750,7 +18568,7 @@ fn t284_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n- b\r\n\r\n- c\r\n", ParserSettings::Html)),
     );
@@ -21766,28 +18584,12 @@ fn t284_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n- b\n\n- c", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5220
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5220>
-/// <https://spec.commonmark.org/0.29/#example-284>
-fn t284_tab() {
-    init!("-\ta\n-\tb\n\n-\tc\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n-\tb\n\n-\tc\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5242
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5242>
@@ -21798,7 +18600,7 @@ fn t285() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li></li>\n<li>\n<p>c</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![], vec![paragraph!(plain!("c"))])
+            unordered_list!('*', loose, item!(paragraph!(plain!("a"))), item!(), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("* a\n*\n\n* c\n", ParserSettings::Html)),
     );
@@ -21814,7 +18616,7 @@ fn t285_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li></li>\n<li>\n<p>c</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![], vec![paragraph!(plain!("c"))])
+            unordered_list!('*', loose, item!(paragraph!(plain!("a"))), item!(), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("* a\r*\r\r* c\r", ParserSettings::Html)),
     );
@@ -21830,7 +18632,7 @@ fn t285_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li></li>\n<li>\n<p>c</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![], vec![paragraph!(plain!("c"))])
+            unordered_list!('*', loose, item!(paragraph!(plain!("a"))), item!(), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("* a\r\n*\r\n\r\n* c\r\n", ParserSettings::Html)),
     );
@@ -21846,28 +18648,12 @@ fn t285_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li></li>\n<li>\n<p>c</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![], vec![paragraph!(plain!("c"))])
+            unordered_list!('*', loose, item!(paragraph!(plain!("a"))), item!(), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("* a\n*\n\n* c", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5242
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5242>
-/// <https://spec.commonmark.org/0.29/#example-285>
-fn t285_tab() {
-    init!("*\ta\n*\n\n*\tc\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li></li>\n<li>\n<p>c</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![], vec![paragraph!(plain!("c"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*\ta\n*\n\n*\tc\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5264
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5264>
@@ -21878,7 +18664,7 @@ fn t286() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b")), paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b")), paragraph!(plain!("c"))), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n- b\n\n  c\n- d\n", ParserSettings::Html)),
     );
@@ -21894,7 +18680,7 @@ fn t286_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b")), paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b")), paragraph!(plain!("c"))), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r- b\r\r  c\r- d\r", ParserSettings::Html)),
     );
@@ -21910,7 +18696,7 @@ fn t286_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b")), paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b")), paragraph!(plain!("c"))), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n- b\r\n\r\n  c\r\n- d\r\n", ParserSettings::Html)),
     );
@@ -21926,28 +18712,12 @@ fn t286_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b")), paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a"))), item!(paragraph!(plain!("b")), paragraph!(plain!("c"))), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n- b\n\n  c\n- d", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5264
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5264>
-/// <https://spec.commonmark.org/0.29/#example-286>
-fn t286_tab() {
-    init!("-\ta\n-\tb\n\n  c\n-\td\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![paragraph!(plain!("b")), paragraph!(plain!("c"))], vec![paragraph!(plain!("d"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n-\tb\n\n  c\n-\td\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5286
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5286>
@@ -21959,12 +18729,13 @@ fn t287() {
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
         [
             unordered_list!(
-              vec![paragraph!(plain!("a"))],
-              vec![
+              '-', loose,
+              item!(paragraph!(plain!("a"))),
+              item!(
                 paragraph!(plain!("b")),
                 linkdef!("ref", "/url"),
-              ],
-              vec![paragraph!(plain!("d"))]
+              ),
+              item!(paragraph!(plain!("d")))
             )
         ].as_slice(),
         body(&mut StringData::new("- a\n- b\n\n  [ref]: /url\n- d\n", ParserSettings::Html)),
@@ -21982,12 +18753,13 @@ fn t287_cr() {
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
         [
             unordered_list!(
-              vec![paragraph!(plain!("a"))],
-              vec![
+              '-', loose,
+              item!(paragraph!(plain!("a"))),
+              item!(
                 paragraph!(plain!("b")),
                 linkdef!("ref", "/url"),
-              ],
-              vec![paragraph!(plain!("d"))]
+              ),
+              item!(paragraph!(plain!("d")))
             )
         ].as_slice(),
         body(&mut StringData::new("- a\r- b\r\r  [ref]: /url\r- d\r", ParserSettings::Html)),
@@ -22005,12 +18777,13 @@ fn t287_cr_nl() {
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
         [
             unordered_list!(
-              vec![paragraph!(plain!("a"))],
-              vec![
+              '-', loose,
+              item!(paragraph!(plain!("a"))),
+              item!(
                 paragraph!(plain!("b")),
                 linkdef!("ref", "/url"),
-              ],
-              vec![paragraph!(plain!("d"))]
+              ),
+              item!(paragraph!(plain!("d")))
             )
         ].as_slice(),
         body(&mut StringData::new("- a\r\n- b\r\n\r\n  [ref]: /url\r\n- d\r\n", ParserSettings::Html)),
@@ -22028,41 +18801,19 @@ fn t287_no_nl() {
         // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
         [
             unordered_list!(
-              vec![paragraph!(plain!("a"))],
-              vec![
+              '-', loose,
+              item!(paragraph!(plain!("a"))),
+              item!(
                 paragraph!(plain!("b")),
                 linkdef!("ref", "/url"),
-              ],
-              vec![paragraph!(plain!("d"))]
+              ),
+              item!(paragraph!(plain!("d")))
             )
         ].as_slice(),
         body(&mut StringData::new("- a\n- b\n\n  [ref]: /url\n- d", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5286
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5286>
-/// <https://spec.commonmark.org/0.29/#example-287>
-fn t287_tab() {
-    init!("-\ta\n-\tb\n\n  [ref]:\t/url\n-\td\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>d</p>\n</li>\n</ul>
-        [
-            unordered_list!(
-            		vec![paragraph!(plain!("a"))],
-            		vec![
-            				paragraph!(plain!("b")),
-            				linkdef!("ref",	"/url"),
-            		],
-            		vec![paragraph!(plain!("d"))]
-            )
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n-\tb\n\n  [ref]:\t/url\n-\td\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5309
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5309>
@@ -22073,7 +18824,7 @@ fn t288() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>\n<pre><code>b\n\n\n</code></pre>\n</li>\n<li>c</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![code_block!("", "b\n\n\n")], vec![paragraph!(plain!("c"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(code_block!("", "b\n\n\n")), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n- ```\n  b\n\n\n  ```\n- c\n", ParserSettings::Html)),
     );
@@ -22089,7 +18840,7 @@ fn t288_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>\n<pre><code>b\n\n\n</code></pre>\n</li>\n<li>c</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![code_block!("", "b\r\r\r")], vec![paragraph!(plain!("c"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(code_block!("", "b\r\r\r")), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r- ```\r  b\r\r\r  ```\r- c\r", ParserSettings::Html)),
     );
@@ -22105,7 +18856,7 @@ fn t288_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>\n<pre><code>b\n\n\n</code></pre>\n</li>\n<li>c</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![code_block!("", "b\r\n\r\n\r\n")], vec![paragraph!(plain!("c"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(code_block!("", "b\r\n\r\n\r\n")), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n- ```\r\n  b\r\n\r\n\r\n  ```\r\n- c\r\n", ParserSettings::Html)),
     );
@@ -22121,28 +18872,12 @@ fn t288_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n<li>\n<pre><code>b\n\n\n</code></pre>\n</li>\n<li>c</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![code_block!("", "b\n\n\n")], vec![paragraph!(plain!("c"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))), item!(code_block!("", "b\n\n\n")), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n- ```\n  b\n\n\n  ```\n- c", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5309
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5309>
-/// <https://spec.commonmark.org/0.29/#example-288>
-fn t288_tab() {
-    init!("-\ta\n-\t```\n  b\n\n\n  ```\n-\tc\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>a</li>\n<li>\n<pre><code>b\n\n\n</code></pre>\n</li>\n<li>c</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a"))], vec![code_block!("", "b\n\n\n")], vec![paragraph!(plain!("c"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n-\t```\n  b\n\n\n  ```\n-\tc\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5335
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5335>
@@ -22153,7 +18888,7 @@ fn t289() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b")), paragraph!(plain!("c"))])], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', item!(paragraph!(plain!("a")), unordered_list!('-', loose, item!(paragraph!(plain!("b")), paragraph!(plain!("c"))))), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n  - b\n\n    c\n- d\n", ParserSettings::Html)),
     );
@@ -22169,7 +18904,7 @@ fn t289_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b")), paragraph!(plain!("c"))])], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', item!(paragraph!(plain!("a")), unordered_list!('-', loose, item!(paragraph!(plain!("b")), paragraph!(plain!("c"))))), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r  - b\r\r    c\r- d\r", ParserSettings::Html)),
     );
@@ -22185,7 +18920,7 @@ fn t289_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b")), paragraph!(plain!("c"))])], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', item!(paragraph!(plain!("a")), unordered_list!('-', loose, item!(paragraph!(plain!("b")), paragraph!(plain!("c"))))), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n  - b\r\n\r\n    c\r\n- d\r\n", ParserSettings::Html)),
     );
@@ -22201,28 +18936,12 @@ fn t289_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b")), paragraph!(plain!("c"))])], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', item!(paragraph!(plain!("a")), unordered_list!('-', loose, item!(paragraph!(plain!("b")), paragraph!(plain!("c"))))), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n  - b\n\n    c\n- d", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5335
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5335>
-/// <https://spec.commonmark.org/0.29/#example-289>
-fn t289_tab() {
-    init!("-\ta\n  -\tb\n\n    c\n-\td\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>a\n<ul>\n<li>\n<p>b</p>\n<p>c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b")), paragraph!(plain!("c"))])], vec![paragraph!(plain!("d"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n  -\tb\n\n    c\n-\td\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5359
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5359>
@@ -22233,7 +18952,7 @@ fn t290() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))], vec![paragraph!(plain!("c"))])
+            unordered_list!('*', item!(paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("* a\n  > b\n  >\n* c\n", ParserSettings::Html)),
     );
@@ -22249,7 +18968,7 @@ fn t290_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))], vec![paragraph!(plain!("c"))])
+            unordered_list!('*', item!(paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("* a\r  > b\r  >\r* c\r", ParserSettings::Html)),
     );
@@ -22265,7 +18984,7 @@ fn t290_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))], vec![paragraph!(plain!("c"))])
+            unordered_list!('*', item!(paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("* a\r\n  > b\r\n  >\r\n* c\r\n", ParserSettings::Html)),
     );
@@ -22281,28 +19000,12 @@ fn t290_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))], vec![paragraph!(plain!("c"))])
+            unordered_list!('*', item!(paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))), item!(paragraph!(plain!("c"))))
         ].as_slice(),
         body(&mut StringData::new("* a\n  > b\n  >\n* c", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5359
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5359>
-/// <https://spec.commonmark.org/0.29/#example-290>
-fn t290_tab() {
-    init!("*\ta\n  >\tb\n  >\n*\tc\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b")))], vec![paragraph!(plain!("c"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*\ta\n  >\tb\n  >\n*\tc\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5379
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5379>
@@ -22313,7 +19016,7 @@ fn t291() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n<pre><code>c\n</code></pre>\n</li>\n<li>d</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\n")], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', item!(paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\n")), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n  > b\n  ```\n  c\n  ```\n- d\n", ParserSettings::Html)),
     );
@@ -22329,7 +19032,7 @@ fn t291_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n<pre><code>c\n</code></pre>\n</li>\n<li>d</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\r")], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', item!(paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\r")), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r  > b\r  ```\r  c\r  ```\r- d\r", ParserSettings::Html)),
     );
@@ -22345,7 +19048,7 @@ fn t291_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n<pre><code>c\n</code></pre>\n</li>\n<li>d</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\r\n")], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', item!(paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\r\n")), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n  > b\r\n  ```\r\n  c\r\n  ```\r\n- d\r\n", ParserSettings::Html)),
     );
@@ -22361,28 +19064,12 @@ fn t291_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n<pre><code>c\n</code></pre>\n</li>\n<li>d</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\n")], vec![paragraph!(plain!("d"))])
+            unordered_list!('-', item!(paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\n")), item!(paragraph!(plain!("d"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n  > b\n  ```\n  c\n  ```\n- d", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5379
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5379>
-/// <https://spec.commonmark.org/0.29/#example-291>
-fn t291_tab() {
-    init!("-\ta\n  >\tb\n  ```\n  c\n  ```\n-\td\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n<pre><code>c\n</code></pre>\n</li>\n<li>d</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a")), quote!(paragraph!(plain!("b"))), code_block!("", "c\n")], vec![paragraph!(plain!("d"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n  >\tb\n  ```\n  c\n  ```\n-\td\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5402
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5402>
@@ -22393,7 +19080,7 @@ fn t292() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))))
         ].as_slice(),
         body(&mut StringData::new("- a\n", ParserSettings::Html)),
     );
@@ -22409,7 +19096,7 @@ fn t292_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r", ParserSettings::Html)),
     );
@@ -22425,7 +19112,7 @@ fn t292_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n", ParserSettings::Html)),
     );
@@ -22441,28 +19128,12 @@ fn t292_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a"))])
+            unordered_list!('-', item!(paragraph!(plain!("a"))))
         ].as_slice(),
         body(&mut StringData::new("- a", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5402
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5402>
-/// <https://spec.commonmark.org/0.29/#example-292>
-fn t292_tab() {
-    init!("-\ta\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>a</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5411
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5411>
@@ -22473,7 +19144,7 @@ fn t293() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))])])
+            unordered_list!('-', item!(paragraph!(plain!("a")), unordered_list!('-', item!(paragraph!(plain!("b"))))))
         ].as_slice(),
         body(&mut StringData::new("- a\n  - b\n", ParserSettings::Html)),
     );
@@ -22489,7 +19160,7 @@ fn t293_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))])])
+            unordered_list!('-', item!(paragraph!(plain!("a")), unordered_list!('-', item!(paragraph!(plain!("b"))))))
         ].as_slice(),
         body(&mut StringData::new("- a\r  - b\r", ParserSettings::Html)),
     );
@@ -22505,7 +19176,7 @@ fn t293_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))])])
+            unordered_list!('-', item!(paragraph!(plain!("a")), unordered_list!('-', item!(paragraph!(plain!("b"))))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n  - b\r\n", ParserSettings::Html)),
     );
@@ -22521,28 +19192,12 @@ fn t293_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))])])
+            unordered_list!('-', item!(paragraph!(plain!("a")), unordered_list!('-', item!(paragraph!(plain!("b"))))))
         ].as_slice(),
         body(&mut StringData::new("- a\n  - b", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5411
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5411>
-/// <https://spec.commonmark.org/0.29/#example-293>
-fn t293_tab() {
-    init!("-\ta\n  -\tb\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n  -\tb\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5428
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5428>
@@ -22553,7 +19208,7 @@ fn t294() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<pre><code>foo\n</code></pre>\n<p>bar</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![code_block!("", "foo\n"), paragraph!(plain!("bar"))])
+            ordered_list!(loose, item!(code_block!("", "foo\n"), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("1. ```\n   foo\n   ```\n\n   bar\n", ParserSettings::Html)),
     );
@@ -22569,7 +19224,7 @@ fn t294_cr() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<pre><code>foo\n</code></pre>\n<p>bar</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![code_block!("", "foo\r"), paragraph!(plain!("bar"))])
+            ordered_list!(loose, item!(code_block!("", "foo\r"), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("1. ```\r   foo\r   ```\r\r   bar\r", ParserSettings::Html)),
     );
@@ -22585,7 +19240,7 @@ fn t294_cr_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<pre><code>foo\n</code></pre>\n<p>bar</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![code_block!("", "foo\r\n"), paragraph!(plain!("bar"))])
+            ordered_list!(loose, item!(code_block!("", "foo\r\n"), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("1. ```\r\n   foo\r\n   ```\r\n\r\n   bar\r\n", ParserSettings::Html)),
     );
@@ -22601,28 +19256,12 @@ fn t294_no_nl() {
     assert_eq!(
         // from spec: <ol>\n<li>\n<pre><code>foo\n</code></pre>\n<p>bar</p>\n</li>\n</ol>
         [
-            ordered_list!(vec![code_block!("", "foo\n"), paragraph!(plain!("bar"))])
+            ordered_list!(loose, item!(code_block!("", "foo\n"), paragraph!(plain!("bar"))))
         ].as_slice(),
         body(&mut StringData::new("1. ```\n   foo\n   ```\n\n   bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5428
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5428>
-/// <https://spec.commonmark.org/0.29/#example-294>
-fn t294_tab() {
-    init!("1.\t```\n   foo\n   ```\n\n   bar\n");
-
-    assert_eq!(
-        // from spec: <ol>\n<li>\n<pre><code>foo\n</code></pre>\n<p>bar</p>\n</li>\n</ol>
-        [
-            ordered_list!(vec![code_block!("", "foo\n"), paragraph!(plain!("bar"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("1.\t```\n   foo\n   ```\n\n   bar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5447
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5447>
@@ -22633,7 +19272,7 @@ fn t295() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))]), paragraph!(plain!("baz"))])
+            unordered_list!('*', loose, item!(paragraph!(plain!("foo")), unordered_list!('*', item!(paragraph!(plain!("bar")))), paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("* foo\n  * bar\n\n  baz\n", ParserSettings::Html)),
     );
@@ -22649,7 +19288,7 @@ fn t295_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))]), paragraph!(plain!("baz"))])
+            unordered_list!('*', loose, item!(paragraph!(plain!("foo")), unordered_list!('*', item!(paragraph!(plain!("bar")))), paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("* foo\r  * bar\r\r  baz\r", ParserSettings::Html)),
     );
@@ -22665,7 +19304,7 @@ fn t295_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))]), paragraph!(plain!("baz"))])
+            unordered_list!('*', loose, item!(paragraph!(plain!("foo")), unordered_list!('*', item!(paragraph!(plain!("bar")))), paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("* foo\r\n  * bar\r\n\r\n  baz\r\n", ParserSettings::Html)),
     );
@@ -22681,28 +19320,12 @@ fn t295_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n<p>baz</p>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))]), paragraph!(plain!("baz"))])
+            unordered_list!('*', loose, item!(paragraph!(plain!("foo")), unordered_list!('*', item!(paragraph!(plain!("bar")))), paragraph!(plain!("baz"))))
         ].as_slice(),
         body(&mut StringData::new("* foo\n  * bar\n\n  baz", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5447
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5447>
-/// <https://spec.commonmark.org/0.29/#example-295>
-fn t295_tab() {
-    init!("*\tfoo\n  *\tbar\n\n  baz\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>foo</p>\n<ul>\n<li>bar</li>\n</ul>\n<p>baz</p>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("foo")), unordered_list!(vec![paragraph!(plain!("bar"))]), paragraph!(plain!("baz"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*\tfoo\n  *\tbar\n\n  baz\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5465
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5465>
@@ -22713,7 +19336,7 @@ fn t296() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>\n<p>d</p>\n<ul>\n<li>e</li>\n<li>f</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])], vec![paragraph!(plain!("d")), unordered_list!(vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))])])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a")), unordered_list!('-', item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))), item!(paragraph!(plain!("d")), unordered_list!('-', item!(paragraph!(plain!("e"))), item!(paragraph!(plain!("f"))))))
         ].as_slice(),
         body(&mut StringData::new("- a\n  - b\n  - c\n\n- d\n  - e\n  - f\n", ParserSettings::Html)),
     );
@@ -22729,7 +19352,7 @@ fn t296_cr() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>\n<p>d</p>\n<ul>\n<li>e</li>\n<li>f</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])], vec![paragraph!(plain!("d")), unordered_list!(vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))])])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a")), unordered_list!('-', item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))), item!(paragraph!(plain!("d")), unordered_list!('-', item!(paragraph!(plain!("e"))), item!(paragraph!(plain!("f"))))))
         ].as_slice(),
         body(&mut StringData::new("- a\r  - b\r  - c\r\r- d\r  - e\r  - f\r", ParserSettings::Html)),
     );
@@ -22745,7 +19368,7 @@ fn t296_cr_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>\n<p>d</p>\n<ul>\n<li>e</li>\n<li>f</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])], vec![paragraph!(plain!("d")), unordered_list!(vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))])])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a")), unordered_list!('-', item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))), item!(paragraph!(plain!("d")), unordered_list!('-', item!(paragraph!(plain!("e"))), item!(paragraph!(plain!("f"))))))
         ].as_slice(),
         body(&mut StringData::new("- a\r\n  - b\r\n  - c\r\n\r\n- d\r\n  - e\r\n  - f\r\n", ParserSettings::Html)),
     );
@@ -22761,28 +19384,12 @@ fn t296_no_nl() {
     assert_eq!(
         // from spec: <ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>\n<p>d</p>\n<ul>\n<li>e</li>\n<li>f</li>\n</ul>\n</li>\n</ul>
         [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])], vec![paragraph!(plain!("d")), unordered_list!(vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))])])
+            unordered_list!('-', loose, item!(paragraph!(plain!("a")), unordered_list!('-', item!(paragraph!(plain!("b"))), item!(paragraph!(plain!("c"))))), item!(paragraph!(plain!("d")), unordered_list!('-', item!(paragraph!(plain!("e"))), item!(paragraph!(plain!("f"))))))
         ].as_slice(),
         body(&mut StringData::new("- a\n  - b\n  - c\n\n- d\n  - e\n  - f", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5465
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5465>
-/// <https://spec.commonmark.org/0.29/#example-296>
-fn t296_tab() {
-    init!("-\ta\n  -\tb\n  -\tc\n\n-\td\n  -\te\n  -\tf\n");
-
-    assert_eq!(
-        // from spec: <ul>\n<li>\n<p>a</p>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>\n<p>d</p>\n<ul>\n<li>e</li>\n<li>f</li>\n</ul>\n</li>\n</ul>
-        [
-            unordered_list!(vec![paragraph!(plain!("a")), unordered_list!(vec![paragraph!(plain!("b"))], vec![paragraph!(plain!("c"))])], vec![paragraph!(plain!("d")), unordered_list!(vec![paragraph!(plain!("e"))], vec![paragraph!(plain!("f"))])])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("-\ta\n  -\tb\n  -\tc\n\n-\td\n  -\te\n  -\tf\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5499
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5499>
@@ -22975,22 +19582,6 @@ fn t299_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5523
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5523>
-/// <https://spec.commonmark.org/0.29/#example-299>
-fn t299_tab() {
-    init!("\\→\\A\\a\\\t\\3\\φ\\«\n");
-
-    assert_eq!(
-        // from spec: <p>\\→\\A\\a\\ \\3\\φ\\«</p>
-        [
-            paragraph!(plain!("\\\t\\A\\a\\	\\3\\φ\\«"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("\\\t\\A\\a\\\t\\3\\\u{3c6}\\\u{ab}\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5533
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5533>
@@ -23001,7 +19592,7 @@ fn t300() {
     assert_eq!(
         // from spec: <p>*not emphasized*\n&lt;br/&gt; not a tag\n[not a link](/foo)\n`not code`\n1. not a list\n* not a list\n# not a heading\n[foo]: /url &quot;not a reference&quot;\n&amp;ouml; not a character entity</p>
         [
-            paragraph!(plain!("*not emphasized*"), SoftBreak, plain!("<br/> not a tag"), SoftBreak, plain!("[not a link](/foo)"), SoftBreak, plain!("`not code`"), SoftBreak, plain!("1. not a list"), SoftBreak, plain!("* not a list"), SoftBreak, plain!("# not a heading"), SoftBreak, plain!("[foo]: /url \"not a reference\""), SoftBreak, plain!("&ouml; not a character entity"))
+            paragraph!(plain!("*not emphasized*"), soft_break!(), plain!("<br/> not a tag"), soft_break!(), plain!("[not a link](/foo)"), soft_break!(), plain!("`not code`"), soft_break!(), plain!("1. not a list"), soft_break!(), plain!("* not a list"), soft_break!(), plain!("# not a heading"), soft_break!(), plain!("[foo]: /url \"not a reference\""), soft_break!(), plain!("&ouml; not a character entity"))
         ].as_slice(),
         body(&mut StringData::new("\\*not emphasized*\n\\<br/> not a tag\n\\[not a link](/foo)\n\\`not code`\n1\\. not a list\n\\* not a list\n\\# not a heading\n\\[foo]: /url \"not a reference\"\n\\&ouml; not a character entity\n", ParserSettings::Html)),
     );
@@ -23017,7 +19608,7 @@ fn t300_cr() {
     assert_eq!(
         // from spec: <p>*not emphasized*\n&lt;br/&gt; not a tag\n[not a link](/foo)\n`not code`\n1. not a list\n* not a list\n# not a heading\n[foo]: /url &quot;not a reference&quot;\n&amp;ouml; not a character entity</p>
         [
-            paragraph!(plain!("*not emphasized*"), SoftBreak, plain!("<br/> not a tag"), SoftBreak, plain!("[not a link](/foo)"), SoftBreak, plain!("`not code`"), SoftBreak, plain!("1. not a list"), SoftBreak, plain!("* not a list"), SoftBreak, plain!("# not a heading"), SoftBreak, plain!("[foo]: /url \"not a reference\""), SoftBreak, plain!("&ouml; not a character entity"))
+            paragraph!(plain!("*not emphasized*"), soft_break!(), plain!("<br/> not a tag"), soft_break!(), plain!("[not a link](/foo)"), soft_break!(), plain!("`not code`"), soft_break!(), plain!("1. not a list"), soft_break!(), plain!("* not a list"), soft_break!(), plain!("# not a heading"), soft_break!(), plain!("[foo]: /url \"not a reference\""), soft_break!(), plain!("&ouml; not a character entity"))
         ].as_slice(),
         body(&mut StringData::new("\\*not emphasized*\r\\<br/> not a tag\r\\[not a link](/foo)\r\\`not code`\r1\\. not a list\r\\* not a list\r\\# not a heading\r\\[foo]: /url \"not a reference\"\r\\&ouml; not a character entity\r", ParserSettings::Html)),
     );
@@ -23033,7 +19624,7 @@ fn t300_cr_nl() {
     assert_eq!(
         // from spec: <p>*not emphasized*\n&lt;br/&gt; not a tag\n[not a link](/foo)\n`not code`\n1. not a list\n* not a list\n# not a heading\n[foo]: /url &quot;not a reference&quot;\n&amp;ouml; not a character entity</p>
         [
-            paragraph!(plain!("*not emphasized*"), SoftBreak, plain!("<br/> not a tag"), SoftBreak, plain!("[not a link](/foo)"), SoftBreak, plain!("`not code`"), SoftBreak, plain!("1. not a list"), SoftBreak, plain!("* not a list"), SoftBreak, plain!("# not a heading"), SoftBreak, plain!("[foo]: /url \"not a reference\""), SoftBreak, plain!("&ouml; not a character entity"))
+            paragraph!(plain!("*not emphasized*"), soft_break!(), plain!("<br/> not a tag"), soft_break!(), plain!("[not a link](/foo)"), soft_break!(), plain!("`not code`"), soft_break!(), plain!("1. not a list"), soft_break!(), plain!("* not a list"), soft_break!(), plain!("# not a heading"), soft_break!(), plain!("[foo]: /url \"not a reference\""), soft_break!(), plain!("&ouml; not a character entity"))
         ].as_slice(),
         body(&mut StringData::new("\\*not emphasized*\r\n\\<br/> not a tag\r\n\\[not a link](/foo)\r\n\\`not code`\r\n1\\. not a list\r\n\\* not a list\r\n\\# not a heading\r\n\\[foo]: /url \"not a reference\"\r\n\\&ouml; not a character entity\r\n", ParserSettings::Html)),
     );
@@ -23049,28 +19640,12 @@ fn t300_no_nl() {
     assert_eq!(
         // from spec: <p>*not emphasized*\n&lt;br/&gt; not a tag\n[not a link](/foo)\n`not code`\n1. not a list\n* not a list\n# not a heading\n[foo]: /url &quot;not a reference&quot;\n&amp;ouml; not a character entity</p>
         [
-            paragraph!(plain!("*not emphasized*"), SoftBreak, plain!("<br/> not a tag"), SoftBreak, plain!("[not a link](/foo)"), SoftBreak, plain!("`not code`"), SoftBreak, plain!("1. not a list"), SoftBreak, plain!("* not a list"), SoftBreak, plain!("# not a heading"), SoftBreak, plain!("[foo]: /url \"not a reference\""), SoftBreak, plain!("&ouml; not a character entity"))
+            paragraph!(plain!("*not emphasized*"), soft_break!(), plain!("<br/> not a tag"), soft_break!(), plain!("[not a link](/foo)"), soft_break!(), plain!("`not code`"), soft_break!(), plain!("1. not a list"), soft_break!(), plain!("* not a list"), soft_break!(), plain!("# not a heading"), soft_break!(), plain!("[foo]: /url \"not a reference\""), soft_break!(), plain!("&ouml; not a character entity"))
         ].as_slice(),
         body(&mut StringData::new("\\*not emphasized*\n\\<br/> not a tag\n\\[not a link](/foo)\n\\`not code`\n1\\. not a list\n\\* not a list\n\\# not a heading\n\\[foo]: /url \"not a reference\"\n\\&ouml; not a character entity", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5533
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5533>
-/// <https://spec.commonmark.org/0.29/#example-300>
-fn t300_tab() {
-    init!("\\*not\temphasized*\n\\<br/>\tnot\ta\ttag\n\\[not\ta\tlink](/foo)\n\\`not\tcode`\n1\\.\tnot\ta\tlist\n\\*\tnot\ta\tlist\n\\#\tnot\ta\theading\n\\[foo]:\t/url\t\"not\ta\treference\"\n\\&ouml;\tnot\ta\tcharacter\tentity\n");
-
-    assert_eq!(
-        // from spec: <p>*not emphasized*\n&lt;br/&gt; not a tag\n[not a link](/foo)\n`not code`\n1. not a list\n* not a list\n# not a heading\n[foo]: /url &quot;not a reference&quot;\n&amp;ouml; not a character entity</p>
-        [
-            paragraph!(plain!("*not	emphasized*"), SoftBreak, plain!("<br/>	not	a	tag"), SoftBreak, plain!("[not	a	link](/foo)"), SoftBreak, plain!("`not	code`"), SoftBreak, plain!("1.	not	a	list"), SoftBreak, plain!("*	not	a	list"), SoftBreak, plain!("#	not	a	heading"), SoftBreak, plain!("[foo]:	/url	\"not	a	reference\""), SoftBreak, plain!("&ouml;	not	a	character	entity"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("\\*not\temphasized*\n\\<br/>\tnot\ta\ttag\n\\[not\ta\tlink](/foo)\n\\`not\tcode`\n1\\.\tnot\ta\tlist\n\\*\tnot\ta\tlist\n\\#\tnot\ta\theading\n\\[foo]:\t/url\t\"not\ta\treference\"\n\\&ouml;\tnot\ta\tcharacter\tentity\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5558
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5558>
@@ -23145,7 +19720,7 @@ fn t302() {
     assert_eq!(
         // from spec: <p>foo<br />\nbar</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\nbar\n", ParserSettings::Html)),
     );
@@ -23161,7 +19736,7 @@ fn t302_cr() {
     assert_eq!(
         // from spec: <p>foo<br />\nbar</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\rbar\r", ParserSettings::Html)),
     );
@@ -23177,7 +19752,7 @@ fn t302_cr_nl() {
     assert_eq!(
         // from spec: <p>foo<br />\nbar</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\r\nbar\r\n", ParserSettings::Html)),
     );
@@ -23193,7 +19768,7 @@ fn t302_no_nl() {
     assert_eq!(
         // from spec: <p>foo<br />\nbar</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\nbar", ParserSettings::Html)),
     );
@@ -23519,22 +20094,6 @@ fn t307_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5611
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5611>
-/// <https://spec.commonmark.org/0.29/#example-307>
-fn t307_tab() {
-    init!("<a\thref=\"/bar\\/)\">\n");
-
-    assert_eq!(
-        // from spec: <a href=\"/bar\\/)\">
-        [
-            html_block!("<a	href=\"/bar\\/)\">\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\thref=\"/bar\\/)\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5621
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5621>
@@ -23599,22 +20158,6 @@ fn t308_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5621
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5621>
-/// <https://spec.commonmark.org/0.29/#example-308>
-fn t308_tab() {
-    init!("[foo](/bar\\*\t\"ti\\*tle\")\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/bar*\" title=\"ti*tle\">foo</a></p>
-        [
-            paragraph!(link!("/bar*", "ti*tle", plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo](/bar\\*\t\"ti\\*tle\")\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5628
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5628>
@@ -23625,7 +20168,7 @@ fn t309() {
     assert_eq!(
         // from spec: <p><a href=\"/bar*\" title=\"ti*tle\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/bar*", "ti*tle")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n[foo]: /bar\\* \"ti\\*tle\"\n", ParserSettings::Html)),
@@ -23642,7 +20185,7 @@ fn t309_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/bar*\" title=\"ti*tle\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/bar*", "ti*tle")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\r[foo]: /bar\\* \"ti\\*tle\"\r", ParserSettings::Html)),
@@ -23659,7 +20202,7 @@ fn t309_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/bar*\" title=\"ti*tle\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/bar*", "ti*tle")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\n\r\n[foo]: /bar\\* \"ti\\*tle\"\r\n", ParserSettings::Html)),
@@ -23676,30 +20219,13 @@ fn t309_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/bar*\" title=\"ti*tle\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/bar*", "ti*tle")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n[foo]: /bar\\* \"ti\\*tle\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5628
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5628>
-/// <https://spec.commonmark.org/0.29/#example-309>
-fn t309_tab() {
-    init!("[foo]\n\n[foo]:\t/bar\\*\t\"ti\\*tle\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/bar*\" title=\"ti*tle\">foo</a></p>
-        [
-            paragraph!(linkref!(plain!("foo"))),
-            linkdef!("foo",	"/bar*",	"ti*tle")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\n\n[foo]:\t/bar\\*\t\"ti\\*tle\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5637
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5637>
@@ -23764,22 +20290,6 @@ fn t310_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5637
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5637>
-/// <https://spec.commonmark.org/0.29/#example-310>
-fn t310_tab() {
-    init!("```\tfoo\\+bar\nfoo\n```\n");
-
-    assert_eq!(
-        // from spec: <pre><code class=\"language-foo+bar\">foo\n</code></pre>
-        [
-            code_block!("foo+bar", "foo\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("```\tfoo\\+bar\nfoo\n```\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5674
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5674>
@@ -23790,7 +20300,7 @@ fn t311() {
     assert_eq!(
         // from spec: <p>\u{a0} &amp; © Æ Ď\n¾ ℋ ⅆ\n∲ ≧̸</p>
         [
-            paragraph!(plain!("\u{a0} & © Æ Ď"), SoftBreak, plain!("¾ ℋ ⅆ"), SoftBreak, plain!("∲ ≧̸"))
+            paragraph!(plain!("\u{a0} & © Æ Ď"), soft_break!(), plain!("¾ ℋ ⅆ"), soft_break!(), plain!("∲ ≧̸"))
         ].as_slice(),
         body(&mut StringData::new("&nbsp; &amp; &copy; &AElig; &Dcaron;\n&frac34; &HilbertSpace; &DifferentialD;\n&ClockwiseContourIntegral; &ngE;\n", ParserSettings::Html)),
     );
@@ -23806,7 +20316,7 @@ fn t311_cr() {
     assert_eq!(
         // from spec: <p>\u{a0} &amp; © Æ Ď\n¾ ℋ ⅆ\n∲ ≧̸</p>
         [
-            paragraph!(plain!("\u{a0} & © Æ Ď"), SoftBreak, plain!("¾ ℋ ⅆ"), SoftBreak, plain!("∲ ≧̸"))
+            paragraph!(plain!("\u{a0} & © Æ Ď"), soft_break!(), plain!("¾ ℋ ⅆ"), soft_break!(), plain!("∲ ≧̸"))
         ].as_slice(),
         body(&mut StringData::new("&nbsp; &amp; &copy; &AElig; &Dcaron;\r&frac34; &HilbertSpace; &DifferentialD;\r&ClockwiseContourIntegral; &ngE;\r", ParserSettings::Html)),
     );
@@ -23822,7 +20332,7 @@ fn t311_cr_nl() {
     assert_eq!(
         // from spec: <p>\u{a0} &amp; © Æ Ď\n¾ ℋ ⅆ\n∲ ≧̸</p>
         [
-            paragraph!(plain!("\u{a0} & © Æ Ď"), SoftBreak, plain!("¾ ℋ ⅆ"), SoftBreak, plain!("∲ ≧̸"))
+            paragraph!(plain!("\u{a0} & © Æ Ď"), soft_break!(), plain!("¾ ℋ ⅆ"), soft_break!(), plain!("∲ ≧̸"))
         ].as_slice(),
         body(&mut StringData::new("&nbsp; &amp; &copy; &AElig; &Dcaron;\r\n&frac34; &HilbertSpace; &DifferentialD;\r\n&ClockwiseContourIntegral; &ngE;\r\n", ParserSettings::Html)),
     );
@@ -23838,28 +20348,12 @@ fn t311_no_nl() {
     assert_eq!(
         // from spec: <p>\u{a0} &amp; © Æ Ď\n¾ ℋ ⅆ\n∲ ≧̸</p>
         [
-            paragraph!(plain!("\u{a0} & © Æ Ď"), SoftBreak, plain!("¾ ℋ ⅆ"), SoftBreak, plain!("∲ ≧̸"))
+            paragraph!(plain!("\u{a0} & © Æ Ď"), soft_break!(), plain!("¾ ℋ ⅆ"), soft_break!(), plain!("∲ ≧̸"))
         ].as_slice(),
         body(&mut StringData::new("&nbsp; &amp; &copy; &AElig; &Dcaron;\n&frac34; &HilbertSpace; &DifferentialD;\n&ClockwiseContourIntegral; &ngE;", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5674
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5674>
-/// <https://spec.commonmark.org/0.29/#example-311>
-fn t311_tab() {
-    init!("&nbsp;\t&amp;\t&copy;\t&AElig;\t&Dcaron;\n&frac34;\t&HilbertSpace;\t&DifferentialD;\n&ClockwiseContourIntegral;\t&ngE;\n");
-
-    assert_eq!(
-        // from spec: <p>\u{a0} &amp; © Æ Ď\n¾ ℋ ⅆ\n∲ ≧̸</p>
-        [
-            paragraph!(plain!("\u{a0}	&	©	Æ	Ď"), SoftBreak, plain!("¾	ℋ	ⅆ"), SoftBreak, plain!("∲	≧̸"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("&nbsp;\t&amp;\t&copy;\t&AElig;\t&Dcaron;\n&frac34;\t&HilbertSpace;\t&DifferentialD;\n&ClockwiseContourIntegral;\t&ngE;\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5693
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5693>
@@ -23924,22 +20418,6 @@ fn t312_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5693
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5693>
-/// <https://spec.commonmark.org/0.29/#example-312>
-fn t312_tab() {
-    init!("&#35;\t&#1234;\t&#992;\t&#0;\n");
-
-    assert_eq!(
-        // from spec: <p># Ӓ Ϡ �</p>
-        [
-            paragraph!(plain!("#	Ӓ	Ϡ	�"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("&#35;\t&#1234;\t&#992;\t&#0;\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5706
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5706>
@@ -24004,22 +20482,6 @@ fn t313_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5706
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5706>
-/// <https://spec.commonmark.org/0.29/#example-313>
-fn t313_tab() {
-    init!("&#X22;\t&#XD06;\t&#xcab;\n");
-
-    assert_eq!(
-        // from spec: <p>&quot; ആ ಫ</p>
-        [
-            paragraph!(plain!("\"	ആ	ಫ"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("&#X22;\t&#XD06;\t&#xcab;\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5715
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5715>
@@ -24030,7 +20492,7 @@ fn t314() {
     assert_eq!(
         // from spec: <p>&amp;nbsp &amp;x; &amp;#; &amp;#x;\n&amp;#87654321;\n&amp;#abcdef0;\n&amp;ThisIsNotDefined; &amp;hi?;</p>
         [
-            paragraph!(plain!("&nbsp &x; &#; &#x;"), SoftBreak, plain!("&#87654321;"), SoftBreak, plain!("&#abcdef0;"), SoftBreak, plain!("&ThisIsNotDefined; &hi?;"))
+            paragraph!(plain!("&nbsp &x; &#; &#x;"), soft_break!(), plain!("&#87654321;"), soft_break!(), plain!("&#abcdef0;"), soft_break!(), plain!("&ThisIsNotDefined; &hi?;"))
         ].as_slice(),
         body(&mut StringData::new("&nbsp &x; &#; &#x;\n&#87654321;\n&#abcdef0;\n&ThisIsNotDefined; &hi?;\n", ParserSettings::Html)),
     );
@@ -24046,7 +20508,7 @@ fn t314_cr() {
     assert_eq!(
         // from spec: <p>&amp;nbsp &amp;x; &amp;#; &amp;#x;\n&amp;#87654321;\n&amp;#abcdef0;\n&amp;ThisIsNotDefined; &amp;hi?;</p>
         [
-            paragraph!(plain!("&nbsp &x; &#; &#x;"), SoftBreak, plain!("&#87654321;"), SoftBreak, plain!("&#abcdef0;"), SoftBreak, plain!("&ThisIsNotDefined; &hi?;"))
+            paragraph!(plain!("&nbsp &x; &#; &#x;"), soft_break!(), plain!("&#87654321;"), soft_break!(), plain!("&#abcdef0;"), soft_break!(), plain!("&ThisIsNotDefined; &hi?;"))
         ].as_slice(),
         body(&mut StringData::new("&nbsp &x; &#; &#x;\r&#87654321;\r&#abcdef0;\r&ThisIsNotDefined; &hi?;\r", ParserSettings::Html)),
     );
@@ -24062,7 +20524,7 @@ fn t314_cr_nl() {
     assert_eq!(
         // from spec: <p>&amp;nbsp &amp;x; &amp;#; &amp;#x;\n&amp;#87654321;\n&amp;#abcdef0;\n&amp;ThisIsNotDefined; &amp;hi?;</p>
         [
-            paragraph!(plain!("&nbsp &x; &#; &#x;"), SoftBreak, plain!("&#87654321;"), SoftBreak, plain!("&#abcdef0;"), SoftBreak, plain!("&ThisIsNotDefined; &hi?;"))
+            paragraph!(plain!("&nbsp &x; &#; &#x;"), soft_break!(), plain!("&#87654321;"), soft_break!(), plain!("&#abcdef0;"), soft_break!(), plain!("&ThisIsNotDefined; &hi?;"))
         ].as_slice(),
         body(&mut StringData::new("&nbsp &x; &#; &#x;\r\n&#87654321;\r\n&#abcdef0;\r\n&ThisIsNotDefined; &hi?;\r\n", ParserSettings::Html)),
     );
@@ -24078,28 +20540,12 @@ fn t314_no_nl() {
     assert_eq!(
         // from spec: <p>&amp;nbsp &amp;x; &amp;#; &amp;#x;\n&amp;#87654321;\n&amp;#abcdef0;\n&amp;ThisIsNotDefined; &amp;hi?;</p>
         [
-            paragraph!(plain!("&nbsp &x; &#; &#x;"), SoftBreak, plain!("&#87654321;"), SoftBreak, plain!("&#abcdef0;"), SoftBreak, plain!("&ThisIsNotDefined; &hi?;"))
+            paragraph!(plain!("&nbsp &x; &#; &#x;"), soft_break!(), plain!("&#87654321;"), soft_break!(), plain!("&#abcdef0;"), soft_break!(), plain!("&ThisIsNotDefined; &hi?;"))
         ].as_slice(),
         body(&mut StringData::new("&nbsp &x; &#; &#x;\n&#87654321;\n&#abcdef0;\n&ThisIsNotDefined; &hi?;", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5715
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5715>
-/// <https://spec.commonmark.org/0.29/#example-314>
-fn t314_tab() {
-    init!("&nbsp\t&x;\t&#;\t&#x;\n&#87654321;\n&#abcdef0;\n&ThisIsNotDefined;\t&hi?;\n");
-
-    assert_eq!(
-        // from spec: <p>&amp;nbsp &amp;x; &amp;#; &amp;#x;\n&amp;#87654321;\n&amp;#abcdef0;\n&amp;ThisIsNotDefined; &amp;hi?;</p>
-        [
-            paragraph!(plain!("&nbsp	&x;	&#;	&#x;"), SoftBreak, plain!("&#87654321;"), SoftBreak, plain!("&#abcdef0;"), SoftBreak, plain!("&ThisIsNotDefined;	&hi?;"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("&nbsp\t&x;\t&#;\t&#x;\n&#87654321;\n&#abcdef0;\n&ThisIsNotDefined;\t&hi?;\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5732
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5732>
@@ -24292,22 +20738,6 @@ fn t317_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5753
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5753>
-/// <https://spec.commonmark.org/0.29/#example-317>
-fn t317_tab() {
-    init!("<a\thref=\"&ouml;&ouml;.html\">\n");
-
-    assert_eq!(
-        // from spec: <a href=\"&ouml;&ouml;.html\">
-        [
-            html_block!("<a	href=\"&ouml;&ouml;.html\">\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\thref=\"&ouml;&ouml;.html\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5760
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5760>
@@ -24372,22 +20802,6 @@ fn t318_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5760
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5760>
-/// <https://spec.commonmark.org/0.29/#example-318>
-fn t318_tab() {
-    init!("[foo](/f&ouml;&ouml;\t\"f&ouml;&ouml;\")\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/f%C3%B6%C3%B6\" title=\"föö\">foo</a></p>
-        [
-            paragraph!(link!("/f\u{f6}\u{f6}", "f\u{f6}\u{f6}", plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo](/f&ouml;&ouml;\t\"f&ouml;&ouml;\")\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5767
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5767>
@@ -24398,7 +20812,7 @@ fn t319() {
     assert_eq!(
         // from spec: <p><a href=\"/f%C3%B6%C3%B6\" title=\"föö\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/f\u{f6}\u{f6}", "f\u{f6}\u{f6}")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n[foo]: /f&ouml;&ouml; \"f&ouml;&ouml;\"\n", ParserSettings::Html)),
@@ -24415,7 +20829,7 @@ fn t319_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/f%C3%B6%C3%B6\" title=\"föö\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/f\u{f6}\u{f6}", "f\u{f6}\u{f6}")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\r[foo]: /f&ouml;&ouml; \"f&ouml;&ouml;\"\r", ParserSettings::Html)),
@@ -24432,7 +20846,7 @@ fn t319_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/f%C3%B6%C3%B6\" title=\"föö\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/f\u{f6}\u{f6}", "f\u{f6}\u{f6}")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\n\r\n[foo]: /f&ouml;&ouml; \"f&ouml;&ouml;\"\r\n", ParserSettings::Html)),
@@ -24449,30 +20863,13 @@ fn t319_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/f%C3%B6%C3%B6\" title=\"föö\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/f\u{f6}\u{f6}", "f\u{f6}\u{f6}")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n[foo]: /f&ouml;&ouml; \"f&ouml;&ouml;\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5767
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5767>
-/// <https://spec.commonmark.org/0.29/#example-319>
-fn t319_tab() {
-    init!("[foo]\n\n[foo]:\t/f&ouml;&ouml;\t\"f&ouml;&ouml;\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/f%C3%B6%C3%B6\" title=\"föö\">foo</a></p>
-        [
-            paragraph!(linkref!(plain!("foo"))),
-            linkdef!("foo",	"/f\u{f6}\u{f6}",	"f\u{f6}\u{f6}")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\n\n[foo]:\t/f&ouml;&ouml;\t\"f&ouml;&ouml;\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5776
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5776>
@@ -24537,22 +20934,6 @@ fn t320_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5776
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5776>
-/// <https://spec.commonmark.org/0.29/#example-320>
-fn t320_tab() {
-    init!("```\tf&ouml;&ouml;\nfoo\n```\n");
-
-    assert_eq!(
-        // from spec: <pre><code class=\"language-föö\">foo\n</code></pre>
-        [
-            code_block!("f\u{f6}\u{f6}", "foo\n")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("```\tf&ouml;&ouml;\nfoo\n```\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5789
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5789>
@@ -24691,7 +21072,7 @@ fn t323() {
     assert_eq!(
         // from spec: <p>*foo*\n<em>foo</em></p>
         [
-            paragraph!(plain!("*foo*"), SoftBreak, emph!(plain!("foo")))
+            paragraph!(plain!("*foo*"), soft_break!(), emph!(plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("&#42;foo&#42;\n*foo*\n", ParserSettings::Html)),
     );
@@ -24707,7 +21088,7 @@ fn t323_cr() {
     assert_eq!(
         // from spec: <p>*foo*\n<em>foo</em></p>
         [
-            paragraph!(plain!("*foo*"), SoftBreak, emph!(plain!("foo")))
+            paragraph!(plain!("*foo*"), soft_break!(), emph!(plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("&#42;foo&#42;\r*foo*\r", ParserSettings::Html)),
     );
@@ -24723,7 +21104,7 @@ fn t323_cr_nl() {
     assert_eq!(
         // from spec: <p>*foo*\n<em>foo</em></p>
         [
-            paragraph!(plain!("*foo*"), SoftBreak, emph!(plain!("foo")))
+            paragraph!(plain!("*foo*"), soft_break!(), emph!(plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("&#42;foo&#42;\r\n*foo*\r\n", ParserSettings::Html)),
     );
@@ -24739,7 +21120,7 @@ fn t323_no_nl() {
     assert_eq!(
         // from spec: <p>*foo*\n<em>foo</em></p>
         [
-            paragraph!(plain!("*foo*"), SoftBreak, emph!(plain!("foo")))
+            paragraph!(plain!("*foo*"), soft_break!(), emph!(plain!("foo")))
         ].as_slice(),
         body(&mut StringData::new("&#42;foo&#42;\n*foo*", ParserSettings::Html)),
     );
@@ -24755,7 +21136,7 @@ fn t324() {
     assert_eq!(
         // from spec: <p>* foo</p>\n<ul>\n<li>foo</li>\n</ul>
         [
-            paragraph!(plain!("* foo")), unordered_list!(vec![paragraph!(plain!("foo"))])
+            paragraph!(plain!("* foo")), unordered_list!('*', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("&#42; foo\n\n* foo\n", ParserSettings::Html)),
     );
@@ -24771,7 +21152,7 @@ fn t324_cr() {
     assert_eq!(
         // from spec: <p>* foo</p>\n<ul>\n<li>foo</li>\n</ul>
         [
-            paragraph!(plain!("* foo")), unordered_list!(vec![paragraph!(plain!("foo"))])
+            paragraph!(plain!("* foo")), unordered_list!('*', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("&#42; foo\r\r* foo\r", ParserSettings::Html)),
     );
@@ -24787,7 +21168,7 @@ fn t324_cr_nl() {
     assert_eq!(
         // from spec: <p>* foo</p>\n<ul>\n<li>foo</li>\n</ul>
         [
-            paragraph!(plain!("* foo")), unordered_list!(vec![paragraph!(plain!("foo"))])
+            paragraph!(plain!("* foo")), unordered_list!('*', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("&#42; foo\r\n\r\n* foo\r\n", ParserSettings::Html)),
     );
@@ -24803,28 +21184,12 @@ fn t324_no_nl() {
     assert_eq!(
         // from spec: <p>* foo</p>\n<ul>\n<li>foo</li>\n</ul>
         [
-            paragraph!(plain!("* foo")), unordered_list!(vec![paragraph!(plain!("foo"))])
+            paragraph!(plain!("* foo")), unordered_list!('*', item!(paragraph!(plain!("foo"))))
         ].as_slice(),
         body(&mut StringData::new("&#42; foo\n\n* foo", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5816
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5816>
-/// <https://spec.commonmark.org/0.29/#example-324>
-fn t324_tab() {
-    init!("&#42;\tfoo\n\n*\tfoo\n");
-
-    assert_eq!(
-        // from spec: <p>* foo</p>\n<ul>\n<li>foo</li>\n</ul>
-        [
-            paragraph!(plain!("*	foo")), unordered_list!(vec![paragraph!(plain!("foo"))])
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("&#42;\tfoo\n\n*\tfoo\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5827
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5827>
@@ -24931,7 +21296,7 @@ fn t327() {
     assert_eq!(
         // from spec: <p>[a](url &quot;tit&quot;)</p>
         [
-            paragraph!(linkref!(plain!("a")), plain!("(url \"tit\")"))
+            paragraph!(linkref!("a", plain!("a")), plain!("(url \"tit\")"))
         ].as_slice(),
         body(&mut StringData::new("[a](url &quot;tit&quot;)\n", ParserSettings::Html)),
     );
@@ -24947,7 +21312,7 @@ fn t327_cr() {
     assert_eq!(
         // from spec: <p>[a](url &quot;tit&quot;)</p>
         [
-            paragraph!(linkref!(plain!("a")), plain!("(url \"tit\")"))
+            paragraph!(linkref!("a", plain!("a")), plain!("(url \"tit\")"))
         ].as_slice(),
         body(&mut StringData::new("[a](url &quot;tit&quot;)\r", ParserSettings::Html)),
     );
@@ -24963,7 +21328,7 @@ fn t327_cr_nl() {
     assert_eq!(
         // from spec: <p>[a](url &quot;tit&quot;)</p>
         [
-            paragraph!(linkref!(plain!("a")), plain!("(url \"tit\")"))
+            paragraph!(linkref!("a", plain!("a")), plain!("(url \"tit\")"))
         ].as_slice(),
         body(&mut StringData::new("[a](url &quot;tit&quot;)\r\n", ParserSettings::Html)),
     );
@@ -24979,28 +21344,12 @@ fn t327_no_nl() {
     assert_eq!(
         // from spec: <p>[a](url &quot;tit&quot;)</p>
         [
-            paragraph!(linkref!(plain!("a")), plain!("(url \"tit\")"))
+            paragraph!(linkref!("a", plain!("a")), plain!("(url \"tit\")"))
         ].as_slice(),
         body(&mut StringData::new("[a](url &quot;tit&quot;)", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5842
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5842>
-/// <https://spec.commonmark.org/0.29/#example-327>
-fn t327_tab() {
-    init!("[a](url\t&quot;tit&quot;)\n");
-
-    assert_eq!(
-        // from spec: <p>[a](url &quot;tit&quot;)</p>
-        [
-            paragraph!(linkref!(plain!("a")),	plain!("(url	\"tit\")"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[a](url\t&quot;tit&quot;)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5870
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5870>
@@ -25129,22 +21478,6 @@ fn t329_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5881
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5881>
-/// <https://spec.commonmark.org/0.29/#example-329>
-fn t329_tab() {
-    init!("`` foo\t`\tbar ``\n");
-
-    assert_eq!(
-        // from spec: <p><code>foo ` bar</code></p>
-        [
-            paragraph!(code!("foo\t`\tbar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("`` foo\t`\tbar ``\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5891
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5891>
@@ -25273,22 +21606,6 @@ fn t331_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5899
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5899>
-/// <https://spec.commonmark.org/0.29/#example-331>
-fn t331_tab() {
-    init!("` \t``\t `\n");
-
-    assert_eq!(
-        // from spec: <p><code> `` </code></p>
-        [
-            paragraph!(code!("\t``\t"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("` \t``\t `\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5908
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5908>
@@ -25353,22 +21670,6 @@ fn t332_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5908
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5908>
-/// <https://spec.commonmark.org/0.29/#example-332>
-fn t332_tab() {
-    init!("`\ta`\n");
-
-    assert_eq!(
-        // from spec: <p><code> a</code></p>
-        [
-            paragraph!(code!("\ta"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("`\ta`\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5917
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5917>
@@ -25443,7 +21744,7 @@ fn t334() {
     assert_eq!(
         // from spec: <p><code>\u{a0}</code>\n<code>  </code></p>
         [
-            paragraph!(code!("\u{a0}"), SoftBreak, code!("  "))
+            paragraph!(code!("\u{a0}"), soft_break!(), code!("  "))
         ].as_slice(),
         body(&mut StringData::new("`\u{a0}`\n`  `\n", ParserSettings::Html)),
     );
@@ -25459,7 +21760,7 @@ fn t334_cr() {
     assert_eq!(
         // from spec: <p><code>\u{a0}</code>\n<code>  </code></p>
         [
-            paragraph!(code!("\u{a0}"), SoftBreak, code!("  "))
+            paragraph!(code!("\u{a0}"), soft_break!(), code!("  "))
         ].as_slice(),
         body(&mut StringData::new("`\u{a0}`\r`  `\r", ParserSettings::Html)),
     );
@@ -25475,7 +21776,7 @@ fn t334_cr_nl() {
     assert_eq!(
         // from spec: <p><code>\u{a0}</code>\n<code>  </code></p>
         [
-            paragraph!(code!("\u{a0}"), SoftBreak, code!("  "))
+            paragraph!(code!("\u{a0}"), soft_break!(), code!("  "))
         ].as_slice(),
         body(&mut StringData::new("`\u{a0}`\r\n`  `\r\n", ParserSettings::Html)),
     );
@@ -25491,7 +21792,7 @@ fn t334_no_nl() {
     assert_eq!(
         // from spec: <p><code>\u{a0}</code>\n<code>  </code></p>
         [
-            paragraph!(code!("\u{a0}"), SoftBreak, code!("  "))
+            paragraph!(code!("\u{a0}"), soft_break!(), code!("  "))
         ].as_slice(),
         body(&mut StringData::new("`\u{a0}`\n`  `", ParserSettings::Html)),
     );
@@ -25625,22 +21926,6 @@ fn t336_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5946
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5946>
-/// <https://spec.commonmark.org/0.29/#example-336>
-fn t336_tab() {
-    init!("``\nfoo\t\n``\n");
-
-    assert_eq!(
-        // from spec: <p><code>foo </code></p>
-        [
-            paragraph!(code!("foo\t"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("``\nfoo\t\n``\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5957
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5957>
@@ -25705,22 +21990,6 @@ fn t337_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5957
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5957>
-/// <https://spec.commonmark.org/0.29/#example-337>
-fn t337_tab() {
-    init!("`foo\t\t\tbar\t\nbaz`\n");
-
-    assert_eq!(
-        // from spec: <p><code>foo   bar  baz</code></p>
-        [
-            paragraph!(code!("foo\t\t\tbar\t\tbaz"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("`foo\t\t\tbar\t\nbaz`\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 5974
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5974>
@@ -25913,22 +22182,6 @@ fn t340_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 5991
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L5991>
-/// <https://spec.commonmark.org/0.29/#example-340>
-fn t340_tab() {
-    init!("` foo\t``\tbar `\n");
-
-    assert_eq!(
-        // from spec: <p><code>foo `` bar</code></p>
-        [
-            paragraph!(code!("foo\t``\tbar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("` foo\t``\tbar `\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6003
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6003>
@@ -26057,22 +22310,6 @@ fn t342_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6012
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6012>
-/// <https://spec.commonmark.org/0.29/#example-342>
-fn t342_tab() {
-    init!("[not\ta\t`link](/foo`)\n");
-
-    assert_eq!(
-        // from spec: <p>[not a <code>link](/foo</code>)</p>
-        [
-            paragraph!(plain!("[not	a	"), code!("link](/foo"), plain!(")"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[not\ta\t`link](/foo`)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6022
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6022>
@@ -26137,22 +22374,6 @@ fn t343_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6022
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6022>
-/// <https://spec.commonmark.org/0.29/#example-343>
-fn t343_tab() {
-    init!("`<a\thref=\"`\">`\n");
-
-    assert_eq!(
-        // from spec: <p><code>&lt;a href=&quot;</code>&quot;&gt;`</p>
-        [
-            paragraph!(code!("<a\thref=\""), plain!("\">`"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("`<a\thref=\"`\">`\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6031
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6031>
@@ -26217,22 +22438,6 @@ fn t344_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6031
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6031>
-/// <https://spec.commonmark.org/0.29/#example-344>
-fn t344_tab() {
-    init!("<a\thref=\"`\">`\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"`\">`</p>
-        [
-            paragraph!(html!("<a	href=\"`\">"),	plain!("`"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\thref=\"`\">`\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6040
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6040>
@@ -26617,22 +22822,6 @@ fn t350_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6292
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6292>
-/// <https://spec.commonmark.org/0.29/#example-350>
-fn t350_tab() {
-    init!("*foo\tbar*\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo bar</em></p>
-        [
-            paragraph!(emph!(plain!("foo	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\tbar*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6302
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6302>
@@ -26697,22 +22886,6 @@ fn t351_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6302
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6302>
-/// <https://spec.commonmark.org/0.29/#example-351>
-fn t351_tab() {
-    init!("a\t*\tfoo\tbar*\n");
-
-    assert_eq!(
-        // from spec: <p>a * foo bar*</p>
-        [
-            paragraph!(plain!("a	*	foo	bar*"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("a\t*\tfoo\tbar*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6313
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6313>
@@ -27033,22 +23206,6 @@ fn t356_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6347
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6347>
-/// <https://spec.commonmark.org/0.29/#example-356>
-fn t356_tab() {
-    init!("_foo\tbar_\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo bar</em></p>
-        [
-            paragraph!(emph!(plain!("foo	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("_foo\tbar_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6357
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6357>
@@ -27113,22 +23270,6 @@ fn t357_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6357
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6357>
-/// <https://spec.commonmark.org/0.29/#example-357>
-fn t357_tab() {
-    init!("_\tfoo\tbar_\n");
-
-    assert_eq!(
-        // from spec: <p>_ foo bar_</p>
-        [
-            paragraph!(plain!("_	foo	bar_"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("_\tfoo\tbar_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6367
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6367>
@@ -27641,22 +23782,6 @@ fn t365_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6433
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6433>
-/// <https://spec.commonmark.org/0.29/#example-365>
-fn t365_tab() {
-    init!("*foo\tbar\t*\n");
-
-    assert_eq!(
-        // from spec: <p>*foo bar *</p>
-        [
-            paragraph!(plain!("*foo	bar	*"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\tbar\t*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6442
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6442>
@@ -27667,7 +23792,7 @@ fn t366() {
     assert_eq!(
         // from spec: <p>*foo bar\n*</p>
         [
-            paragraph!(plain!("*foo bar"), SoftBreak, plain!("*"))
+            paragraph!(plain!("*foo bar"), soft_break!(), plain!("*"))
         ].as_slice(),
         body(&mut StringData::new("*foo bar\n*\n", ParserSettings::Html)),
     );
@@ -27683,7 +23808,7 @@ fn t366_cr() {
     assert_eq!(
         // from spec: <p>*foo bar\n*</p>
         [
-            paragraph!(plain!("*foo bar"), SoftBreak, plain!("*"))
+            paragraph!(plain!("*foo bar"), soft_break!(), plain!("*"))
         ].as_slice(),
         body(&mut StringData::new("*foo bar\r*\r", ParserSettings::Html)),
     );
@@ -27699,7 +23824,7 @@ fn t366_cr_nl() {
     assert_eq!(
         // from spec: <p>*foo bar\n*</p>
         [
-            paragraph!(plain!("*foo bar"), SoftBreak, plain!("*"))
+            paragraph!(plain!("*foo bar"), soft_break!(), plain!("*"))
         ].as_slice(),
         body(&mut StringData::new("*foo bar\r\n*\r\n", ParserSettings::Html)),
     );
@@ -27715,28 +23840,12 @@ fn t366_no_nl() {
     assert_eq!(
         // from spec: <p>*foo bar\n*</p>
         [
-            paragraph!(plain!("*foo bar"), SoftBreak, plain!("*"))
+            paragraph!(plain!("*foo bar"), soft_break!(), plain!("*"))
         ].as_slice(),
         body(&mut StringData::new("*foo bar\n*", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6442
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6442>
-/// <https://spec.commonmark.org/0.29/#example-366>
-fn t366_tab() {
-    init!("*foo\tbar\n*\n");
-
-    assert_eq!(
-        // from spec: <p>*foo bar\n*</p>
-        [
-            paragraph!(plain!("*foo	bar"), SoftBreak, plain!("*"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\tbar\n*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6455
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6455>
@@ -27993,22 +24102,6 @@ fn t370_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6487
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6487>
-/// <https://spec.commonmark.org/0.29/#example-370>
-fn t370_tab() {
-    init!("_foo\tbar\t_\n");
-
-    assert_eq!(
-        // from spec: <p>_foo bar _</p>
-        [
-            paragraph!(plain!("_foo	bar	_"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("_foo\tbar\t_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6497
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6497>
@@ -28457,22 +24550,6 @@ fn t377_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6549
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6549>
-/// <https://spec.commonmark.org/0.29/#example-377>
-fn t377_tab() {
-    init!("**foo\tbar**\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo bar</strong></p>
-        [
-            paragraph!(strong!(plain!("foo	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\tbar**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6559
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6559>
@@ -28537,22 +24614,6 @@ fn t378_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6559
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6559>
-/// <https://spec.commonmark.org/0.29/#example-378>
-fn t378_tab() {
-    init!("**\tfoo\tbar**\n");
-
-    assert_eq!(
-        // from spec: <p>** foo bar**</p>
-        [
-            paragraph!(plain!("**	foo	bar**"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**\tfoo\tbar**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6570
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6570>
@@ -28745,22 +24806,6 @@ fn t381_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6588
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6588>
-/// <https://spec.commonmark.org/0.29/#example-381>
-fn t381_tab() {
-    init!("__foo\tbar__\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo bar</strong></p>
-        [
-            paragraph!(strong!(plain!("foo	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__foo\tbar__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6598
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6598>
@@ -28825,22 +24870,6 @@ fn t382_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6598
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6598>
-/// <https://spec.commonmark.org/0.29/#example-382>
-fn t382_tab() {
-    init!("__\tfoo\tbar__\n");
-
-    assert_eq!(
-        // from spec: <p>__ foo bar__</p>
-        [
-            paragraph!(plain!("__	foo	bar__"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__\tfoo\tbar__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6606
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6606>
@@ -28851,7 +24880,7 @@ fn t383() {
     assert_eq!(
         // from spec: <p>__\nfoo bar__</p>
         [
-            paragraph!(plain!("__"), SoftBreak, plain!("foo bar__"))
+            paragraph!(plain!("__"), soft_break!(), plain!("foo bar__"))
         ].as_slice(),
         body(&mut StringData::new("__\nfoo bar__\n", ParserSettings::Html)),
     );
@@ -28867,7 +24896,7 @@ fn t383_cr() {
     assert_eq!(
         // from spec: <p>__\nfoo bar__</p>
         [
-            paragraph!(plain!("__"), SoftBreak, plain!("foo bar__"))
+            paragraph!(plain!("__"), soft_break!(), plain!("foo bar__"))
         ].as_slice(),
         body(&mut StringData::new("__\rfoo bar__\r", ParserSettings::Html)),
     );
@@ -28883,7 +24912,7 @@ fn t383_cr_nl() {
     assert_eq!(
         // from spec: <p>__\nfoo bar__</p>
         [
-            paragraph!(plain!("__"), SoftBreak, plain!("foo bar__"))
+            paragraph!(plain!("__"), soft_break!(), plain!("foo bar__"))
         ].as_slice(),
         body(&mut StringData::new("__\r\nfoo bar__\r\n", ParserSettings::Html)),
     );
@@ -28899,28 +24928,12 @@ fn t383_no_nl() {
     assert_eq!(
         // from spec: <p>__\nfoo bar__</p>
         [
-            paragraph!(plain!("__"), SoftBreak, plain!("foo bar__"))
+            paragraph!(plain!("__"), soft_break!(), plain!("foo bar__"))
         ].as_slice(),
         body(&mut StringData::new("__\nfoo bar__", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6606
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6606>
-/// <https://spec.commonmark.org/0.29/#example-383>
-fn t383_tab() {
-    init!("__\nfoo\tbar__\n");
-
-    assert_eq!(
-        // from spec: <p>__\nfoo bar__</p>
-        [
-            paragraph!(plain!("__"), SoftBreak, plain!("foo	bar__"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__\nfoo\tbar__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6618
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6618>
@@ -29241,22 +25254,6 @@ fn t388_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6648
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6648>
-/// <https://spec.commonmark.org/0.29/#example-388>
-fn t388_tab() {
-    init!("__foo,\t__bar__,\tbaz__\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo, <strong>bar</strong>, baz</strong></p>
-        [
-            paragraph!(strong!(plain!("foo,	"), strong!(plain!("bar")), plain!(",	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__foo,\t__bar__,\tbaz__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6659
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6659>
@@ -29385,22 +25382,6 @@ fn t390_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6672
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6672>
-/// <https://spec.commonmark.org/0.29/#example-390>
-fn t390_tab() {
-    init!("**foo\tbar\t**\n");
-
-    assert_eq!(
-        // from spec: <p>**foo bar **</p>
-        [
-            paragraph!(plain!("**foo	bar	**"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\tbar\t**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6685
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6685>
@@ -29539,7 +25520,7 @@ fn t393() {
     assert_eq!(
         // from spec: <p><strong>Gomphocarpus (<em>Gomphocarpus physocarpus</em>, syn.\n<em>Asclepias physocarpa</em>)</strong></p>
         [
-            paragraph!(strong!(plain!("Gomphocarpus ("), emph!(plain!("Gomphocarpus physocarpus")), plain!(", syn."), SoftBreak, emph!(plain!("Asclepias physocarpa")), plain!(")")))
+            paragraph!(strong!(plain!("Gomphocarpus ("), emph!(plain!("Gomphocarpus physocarpus")), plain!(", syn."), soft_break!(), emph!(plain!("Asclepias physocarpa")), plain!(")")))
         ].as_slice(),
         body(&mut StringData::new("**Gomphocarpus (*Gomphocarpus physocarpus*, syn.\n*Asclepias physocarpa*)**\n", ParserSettings::Html)),
     );
@@ -29555,7 +25536,7 @@ fn t393_cr() {
     assert_eq!(
         // from spec: <p><strong>Gomphocarpus (<em>Gomphocarpus physocarpus</em>, syn.\n<em>Asclepias physocarpa</em>)</strong></p>
         [
-            paragraph!(strong!(plain!("Gomphocarpus ("), emph!(plain!("Gomphocarpus physocarpus")), plain!(", syn."), SoftBreak, emph!(plain!("Asclepias physocarpa")), plain!(")")))
+            paragraph!(strong!(plain!("Gomphocarpus ("), emph!(plain!("Gomphocarpus physocarpus")), plain!(", syn."), soft_break!(), emph!(plain!("Asclepias physocarpa")), plain!(")")))
         ].as_slice(),
         body(&mut StringData::new("**Gomphocarpus (*Gomphocarpus physocarpus*, syn.\r*Asclepias physocarpa*)**\r", ParserSettings::Html)),
     );
@@ -29571,7 +25552,7 @@ fn t393_cr_nl() {
     assert_eq!(
         // from spec: <p><strong>Gomphocarpus (<em>Gomphocarpus physocarpus</em>, syn.\n<em>Asclepias physocarpa</em>)</strong></p>
         [
-            paragraph!(strong!(plain!("Gomphocarpus ("), emph!(plain!("Gomphocarpus physocarpus")), plain!(", syn."), SoftBreak, emph!(plain!("Asclepias physocarpa")), plain!(")")))
+            paragraph!(strong!(plain!("Gomphocarpus ("), emph!(plain!("Gomphocarpus physocarpus")), plain!(", syn."), soft_break!(), emph!(plain!("Asclepias physocarpa")), plain!(")")))
         ].as_slice(),
         body(&mut StringData::new("**Gomphocarpus (*Gomphocarpus physocarpus*, syn.\r\n*Asclepias physocarpa*)**\r\n", ParserSettings::Html)),
     );
@@ -29587,28 +25568,12 @@ fn t393_no_nl() {
     assert_eq!(
         // from spec: <p><strong>Gomphocarpus (<em>Gomphocarpus physocarpus</em>, syn.\n<em>Asclepias physocarpa</em>)</strong></p>
         [
-            paragraph!(strong!(plain!("Gomphocarpus ("), emph!(plain!("Gomphocarpus physocarpus")), plain!(", syn."), SoftBreak, emph!(plain!("Asclepias physocarpa")), plain!(")")))
+            paragraph!(strong!(plain!("Gomphocarpus ("), emph!(plain!("Gomphocarpus physocarpus")), plain!(", syn."), soft_break!(), emph!(plain!("Asclepias physocarpa")), plain!(")")))
         ].as_slice(),
         body(&mut StringData::new("**Gomphocarpus (*Gomphocarpus physocarpus*, syn.\n*Asclepias physocarpa*)**", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6702
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6702>
-/// <https://spec.commonmark.org/0.29/#example-393>
-fn t393_tab() {
-    init!("**Gomphocarpus\t(*Gomphocarpus\tphysocarpus*,\tsyn.\n*Asclepias\tphysocarpa*)**\n");
-
-    assert_eq!(
-        // from spec: <p><strong>Gomphocarpus (<em>Gomphocarpus physocarpus</em>, syn.\n<em>Asclepias physocarpa</em>)</strong></p>
-        [
-            paragraph!(strong!(plain!("Gomphocarpus	("), emph!(plain!("Gomphocarpus	physocarpus")), plain!(",	syn."), SoftBreak, emph!(plain!("Asclepias	physocarpa")), plain!(")")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**Gomphocarpus\t(*Gomphocarpus\tphysocarpus*,\tsyn.\n*Asclepias\tphysocarpa*)**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6711
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6711>
@@ -29673,22 +25638,6 @@ fn t394_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6711
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6711>
-/// <https://spec.commonmark.org/0.29/#example-394>
-fn t394_tab() {
-    init!("**foo\t\"*bar*\"\tfoo**\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo &quot;<em>bar</em>&quot; foo</strong></p>
-        [
-            paragraph!(strong!(plain!("foo	\""), emph!(plain!("bar")), plain!("\"	foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\t\"*bar*\"\tfoo**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6720
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6720>
@@ -29817,22 +25766,6 @@ fn t396_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6732
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6732>
-/// <https://spec.commonmark.org/0.29/#example-396>
-fn t396_tab() {
-    init!("__foo\tbar\t__\n");
-
-    assert_eq!(
-        // from spec: <p>__foo bar __</p>
-        [
-            paragraph!(plain!("__foo	bar	__"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__foo\tbar\t__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6742
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6742>
@@ -30281,22 +26214,6 @@ fn t403_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6798
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6798>
-/// <https://spec.commonmark.org/0.29/#example-403>
-fn t403_tab() {
-    init!("*foo\t[bar](/url)*\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <a href=\"/url\">bar</a></em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), link!("/url", "", plain!("bar"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t[bar](/url)*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6805
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6805>
@@ -30307,7 +26224,7 @@ fn t404() {
     assert_eq!(
         // from spec: <p><em>foo\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo\nbar*\n", ParserSettings::Html)),
     );
@@ -30323,7 +26240,7 @@ fn t404_cr() {
     assert_eq!(
         // from spec: <p><em>foo\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo\rbar*\r", ParserSettings::Html)),
     );
@@ -30339,7 +26256,7 @@ fn t404_cr_nl() {
     assert_eq!(
         // from spec: <p><em>foo\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo\r\nbar*\r\n", ParserSettings::Html)),
     );
@@ -30355,7 +26272,7 @@ fn t404_no_nl() {
     assert_eq!(
         // from spec: <p><em>foo\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo\nbar*", ParserSettings::Html)),
     );
@@ -30425,22 +26342,6 @@ fn t405_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6817
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6817>
-/// <https://spec.commonmark.org/0.29/#example-405>
-fn t405_tab() {
-    init!("_foo\t__bar__\tbaz_\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <strong>bar</strong> baz</em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), strong!(plain!("bar")), plain!("	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("_foo\t__bar__\tbaz_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6824
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6824>
@@ -30505,22 +26406,6 @@ fn t406_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6824
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6824>
-/// <https://spec.commonmark.org/0.29/#example-406>
-fn t406_tab() {
-    init!("_foo\t_bar_\tbaz_\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <em>bar</em> baz</em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), emph!(plain!("bar")), plain!("	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("_foo\t_bar_\tbaz_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6831
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6831>
@@ -30585,22 +26470,6 @@ fn t407_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6831
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6831>
-/// <https://spec.commonmark.org/0.29/#example-407>
-fn t407_tab() {
-    init!("__foo_\tbar_\n");
-
-    assert_eq!(
-        // from spec: <p><em><em>foo</em> bar</em></p>
-        [
-            paragraph!(emph!(emph!(plain!("foo")), plain!("	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__foo_\tbar_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6838
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6838>
@@ -30665,22 +26534,6 @@ fn t408_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6838
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6838>
-/// <https://spec.commonmark.org/0.29/#example-408>
-fn t408_tab() {
-    init!("*foo\t*bar**\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <em>bar</em></em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), emph!(plain!("bar"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t*bar**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6845
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6845>
@@ -30745,22 +26598,6 @@ fn t409_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6845
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6845>
-/// <https://spec.commonmark.org/0.29/#example-409>
-fn t409_tab() {
-    init!("*foo\t**bar**\tbaz*\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <strong>bar</strong> baz</em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), strong!(plain!("bar")), plain!("	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t**bar**\tbaz*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6851
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6851>
@@ -30953,22 +26790,6 @@ fn t412_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6888
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6888>
-/// <https://spec.commonmark.org/0.29/#example-412>
-fn t412_tab() {
-    init!("***foo**\tbar*\n");
-
-    assert_eq!(
-        // from spec: <p><em><strong>foo</strong> bar</em></p>
-        [
-            paragraph!(emph!(strong!(plain!("foo")), plain!("	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("***foo**\tbar*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6895
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6895>
@@ -31033,22 +26854,6 @@ fn t413_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6895
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6895>
-/// <https://spec.commonmark.org/0.29/#example-413>
-fn t413_tab() {
-    init!("*foo\t**bar***\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <strong>bar</strong></em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), strong!(plain!("bar"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t**bar***\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6902
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6902>
@@ -31305,22 +27110,6 @@ fn t417_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6928
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6928>
-/// <https://spec.commonmark.org/0.29/#example-417>
-fn t417_tab() {
-    init!("*foo\t**bar\t*baz*\tbim**\tbop*\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <strong>bar <em>baz</em> bim</strong> bop</em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), strong!(plain!("bar	"), emph!(plain!("baz")), plain!("	bim")), plain!("	bop")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t**bar\t*baz*\tbim**\tbop*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6935
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6935>
@@ -31385,22 +27174,6 @@ fn t418_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6935
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6935>
-/// <https://spec.commonmark.org/0.29/#example-418>
-fn t418_tab() {
-    init!("*foo\t[*bar*](/url)*\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <a href=\"/url\"><em>bar</em></a></em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), link!("/url", "", emph!(plain!("bar")))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t[*bar*](/url)*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6944
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6944>
@@ -31465,22 +27238,6 @@ fn t419_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6944
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6944>
-/// <https://spec.commonmark.org/0.29/#example-419>
-fn t419_tab() {
-    init!("**\tis\tnot\tan\tempty\temphasis\n");
-
-    assert_eq!(
-        // from spec: <p>** is not an empty emphasis</p>
-        [
-            paragraph!(plain!("**	is	not	an	empty	emphasis"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**\tis\tnot\tan\tempty\temphasis\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6951
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6951>
@@ -31545,22 +27302,6 @@ fn t420_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6951
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6951>
-/// <https://spec.commonmark.org/0.29/#example-420>
-fn t420_tab() {
-    init!("****\tis\tnot\tan\tempty\tstrong\temphasis\n");
-
-    assert_eq!(
-        // from spec: <p>**** is not an empty strong emphasis</p>
-        [
-            paragraph!(plain!("****	is	not	an	empty	strong	emphasis"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("****\tis\tnot\tan\tempty\tstrong\temphasis\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6964
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6964>
@@ -31625,22 +27366,6 @@ fn t421_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6964
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6964>
-/// <https://spec.commonmark.org/0.29/#example-421>
-fn t421_tab() {
-    init!("**foo\t[bar](/url)**\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo <a href=\"/url\">bar</a></strong></p>
-        [
-            paragraph!(strong!(plain!("foo	"), link!("/url", "", plain!("bar"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\t[bar](/url)**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6971
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6971>
@@ -31651,7 +27376,7 @@ fn t422() {
     assert_eq!(
         // from spec: <p><strong>foo\nbar</strong></p>
         [
-            paragraph!(strong!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(strong!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("**foo\nbar**\n", ParserSettings::Html)),
     );
@@ -31667,7 +27392,7 @@ fn t422_cr() {
     assert_eq!(
         // from spec: <p><strong>foo\nbar</strong></p>
         [
-            paragraph!(strong!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(strong!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("**foo\rbar**\r", ParserSettings::Html)),
     );
@@ -31683,7 +27408,7 @@ fn t422_cr_nl() {
     assert_eq!(
         // from spec: <p><strong>foo\nbar</strong></p>
         [
-            paragraph!(strong!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(strong!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("**foo\r\nbar**\r\n", ParserSettings::Html)),
     );
@@ -31699,7 +27424,7 @@ fn t422_no_nl() {
     assert_eq!(
         // from spec: <p><strong>foo\nbar</strong></p>
         [
-            paragraph!(strong!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(strong!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("**foo\nbar**", ParserSettings::Html)),
     );
@@ -31769,22 +27494,6 @@ fn t423_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6983
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6983>
-/// <https://spec.commonmark.org/0.29/#example-423>
-fn t423_tab() {
-    init!("__foo\t_bar_\tbaz__\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo <em>bar</em> baz</strong></p>
-        [
-            paragraph!(strong!(plain!("foo	"), emph!(plain!("bar")), plain!("	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__foo\t_bar_\tbaz__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6990
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6990>
@@ -31849,22 +27558,6 @@ fn t424_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6990
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6990>
-/// <https://spec.commonmark.org/0.29/#example-424>
-fn t424_tab() {
-    init!("__foo\t__bar__\tbaz__\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo <strong>bar</strong> baz</strong></p>
-        [
-            paragraph!(strong!(plain!("foo	"), strong!(plain!("bar")), plain!("	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__foo\t__bar__\tbaz__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 6997
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6997>
@@ -31929,22 +27622,6 @@ fn t425_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 6997
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L6997>
-/// <https://spec.commonmark.org/0.29/#example-425>
-fn t425_tab() {
-    init!("____foo__\tbar__\n");
-
-    assert_eq!(
-        // from spec: <p><strong><strong>foo</strong> bar</strong></p>
-        [
-            paragraph!(strong!(strong!(plain!("foo")), plain!("	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("____foo__\tbar__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7004
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7004>
@@ -32009,22 +27686,6 @@ fn t426_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7004
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7004>
-/// <https://spec.commonmark.org/0.29/#example-426>
-fn t426_tab() {
-    init!("**foo\t**bar****\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo <strong>bar</strong></strong></p>
-        [
-            paragraph!(strong!(plain!("foo	"), strong!(plain!("bar"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\t**bar****\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7011
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7011>
@@ -32089,22 +27750,6 @@ fn t427_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7011
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7011>
-/// <https://spec.commonmark.org/0.29/#example-427>
-fn t427_tab() {
-    init!("**foo\t*bar*\tbaz**\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo <em>bar</em> baz</strong></p>
-        [
-            paragraph!(strong!(plain!("foo	"), emph!(plain!("bar")), plain!("	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\t*bar*\tbaz**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7018
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7018>
@@ -32233,22 +27878,6 @@ fn t429_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7025
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7025>
-/// <https://spec.commonmark.org/0.29/#example-429>
-fn t429_tab() {
-    init!("***foo*\tbar**\n");
-
-    assert_eq!(
-        // from spec: <p><strong><em>foo</em> bar</strong></p>
-        [
-            paragraph!(strong!(emph!(plain!("foo")), plain!("	bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("***foo*\tbar**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7032
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7032>
@@ -32313,22 +27942,6 @@ fn t430_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7032
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7032>
-/// <https://spec.commonmark.org/0.29/#example-430>
-fn t430_tab() {
-    init!("**foo\t*bar***\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo <em>bar</em></strong></p>
-        [
-            paragraph!(strong!(plain!("foo	"), emph!(plain!("bar"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\t*bar***\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7041
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7041>
@@ -32339,7 +27952,7 @@ fn t431() {
     assert_eq!(
         // from spec: <p><strong>foo <em>bar <strong>baz</strong>\nbim</em> bop</strong></p>
         [
-            paragraph!(strong!(plain!("foo "), emph!(plain!("bar "), strong!(plain!("baz")), SoftBreak, plain!("bim")), plain!(" bop")))
+            paragraph!(strong!(plain!("foo "), emph!(plain!("bar "), strong!(plain!("baz")), soft_break!(), plain!("bim")), plain!(" bop")))
         ].as_slice(),
         body(&mut StringData::new("**foo *bar **baz**\nbim* bop**\n", ParserSettings::Html)),
     );
@@ -32355,7 +27968,7 @@ fn t431_cr() {
     assert_eq!(
         // from spec: <p><strong>foo <em>bar <strong>baz</strong>\nbim</em> bop</strong></p>
         [
-            paragraph!(strong!(plain!("foo "), emph!(plain!("bar "), strong!(plain!("baz")), SoftBreak, plain!("bim")), plain!(" bop")))
+            paragraph!(strong!(plain!("foo "), emph!(plain!("bar "), strong!(plain!("baz")), soft_break!(), plain!("bim")), plain!(" bop")))
         ].as_slice(),
         body(&mut StringData::new("**foo *bar **baz**\rbim* bop**\r", ParserSettings::Html)),
     );
@@ -32371,7 +27984,7 @@ fn t431_cr_nl() {
     assert_eq!(
         // from spec: <p><strong>foo <em>bar <strong>baz</strong>\nbim</em> bop</strong></p>
         [
-            paragraph!(strong!(plain!("foo "), emph!(plain!("bar "), strong!(plain!("baz")), SoftBreak, plain!("bim")), plain!(" bop")))
+            paragraph!(strong!(plain!("foo "), emph!(plain!("bar "), strong!(plain!("baz")), soft_break!(), plain!("bim")), plain!(" bop")))
         ].as_slice(),
         body(&mut StringData::new("**foo *bar **baz**\r\nbim* bop**\r\n", ParserSettings::Html)),
     );
@@ -32387,28 +28000,12 @@ fn t431_no_nl() {
     assert_eq!(
         // from spec: <p><strong>foo <em>bar <strong>baz</strong>\nbim</em> bop</strong></p>
         [
-            paragraph!(strong!(plain!("foo "), emph!(plain!("bar "), strong!(plain!("baz")), SoftBreak, plain!("bim")), plain!(" bop")))
+            paragraph!(strong!(plain!("foo "), emph!(plain!("bar "), strong!(plain!("baz")), soft_break!(), plain!("bim")), plain!(" bop")))
         ].as_slice(),
         body(&mut StringData::new("**foo *bar **baz**\nbim* bop**", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7041
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7041>
-/// <https://spec.commonmark.org/0.29/#example-431>
-fn t431_tab() {
-    init!("**foo\t*bar\t**baz**\nbim*\tbop**\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo <em>bar <strong>baz</strong>\nbim</em> bop</strong></p>
-        [
-            paragraph!(strong!(plain!("foo	"), emph!(plain!("bar	"), strong!(plain!("baz")), SoftBreak, plain!("bim")), plain!("	bop")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\t*bar\t**baz**\nbim*\tbop**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7050
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7050>
@@ -32473,22 +28070,6 @@ fn t432_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7050
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7050>
-/// <https://spec.commonmark.org/0.29/#example-432>
-fn t432_tab() {
-    init!("**foo\t[*bar*](/url)**\n");
-
-    assert_eq!(
-        // from spec: <p><strong>foo <a href=\"/url\"><em>bar</em></a></strong></p>
-        [
-            paragraph!(strong!(plain!("foo	"), link!("/url", "", emph!(plain!("bar")))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\t[*bar*](/url)**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7059
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7059>
@@ -32553,22 +28134,6 @@ fn t433_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7059
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7059>
-/// <https://spec.commonmark.org/0.29/#example-433>
-fn t433_tab() {
-    init!("__\tis\tnot\tan\tempty\temphasis\n");
-
-    assert_eq!(
-        // from spec: <p>__ is not an empty emphasis</p>
-        [
-            paragraph!(plain!("__	is	not	an	empty	emphasis"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__\tis\tnot\tan\tempty\temphasis\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7066
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7066>
@@ -32633,22 +28198,6 @@ fn t434_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7066
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7066>
-/// <https://spec.commonmark.org/0.29/#example-434>
-fn t434_tab() {
-    init!("____\tis\tnot\tan\tempty\tstrong\temphasis\n");
-
-    assert_eq!(
-        // from spec: <p>____ is not an empty strong emphasis</p>
-        [
-            paragraph!(plain!("____	is	not	an	empty	strong	emphasis"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("____\tis\tnot\tan\tempty\tstrong\temphasis\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7076
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7076>
@@ -32713,22 +28262,6 @@ fn t435_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7076
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7076>
-/// <https://spec.commonmark.org/0.29/#example-435>
-fn t435_tab() {
-    init!("foo\t***\n");
-
-    assert_eq!(
-        // from spec: <p>foo ***</p>
-        [
-            paragraph!(plain!("foo	***"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t***\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7083
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7083>
@@ -32793,22 +28326,6 @@ fn t436_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7083
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7083>
-/// <https://spec.commonmark.org/0.29/#example-436>
-fn t436_tab() {
-    init!("foo\t*\\**\n");
-
-    assert_eq!(
-        // from spec: <p>foo <em>*</em></p>
-        [
-            paragraph!(plain!("foo	"), emph!(plain!("*")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t*\\**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7090
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7090>
@@ -32873,22 +28390,6 @@ fn t437_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7090
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7090>
-/// <https://spec.commonmark.org/0.29/#example-437>
-fn t437_tab() {
-    init!("foo\t*_*\n");
-
-    assert_eq!(
-        // from spec: <p>foo <em>_</em></p>
-        [
-            paragraph!(plain!("foo	"), emph!(plain!("_")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t*_*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7097
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7097>
@@ -32953,22 +28454,6 @@ fn t438_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7097
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7097>
-/// <https://spec.commonmark.org/0.29/#example-438>
-fn t438_tab() {
-    init!("foo\t*****\n");
-
-    assert_eq!(
-        // from spec: <p>foo *****</p>
-        [
-            paragraph!(plain!("foo	*****"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t*****\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7104
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7104>
@@ -33033,22 +28518,6 @@ fn t439_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7104
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7104>
-/// <https://spec.commonmark.org/0.29/#example-439>
-fn t439_tab() {
-    init!("foo\t**\\***\n");
-
-    assert_eq!(
-        // from spec: <p>foo <strong>*</strong></p>
-        [
-            paragraph!(plain!("foo	"), strong!(plain!("*")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t**\\***\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7111
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7111>
@@ -33113,22 +28582,6 @@ fn t440_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7111
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7111>
-/// <https://spec.commonmark.org/0.29/#example-440>
-fn t440_tab() {
-    init!("foo\t**_**\n");
-
-    assert_eq!(
-        // from spec: <p>foo <strong>_</strong></p>
-        [
-            paragraph!(plain!("foo	"), strong!(plain!("_")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t**_**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7122
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7122>
@@ -33577,22 +29030,6 @@ fn t447_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7167
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7167>
-/// <https://spec.commonmark.org/0.29/#example-447>
-fn t447_tab() {
-    init!("foo\t___\n");
-
-    assert_eq!(
-        // from spec: <p>foo ___</p>
-        [
-            paragraph!(plain!("foo	___"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t___\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7174
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7174>
@@ -33657,22 +29094,6 @@ fn t448_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7174
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7174>
-/// <https://spec.commonmark.org/0.29/#example-448>
-fn t448_tab() {
-    init!("foo\t_\\__\n");
-
-    assert_eq!(
-        // from spec: <p>foo <em>_</em></p>
-        [
-            paragraph!(plain!("foo	"), emph!(plain!("_")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t_\\__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7181
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7181>
@@ -33737,22 +29158,6 @@ fn t449_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7181
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7181>
-/// <https://spec.commonmark.org/0.29/#example-449>
-fn t449_tab() {
-    init!("foo\t_*_\n");
-
-    assert_eq!(
-        // from spec: <p>foo <em>*</em></p>
-        [
-            paragraph!(plain!("foo	"), emph!(plain!("*")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t_*_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7188
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7188>
@@ -33817,22 +29222,6 @@ fn t450_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7188
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7188>
-/// <https://spec.commonmark.org/0.29/#example-450>
-fn t450_tab() {
-    init!("foo\t_____\n");
-
-    assert_eq!(
-        // from spec: <p>foo _____</p>
-        [
-            paragraph!(plain!("foo	_____"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t_____\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7195
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7195>
@@ -33897,22 +29286,6 @@ fn t451_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7195
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7195>
-/// <https://spec.commonmark.org/0.29/#example-451>
-fn t451_tab() {
-    init!("foo\t__\\___\n");
-
-    assert_eq!(
-        // from spec: <p>foo <strong>_</strong></p>
-        [
-            paragraph!(plain!("foo	"), strong!(plain!("_")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t__\\___\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7202
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7202>
@@ -33977,22 +29350,6 @@ fn t452_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7202
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7202>
-/// <https://spec.commonmark.org/0.29/#example-452>
-fn t452_tab() {
-    init!("foo\t__*__\n");
-
-    assert_eq!(
-        // from spec: <p>foo <strong>*</strong></p>
-        [
-            paragraph!(plain!("foo	"), strong!(plain!("*")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t__*__\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7209
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7209>
@@ -35017,22 +30374,6 @@ fn t468_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7332
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7332>
-/// <https://spec.commonmark.org/0.29/#example-468>
-fn t468_tab() {
-    init!("*foo\t_bar*\tbaz_\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo _bar</em> baz_</p>
-        [
-            paragraph!(emph!(plain!("foo	_bar")), plain!("	baz_"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t_bar*\tbaz_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7339
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7339>
@@ -35097,22 +30438,6 @@ fn t469_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7339
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7339>
-/// <https://spec.commonmark.org/0.29/#example-469>
-fn t469_tab() {
-    init!("*foo\t__bar\t*baz\tbim__\tbam*\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo <strong>bar *baz bim</strong> bam</em></p>
-        [
-            paragraph!(emph!(plain!("foo	"), strong!(plain!("bar	*baz	bim")), plain!("	bam")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t__bar\t*baz\tbim__\tbam*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7348
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7348>
@@ -35177,22 +30502,6 @@ fn t470_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7348
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7348>
-/// <https://spec.commonmark.org/0.29/#example-470>
-fn t470_tab() {
-    init!("**foo\t**bar\tbaz**\n");
-
-    assert_eq!(
-        // from spec: <p>**foo <strong>bar baz</strong></p>
-        [
-            paragraph!(plain!("**foo	"), strong!(plain!("bar	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**foo\t**bar\tbaz**\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7355
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7355>
@@ -35257,22 +30566,6 @@ fn t471_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7355
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7355>
-/// <https://spec.commonmark.org/0.29/#example-471>
-fn t471_tab() {
-    init!("*foo\t*bar\tbaz*\n");
-
-    assert_eq!(
-        // from spec: <p>*foo <em>bar baz</em></p>
-        [
-            paragraph!(plain!("*foo	"), emph!(plain!("bar	baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t*bar\tbaz*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7364
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7364>
@@ -35401,22 +30694,6 @@ fn t473_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7371
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7371>
-/// <https://spec.commonmark.org/0.29/#example-473>
-fn t473_tab() {
-    init!("_foo\t[bar_](/url)\n");
-
-    assert_eq!(
-        // from spec: <p>_foo <a href=\"/url\">bar_</a></p>
-        [
-            paragraph!(plain!("_foo	"), link!("/url", "", plain!("bar_")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("_foo\t[bar_](/url)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7378
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7378>
@@ -35481,22 +30758,6 @@ fn t474_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7378
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7378>
-/// <https://spec.commonmark.org/0.29/#example-474>
-fn t474_tab() {
-    init!("*<img\tsrc=\"foo\"\ttitle=\"*\"/>\n");
-
-    assert_eq!(
-        // from spec: <p>*<img src=\"foo\" title=\"*\"/></p>
-        [
-            paragraph!(plain!("*"),	html!("<img	src=\"foo\"	title=\"*\"/>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*<img\tsrc=\"foo\"\ttitle=\"*\"/>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7385
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7385>
@@ -35561,22 +30822,6 @@ fn t475_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7385
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7385>
-/// <https://spec.commonmark.org/0.29/#example-475>
-fn t475_tab() {
-    init!("**<a\thref=\"**\">\n");
-
-    assert_eq!(
-        // from spec: <p>**<a href=\"**\"></p>
-        [
-            paragraph!(plain!("**"),	html!("<a	href=\"**\">"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("**<a\thref=\"**\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7392
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7392>
@@ -35641,22 +30886,6 @@ fn t476_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7392
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7392>
-/// <https://spec.commonmark.org/0.29/#example-476>
-fn t476_tab() {
-    init!("__<a\thref=\"__\">\n");
-
-    assert_eq!(
-        // from spec: <p>__<a href=\"__\"></p>
-        [
-            paragraph!(plain!("__"),	html!("<a	href=\"__\">"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("__<a\thref=\"__\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7399
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7399>
@@ -35721,22 +30950,6 @@ fn t477_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7399
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7399>
-/// <https://spec.commonmark.org/0.29/#example-477>
-fn t477_tab() {
-    init!("*a\t`*`*\n");
-
-    assert_eq!(
-        // from spec: <p><em>a <code>*</code></em></p>
-        [
-            paragraph!(emph!(plain!("a	"), code!("*")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*a\t`*`*\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7406
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7406>
@@ -35801,22 +31014,6 @@ fn t478_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7406
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7406>
-/// <https://spec.commonmark.org/0.29/#example-478>
-fn t478_tab() {
-    init!("_a\t`_`_\n");
-
-    assert_eq!(
-        // from spec: <p><em>a <code>_</code></em></p>
-        [
-            paragraph!(emph!(plain!("a	"), code!("_")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("_a\t`_`_\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7413
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7413>
@@ -36009,22 +31206,6 @@ fn t481_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7503
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7503>
-/// <https://spec.commonmark.org/0.29/#example-481>
-fn t481_tab() {
-    init!("[link](/uri\t\"title\")\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\" title=\"title\">link</a></p>
-        [
-            paragraph!(link!("/uri", "title", plain!("link")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link](/uri\t\"title\")\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7512
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7512>
@@ -36227,7 +31408,7 @@ fn t485() {
     assert_eq!(
         // from spec: <p>[link](/my uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(/my uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(/my uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link](/my uri)\n", ParserSettings::Html)),
     );
@@ -36243,7 +31424,7 @@ fn t485_cr() {
     assert_eq!(
         // from spec: <p>[link](/my uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(/my uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(/my uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link](/my uri)\r", ParserSettings::Html)),
     );
@@ -36259,7 +31440,7 @@ fn t485_cr_nl() {
     assert_eq!(
         // from spec: <p>[link](/my uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(/my uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(/my uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link](/my uri)\r\n", ParserSettings::Html)),
     );
@@ -36275,28 +31456,12 @@ fn t485_no_nl() {
     assert_eq!(
         // from spec: <p>[link](/my uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(/my uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(/my uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link](/my uri)", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7537
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7537>
-/// <https://spec.commonmark.org/0.29/#example-485>
-fn t485_tab() {
-    init!("[link](/my\turi)\n");
-
-    assert_eq!(
-        // from spec: <p>[link](/my uri)</p>
-        [
-            paragraph!(linkref!(plain!("link")),	plain!("(/my	uri)"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link](/my\turi)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7543
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7543>
@@ -36361,22 +31526,6 @@ fn t486_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7543
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7543>
-/// <https://spec.commonmark.org/0.29/#example-486>
-fn t486_tab() {
-    init!("[link](</my\turi>)\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/my%20uri\">link</a></p>
-        [
-            paragraph!(link!("/my\turi", "", plain!("link")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link](</my\turi>)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7552
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7552>
@@ -36387,7 +31536,7 @@ fn t487() {
     assert_eq!(
         // from spec: <p>[link](foo\nbar)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(foo"), SoftBreak, plain!("bar)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(foo"), soft_break!(), plain!("bar)"))
         ].as_slice(),
         body(&mut StringData::new("[link](foo\nbar)\n", ParserSettings::Html)),
     );
@@ -36403,7 +31552,7 @@ fn t487_cr() {
     assert_eq!(
         // from spec: <p>[link](foo\nbar)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(foo"), SoftBreak, plain!("bar)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(foo"), soft_break!(), plain!("bar)"))
         ].as_slice(),
         body(&mut StringData::new("[link](foo\rbar)\r", ParserSettings::Html)),
     );
@@ -36419,7 +31568,7 @@ fn t487_cr_nl() {
     assert_eq!(
         // from spec: <p>[link](foo\nbar)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(foo"), SoftBreak, plain!("bar)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(foo"), soft_break!(), plain!("bar)"))
         ].as_slice(),
         body(&mut StringData::new("[link](foo\r\nbar)\r\n", ParserSettings::Html)),
     );
@@ -36435,7 +31584,7 @@ fn t487_no_nl() {
     assert_eq!(
         // from spec: <p>[link](foo\nbar)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(foo"), SoftBreak, plain!("bar)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(foo"), soft_break!(), plain!("bar)"))
         ].as_slice(),
         body(&mut StringData::new("[link](foo\nbar)", ParserSettings::Html)),
     );
@@ -36452,7 +31601,7 @@ fn t488() {
         // from spec: <p>[link](<foo\nbar>)</p>
         [
             paragraph!(
-              linkref!(plain!("link")), plain!("("), html!("<foo\nbar>"), plain!(")")
+              linkref!("link", plain!("link")), plain!("("), html!("<foo\nbar>"), plain!(")")
             )
         ].as_slice(),
         body(&mut StringData::new("[link](<foo\nbar>)\n", ParserSettings::Html)),
@@ -36470,7 +31619,7 @@ fn t488_cr() {
         // from spec: <p>[link](<foo\nbar>)</p>
         [
             paragraph!(
-              linkref!(plain!("link")), plain!("("), html!("<foo\rbar>"), plain!(")")
+              linkref!("link", plain!("link")), plain!("("), html!("<foo\rbar>"), plain!(")")
             )
         ].as_slice(),
         body(&mut StringData::new("[link](<foo\rbar>)\r", ParserSettings::Html)),
@@ -36488,7 +31637,7 @@ fn t488_cr_nl() {
         // from spec: <p>[link](<foo\nbar>)</p>
         [
             paragraph!(
-              linkref!(plain!("link")), plain!("("), html!("<foo\r\nbar>"), plain!(")")
+              linkref!("link", plain!("link")), plain!("("), html!("<foo\r\nbar>"), plain!(")")
             )
         ].as_slice(),
         body(&mut StringData::new("[link](<foo\r\nbar>)\r\n", ParserSettings::Html)),
@@ -36506,7 +31655,7 @@ fn t488_no_nl() {
         // from spec: <p>[link](<foo\nbar>)</p>
         [
             paragraph!(
-              linkref!(plain!("link")), plain!("("), html!("<foo\nbar>"), plain!(")")
+              linkref!("link", plain!("link")), plain!("("), html!("<foo\nbar>"), plain!(")")
             )
         ].as_slice(),
         body(&mut StringData::new("[link](<foo\nbar>)", ParserSettings::Html)),
@@ -36587,7 +31736,7 @@ fn t490() {
     assert_eq!(
         // from spec: <p>[link](&lt;foo&gt;)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(<foo>)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(<foo>)"))
         ].as_slice(),
         body(&mut StringData::new("[link](<foo\\>)\n", ParserSettings::Html)),
     );
@@ -36603,7 +31752,7 @@ fn t490_cr() {
     assert_eq!(
         // from spec: <p>[link](&lt;foo&gt;)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(<foo>)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(<foo>)"))
         ].as_slice(),
         body(&mut StringData::new("[link](<foo\\>)\r", ParserSettings::Html)),
     );
@@ -36619,7 +31768,7 @@ fn t490_cr_nl() {
     assert_eq!(
         // from spec: <p>[link](&lt;foo&gt;)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(<foo>)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(<foo>)"))
         ].as_slice(),
         body(&mut StringData::new("[link](<foo\\>)\r\n", ParserSettings::Html)),
     );
@@ -36635,7 +31784,7 @@ fn t490_no_nl() {
     assert_eq!(
         // from spec: <p>[link](&lt;foo&gt;)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(<foo>)"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(<foo>)"))
         ].as_slice(),
         body(&mut StringData::new("[link](<foo\\>)", ParserSettings::Html)),
     );
@@ -36652,9 +31801,9 @@ fn t491() {
         // from spec: <p>[a](&lt;b)c\n[a](&lt;b)c&gt;\n[a](<b>c)</p>
         [
             paragraph!(
-              linkref!(plain!("a")), plain!("(<b)c"), SoftBreak,
-              linkref!(plain!("a")), plain!("(<b)c>"), SoftBreak,
-              linkref!(plain!("a")), plain!("("), html!("<b>"), plain!("c)")
+              linkref!("a", plain!("a")), plain!("(<b)c"), soft_break!(),
+              linkref!("a", plain!("a")), plain!("(<b)c>"), soft_break!(),
+              linkref!("a", plain!("a")), plain!("("), html!("<b>"), plain!("c)")
             )
         ].as_slice(),
         body(&mut StringData::new("[a](<b)c\n[a](<b)c>\n[a](<b>c)\n", ParserSettings::Html)),
@@ -36672,9 +31821,9 @@ fn t491_cr() {
         // from spec: <p>[a](&lt;b)c\n[a](&lt;b)c&gt;\n[a](<b>c)</p>
         [
             paragraph!(
-              linkref!(plain!("a")), plain!("(<b)c"), SoftBreak,
-              linkref!(plain!("a")), plain!("(<b)c>"), SoftBreak,
-              linkref!(plain!("a")), plain!("("), html!("<b>"), plain!("c)")
+              linkref!("a", plain!("a")), plain!("(<b)c"), soft_break!(),
+              linkref!("a", plain!("a")), plain!("(<b)c>"), soft_break!(),
+              linkref!("a", plain!("a")), plain!("("), html!("<b>"), plain!("c)")
             )
         ].as_slice(),
         body(&mut StringData::new("[a](<b)c\r[a](<b)c>\r[a](<b>c)\r", ParserSettings::Html)),
@@ -36692,9 +31841,9 @@ fn t491_cr_nl() {
         // from spec: <p>[a](&lt;b)c\n[a](&lt;b)c&gt;\n[a](<b>c)</p>
         [
             paragraph!(
-              linkref!(plain!("a")), plain!("(<b)c"), SoftBreak,
-              linkref!(plain!("a")), plain!("(<b)c>"), SoftBreak,
-              linkref!(plain!("a")), plain!("("), html!("<b>"), plain!("c)")
+              linkref!("a", plain!("a")), plain!("(<b)c"), soft_break!(),
+              linkref!("a", plain!("a")), plain!("(<b)c>"), soft_break!(),
+              linkref!("a", plain!("a")), plain!("("), html!("<b>"), plain!("c)")
             )
         ].as_slice(),
         body(&mut StringData::new("[a](<b)c\r\n[a](<b)c>\r\n[a](<b>c)\r\n", ParserSettings::Html)),
@@ -36712,9 +31861,9 @@ fn t491_no_nl() {
         // from spec: <p>[a](&lt;b)c\n[a](&lt;b)c&gt;\n[a](<b>c)</p>
         [
             paragraph!(
-              linkref!(plain!("a")), plain!("(<b)c"), SoftBreak,
-              linkref!(plain!("a")), plain!("(<b)c>"), SoftBreak,
-              linkref!(plain!("a")), plain!("("), html!("<b>"), plain!("c)")
+              linkref!("a", plain!("a")), plain!("(<b)c"), soft_break!(),
+              linkref!("a", plain!("a")), plain!("(<b)c>"), soft_break!(),
+              linkref!("a", plain!("a")), plain!("("), html!("<b>"), plain!("c)")
             )
         ].as_slice(),
         body(&mut StringData::new("[a](<b)c\n[a](<b)c>\n[a](<b>c)", ParserSettings::Html)),
@@ -37307,7 +32456,7 @@ fn t501() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a></p>
         [
-            paragraph!(link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")))
+            paragraph!(link!("/url", "title", plain!("link")), soft_break!(), link!("/url", "title", plain!("link")), soft_break!(), link!("/url", "title", plain!("link")))
         ].as_slice(),
         body(&mut StringData::new("[link](/url \"title\")\n[link](/url \'title\')\n[link](/url (title))\n", ParserSettings::Html)),
     );
@@ -37323,7 +32472,7 @@ fn t501_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a></p>
         [
-            paragraph!(link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")))
+            paragraph!(link!("/url", "title", plain!("link")), soft_break!(), link!("/url", "title", plain!("link")), soft_break!(), link!("/url", "title", plain!("link")))
         ].as_slice(),
         body(&mut StringData::new("[link](/url \"title\")\r[link](/url \'title\')\r[link](/url (title))\r", ParserSettings::Html)),
     );
@@ -37339,7 +32488,7 @@ fn t501_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a></p>
         [
-            paragraph!(link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")))
+            paragraph!(link!("/url", "title", plain!("link")), soft_break!(), link!("/url", "title", plain!("link")), soft_break!(), link!("/url", "title", plain!("link")))
         ].as_slice(),
         body(&mut StringData::new("[link](/url \"title\")\r\n[link](/url \'title\')\r\n[link](/url (title))\r\n", ParserSettings::Html)),
     );
@@ -37355,28 +32504,12 @@ fn t501_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a></p>
         [
-            paragraph!(link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")))
+            paragraph!(link!("/url", "title", plain!("link")), soft_break!(), link!("/url", "title", plain!("link")), soft_break!(), link!("/url", "title", plain!("link")))
         ].as_slice(),
         body(&mut StringData::new("[link](/url \"title\")\n[link](/url \'title\')\n[link](/url (title))", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7696
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7696>
-/// <https://spec.commonmark.org/0.29/#example-501>
-fn t501_tab() {
-    init!("[link](/url\t\"title\")\n[link](/url\t\'title\')\n[link](/url\t(title))\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a>\n<a href=\"/url\" title=\"title\">link</a></p>
-        [
-            paragraph!(link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")), SoftBreak, link!("/url", "title", plain!("link")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link](/url\t\"title\")\n[link](/url\t\'title\')\n[link](/url\t(title))\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7710
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7710>
@@ -37441,22 +32574,6 @@ fn t502_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7710
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7710>
-/// <https://spec.commonmark.org/0.29/#example-502>
-fn t502_tab() {
-    init!("[link](/url\t\"title\t\\\"&quot;\")\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title &quot;&quot;\">link</a></p>
-        [
-            paragraph!(link!("/url", "title\t\"\"", plain!("link")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link](/url\t\"title\t\\\"&quot;\")\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7720
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7720>
@@ -37531,7 +32648,7 @@ fn t504() {
     assert_eq!(
         // from spec: <p>[link](/url &quot;title &quot;and&quot; title&quot;)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(/url \"title \"and\" title\")"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(/url \"title \"and\" title\")"))
         ].as_slice(),
         body(&mut StringData::new("[link](/url \"title \"and\" title\")\n", ParserSettings::Html)),
     );
@@ -37547,7 +32664,7 @@ fn t504_cr() {
     assert_eq!(
         // from spec: <p>[link](/url &quot;title &quot;and&quot; title&quot;)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(/url \"title \"and\" title\")"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(/url \"title \"and\" title\")"))
         ].as_slice(),
         body(&mut StringData::new("[link](/url \"title \"and\" title\")\r", ParserSettings::Html)),
     );
@@ -37563,7 +32680,7 @@ fn t504_cr_nl() {
     assert_eq!(
         // from spec: <p>[link](/url &quot;title &quot;and&quot; title&quot;)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(/url \"title \"and\" title\")"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(/url \"title \"and\" title\")"))
         ].as_slice(),
         body(&mut StringData::new("[link](/url \"title \"and\" title\")\r\n", ParserSettings::Html)),
     );
@@ -37579,28 +32696,12 @@ fn t504_no_nl() {
     assert_eq!(
         // from spec: <p>[link](/url &quot;title &quot;and&quot; title&quot;)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!("(/url \"title \"and\" title\")"))
+            paragraph!(linkref!("link", plain!("link")), plain!("(/url \"title \"and\" title\")"))
         ].as_slice(),
         body(&mut StringData::new("[link](/url \"title \"and\" title\")", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7729
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7729>
-/// <https://spec.commonmark.org/0.29/#example-504>
-fn t504_tab() {
-    init!("[link](/url\t\"title\t\"and\"\ttitle\")\n");
-
-    assert_eq!(
-        // from spec: <p>[link](/url &quot;title &quot;and&quot; title&quot;)</p>
-        [
-            paragraph!(linkref!(plain!("link")),	plain!("(/url	\"title	\"and\"	title\")"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link](/url\t\"title\t\"and\"\ttitle\")\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7738
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7738>
@@ -37665,22 +32766,6 @@ fn t505_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7738
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7738>
-/// <https://spec.commonmark.org/0.29/#example-505>
-fn t505_tab() {
-    init!("[link](/url\t\'title\t\"and\"\ttitle\')\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title &quot;and&quot; title\">link</a></p>
-        [
-            paragraph!(link!("/url", "title\t\"and\"\ttitle", plain!("link")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link](/url\t\'title\t\"and\"\ttitle\')\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7762
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7762>
@@ -37745,22 +32830,6 @@ fn t506_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7762
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7762>
-/// <https://spec.commonmark.org/0.29/#example-506>
-fn t506_tab() {
-    init!("[link](\t\t\t/uri\n  \"title\"\t\t)\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\" title=\"title\">link</a></p>
-        [
-            paragraph!(link!("/uri", "title", plain!("link")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link](\t\t\t/uri\n  \"title\"\t\t)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7773
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7773>
@@ -37771,7 +32840,7 @@ fn t507() {
     assert_eq!(
         // from spec: <p>[link] (/uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!(" (/uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!(" (/uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link] (/uri)\n", ParserSettings::Html)),
     );
@@ -37787,7 +32856,7 @@ fn t507_cr() {
     assert_eq!(
         // from spec: <p>[link] (/uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!(" (/uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!(" (/uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link] (/uri)\r", ParserSettings::Html)),
     );
@@ -37803,7 +32872,7 @@ fn t507_cr_nl() {
     assert_eq!(
         // from spec: <p>[link] (/uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!(" (/uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!(" (/uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link] (/uri)\r\n", ParserSettings::Html)),
     );
@@ -37819,28 +32888,12 @@ fn t507_no_nl() {
     assert_eq!(
         // from spec: <p>[link] (/uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!(" (/uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!(" (/uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link] (/uri)", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7773
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7773>
-/// <https://spec.commonmark.org/0.29/#example-507>
-fn t507_tab() {
-    init!("[link]\t(/uri)\n");
-
-    assert_eq!(
-        // from spec: <p>[link] (/uri)</p>
-        [
-            paragraph!(linkref!(plain!("link")),	plain!("	(/uri)"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link]\t(/uri)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7783
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7783>
@@ -37854,7 +32907,7 @@ fn t508() {
             paragraph!(link!(
               "/uri", "",
               plain!("link "),
-              linkref!(plain!("foo "), linkref!(plain!("bar")))
+              linkref!(plain!("foo "), linkref!("bar", plain!("bar")))
             ))
         ].as_slice(),
         body(&mut StringData::new("[link [foo [bar]]](/uri)\n", ParserSettings::Html)),
@@ -37874,7 +32927,7 @@ fn t508_cr() {
             paragraph!(link!(
               "/uri", "",
               plain!("link "),
-              linkref!(plain!("foo "), linkref!(plain!("bar")))
+              linkref!(plain!("foo "), linkref!("bar", plain!("bar")))
             ))
         ].as_slice(),
         body(&mut StringData::new("[link [foo [bar]]](/uri)\r", ParserSettings::Html)),
@@ -37894,7 +32947,7 @@ fn t508_cr_nl() {
             paragraph!(link!(
               "/uri", "",
               plain!("link "),
-              linkref!(plain!("foo "), linkref!(plain!("bar")))
+              linkref!(plain!("foo "), linkref!("bar", plain!("bar")))
             ))
         ].as_slice(),
         body(&mut StringData::new("[link [foo [bar]]](/uri)\r\n", ParserSettings::Html)),
@@ -37914,33 +32967,13 @@ fn t508_no_nl() {
             paragraph!(link!(
               "/uri", "",
               plain!("link "),
-              linkref!(plain!("foo "), linkref!(plain!("bar")))
+              linkref!(plain!("foo "), linkref!("bar", plain!("bar")))
             ))
         ].as_slice(),
         body(&mut StringData::new("[link [foo [bar]]](/uri)", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7783
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7783>
-/// <https://spec.commonmark.org/0.29/#example-508>
-fn t508_tab() {
-    init!("[link\t[foo\t[bar]]](/uri)\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">link [foo [bar]]</a></p>
-        [
-            paragraph!(link!(
-            		"/uri",	"",
-            		plain!("link	"),
-            		linkref!(plain!("foo	"),	linkref!(plain!("bar")))
-            ))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link\t[foo\t[bar]]](/uri)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7790
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7790>
@@ -37951,7 +32984,7 @@ fn t509() {
     assert_eq!(
         // from spec: <p>[link] bar](/uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!(" bar](/uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!(" bar](/uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link] bar](/uri)\n", ParserSettings::Html)),
     );
@@ -37967,7 +33000,7 @@ fn t509_cr() {
     assert_eq!(
         // from spec: <p>[link] bar](/uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!(" bar](/uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!(" bar](/uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link] bar](/uri)\r", ParserSettings::Html)),
     );
@@ -37983,7 +33016,7 @@ fn t509_cr_nl() {
     assert_eq!(
         // from spec: <p>[link] bar](/uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!(" bar](/uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!(" bar](/uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link] bar](/uri)\r\n", ParserSettings::Html)),
     );
@@ -37999,28 +33032,12 @@ fn t509_no_nl() {
     assert_eq!(
         // from spec: <p>[link] bar](/uri)</p>
         [
-            paragraph!(linkref!(plain!("link")), plain!(" bar](/uri)"))
+            paragraph!(linkref!("link", plain!("link")), plain!(" bar](/uri)"))
         ].as_slice(),
         body(&mut StringData::new("[link] bar](/uri)", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7790
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7790>
-/// <https://spec.commonmark.org/0.29/#example-509>
-fn t509_tab() {
-    init!("[link]\tbar](/uri)\n");
-
-    assert_eq!(
-        // from spec: <p>[link] bar](/uri)</p>
-        [
-            paragraph!(linkref!(plain!("link")),	plain!("	bar](/uri)"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link]\tbar](/uri)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7797
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7797>
@@ -38085,22 +33102,6 @@ fn t510_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7797
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7797>
-/// <https://spec.commonmark.org/0.29/#example-510>
-fn t510_tab() {
-    init!("[link\t[bar](/uri)\n");
-
-    assert_eq!(
-        // from spec: <p>[link <a href=\"/uri\">bar</a></p>
-        [
-            paragraph!(plain!("[link	"), link!("/uri", "", plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link\t[bar](/uri)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7804
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7804>
@@ -38165,22 +33166,6 @@ fn t511_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7804
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7804>
-/// <https://spec.commonmark.org/0.29/#example-511>
-fn t511_tab() {
-    init!("[link\t\\[bar](/uri)\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">link [bar</a></p>
-        [
-            paragraph!(link!("/uri", "", plain!("link	[bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link\t\\[bar](/uri)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7813
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7813>
@@ -38245,22 +33230,6 @@ fn t512_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7813
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7813>
-/// <https://spec.commonmark.org/0.29/#example-512>
-fn t512_tab() {
-    init!("[link\t*foo\t**bar**\t`#`*](/uri)\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">link <em>foo <strong>bar</strong> <code>#</code></em></a></p>
-        [
-            paragraph!(link!("/uri", "", plain!("link	"), emph!(plain!("foo	"), strong!(plain!("bar")), plain!("	"), code!("#"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link\t*foo\t**bar**\t`#`*](/uri)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7820
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7820>
@@ -38389,22 +33358,6 @@ fn t514_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7829
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7829>
-/// <https://spec.commonmark.org/0.29/#example-514>
-fn t514_tab() {
-    init!("[foo\t[bar](/uri)](/uri)\n");
-
-    assert_eq!(
-        // from spec: <p>[foo <a href=\"/uri\">bar</a>](/uri)</p>
-        [
-            paragraph!(plain!("[foo	"), link!("/uri", "", plain!("bar")), plain!("](/uri)"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo\t[bar](/uri)](/uri)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7836
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7836>
@@ -38469,22 +33422,6 @@ fn t515_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7836
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7836>
-/// <https://spec.commonmark.org/0.29/#example-515>
-fn t515_tab() {
-    init!("[foo\t*[bar\t[baz](/uri)](/uri)*](/uri)\n");
-
-    assert_eq!(
-        // from spec: <p>[foo <em>[bar <a href=\"/uri\">baz</a>](/uri)</em>](/uri)</p>
-        [
-            paragraph!(plain!("[foo	"), emph!(plain!("[bar	"), link!("/uri", "", plain!("baz")), plain!("](/uri)")), plain!("](/uri)"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo\t*[bar\t[baz](/uri)](/uri)*](/uri)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7843
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7843>
@@ -38677,22 +33614,6 @@ fn t518_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7860
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7860>
-/// <https://spec.commonmark.org/0.29/#example-518>
-fn t518_tab() {
-    init!("[foo\t*bar](baz*)\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"baz*\">foo *bar</a></p>
-        [
-            paragraph!(link!("baz*", "", plain!("foo	*bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo\t*bar](baz*)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7870
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7870>
@@ -38757,22 +33678,6 @@ fn t519_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7870
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7870>
-/// <https://spec.commonmark.org/0.29/#example-519>
-fn t519_tab() {
-    init!("*foo\t[bar*\tbaz]\n");
-
-    assert_eq!(
-        // from spec: <p><em>foo [bar</em> baz]</p>
-        [
-            paragraph!(emph!(plain!("foo	[bar")), plain!("	baz]"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*foo\t[bar*\tbaz]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7880
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7880>
@@ -38837,22 +33742,6 @@ fn t520_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7880
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7880>
-/// <https://spec.commonmark.org/0.29/#example-520>
-fn t520_tab() {
-    init!("[foo\t<bar\tattr=\"](baz)\">\n");
-
-    assert_eq!(
-        // from spec: <p>[foo <bar attr=\"](baz)\"></p>
-        [
-            paragraph!(plain!("[foo	"),	html!("<bar	attr=\"](baz)\">"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo\t<bar\tattr=\"](baz)\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7887
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7887>
@@ -39049,23 +33938,6 @@ fn t523_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7931
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7931>
-/// <https://spec.commonmark.org/0.29/#example-523>
-fn t523_tab() {
-    init!("[foo][bar]\n\n[bar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
-        [
-            paragraph!(linkref!("bar",	plain!("foo"))),
-            linkdef!("bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][bar]\n\n[bar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7946
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7946>
@@ -39079,7 +33951,7 @@ fn t524() {
             paragraph!(linkref!(
               "ref",
               plain!("link "),
-              linkref!(plain!("foo "), linkref!(plain!("bar")))
+              linkref!(plain!("foo "), linkref!("bar", plain!("bar")))
             )),
             linkdef!("ref", "/uri")
         ].as_slice(),
@@ -39100,7 +33972,7 @@ fn t524_cr() {
             paragraph!(linkref!(
               "ref",
               plain!("link "),
-              linkref!(plain!("foo "), linkref!(plain!("bar")))
+              linkref!(plain!("foo "), linkref!("bar", plain!("bar")))
             )),
             linkdef!("ref", "/uri")
         ].as_slice(),
@@ -39121,7 +33993,7 @@ fn t524_cr_nl() {
             paragraph!(linkref!(
               "ref",
               plain!("link "),
-              linkref!(plain!("foo "), linkref!(plain!("bar")))
+              linkref!(plain!("foo "), linkref!("bar", plain!("bar")))
             )),
             linkdef!("ref", "/uri")
         ].as_slice(),
@@ -39142,7 +34014,7 @@ fn t524_no_nl() {
             paragraph!(linkref!(
               "ref",
               plain!("link "),
-              linkref!(plain!("foo "), linkref!(plain!("bar")))
+              linkref!(plain!("foo "), linkref!("bar", plain!("bar")))
             )),
             linkdef!("ref", "/uri")
         ].as_slice(),
@@ -39150,27 +34022,6 @@ fn t524_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7946
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7946>
-/// <https://spec.commonmark.org/0.29/#example-524>
-fn t524_tab() {
-    init!("[link\t[foo\t[bar]]][ref]\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">link [foo [bar]]</a></p>
-        [
-            paragraph!(linkref!(
-            		"ref",
-            		plain!("link	"),
-            		linkref!(plain!("foo	"),	linkref!(plain!("bar")))
-            )),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link\t[foo\t[bar]]][ref]\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7955
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7955>
@@ -39239,23 +34090,6 @@ fn t525_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7955
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7955>
-/// <https://spec.commonmark.org/0.29/#example-525>
-fn t525_tab() {
-    init!("[link\t\\[bar][ref]\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">link [bar</a></p>
-        [
-            paragraph!(linkref!("ref",	plain!("link	[bar"))),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link\t\\[bar][ref]\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7966
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7966>
@@ -39340,27 +34174,6 @@ fn t526_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7966
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7966>
-/// <https://spec.commonmark.org/0.29/#example-526>
-fn t526_tab() {
-    init!("[link\t*foo\t**bar**\t`#`*][ref]\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">link <em>foo <strong>bar</strong> <code>#</code></em></a></p>
-        [
-            paragraph!(linkref!(
-            		"ref",
-            		plain!("link	"),
-            		emph!(plain!("foo	"),	strong!(plain!("bar")),	plain!("	"),	code!("#"))
-            )),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[link\t*foo\t**bar**\t`#`*][ref]\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7975
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7975>
@@ -39429,23 +34242,6 @@ fn t527_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7975
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7975>
-/// <https://spec.commonmark.org/0.29/#example-527>
-fn t527_tab() {
-    init!("[![moon](moon.jpg)][ref]\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\"><img src=\"moon.jpg\" alt=\"moon\" /></a></p>
-        [
-            paragraph!(linkref!("ref",	image!("moon.jpg",	"",	plain!("moon")))),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[![moon](moon.jpg)][ref]\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7986
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7986>
@@ -39458,7 +34254,7 @@ fn t528() {
         [
             paragraph!(
               plain!("[foo "), link!("/uri", "", plain!("bar")), plain!("]"),
-              linkref!(plain!("ref"))
+              linkref!("ref", plain!("ref"))
             ),
             linkdef!("ref", "/uri")
         ].as_slice(),
@@ -39478,7 +34274,7 @@ fn t528_cr() {
         [
             paragraph!(
               plain!("[foo "), link!("/uri", "", plain!("bar")), plain!("]"),
-              linkref!(plain!("ref"))
+              linkref!("ref", plain!("ref"))
             ),
             linkdef!("ref", "/uri")
         ].as_slice(),
@@ -39498,7 +34294,7 @@ fn t528_cr_nl() {
         [
             paragraph!(
               plain!("[foo "), link!("/uri", "", plain!("bar")), plain!("]"),
-              linkref!(plain!("ref"))
+              linkref!("ref", plain!("ref"))
             ),
             linkdef!("ref", "/uri")
         ].as_slice(),
@@ -39518,7 +34314,7 @@ fn t528_no_nl() {
         [
             paragraph!(
               plain!("[foo "), link!("/uri", "", plain!("bar")), plain!("]"),
-              linkref!(plain!("ref"))
+              linkref!("ref", plain!("ref"))
             ),
             linkdef!("ref", "/uri")
         ].as_slice(),
@@ -39526,26 +34322,6 @@ fn t528_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7986
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7986>
-/// <https://spec.commonmark.org/0.29/#example-528>
-fn t528_tab() {
-    init!("[foo\t[bar](/uri)][ref]\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[foo <a href=\"/uri\">bar</a>]<a href=\"/uri\">ref</a></p>
-        [
-            paragraph!(
-            		plain!("[foo	"),	link!("/uri",	"",	plain!("bar")),	plain!("]"),
-            		linkref!(plain!("ref"))
-            ),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo\t[bar](/uri)][ref]\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 7995
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7995>
@@ -39638,29 +34414,6 @@ fn t529_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 7995
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L7995>
-/// <https://spec.commonmark.org/0.29/#example-529>
-fn t529_tab() {
-    init!("[foo\t*bar\t[baz][ref]*][ref]\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[foo <em>bar <a href=\"/uri\">baz</a></em>]<a href=\"/uri\">ref</a></p>
-        [
-            paragraph!(
-            		linkref!(
-            				"ref",
-            				plain!("foo	"),
-            				emph!(plain!("bar	"),	linkref!("ref",	plain!("baz")))
-            		)
-            ),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo\t*bar\t[baz][ref]*][ref]\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8010
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8010>
@@ -39729,23 +34482,6 @@ fn t530_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8010
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8010>
-/// <https://spec.commonmark.org/0.29/#example-530>
-fn t530_tab() {
-    init!("*[foo*][ref]\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>*<a href=\"/uri\">foo*</a></p>
-        [
-            paragraph!(plain!("*"),	linkref!("ref",	plain!("foo*"))),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("*[foo*][ref]\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8019
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8019>
@@ -39826,26 +34562,6 @@ fn t531_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8019
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8019>
-/// <https://spec.commonmark.org/0.29/#example-531>
-fn t531_tab() {
-    init!("[foo\t*bar][ref]*\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">foo *bar</a>*</p>
-        [
-            paragraph!(
-            		linkref!("ref",	plain!("foo	*bar")),
-            		plain!("*")
-            ),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo\t*bar][ref]*\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8031
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8031>
@@ -39914,23 +34630,6 @@ fn t532_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8031
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8031>
-/// <https://spec.commonmark.org/0.29/#example-532>
-fn t532_tab() {
-    init!("[foo\t<bar\tattr=\"][ref]\">\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[foo <bar attr=\"][ref]\"></p>
-        [
-            paragraph!(plain!("[foo	"),	html!("<bar	attr=\"][ref]\">")),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo\t<bar\tattr=\"][ref]\">\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8040
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8040>
@@ -39999,23 +34698,6 @@ fn t533_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8040
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8040>
-/// <https://spec.commonmark.org/0.29/#example-533>
-fn t533_tab() {
-    init!("[foo`][ref]`\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[foo<code>][ref]</code></p>
-        [
-            paragraph!(plain!("[foo"),	code!("][ref]")),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo`][ref]`\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8049
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8049>
@@ -40096,26 +34778,6 @@ fn t534_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8049
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8049>
-/// <https://spec.commonmark.org/0.29/#example-534>
-fn t534_tab() {
-    init!("[foo<http://example.com/?search=][ref]>\n\n[ref]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[foo<a href=\"http://example.com/?search=%5D%5Bref%5D\">http://example.com/?search=][ref]</a></p>
-        [
-            paragraph!(plain!("[foo"),
-            		link!("http://example.com/?search=][ref]",	"",
-            				plain!("http://example.com/?search=][ref]"))
-            ),
-            linkdef!("ref",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo<http://example.com/?search=][ref]>\n\n[ref]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8060
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8060>
@@ -40184,23 +34846,6 @@ fn t535_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8060
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8060>
-/// <https://spec.commonmark.org/0.29/#example-535>
-fn t535_tab() {
-    init!("[foo][BaR]\n\n[bar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
-        [
-            paragraph!(linkref!("BaR",	plain!("foo"))),
-            linkdef!("bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][BaR]\n\n[bar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8071
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8071>
@@ -40211,7 +34856,7 @@ fn t536() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">ẞ</a></p>
         [
-            paragraph!(linkref!(plain!("ẞ"))),
+            paragraph!(linkref!("ẞ", plain!("ẞ"))),
             linkdef!("SS", "/url")
         ].as_slice(),
         body(&mut StringData::new("[\u{1e9e}]\n\n[SS]: /url\n", ParserSettings::Html)),
@@ -40228,7 +34873,7 @@ fn t536_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">ẞ</a></p>
         [
-            paragraph!(linkref!(plain!("ẞ"))),
+            paragraph!(linkref!("ẞ", plain!("ẞ"))),
             linkdef!("SS", "/url")
         ].as_slice(),
         body(&mut StringData::new("[\u{1e9e}]\r\r[SS]: /url\r", ParserSettings::Html)),
@@ -40245,7 +34890,7 @@ fn t536_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">ẞ</a></p>
         [
-            paragraph!(linkref!(plain!("ẞ"))),
+            paragraph!(linkref!("ẞ", plain!("ẞ"))),
             linkdef!("SS", "/url")
         ].as_slice(),
         body(&mut StringData::new("[\u{1e9e}]\r\n\r\n[SS]: /url\r\n", ParserSettings::Html)),
@@ -40262,30 +34907,13 @@ fn t536_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">ẞ</a></p>
         [
-            paragraph!(linkref!(plain!("ẞ"))),
+            paragraph!(linkref!("ẞ", plain!("ẞ"))),
             linkdef!("SS", "/url")
         ].as_slice(),
         body(&mut StringData::new("[\u{1e9e}]\n\n[SS]: /url", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8071
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8071>
-/// <https://spec.commonmark.org/0.29/#example-536>
-fn t536_tab() {
-    init!("[ẞ]\n\n[SS]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\">ẞ</a></p>
-        [
-            paragraph!(linkref!(plain!("ẞ"))),
-            linkdef!("SS",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[\u{1e9e}]\n\n[SS]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8083
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8083>
@@ -40354,23 +34982,6 @@ fn t537_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8083
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8083>
-/// <https://spec.commonmark.org/0.29/#example-537>
-fn t537_tab() {
-    init!("[Foo\n  bar]:\t/url\n\n[Baz][Foo\tbar]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\">Baz</a></p>
-        [
-            linkdef!("Foo\n		bar",	"/url",	""),
-            paragraph!(linkref!("Foo	bar",	plain!("Baz")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[Foo\n  bar]:\t/url\n\n[Baz][Foo\tbar]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8096
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8096>
@@ -40381,7 +34992,7 @@ fn t538() {
     assert_eq!(
         // from spec: <p>[foo] <a href=\"/url\" title=\"title\">bar</a></p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(" "), linkref!(plain!("bar"))),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(" "), linkref!("bar", plain!("bar"))),
             linkdef!("bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo] [bar]\n\n[bar]: /url \"title\"\n", ParserSettings::Html)),
@@ -40398,7 +35009,7 @@ fn t538_cr() {
     assert_eq!(
         // from spec: <p>[foo] <a href=\"/url\" title=\"title\">bar</a></p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(" "), linkref!(plain!("bar"))),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(" "), linkref!("bar", plain!("bar"))),
             linkdef!("bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo] [bar]\r\r[bar]: /url \"title\"\r", ParserSettings::Html)),
@@ -40415,7 +35026,7 @@ fn t538_cr_nl() {
     assert_eq!(
         // from spec: <p>[foo] <a href=\"/url\" title=\"title\">bar</a></p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(" "), linkref!(plain!("bar"))),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(" "), linkref!("bar", plain!("bar"))),
             linkdef!("bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo] [bar]\r\n\r\n[bar]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -40432,30 +35043,13 @@ fn t538_no_nl() {
     assert_eq!(
         // from spec: <p>[foo] <a href=\"/url\" title=\"title\">bar</a></p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(" "), linkref!(plain!("bar"))),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(" "), linkref!("bar", plain!("bar"))),
             linkdef!("bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo] [bar]\n\n[bar]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8096
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8096>
-/// <https://spec.commonmark.org/0.29/#example-538>
-fn t538_tab() {
-    init!("[foo]\t[bar]\n\n[bar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p>[foo] <a href=\"/url\" title=\"title\">bar</a></p>
-        [
-            paragraph!(linkref!(plain!("foo")),	plain!("	"),	linkref!(plain!("bar"))),
-            linkdef!("bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\t[bar]\n\n[bar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8105
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8105>
@@ -40466,7 +35060,7 @@ fn t539() {
     assert_eq!(
         // from spec: <p>[foo]\n<a href=\"/url\" title=\"title\">bar</a></p>
         [
-            paragraph!(linkref!(plain!("foo")), SoftBreak, linkref!(plain!("bar"))),
+            paragraph!(linkref!("foo", plain!("foo")), soft_break!(), linkref!("bar", plain!("bar"))),
             linkdef!("bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n[bar]\n\n[bar]: /url \"title\"\n", ParserSettings::Html)),
@@ -40483,7 +35077,7 @@ fn t539_cr() {
     assert_eq!(
         // from spec: <p>[foo]\n<a href=\"/url\" title=\"title\">bar</a></p>
         [
-            paragraph!(linkref!(plain!("foo")), SoftBreak, linkref!(plain!("bar"))),
+            paragraph!(linkref!("foo", plain!("foo")), soft_break!(), linkref!("bar", plain!("bar"))),
             linkdef!("bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r[bar]\r\r[bar]: /url \"title\"\r", ParserSettings::Html)),
@@ -40500,7 +35094,7 @@ fn t539_cr_nl() {
     assert_eq!(
         // from spec: <p>[foo]\n<a href=\"/url\" title=\"title\">bar</a></p>
         [
-            paragraph!(linkref!(plain!("foo")), SoftBreak, linkref!(plain!("bar"))),
+            paragraph!(linkref!("foo", plain!("foo")), soft_break!(), linkref!("bar", plain!("bar"))),
             linkdef!("bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\n[bar]\r\n\r\n[bar]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -40517,30 +35111,13 @@ fn t539_no_nl() {
     assert_eq!(
         // from spec: <p>[foo]\n<a href=\"/url\" title=\"title\">bar</a></p>
         [
-            paragraph!(linkref!(plain!("foo")), SoftBreak, linkref!(plain!("bar"))),
+            paragraph!(linkref!("foo", plain!("foo")), soft_break!(), linkref!("bar", plain!("bar"))),
             linkdef!("bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n[bar]\n\n[bar]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8105
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8105>
-/// <https://spec.commonmark.org/0.29/#example-539>
-fn t539_tab() {
-    init!("[foo]\n[bar]\n\n[bar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p>[foo]\n<a href=\"/url\" title=\"title\">bar</a></p>
-        [
-            paragraph!(linkref!(plain!("foo")),	SoftBreak,	linkref!(plain!("bar"))),
-            linkdef!("bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\n[bar]\n\n[bar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8146
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8146>
@@ -40613,24 +35190,6 @@ fn t540_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8146
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8146>
-/// <https://spec.commonmark.org/0.29/#example-540>
-fn t540_tab() {
-    init!("[foo]:\t/url1\n\n[foo]:\t/url2\n\n[bar][foo]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url1\">bar</a></p>
-        [
-            linkdef!("foo",	"/url1"),
-            linkdef!("foo",	"/url2"),
-            paragraph!(linkref!("foo",	plain!("bar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]:\t/url1\n\n[foo]:\t/url2\n\n[bar][foo]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8161
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8161>
@@ -40699,23 +35258,6 @@ fn t541_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8161
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8161>
-/// <https://spec.commonmark.org/0.29/#example-541>
-fn t541_tab() {
-    init!("[bar][foo\\!]\n\n[foo!]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p>[bar][foo!]</p>
-        [
-            paragraph!(linkref!("foo\\!",	plain!("bar"))),
-            linkdef!("foo!",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[bar][foo\\!]\n\n[foo!]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8173
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8173>
@@ -40726,7 +35268,7 @@ fn t542() {
     assert_eq!(
         // from spec: <p>[foo][ref[]</p>\n<p>[ref[]: /uri</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!("[ref[]")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!("[ref[]")),
             paragraph!(plain!("[ref[]: /uri"))
         ].as_slice(),
         body(&mut StringData::new("[foo][ref[]\n\n[ref[]: /uri\n", ParserSettings::Html)),
@@ -40743,7 +35285,7 @@ fn t542_cr() {
     assert_eq!(
         // from spec: <p>[foo][ref[]</p>\n<p>[ref[]: /uri</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!("[ref[]")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!("[ref[]")),
             paragraph!(plain!("[ref[]: /uri"))
         ].as_slice(),
         body(&mut StringData::new("[foo][ref[]\r\r[ref[]: /uri\r", ParserSettings::Html)),
@@ -40760,7 +35302,7 @@ fn t542_cr_nl() {
     assert_eq!(
         // from spec: <p>[foo][ref[]</p>\n<p>[ref[]: /uri</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!("[ref[]")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!("[ref[]")),
             paragraph!(plain!("[ref[]: /uri"))
         ].as_slice(),
         body(&mut StringData::new("[foo][ref[]\r\n\r\n[ref[]: /uri\r\n", ParserSettings::Html)),
@@ -40777,30 +35319,13 @@ fn t542_no_nl() {
     assert_eq!(
         // from spec: <p>[foo][ref[]</p>\n<p>[ref[]: /uri</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!("[ref[]")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!("[ref[]")),
             paragraph!(plain!("[ref[]: /uri"))
         ].as_slice(),
         body(&mut StringData::new("[foo][ref[]\n\n[ref[]: /uri", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8173
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8173>
-/// <https://spec.commonmark.org/0.29/#example-542>
-fn t542_tab() {
-    init!("[foo][ref[]\n\n[ref[]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[foo][ref[]</p>\n<p>[ref[]: /uri</p>
-        [
-            paragraph!(linkref!(plain!("foo")),	plain!("[ref[]")),
-            paragraph!(plain!("[ref[]:	/uri"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][ref[]\n\n[ref[]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8183
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8183>
@@ -40812,14 +35337,14 @@ fn t543() {
         // from spec: <p>[foo][ref[bar]]</p>\n<p>[ref[bar]]: /uri</p>
         [
             paragraph!(
-              linkref!(plain!("foo")),
+              linkref!("foo", plain!("foo")),
               linkref!(
                 plain!("ref"),
-                linkref!(plain!("bar"))
+                linkref!("bar", plain!("bar"))
               )
             ),
             paragraph!(
-              linkref!(plain!("ref"), linkref!(plain!("bar"))),
+              linkref!(plain!("ref"), linkref!("bar", plain!("bar"))),
               plain!(": /uri")
             )
         ].as_slice(),
@@ -40838,14 +35363,14 @@ fn t543_cr() {
         // from spec: <p>[foo][ref[bar]]</p>\n<p>[ref[bar]]: /uri</p>
         [
             paragraph!(
-              linkref!(plain!("foo")),
+              linkref!("foo", plain!("foo")),
               linkref!(
                 plain!("ref"),
-                linkref!(plain!("bar"))
+                linkref!("bar", plain!("bar"))
               )
             ),
             paragraph!(
-              linkref!(plain!("ref"), linkref!(plain!("bar"))),
+              linkref!(plain!("ref"), linkref!("bar", plain!("bar"))),
               plain!(": /uri")
             )
         ].as_slice(),
@@ -40864,14 +35389,14 @@ fn t543_cr_nl() {
         // from spec: <p>[foo][ref[bar]]</p>\n<p>[ref[bar]]: /uri</p>
         [
             paragraph!(
-              linkref!(plain!("foo")),
+              linkref!("foo", plain!("foo")),
               linkref!(
                 plain!("ref"),
-                linkref!(plain!("bar"))
+                linkref!("bar", plain!("bar"))
               )
             ),
             paragraph!(
-              linkref!(plain!("ref"), linkref!(plain!("bar"))),
+              linkref!(plain!("ref"), linkref!("bar", plain!("bar"))),
               plain!(": /uri")
             )
         ].as_slice(),
@@ -40890,14 +35415,14 @@ fn t543_no_nl() {
         // from spec: <p>[foo][ref[bar]]</p>\n<p>[ref[bar]]: /uri</p>
         [
             paragraph!(
-              linkref!(plain!("foo")),
+              linkref!("foo", plain!("foo")),
               linkref!(
                 plain!("ref"),
-                linkref!(plain!("bar"))
+                linkref!("bar", plain!("bar"))
               )
             ),
             paragraph!(
-              linkref!(plain!("ref"), linkref!(plain!("bar"))),
+              linkref!(plain!("ref"), linkref!("bar", plain!("bar"))),
               plain!(": /uri")
             )
         ].as_slice(),
@@ -40905,32 +35430,6 @@ fn t543_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8183
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8183>
-/// <https://spec.commonmark.org/0.29/#example-543>
-fn t543_tab() {
-    init!("[foo][ref[bar]]\n\n[ref[bar]]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[foo][ref[bar]]</p>\n<p>[ref[bar]]: /uri</p>
-        [
-            paragraph!(
-            		linkref!(plain!("foo")),
-            		linkref!(
-            				plain!("ref"),
-            				linkref!(plain!("bar"))
-            		)
-            ),
-            paragraph!(
-            		linkref!(plain!("ref"),	linkref!(plain!("bar"))),
-            		plain!(":	/uri")
-            )
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][ref[bar]]\n\n[ref[bar]]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8193
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8193>
@@ -40942,10 +35441,10 @@ fn t544() {
         // from spec: <p>[[[foo]]]</p>\n<p>[[[foo]]]: /url</p>
         [
             paragraph!(
-              linkref!(linkref!(linkref!(plain!("foo"))))
+              linkref!(linkref!(linkref!("foo", plain!("foo"))))
             ),
             paragraph!(
-              linkref!(linkref!(linkref!(plain!("foo")))),
+              linkref!(linkref!(linkref!("foo", plain!("foo")))),
               plain!(": /url")
             )
         ].as_slice(),
@@ -40964,10 +35463,10 @@ fn t544_cr() {
         // from spec: <p>[[[foo]]]</p>\n<p>[[[foo]]]: /url</p>
         [
             paragraph!(
-              linkref!(linkref!(linkref!(plain!("foo"))))
+              linkref!(linkref!(linkref!("foo", plain!("foo"))))
             ),
             paragraph!(
-              linkref!(linkref!(linkref!(plain!("foo")))),
+              linkref!(linkref!(linkref!("foo", plain!("foo")))),
               plain!(": /url")
             )
         ].as_slice(),
@@ -40986,10 +35485,10 @@ fn t544_cr_nl() {
         // from spec: <p>[[[foo]]]</p>\n<p>[[[foo]]]: /url</p>
         [
             paragraph!(
-              linkref!(linkref!(linkref!(plain!("foo"))))
+              linkref!(linkref!(linkref!("foo", plain!("foo"))))
             ),
             paragraph!(
-              linkref!(linkref!(linkref!(plain!("foo")))),
+              linkref!(linkref!(linkref!("foo", plain!("foo")))),
               plain!(": /url")
             )
         ].as_slice(),
@@ -41008,10 +35507,10 @@ fn t544_no_nl() {
         // from spec: <p>[[[foo]]]</p>\n<p>[[[foo]]]: /url</p>
         [
             paragraph!(
-              linkref!(linkref!(linkref!(plain!("foo"))))
+              linkref!(linkref!(linkref!("foo", plain!("foo"))))
             ),
             paragraph!(
-              linkref!(linkref!(linkref!(plain!("foo")))),
+              linkref!(linkref!(linkref!("foo", plain!("foo")))),
               plain!(": /url")
             )
         ].as_slice(),
@@ -41019,28 +35518,6 @@ fn t544_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8193
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8193>
-/// <https://spec.commonmark.org/0.29/#example-544>
-fn t544_tab() {
-    init!("[[[foo]]]\n\n[[[foo]]]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p>[[[foo]]]</p>\n<p>[[[foo]]]: /url</p>
-        [
-            paragraph!(
-            		linkref!(linkref!(linkref!(plain!("foo"))))
-            ),
-            paragraph!(
-            		linkref!(linkref!(linkref!(plain!("foo")))),
-            		plain!(":	/url")
-            )
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[[[foo]]]\n\n[[[foo]]]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8203
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8203>
@@ -41109,23 +35586,6 @@ fn t545_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8203
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8203>
-/// <https://spec.commonmark.org/0.29/#example-545>
-fn t545_tab() {
-    init!("[foo][ref\\[]\n\n[ref\\[]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">foo</a></p>
-        [
-            paragraph!(linkref!("ref[",	plain!("foo"))),
-            linkdef!("ref[",	"/uri")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][ref\\[]\n\n[ref\\[]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8214
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8214>
@@ -41137,7 +35597,7 @@ fn t546() {
         // from spec: <p><a href=\"/uri\">bar\\</a></p>
         [
             linkdef!(r"bar\\", "/uri"),
-            paragraph!(linkref!(plain!("bar\\")))
+            paragraph!(linkref!(r"bar\\", plain!("bar\\")))
         ].as_slice(),
         body(&mut StringData::new("[bar\\\\]: /uri\n\n[bar\\\\]\n", ParserSettings::Html)),
     );
@@ -41154,7 +35614,7 @@ fn t546_cr() {
         // from spec: <p><a href=\"/uri\">bar\\</a></p>
         [
             linkdef!(r"bar\\", "/uri"),
-            paragraph!(linkref!(plain!("bar\\")))
+            paragraph!(linkref!(r"bar\\", plain!("bar\\")))
         ].as_slice(),
         body(&mut StringData::new("[bar\\\\]: /uri\r\r[bar\\\\]\r", ParserSettings::Html)),
     );
@@ -41171,7 +35631,7 @@ fn t546_cr_nl() {
         // from spec: <p><a href=\"/uri\">bar\\</a></p>
         [
             linkdef!(r"bar\\", "/uri"),
-            paragraph!(linkref!(plain!("bar\\")))
+            paragraph!(linkref!(r"bar\\", plain!("bar\\")))
         ].as_slice(),
         body(&mut StringData::new("[bar\\\\]: /uri\r\n\r\n[bar\\\\]\r\n", ParserSettings::Html)),
     );
@@ -41188,29 +35648,12 @@ fn t546_no_nl() {
         // from spec: <p><a href=\"/uri\">bar\\</a></p>
         [
             linkdef!(r"bar\\", "/uri"),
-            paragraph!(linkref!(plain!("bar\\")))
+            paragraph!(linkref!(r"bar\\", plain!("bar\\")))
         ].as_slice(),
         body(&mut StringData::new("[bar\\\\]: /uri\n\n[bar\\\\]", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8214
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8214>
-/// <https://spec.commonmark.org/0.29/#example-546>
-fn t546_tab() {
-    init!("[bar\\\\]:\t/uri\n\n[bar\\\\]\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/uri\">bar\\</a></p>
-        [
-            linkdef!(r"bar\\",	"/uri"),
-            paragraph!(linkref!(plain!("bar\\")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[bar\\\\]:\t/uri\n\n[bar\\\\]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8225
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8225>
@@ -41275,22 +35718,6 @@ fn t547_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8225
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8225>
-/// <https://spec.commonmark.org/0.29/#example-547>
-fn t547_tab() {
-    init!("[]\n\n[]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[]</p>\n<p>[]: /uri</p>
-        [
-            paragraph!(plain!("[]")), paragraph!(plain!("[]:	/uri"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[]\n\n[]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8235
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8235>
@@ -41301,7 +35728,7 @@ fn t548() {
     assert_eq!(
         // from spec: <p>[\n]</p>\n<p>[\n]: /uri</p>
         [
-            paragraph!(plain!("["), SoftBreak, plain!("]")), paragraph!(plain!("["), SoftBreak, plain!("]: /uri"))
+            paragraph!(plain!("["), soft_break!(), plain!("]")), paragraph!(plain!("["), soft_break!(), plain!("]: /uri"))
         ].as_slice(),
         body(&mut StringData::new("[\n ]\n\n[\n ]: /uri\n", ParserSettings::Html)),
     );
@@ -41317,7 +35744,7 @@ fn t548_cr() {
     assert_eq!(
         // from spec: <p>[\n]</p>\n<p>[\n]: /uri</p>
         [
-            paragraph!(plain!("["), SoftBreak, plain!("]")), paragraph!(plain!("["), SoftBreak, plain!("]: /uri"))
+            paragraph!(plain!("["), soft_break!(), plain!("]")), paragraph!(plain!("["), soft_break!(), plain!("]: /uri"))
         ].as_slice(),
         body(&mut StringData::new("[\r ]\r\r[\r ]: /uri\r", ParserSettings::Html)),
     );
@@ -41333,7 +35760,7 @@ fn t548_cr_nl() {
     assert_eq!(
         // from spec: <p>[\n]</p>\n<p>[\n]: /uri</p>
         [
-            paragraph!(plain!("["), SoftBreak, plain!("]")), paragraph!(plain!("["), SoftBreak, plain!("]: /uri"))
+            paragraph!(plain!("["), soft_break!(), plain!("]")), paragraph!(plain!("["), soft_break!(), plain!("]: /uri"))
         ].as_slice(),
         body(&mut StringData::new("[\r\n ]\r\n\r\n[\r\n ]: /uri\r\n", ParserSettings::Html)),
     );
@@ -41349,28 +35776,12 @@ fn t548_no_nl() {
     assert_eq!(
         // from spec: <p>[\n]</p>\n<p>[\n]: /uri</p>
         [
-            paragraph!(plain!("["), SoftBreak, plain!("]")), paragraph!(plain!("["), SoftBreak, plain!("]: /uri"))
+            paragraph!(plain!("["), soft_break!(), plain!("]")), paragraph!(plain!("["), soft_break!(), plain!("]: /uri"))
         ].as_slice(),
         body(&mut StringData::new("[\n ]\n\n[\n ]: /uri", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8235
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8235>
-/// <https://spec.commonmark.org/0.29/#example-548>
-fn t548_tab() {
-    init!("[\n ]\n\n[\n ]:\t/uri\n");
-
-    assert_eq!(
-        // from spec: <p>[\n]</p>\n<p>[\n]: /uri</p>
-        [
-            paragraph!(plain!("["), SoftBreak, plain!("]")), paragraph!(plain!("["), SoftBreak, plain!("]:	/uri"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[\n ]\n\n[\n ]:\t/uri\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8258
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8258>
@@ -41381,7 +35792,7 @@ fn t549() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo][]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -41398,7 +35809,7 @@ fn t549_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo][]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -41415,7 +35826,7 @@ fn t549_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo][]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -41432,30 +35843,13 @@ fn t549_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo][]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8258
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8258>
-/// <https://spec.commonmark.org/0.29/#example-549>
-fn t549_tab() {
-    init!("[foo][]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
-        [
-            paragraph!(linkref!(plain!("foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8267
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8267>
@@ -41466,7 +35860,7 @@ fn t550() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
         [
-            paragraph!(linkref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[*foo* bar][]\n\n[*foo* bar]: /url \"title\"\n", ParserSettings::Html)),
@@ -41483,7 +35877,7 @@ fn t550_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
         [
-            paragraph!(linkref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[*foo* bar][]\r\r[*foo* bar]: /url \"title\"\r", ParserSettings::Html)),
@@ -41500,7 +35894,7 @@ fn t550_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
         [
-            paragraph!(linkref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[*foo* bar][]\r\n\r\n[*foo* bar]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -41517,30 +35911,13 @@ fn t550_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
         [
-            paragraph!(linkref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[*foo* bar][]\n\n[*foo* bar]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8267
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8267>
-/// <https://spec.commonmark.org/0.29/#example-550>
-fn t550_tab() {
-    init!("[*foo*\tbar][]\n\n[*foo*\tbar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
-        [
-            paragraph!(linkref!(emph!(plain!("foo")),	plain!("	bar"))),
-            linkdef!("*foo*	bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[*foo*\tbar][]\n\n[*foo*\tbar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8278
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8278>
@@ -41551,7 +35928,7 @@ fn t551() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
         [
-            paragraph!(linkref!(plain!("Foo"))),
+            paragraph!(linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[Foo][]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -41568,7 +35945,7 @@ fn t551_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
         [
-            paragraph!(linkref!(plain!("Foo"))),
+            paragraph!(linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[Foo][]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -41585,7 +35962,7 @@ fn t551_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
         [
-            paragraph!(linkref!(plain!("Foo"))),
+            paragraph!(linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[Foo][]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -41602,30 +35979,13 @@ fn t551_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
         [
-            paragraph!(linkref!(plain!("Foo"))),
+            paragraph!(linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[Foo][]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8278
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8278>
-/// <https://spec.commonmark.org/0.29/#example-551>
-fn t551_tab() {
-    init!("[Foo][]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
-        [
-            paragraph!(linkref!(plain!("Foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[Foo][]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8291
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8291>
@@ -41636,7 +35996,7 @@ fn t552() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a>\n[]</p>
         [
-            paragraph!(linkref!(plain!("foo")), SoftBreak, plain!("[]")),
+            paragraph!(linkref!("foo", plain!("foo")), soft_break!(), plain!("[]")),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo] \n[]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -41653,7 +36013,7 @@ fn t552_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a>\n[]</p>
         [
-            paragraph!(linkref!(plain!("foo")), SoftBreak, plain!("[]")),
+            paragraph!(linkref!("foo", plain!("foo")), soft_break!(), plain!("[]")),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo] \r[]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -41670,7 +36030,7 @@ fn t552_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a>\n[]</p>
         [
-            paragraph!(linkref!(plain!("foo")), SoftBreak, plain!("[]")),
+            paragraph!(linkref!("foo", plain!("foo")), soft_break!(), plain!("[]")),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo] \r\n[]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -41687,7 +36047,7 @@ fn t552_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a>\n[]</p>
         [
-            paragraph!(linkref!(plain!("foo")), SoftBreak, plain!("[]")),
+            paragraph!(linkref!("foo", plain!("foo")), soft_break!(), plain!("[]")),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo] \n[]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
@@ -41704,7 +36064,7 @@ fn t553() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -41721,7 +36081,7 @@ fn t553_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -41738,7 +36098,7 @@ fn t553_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -41755,30 +36115,13 @@ fn t553_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[foo]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8311
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8311>
-/// <https://spec.commonmark.org/0.29/#example-553>
-fn t553_tab() {
-    init!("[foo]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\">foo</a></p>
-        [
-            paragraph!(linkref!(plain!("foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8320
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8320>
@@ -41789,7 +36132,7 @@ fn t554() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
         [
-            paragraph!(linkref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[*foo* bar]\n\n[*foo* bar]: /url \"title\"\n", ParserSettings::Html)),
@@ -41806,7 +36149,7 @@ fn t554_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
         [
-            paragraph!(linkref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[*foo* bar]\r\r[*foo* bar]: /url \"title\"\r", ParserSettings::Html)),
@@ -41823,7 +36166,7 @@ fn t554_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
         [
-            paragraph!(linkref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[*foo* bar]\r\n\r\n[*foo* bar]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -41840,30 +36183,13 @@ fn t554_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
         [
-            paragraph!(linkref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[*foo* bar]\n\n[*foo* bar]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8320
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8320>
-/// <https://spec.commonmark.org/0.29/#example-554>
-fn t554_tab() {
-    init!("[*foo*\tbar]\n\n[*foo*\tbar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\"><em>foo</em> bar</a></p>
-        [
-            paragraph!(linkref!(emph!(plain!("foo")),	plain!("	bar"))),
-            linkdef!("*foo*	bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[*foo*\tbar]\n\n[*foo*\tbar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8329
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8329>
@@ -41876,7 +36202,7 @@ fn t555() {
         [
             paragraph!(
               linkref!(
-                linkref!(emph!(plain!("foo")), plain!(" bar"))
+                linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))
               )
             ),
             linkdef!("*foo* bar", "/url", "title")
@@ -41897,7 +36223,7 @@ fn t555_cr() {
         [
             paragraph!(
               linkref!(
-                linkref!(emph!(plain!("foo")), plain!(" bar"))
+                linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))
               )
             ),
             linkdef!("*foo* bar", "/url", "title")
@@ -41918,7 +36244,7 @@ fn t555_cr_nl() {
         [
             paragraph!(
               linkref!(
-                linkref!(emph!(plain!("foo")), plain!(" bar"))
+                linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))
               )
             ),
             linkdef!("*foo* bar", "/url", "title")
@@ -41939,7 +36265,7 @@ fn t555_no_nl() {
         [
             paragraph!(
               linkref!(
-                linkref!(emph!(plain!("foo")), plain!(" bar"))
+                linkref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))
               )
             ),
             linkdef!("*foo* bar", "/url", "title")
@@ -41948,27 +36274,6 @@ fn t555_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8329
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8329>
-/// <https://spec.commonmark.org/0.29/#example-555>
-fn t555_tab() {
-    init!("[[*foo*\tbar]]\n\n[*foo*\tbar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p>[<a href=\"/url\" title=\"title\"><em>foo</em> bar</a>]</p>
-        [
-            paragraph!(
-            		linkref!(
-            				linkref!(emph!(plain!("foo")),	plain!("	bar"))
-            		)
-            ),
-            linkdef!("*foo*	bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[[*foo*\tbar]]\n\n[*foo*\tbar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8338
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8338>
@@ -41980,7 +36285,7 @@ fn t556() {
         // from spec: <p>[[bar <a href=\"/url\">foo</a></p>
         [
             paragraph!(
-              plain!("[[bar "), linkref!(plain!("foo"))
+              plain!("[[bar "), linkref!("foo", plain!("foo"))
             ),
             linkdef!("foo", "/url")
         ].as_slice(),
@@ -41999,7 +36304,7 @@ fn t556_cr() {
         // from spec: <p>[[bar <a href=\"/url\">foo</a></p>
         [
             paragraph!(
-              plain!("[[bar "), linkref!(plain!("foo"))
+              plain!("[[bar "), linkref!("foo", plain!("foo"))
             ),
             linkdef!("foo", "/url")
         ].as_slice(),
@@ -42018,7 +36323,7 @@ fn t556_cr_nl() {
         // from spec: <p>[[bar <a href=\"/url\">foo</a></p>
         [
             paragraph!(
-              plain!("[[bar "), linkref!(plain!("foo"))
+              plain!("[[bar "), linkref!("foo", plain!("foo"))
             ),
             linkdef!("foo", "/url")
         ].as_slice(),
@@ -42037,7 +36342,7 @@ fn t556_no_nl() {
         // from spec: <p>[[bar <a href=\"/url\">foo</a></p>
         [
             paragraph!(
-              plain!("[[bar "), linkref!(plain!("foo"))
+              plain!("[[bar "), linkref!("foo", plain!("foo"))
             ),
             linkdef!("foo", "/url")
         ].as_slice(),
@@ -42045,25 +36350,6 @@ fn t556_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8338
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8338>
-/// <https://spec.commonmark.org/0.29/#example-556>
-fn t556_tab() {
-    init!("[[bar\t[foo]\n\n[foo]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p>[[bar <a href=\"/url\">foo</a></p>
-        [
-            paragraph!(
-            		plain!("[[bar	"),	linkref!(plain!("foo"))
-            ),
-            linkdef!("foo",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[[bar\t[foo]\n\n[foo]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8349
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8349>
@@ -42074,7 +36360,7 @@ fn t557() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
         [
-            paragraph!(linkref!(plain!("Foo"))),
+            paragraph!(linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[Foo]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -42091,7 +36377,7 @@ fn t557_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
         [
-            paragraph!(linkref!(plain!("Foo"))),
+            paragraph!(linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[Foo]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -42108,7 +36394,7 @@ fn t557_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
         [
-            paragraph!(linkref!(plain!("Foo"))),
+            paragraph!(linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[Foo]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -42125,30 +36411,13 @@ fn t557_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
         [
-            paragraph!(linkref!(plain!("Foo"))),
+            paragraph!(linkref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("[Foo]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8349
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8349>
-/// <https://spec.commonmark.org/0.29/#example-557>
-fn t557_tab() {
-    init!("[Foo]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\" title=\"title\">Foo</a></p>
-        [
-            paragraph!(linkref!(plain!("Foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[Foo]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8360
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8360>
@@ -42159,7 +36428,7 @@ fn t558() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">foo</a> bar</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(" bar")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(" bar")),
             linkdef!("foo", "/url")
         ].as_slice(),
         body(&mut StringData::new("[foo] bar\n\n[foo]: /url\n", ParserSettings::Html)),
@@ -42176,7 +36445,7 @@ fn t558_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">foo</a> bar</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(" bar")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(" bar")),
             linkdef!("foo", "/url")
         ].as_slice(),
         body(&mut StringData::new("[foo] bar\r\r[foo]: /url\r", ParserSettings::Html)),
@@ -42193,7 +36462,7 @@ fn t558_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">foo</a> bar</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(" bar")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(" bar")),
             linkdef!("foo", "/url")
         ].as_slice(),
         body(&mut StringData::new("[foo] bar\r\n\r\n[foo]: /url\r\n", ParserSettings::Html)),
@@ -42210,30 +36479,13 @@ fn t558_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url\">foo</a> bar</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!(" bar")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!(" bar")),
             linkdef!("foo", "/url")
         ].as_slice(),
         body(&mut StringData::new("[foo] bar\n\n[foo]: /url", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8360
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8360>
-/// <https://spec.commonmark.org/0.29/#example-558>
-fn t558_tab() {
-    init!("[foo]\tbar\n\n[foo]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url\">foo</a> bar</p>
-        [
-            paragraph!(linkref!(plain!("foo")),	plain!("	bar")),
-            linkdef!("foo",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]\tbar\n\n[foo]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8372
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8372>
@@ -42302,23 +36554,6 @@ fn t559_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8372
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8372>
-/// <https://spec.commonmark.org/0.29/#example-559>
-fn t559_tab() {
-    init!("\\[foo]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p>[foo]</p>
-        [
-            paragraph!(plain!("[foo]")),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("\\[foo]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8384
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8384>
@@ -42387,23 +36622,6 @@ fn t560_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8384
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8384>
-/// <https://spec.commonmark.org/0.29/#example-560>
-fn t560_tab() {
-    init!("[foo*]:\t/url\n\n*[foo*]\n");
-
-    assert_eq!(
-        // from spec: <p>*<a href=\"/url\">foo*</a></p>
-        [
-            linkdef!("foo*",	"/url"),
-            paragraph!(emph!(plain!("[foo")),	plain!("]"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo*]:\t/url\n\n*[foo*]\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8396
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8396>
@@ -42476,24 +36694,6 @@ fn t561_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8396
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8396>
-/// <https://spec.commonmark.org/0.29/#example-561>
-fn t561_tab() {
-    init!("[foo][bar]\n\n[foo]:\t/url1\n[bar]:\t/url2\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url2\">foo</a></p>
-        [
-            paragraph!(linkref!("bar",	plain!("foo"))),
-            linkdef!("foo",	"/url1"),
-            linkdef!("bar",	"/url2")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][bar]\n\n[foo]:\t/url1\n[bar]:\t/url2\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8405
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8405>
@@ -42504,7 +36704,7 @@ fn t562() {
     assert_eq!(
         // from spec: <p><a href=\"/url1\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url1")
         ].as_slice(),
         body(&mut StringData::new("[foo][]\n\n[foo]: /url1\n", ParserSettings::Html)),
@@ -42521,7 +36721,7 @@ fn t562_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url1\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url1")
         ].as_slice(),
         body(&mut StringData::new("[foo][]\r\r[foo]: /url1\r", ParserSettings::Html)),
@@ -42538,7 +36738,7 @@ fn t562_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url1\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url1")
         ].as_slice(),
         body(&mut StringData::new("[foo][]\r\n\r\n[foo]: /url1\r\n", ParserSettings::Html)),
@@ -42555,30 +36755,13 @@ fn t562_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url1\">foo</a></p>
         [
-            paragraph!(linkref!(plain!("foo"))),
+            paragraph!(linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url1")
         ].as_slice(),
         body(&mut StringData::new("[foo][]\n\n[foo]: /url1", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8405
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8405>
-/// <https://spec.commonmark.org/0.29/#example-562>
-fn t562_tab() {
-    init!("[foo][]\n\n[foo]:\t/url1\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url1\">foo</a></p>
-        [
-            paragraph!(linkref!(plain!("foo"))),
-            linkdef!("foo",	"/url1")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][]\n\n[foo]:\t/url1\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8415
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8415>
@@ -42647,23 +36830,6 @@ fn t563_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8415
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8415>
-/// <https://spec.commonmark.org/0.29/#example-563>
-fn t563_tab() {
-    init!("[foo]()\n\n[foo]:\t/url1\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"\">foo</a></p>
-        [
-            paragraph!(link!("",	"",	plain!("foo"))),
-            linkdef!("foo",	"/url1")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo]()\n\n[foo]:\t/url1\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8423
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8423>
@@ -42674,7 +36840,7 @@ fn t564() {
     assert_eq!(
         // from spec: <p><a href=\"/url1\">foo</a>(not a link)</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!("(not a link)")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!("(not a link)")),
             linkdef!("foo", "/url1")
         ].as_slice(),
         body(&mut StringData::new("[foo](not a link)\n\n[foo]: /url1\n", ParserSettings::Html)),
@@ -42691,7 +36857,7 @@ fn t564_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url1\">foo</a>(not a link)</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!("(not a link)")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!("(not a link)")),
             linkdef!("foo", "/url1")
         ].as_slice(),
         body(&mut StringData::new("[foo](not a link)\r\r[foo]: /url1\r", ParserSettings::Html)),
@@ -42708,7 +36874,7 @@ fn t564_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url1\">foo</a>(not a link)</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!("(not a link)")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!("(not a link)")),
             linkdef!("foo", "/url1")
         ].as_slice(),
         body(&mut StringData::new("[foo](not a link)\r\n\r\n[foo]: /url1\r\n", ParserSettings::Html)),
@@ -42725,30 +36891,13 @@ fn t564_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url1\">foo</a>(not a link)</p>
         [
-            paragraph!(linkref!(plain!("foo")), plain!("(not a link)")),
+            paragraph!(linkref!("foo", plain!("foo")), plain!("(not a link)")),
             linkdef!("foo", "/url1")
         ].as_slice(),
         body(&mut StringData::new("[foo](not a link)\n\n[foo]: /url1", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8423
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8423>
-/// <https://spec.commonmark.org/0.29/#example-564>
-fn t564_tab() {
-    init!("[foo](not\ta\tlink)\n\n[foo]:\t/url1\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url1\">foo</a>(not a link)</p>
-        [
-            paragraph!(linkref!(plain!("foo")),	plain!("(not	a	link)")),
-            linkdef!("foo",	"/url1")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo](not\ta\tlink)\n\n[foo]:\t/url1\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8434
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8434>
@@ -42761,8 +36910,8 @@ fn t565() {
         [
             // This is a violation of the spec, because the parser doesn't know which
             // labels are valid while parsing. The spec expects
-            // `linkref!(plain!("foo")), linkref!("baz", plain!("bar"))`
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            // `linkref!("foo", plain!("foo")), linkref!("baz", plain!("bar"))`
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url")
         ].as_slice(),
         body(&mut StringData::new("[foo][bar][baz]\n\n[baz]: /url\n", ParserSettings::Html)),
@@ -42781,8 +36930,8 @@ fn t565_cr() {
         [
             // This is a violation of the spec, because the parser doesn't know which
             // labels are valid while parsing. The spec expects
-            // `linkref!(plain!("foo")), linkref!("baz", plain!("bar"))`
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            // `linkref!("foo", plain!("foo")), linkref!("baz", plain!("bar"))`
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url")
         ].as_slice(),
         body(&mut StringData::new("[foo][bar][baz]\r\r[baz]: /url\r", ParserSettings::Html)),
@@ -42801,8 +36950,8 @@ fn t565_cr_nl() {
         [
             // This is a violation of the spec, because the parser doesn't know which
             // labels are valid while parsing. The spec expects
-            // `linkref!(plain!("foo")), linkref!("baz", plain!("bar"))`
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            // `linkref!("foo", plain!("foo")), linkref!("baz", plain!("bar"))`
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url")
         ].as_slice(),
         body(&mut StringData::new("[foo][bar][baz]\r\n\r\n[baz]: /url\r\n", ParserSettings::Html)),
@@ -42821,34 +36970,14 @@ fn t565_no_nl() {
         [
             // This is a violation of the spec, because the parser doesn't know which
             // labels are valid while parsing. The spec expects
-            // `linkref!(plain!("foo")), linkref!("baz", plain!("bar"))`
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            // `linkref!("foo", plain!("foo")), linkref!("baz", plain!("bar"))`
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url")
         ].as_slice(),
         body(&mut StringData::new("[foo][bar][baz]\n\n[baz]: /url", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8434
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8434>
-/// <https://spec.commonmark.org/0.29/#example-565>
-fn t565_tab() {
-    init!("[foo][bar][baz]\n\n[baz]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p>[foo]<a href=\"/url\">bar</a></p>
-        [
-            //	This	is	a	violation	of	the	spec,	because	the	parser	doesn't	know	which
-            //	labels	are	valid	while	parsing.	The	spec	expects
-            //	`linkref!(plain!("foo")),	linkref!("baz",	plain!("bar"))`
-            paragraph!(linkref!("bar",	plain!("foo")),	linkref!(plain!("baz"))),
-            linkdef!("baz",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][bar][baz]\n\n[baz]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8446
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8446>
@@ -42859,7 +36988,7 @@ fn t566() {
     assert_eq!(
         // from spec: <p><a href=\"/url2\">foo</a><a href=\"/url1\">baz</a></p>
         [
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url1"),
             linkdef!("bar", "/url2")
         ].as_slice(),
@@ -42877,7 +37006,7 @@ fn t566_cr() {
     assert_eq!(
         // from spec: <p><a href=\"/url2\">foo</a><a href=\"/url1\">baz</a></p>
         [
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url1"),
             linkdef!("bar", "/url2")
         ].as_slice(),
@@ -42895,7 +37024,7 @@ fn t566_cr_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url2\">foo</a><a href=\"/url1\">baz</a></p>
         [
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url1"),
             linkdef!("bar", "/url2")
         ].as_slice(),
@@ -42913,7 +37042,7 @@ fn t566_no_nl() {
     assert_eq!(
         // from spec: <p><a href=\"/url2\">foo</a><a href=\"/url1\">baz</a></p>
         [
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url1"),
             linkdef!("bar", "/url2")
         ].as_slice(),
@@ -42921,24 +37050,6 @@ fn t566_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8446
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8446>
-/// <https://spec.commonmark.org/0.29/#example-566>
-fn t566_tab() {
-    init!("[foo][bar][baz]\n\n[baz]:\t/url1\n[bar]:\t/url2\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"/url2\">foo</a><a href=\"/url1\">baz</a></p>
-        [
-            paragraph!(linkref!("bar",	plain!("foo")),	linkref!(plain!("baz"))),
-            linkdef!("baz",	"/url1"),
-            linkdef!("bar",	"/url2")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][bar][baz]\n\n[baz]:\t/url1\n[bar]:\t/url2\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8459
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8459>
@@ -42951,7 +37062,7 @@ fn t567() {
         [
             // This is a violation of the spec, because we don't know which labels are
             // valid during parsing
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url1"),
             linkdef!("foo", "/url2")
         ].as_slice(),
@@ -42971,7 +37082,7 @@ fn t567_cr() {
         [
             // This is a violation of the spec, because we don't know which labels are
             // valid during parsing
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url1"),
             linkdef!("foo", "/url2")
         ].as_slice(),
@@ -42991,7 +37102,7 @@ fn t567_cr_nl() {
         [
             // This is a violation of the spec, because we don't know which labels are
             // valid during parsing
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url1"),
             linkdef!("foo", "/url2")
         ].as_slice(),
@@ -43011,7 +37122,7 @@ fn t567_no_nl() {
         [
             // This is a violation of the spec, because we don't know which labels are
             // valid during parsing
-            paragraph!(linkref!("bar", plain!("foo")), linkref!(plain!("baz"))),
+            paragraph!(linkref!("bar", plain!("foo")), linkref!("baz", plain!("baz"))),
             linkdef!("baz", "/url1"),
             linkdef!("foo", "/url2")
         ].as_slice(),
@@ -43019,26 +37130,6 @@ fn t567_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8459
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8459>
-/// <https://spec.commonmark.org/0.29/#example-567>
-fn t567_tab() {
-    init!("[foo][bar][baz]\n\n[baz]:\t/url1\n[foo]:\t/url2\n");
-
-    assert_eq!(
-        // from spec: <p>[foo]<a href=\"/url1\">bar</a></p>
-        [
-            //	This	is	a	violation	of	the	spec,	because	we	don't	know	which	labels	are
-            //	valid	during	parsing
-            paragraph!(linkref!("bar",	plain!("foo")),	linkref!(plain!("baz"))),
-            linkdef!("baz",	"/url1"),
-            linkdef!("foo",	"/url2")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("[foo][bar][baz]\n\n[baz]:\t/url1\n[foo]:\t/url2\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8482
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8482>
@@ -43103,22 +37194,6 @@ fn t568_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8482
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8482>
-/// <https://spec.commonmark.org/0.29/#example-568>
-fn t568_tab() {
-    init!("![foo](/url\t\"title\")\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
-        [
-            paragraph!(image!("/url", "title", plain!("foo")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo](/url\t\"title\")\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8489
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8489>
@@ -43129,7 +37204,7 @@ fn t569() {
     assert_eq!(
         // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
         [
-            paragraph!(imageref!(plain!("foo "), emph!(plain!("bar")))),
+            paragraph!(imageref!("foo *bar*", plain!("foo "), emph!(plain!("bar")))),
             linkdef!("foo *bar*", "train.jpg", "train & tracks")
         ].as_slice(),
         body(&mut StringData::new("![foo *bar*]\n\n[foo *bar*]: train.jpg \"train & tracks\"\n", ParserSettings::Html)),
@@ -43146,7 +37221,7 @@ fn t569_cr() {
     assert_eq!(
         // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
         [
-            paragraph!(imageref!(plain!("foo "), emph!(plain!("bar")))),
+            paragraph!(imageref!("foo *bar*", plain!("foo "), emph!(plain!("bar")))),
             linkdef!("foo *bar*", "train.jpg", "train & tracks")
         ].as_slice(),
         body(&mut StringData::new("![foo *bar*]\r\r[foo *bar*]: train.jpg \"train & tracks\"\r", ParserSettings::Html)),
@@ -43163,7 +37238,7 @@ fn t569_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
         [
-            paragraph!(imageref!(plain!("foo "), emph!(plain!("bar")))),
+            paragraph!(imageref!("foo *bar*", plain!("foo "), emph!(plain!("bar")))),
             linkdef!("foo *bar*", "train.jpg", "train & tracks")
         ].as_slice(),
         body(&mut StringData::new("![foo *bar*]\r\n\r\n[foo *bar*]: train.jpg \"train & tracks\"\r\n", ParserSettings::Html)),
@@ -43180,30 +37255,13 @@ fn t569_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
         [
-            paragraph!(imageref!(plain!("foo "), emph!(plain!("bar")))),
+            paragraph!(imageref!("foo *bar*", plain!("foo "), emph!(plain!("bar")))),
             linkdef!("foo *bar*", "train.jpg", "train & tracks")
         ].as_slice(),
         body(&mut StringData::new("![foo *bar*]\n\n[foo *bar*]: train.jpg \"train & tracks\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8489
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8489>
-/// <https://spec.commonmark.org/0.29/#example-569>
-fn t569_tab() {
-    init!("![foo\t*bar*]\n\n[foo\t*bar*]:\ttrain.jpg\t\"train\t&\ttracks\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
-        [
-            paragraph!(imageref!(plain!("foo	"),	emph!(plain!("bar")))),
-            linkdef!("foo	*bar*",	"train.jpg",	"train	&	tracks")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo\t*bar*]\n\n[foo\t*bar*]:\ttrain.jpg\t\"train\t&\ttracks\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8498
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8498>
@@ -43268,22 +37326,6 @@ fn t570_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8498
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8498>
-/// <https://spec.commonmark.org/0.29/#example-570>
-fn t570_tab() {
-    init!("![foo\t![bar](/url)](/url2)\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url2\" alt=\"foo bar\" /></p>
-        [
-            paragraph!(image!("/url2",	"",	plain!("foo	"),	image!("/url",	"",	plain!("bar"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo\t![bar](/url)](/url2)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8505
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8505>
@@ -43348,22 +37390,6 @@ fn t571_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8505
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8505>
-/// <https://spec.commonmark.org/0.29/#example-571>
-fn t571_tab() {
-    init!("![foo\t[bar](/url)](/url2)\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url2\" alt=\"foo bar\" /></p>
-        [
-            paragraph!(image!("/url2",	"",	plain!("foo	"),	link!("/url",	"",	plain!("bar"))))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo\t[bar](/url)](/url2)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8519
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8519>
@@ -43374,7 +37400,7 @@ fn t572() {
     assert_eq!(
         // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
         [
-            paragraph!(imageref!(plain!("foo "), emph!(plain!("bar")))),
+            paragraph!(imageref!("foo *bar*", plain!("foo "), emph!(plain!("bar")))),
             linkdef!("foo *bar*", "train.jpg", "train & tracks")
         ].as_slice(),
         body(&mut StringData::new("![foo *bar*][]\n\n[foo *bar*]: train.jpg \"train & tracks\"\n", ParserSettings::Html)),
@@ -43391,7 +37417,7 @@ fn t572_cr() {
     assert_eq!(
         // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
         [
-            paragraph!(imageref!(plain!("foo "), emph!(plain!("bar")))),
+            paragraph!(imageref!("foo *bar*", plain!("foo "), emph!(plain!("bar")))),
             linkdef!("foo *bar*", "train.jpg", "train & tracks")
         ].as_slice(),
         body(&mut StringData::new("![foo *bar*][]\r\r[foo *bar*]: train.jpg \"train & tracks\"\r", ParserSettings::Html)),
@@ -43408,7 +37434,7 @@ fn t572_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
         [
-            paragraph!(imageref!(plain!("foo "), emph!(plain!("bar")))),
+            paragraph!(imageref!("foo *bar*", plain!("foo "), emph!(plain!("bar")))),
             linkdef!("foo *bar*", "train.jpg", "train & tracks")
         ].as_slice(),
         body(&mut StringData::new("![foo *bar*][]\r\n\r\n[foo *bar*]: train.jpg \"train & tracks\"\r\n", ParserSettings::Html)),
@@ -43425,30 +37451,13 @@ fn t572_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
         [
-            paragraph!(imageref!(plain!("foo "), emph!(plain!("bar")))),
+            paragraph!(imageref!("foo *bar*", plain!("foo "), emph!(plain!("bar")))),
             linkdef!("foo *bar*", "train.jpg", "train & tracks")
         ].as_slice(),
         body(&mut StringData::new("![foo *bar*][]\n\n[foo *bar*]: train.jpg \"train & tracks\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8519
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8519>
-/// <https://spec.commonmark.org/0.29/#example-572>
-fn t572_tab() {
-    init!("![foo\t*bar*][]\n\n[foo\t*bar*]:\ttrain.jpg\t\"train\t&\ttracks\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
-        [
-            paragraph!(imageref!(plain!("foo	"),	emph!(plain!("bar")))),
-            linkdef!("foo	*bar*",	"train.jpg",	"train	&	tracks")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo\t*bar*][]\n\n[foo\t*bar*]:\ttrain.jpg\t\"train\t&\ttracks\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8528
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8528>
@@ -43517,23 +37526,6 @@ fn t573_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8528
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8528>
-/// <https://spec.commonmark.org/0.29/#example-573>
-fn t573_tab() {
-    init!("![foo\t*bar*][foobar]\n\n[FOOBAR]:\ttrain.jpg\t\"train\t&\ttracks\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"train.jpg\" alt=\"foo bar\" title=\"train &amp; tracks\" /></p>
-        [
-            paragraph!(imageref!("foobar",	plain!("foo	"),	emph!(plain!("bar")))),
-            linkdef!("FOOBAR",	"train.jpg",	"train	&	tracks")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo\t*bar*][foobar]\n\n[FOOBAR]:\ttrain.jpg\t\"train\t&\ttracks\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8537
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8537>
@@ -43662,22 +37654,6 @@ fn t575_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8544
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8544>
-/// <https://spec.commonmark.org/0.29/#example-575>
-fn t575_tab() {
-    init!("My\t![foo\tbar](/path/to/train.jpg\t\t\"title\"\t\t\t)\n");
-
-    assert_eq!(
-        // from spec: <p>My <img src=\"/path/to/train.jpg\" alt=\"foo bar\" title=\"title\" /></p>
-        [
-            paragraph!(plain!("My	"), image!("/path/to/train.jpg", "title", plain!("foo\tbar")))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("My\t![foo\tbar](/path/to/train.jpg\t\t\"title\"\t\t\t)\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8551
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8551>
@@ -43874,23 +37850,6 @@ fn t578_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8567
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8567>
-/// <https://spec.commonmark.org/0.29/#example-578>
-fn t578_tab() {
-    init!("![foo][bar]\n\n[bar]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"foo\" /></p>
-        [
-            paragraph!(imageref!("bar",	plain!("foo"))),
-            linkdef!("bar",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo][bar]\n\n[bar]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8576
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8576>
@@ -43959,23 +37918,6 @@ fn t579_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8576
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8576>
-/// <https://spec.commonmark.org/0.29/#example-579>
-fn t579_tab() {
-    init!("![foo][bar]\n\n[BAR]:\t/url\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"foo\" /></p>
-        [
-            paragraph!(imageref!("bar",	plain!("foo"))),
-            linkdef!("BAR",	"/url")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo][bar]\n\n[BAR]:\t/url\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8587
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8587>
@@ -43986,7 +37928,7 @@ fn t580() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("foo"))),
+            paragraph!(imageref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo][]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -44003,7 +37945,7 @@ fn t580_cr() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("foo"))),
+            paragraph!(imageref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo][]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -44020,7 +37962,7 @@ fn t580_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("foo"))),
+            paragraph!(imageref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo][]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -44037,30 +37979,13 @@ fn t580_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("foo"))),
+            paragraph!(imageref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo][]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8587
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8587>
-/// <https://spec.commonmark.org/0.29/#example-580>
-fn t580_tab() {
-    init!("![foo][]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
-        [
-            paragraph!(imageref!(plain!("foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo][]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8596
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8596>
@@ -44071,7 +37996,7 @@ fn t581() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(imageref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![*foo* bar][]\n\n[*foo* bar]: /url \"title\"\n", ParserSettings::Html)),
@@ -44088,7 +38013,7 @@ fn t581_cr() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(imageref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![*foo* bar][]\r\r[*foo* bar]: /url \"title\"\r", ParserSettings::Html)),
@@ -44105,7 +38030,7 @@ fn t581_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(imageref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![*foo* bar][]\r\n\r\n[*foo* bar]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -44122,30 +38047,13 @@ fn t581_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(imageref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![*foo* bar][]\n\n[*foo* bar]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8596
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8596>
-/// <https://spec.commonmark.org/0.29/#example-581>
-fn t581_tab() {
-    init!("![*foo*\tbar][]\n\n[*foo*\tbar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
-        [
-            paragraph!(imageref!(emph!(plain!("foo")),	plain!("	bar"))),
-            linkdef!("*foo*	bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![*foo*\tbar][]\n\n[*foo*\tbar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8607
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8607>
@@ -44156,7 +38064,7 @@ fn t582() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("Foo"))),
+            paragraph!(imageref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![Foo][]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -44173,7 +38081,7 @@ fn t582_cr() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("Foo"))),
+            paragraph!(imageref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![Foo][]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -44190,7 +38098,7 @@ fn t582_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("Foo"))),
+            paragraph!(imageref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![Foo][]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -44207,30 +38115,13 @@ fn t582_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("Foo"))),
+            paragraph!(imageref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![Foo][]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8607
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8607>
-/// <https://spec.commonmark.org/0.29/#example-582>
-fn t582_tab() {
-    init!("![Foo][]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
-        [
-            paragraph!(imageref!(plain!("Foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![Foo][]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8619
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8619>
@@ -44241,7 +38132,7 @@ fn t583() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" />\n[]</p>
         [
-            paragraph!(imageref!(plain!("foo")), SoftBreak, plain!("[]")),
+            paragraph!(imageref!("foo", plain!("foo")), soft_break!(), plain!("[]")),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo] \n[]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -44258,7 +38149,7 @@ fn t583_cr() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" />\n[]</p>
         [
-            paragraph!(imageref!(plain!("foo")), SoftBreak, plain!("[]")),
+            paragraph!(imageref!("foo", plain!("foo")), soft_break!(), plain!("[]")),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo] \r[]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -44275,7 +38166,7 @@ fn t583_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" />\n[]</p>
         [
-            paragraph!(imageref!(plain!("foo")), SoftBreak, plain!("[]")),
+            paragraph!(imageref!("foo", plain!("foo")), soft_break!(), plain!("[]")),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo] \r\n[]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -44292,7 +38183,7 @@ fn t583_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" />\n[]</p>
         [
-            paragraph!(imageref!(plain!("foo")), SoftBreak, plain!("[]")),
+            paragraph!(imageref!("foo", plain!("foo")), soft_break!(), plain!("[]")),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo] \n[]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
@@ -44309,7 +38200,7 @@ fn t584() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("foo"))),
+            paragraph!(imageref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -44326,7 +38217,7 @@ fn t584_cr() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("foo"))),
+            paragraph!(imageref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -44343,7 +38234,7 @@ fn t584_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("foo"))),
+            paragraph!(imageref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -44360,30 +38251,13 @@ fn t584_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("foo"))),
+            paragraph!(imageref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![foo]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8632
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8632>
-/// <https://spec.commonmark.org/0.29/#example-584>
-fn t584_tab() {
-    init!("![foo]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"foo\" title=\"title\" /></p>
-        [
-            paragraph!(imageref!(plain!("foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![foo]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8641
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8641>
@@ -44394,7 +38268,7 @@ fn t585() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(imageref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![*foo* bar]\n\n[*foo* bar]: /url \"title\"\n", ParserSettings::Html)),
@@ -44411,7 +38285,7 @@ fn t585_cr() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(imageref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![*foo* bar]\r\r[*foo* bar]: /url \"title\"\r", ParserSettings::Html)),
@@ -44428,7 +38302,7 @@ fn t585_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(imageref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![*foo* bar]\r\n\r\n[*foo* bar]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -44445,30 +38319,13 @@ fn t585_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(emph!(plain!("foo")), plain!(" bar"))),
+            paragraph!(imageref!("*foo* bar", emph!(plain!("foo")), plain!(" bar"))),
             linkdef!("*foo* bar", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![*foo* bar]\n\n[*foo* bar]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8641
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8641>
-/// <https://spec.commonmark.org/0.29/#example-585>
-fn t585_tab() {
-    init!("![*foo*\tbar]\n\n[*foo*\tbar]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"foo bar\" title=\"title\" /></p>
-        [
-            paragraph!(imageref!(emph!(plain!("foo")),	plain!("	bar"))),
-            linkdef!("*foo*	bar",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![*foo*\tbar]\n\n[*foo*\tbar]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8652
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8652>
@@ -44479,9 +38336,9 @@ fn t586() {
     assert_eq!(
         // from spec: <p>![[foo]]</p>\n<p>[[foo]]: /url &quot;title&quot;</p>
         [
-            paragraph!(imageref!(linkref!(plain!("foo")))),
+            paragraph!(imageref!(linkref!("foo", plain!("foo")))),
             paragraph!(
-              linkref!(linkref!(plain!("foo"))),
+              linkref!(linkref!("foo", plain!("foo"))),
               plain!(": /url \"title\"")
             )
         ].as_slice(),
@@ -44499,9 +38356,9 @@ fn t586_cr() {
     assert_eq!(
         // from spec: <p>![[foo]]</p>\n<p>[[foo]]: /url &quot;title&quot;</p>
         [
-            paragraph!(imageref!(linkref!(plain!("foo")))),
+            paragraph!(imageref!(linkref!("foo", plain!("foo")))),
             paragraph!(
-              linkref!(linkref!(plain!("foo"))),
+              linkref!(linkref!("foo", plain!("foo"))),
               plain!(": /url \"title\"")
             )
         ].as_slice(),
@@ -44519,9 +38376,9 @@ fn t586_cr_nl() {
     assert_eq!(
         // from spec: <p>![[foo]]</p>\n<p>[[foo]]: /url &quot;title&quot;</p>
         [
-            paragraph!(imageref!(linkref!(plain!("foo")))),
+            paragraph!(imageref!(linkref!("foo", plain!("foo")))),
             paragraph!(
-              linkref!(linkref!(plain!("foo"))),
+              linkref!(linkref!("foo", plain!("foo"))),
               plain!(": /url \"title\"")
             )
         ].as_slice(),
@@ -44539,9 +38396,9 @@ fn t586_no_nl() {
     assert_eq!(
         // from spec: <p>![[foo]]</p>\n<p>[[foo]]: /url &quot;title&quot;</p>
         [
-            paragraph!(imageref!(linkref!(plain!("foo")))),
+            paragraph!(imageref!(linkref!("foo", plain!("foo")))),
             paragraph!(
-              linkref!(linkref!(plain!("foo"))),
+              linkref!(linkref!("foo", plain!("foo"))),
               plain!(": /url \"title\"")
             )
         ].as_slice(),
@@ -44549,26 +38406,6 @@ fn t586_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8652
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8652>
-/// <https://spec.commonmark.org/0.29/#example-586>
-fn t586_tab() {
-    init!("![[foo]]\n\n[[foo]]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p>![[foo]]</p>\n<p>[[foo]]: /url &quot;title&quot;</p>
-        [
-            paragraph!(imageref!(linkref!(plain!("foo")))),
-            paragraph!(
-            		linkref!(linkref!(plain!("foo"))),
-            		plain!(":	/url	\"title\"")
-            )
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![[foo]]\n\n[[foo]]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8664
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8664>
@@ -44579,7 +38416,7 @@ fn t587() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("Foo"))),
+            paragraph!(imageref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![Foo]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -44596,7 +38433,7 @@ fn t587_cr() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("Foo"))),
+            paragraph!(imageref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![Foo]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -44613,7 +38450,7 @@ fn t587_cr_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("Foo"))),
+            paragraph!(imageref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![Foo]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -44630,30 +38467,13 @@ fn t587_no_nl() {
     assert_eq!(
         // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
         [
-            paragraph!(imageref!(plain!("Foo"))),
+            paragraph!(imageref!("Foo", plain!("Foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("![Foo]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8664
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8664>
-/// <https://spec.commonmark.org/0.29/#example-587>
-fn t587_tab() {
-    init!("![Foo]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p><img src=\"/url\" alt=\"Foo\" title=\"title\" /></p>
-        [
-            paragraph!(imageref!(plain!("Foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("![Foo]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8676
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8676>
@@ -44722,23 +38542,6 @@ fn t588_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8676
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8676>
-/// <https://spec.commonmark.org/0.29/#example-588>
-fn t588_tab() {
-    init!("!\\[foo]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p>![foo]</p>
-        [
-            paragraph!(plain!("![foo]")),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("!\\[foo]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8688
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8688>
@@ -44749,7 +38552,7 @@ fn t589() {
     assert_eq!(
         // from spec: <p>!<a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(plain!("!"), linkref!(plain!("foo"))),
+            paragraph!(plain!("!"), linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("\\![foo]\n\n[foo]: /url \"title\"\n", ParserSettings::Html)),
@@ -44766,7 +38569,7 @@ fn t589_cr() {
     assert_eq!(
         // from spec: <p>!<a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(plain!("!"), linkref!(plain!("foo"))),
+            paragraph!(plain!("!"), linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("\\![foo]\r\r[foo]: /url \"title\"\r", ParserSettings::Html)),
@@ -44783,7 +38586,7 @@ fn t589_cr_nl() {
     assert_eq!(
         // from spec: <p>!<a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(plain!("!"), linkref!(plain!("foo"))),
+            paragraph!(plain!("!"), linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("\\![foo]\r\n\r\n[foo]: /url \"title\"\r\n", ParserSettings::Html)),
@@ -44800,30 +38603,13 @@ fn t589_no_nl() {
     assert_eq!(
         // from spec: <p>!<a href=\"/url\" title=\"title\">foo</a></p>
         [
-            paragraph!(plain!("!"), linkref!(plain!("foo"))),
+            paragraph!(plain!("!"), linkref!("foo", plain!("foo"))),
             linkdef!("foo", "/url", "title")
         ].as_slice(),
         body(&mut StringData::new("\\![foo]\n\n[foo]: /url \"title\"", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8688
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8688>
-/// <https://spec.commonmark.org/0.29/#example-589>
-fn t589_tab() {
-    init!("\\![foo]\n\n[foo]:\t/url\t\"title\"\n");
-
-    assert_eq!(
-        // from spec: <p>!<a href=\"/url\" title=\"title\">foo</a></p>
-        [
-            paragraph!(plain!("!"),	linkref!(plain!("foo"))),
-            linkdef!("foo",	"/url",	"title")
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("\\![foo]\n\n[foo]:\t/url\t\"title\"\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8721
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8721>
@@ -45400,22 +39186,6 @@ fn t598_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8786
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8786>
-/// <https://spec.commonmark.org/0.29/#example-598>
-fn t598_tab() {
-    init!("<http://foo.bar/baz\tbim>\n");
-
-    assert_eq!(
-        // from spec: <p>&lt;http://foo.bar/baz bim&gt;</p>
-        [
-            paragraph!(plain!("<http://foo.bar/baz	bim>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<http://foo.bar/baz\tbim>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8795
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8795>
@@ -45800,22 +39570,6 @@ fn t604_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8849
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8849>
-/// <https://spec.commonmark.org/0.29/#example-604>
-fn t604_tab() {
-    init!("<\thttp://foo.bar\t>\n");
-
-    assert_eq!(
-        // from spec: <p>&lt; http://foo.bar &gt;</p>
-        [
-            paragraph!(plain!("<	http://foo.bar	>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<\thttp://foo.bar\t>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8856
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8856>
@@ -46264,22 +40018,6 @@ fn t611_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8977
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8977>
-/// <https://spec.commonmark.org/0.29/#example-611>
-fn t611_tab() {
-    init!("<a\t\t/><b2\ndata=\"foo\"\t>\n");
-
-    assert_eq!(
-        // from spec: <p><a  /><b2\ndata=\"foo\" ></p>
-        [
-            paragraph!(html!("<a		/>"),	html!("<b2\ndata=\"foo\"	>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\t\t/><b2\ndata=\"foo\"\t>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8988
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8988>
@@ -46344,22 +40082,6 @@ fn t612_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8988
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8988>
-/// <https://spec.commonmark.org/0.29/#example-612>
-fn t612_tab() {
-    init!("<a\tfoo=\"bar\"\tbam\t=\t\'baz\t<em>\"</em>\'\n_boolean\tzoop:33=zoop:33\t/>\n");
-
-    assert_eq!(
-        // from spec: <p><a foo=\"bar\" bam = \'baz <em>\"</em>\'\n_boolean zoop:33=zoop:33 /></p>
-        [
-            paragraph!(html!("<a	foo=\"bar\"	bam	=	'baz	<em>\"</em>'\n_boolean	zoop:33=zoop:33	/>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\tfoo=\"bar\"\tbam\t=\t\'baz\t<em>\"</em>\'\n_boolean\tzoop:33=zoop:33\t/>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 8999
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8999>
@@ -46424,22 +40146,6 @@ fn t613_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 8999
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L8999>
-/// <https://spec.commonmark.org/0.29/#example-613>
-fn t613_tab() {
-    init!("Foo\t<responsive-image\tsrc=\"foo.jpg\"\t/>\n");
-
-    assert_eq!(
-        // from spec: <p>Foo <responsive-image src=\"foo.jpg\" /></p>
-        [
-            paragraph!(plain!("Foo	"),	html!("<responsive-image	src=\"foo.jpg\"	/>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\t<responsive-image\tsrc=\"foo.jpg\"\t/>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9008
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9008>
@@ -46504,22 +40210,6 @@ fn t614_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9008
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9008>
-/// <https://spec.commonmark.org/0.29/#example-614>
-fn t614_tab() {
-    init!("<33>\t<__>\n");
-
-    assert_eq!(
-        // from spec: <p>&lt;33&gt; &lt;__&gt;</p>
-        [
-            paragraph!(plain!("<33>	<__>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<33>\t<__>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9017
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9017>
@@ -46584,22 +40274,6 @@ fn t615_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9017
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9017>
-/// <https://spec.commonmark.org/0.29/#example-615>
-fn t615_tab() {
-    init!("<a\th*#ref=\"hi\">\n");
-
-    assert_eq!(
-        // from spec: <p>&lt;a h*#ref=&quot;hi&quot;&gt;</p>
-        [
-            paragraph!(plain!("<a	h*#ref=\"hi\">"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\th*#ref=\"hi\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9026
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9026>
@@ -46664,22 +40338,6 @@ fn t616_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9026
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9026>
-/// <https://spec.commonmark.org/0.29/#example-616>
-fn t616_tab() {
-    init!("<a\thref=\"hi\'>\t<a\thref=hi\'>\n");
-
-    assert_eq!(
-        // from spec: <p>&lt;a href=&quot;hi\'&gt; &lt;a href=hi\'&gt;</p>
-        [
-            paragraph!(plain!("<a	href=\"hi\'>	<a	href=hi\'>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\thref=\"hi\'>\t<a\thref=hi\'>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9035
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9035>
@@ -46690,7 +40348,7 @@ fn t617() {
     assert_eq!(
         // from spec: <p>&lt; a&gt;&lt;\nfoo&gt;&lt;bar/ &gt;\n&lt;foo bar=baz\nbim!bop /&gt;</p>
         [
-            paragraph!(plain!("< a><"), SoftBreak, plain!("foo><bar/ >"), SoftBreak, plain!("<foo bar=baz"), SoftBreak, plain!("bim!bop />"))
+            paragraph!(plain!("< a><"), soft_break!(), plain!("foo><bar/ >"), soft_break!(), plain!("<foo bar=baz"), soft_break!(), plain!("bim!bop />"))
         ].as_slice(),
         body(&mut StringData::new("< a><\nfoo><bar/ >\n<foo bar=baz\nbim!bop />\n", ParserSettings::Html)),
     );
@@ -46706,7 +40364,7 @@ fn t617_cr() {
     assert_eq!(
         // from spec: <p>&lt; a&gt;&lt;\nfoo&gt;&lt;bar/ &gt;\n&lt;foo bar=baz\nbim!bop /&gt;</p>
         [
-            paragraph!(plain!("< a><"), SoftBreak, plain!("foo><bar/ >"), SoftBreak, plain!("<foo bar=baz"), SoftBreak, plain!("bim!bop />"))
+            paragraph!(plain!("< a><"), soft_break!(), plain!("foo><bar/ >"), soft_break!(), plain!("<foo bar=baz"), soft_break!(), plain!("bim!bop />"))
         ].as_slice(),
         body(&mut StringData::new("< a><\rfoo><bar/ >\r<foo bar=baz\rbim!bop />\r", ParserSettings::Html)),
     );
@@ -46722,7 +40380,7 @@ fn t617_cr_nl() {
     assert_eq!(
         // from spec: <p>&lt; a&gt;&lt;\nfoo&gt;&lt;bar/ &gt;\n&lt;foo bar=baz\nbim!bop /&gt;</p>
         [
-            paragraph!(plain!("< a><"), SoftBreak, plain!("foo><bar/ >"), SoftBreak, plain!("<foo bar=baz"), SoftBreak, plain!("bim!bop />"))
+            paragraph!(plain!("< a><"), soft_break!(), plain!("foo><bar/ >"), soft_break!(), plain!("<foo bar=baz"), soft_break!(), plain!("bim!bop />"))
         ].as_slice(),
         body(&mut StringData::new("< a><\r\nfoo><bar/ >\r\n<foo bar=baz\r\nbim!bop />\r\n", ParserSettings::Html)),
     );
@@ -46738,28 +40396,12 @@ fn t617_no_nl() {
     assert_eq!(
         // from spec: <p>&lt; a&gt;&lt;\nfoo&gt;&lt;bar/ &gt;\n&lt;foo bar=baz\nbim!bop /&gt;</p>
         [
-            paragraph!(plain!("< a><"), SoftBreak, plain!("foo><bar/ >"), SoftBreak, plain!("<foo bar=baz"), SoftBreak, plain!("bim!bop />"))
+            paragraph!(plain!("< a><"), soft_break!(), plain!("foo><bar/ >"), soft_break!(), plain!("<foo bar=baz"), soft_break!(), plain!("bim!bop />"))
         ].as_slice(),
         body(&mut StringData::new("< a><\nfoo><bar/ >\n<foo bar=baz\nbim!bop />", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9035
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9035>
-/// <https://spec.commonmark.org/0.29/#example-617>
-fn t617_tab() {
-    init!("<\ta><\nfoo><bar/\t>\n<foo\tbar=baz\nbim!bop\t/>\n");
-
-    assert_eq!(
-        // from spec: <p>&lt; a&gt;&lt;\nfoo&gt;&lt;bar/ &gt;\n&lt;foo bar=baz\nbim!bop /&gt;</p>
-        [
-            paragraph!(plain!("<	a><"), SoftBreak, plain!("foo><bar/	>"), SoftBreak, plain!("<foo	bar=baz"), SoftBreak, plain!("bim!bop	/>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<\ta><\nfoo><bar/\t>\n<foo\tbar=baz\nbim!bop\t/>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9050
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9050>
@@ -46824,22 +40466,6 @@ fn t618_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9050
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9050>
-/// <https://spec.commonmark.org/0.29/#example-618>
-fn t618_tab() {
-    init!("<a\thref=\'bar\'title=title>\n");
-
-    assert_eq!(
-        // from spec: <p>&lt;a href=\'bar\'title=title&gt;</p>
-        [
-            paragraph!(plain!("<a	href=\'bar\'title=title>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\thref=\'bar\'title=title>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9059
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9059>
@@ -46904,22 +40530,6 @@ fn t619_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9059
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9059>
-/// <https://spec.commonmark.org/0.29/#example-619>
-fn t619_tab() {
-    init!("</a></foo\t>\n");
-
-    assert_eq!(
-        // from spec: <p></a></foo ></p>
-        [
-            paragraph!(html!("</a>"),	html!("</foo	>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("</a></foo\t>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9068
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9068>
@@ -46984,22 +40594,6 @@ fn t620_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9068
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9068>
-/// <https://spec.commonmark.org/0.29/#example-620>
-fn t620_tab() {
-    init!("</a\thref=\"foo\">\n");
-
-    assert_eq!(
-        // from spec: <p>&lt;/a href=&quot;foo&quot;&gt;</p>
-        [
-            paragraph!(plain!("</a	href=\"foo\">"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("</a\thref=\"foo\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9077
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9077>
@@ -47064,22 +40658,6 @@ fn t621_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9077
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9077>
-/// <https://spec.commonmark.org/0.29/#example-621>
-fn t621_tab() {
-    init!("foo\t<!--\tthis\tis\ta\ncomment\t-\twith\thyphen\t-->\n");
-
-    assert_eq!(
-        // from spec: <p>foo <!-- this is a\ncomment - with hyphen --></p>
-        [
-            paragraph!(plain!("foo	"), html!("<!--\tthis\tis\ta\ncomment\t-\twith\thyphen\t-->"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t<!--\tthis\tis\ta\ncomment\t-\twith\thyphen\t-->\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9086
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9086>
@@ -47144,22 +40722,6 @@ fn t622_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9086
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9086>
-/// <https://spec.commonmark.org/0.29/#example-622>
-fn t622_tab() {
-    init!("foo\t<!--\tnot\ta\tcomment\t--\ttwo\thyphens\t-->\n");
-
-    assert_eq!(
-        // from spec: <p>foo &lt;!-- not a comment -- two hyphens --&gt;</p>
-        [
-            paragraph!(plain!("foo	<!--	not	a	comment	--	two	hyphens	-->"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t<!--\tnot\ta\tcomment\t--\ttwo\thyphens\t-->\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9095
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9095>
@@ -47224,22 +40786,6 @@ fn t623_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9095
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9095>
-/// <https://spec.commonmark.org/0.29/#example-623>
-fn t623_tab() {
-    init!("foo\t<!-->\tfoo\t-->\n\nfoo\t<!--\tfoo--->\n");
-
-    assert_eq!(
-        // from spec: <p>foo &lt;!--&gt; foo --&gt;</p>\n<p>foo &lt;!-- foo---&gt;</p>
-        [
-            paragraph!(plain!("foo	<!-->	foo	-->")), paragraph!(plain!("foo	<!--	foo--->"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t<!-->\tfoo\t-->\n\nfoo\t<!--\tfoo--->\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9107
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9107>
@@ -47304,22 +40850,6 @@ fn t624_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9107
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9107>
-/// <https://spec.commonmark.org/0.29/#example-624>
-fn t624_tab() {
-    init!("foo\t<?php\techo\t$a;\t?>\n");
-
-    assert_eq!(
-        // from spec: <p>foo <?php echo $a; ?></p>
-        [
-            paragraph!(plain!("foo	"), html!("<?php\techo\t$a;\t?>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t<?php\techo\t$a;\t?>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9116
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9116>
@@ -47384,22 +40914,6 @@ fn t625_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9116
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9116>
-/// <https://spec.commonmark.org/0.29/#example-625>
-fn t625_tab() {
-    init!("foo\t<!ELEMENT\tbr\tEMPTY>\n");
-
-    assert_eq!(
-        // from spec: <p>foo <!ELEMENT br EMPTY></p>
-        [
-            paragraph!(plain!("foo	"),	html!("<!ELEMENT	br	EMPTY>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t<!ELEMENT\tbr\tEMPTY>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9125
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9125>
@@ -47464,22 +40978,6 @@ fn t626_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9125
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9125>
-/// <https://spec.commonmark.org/0.29/#example-626>
-fn t626_tab() {
-    init!("foo\t<![CDATA[>&<]]>\n");
-
-    assert_eq!(
-        // from spec: <p>foo <![CDATA[>&<]]></p>
-        [
-            paragraph!(plain!("foo	"),	html!("<![CDATA[>&<]]>"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t<![CDATA[>&<]]>\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9135
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9135>
@@ -47544,22 +41042,6 @@ fn t627_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9135
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9135>
-/// <https://spec.commonmark.org/0.29/#example-627>
-fn t627_tab() {
-    init!("foo\t<a\thref=\"&ouml;\">\n");
-
-    assert_eq!(
-        // from spec: <p>foo <a href=\"&ouml;\"></p>
-        [
-            paragraph!(plain!("foo	"),	html!(r#"<a	href="&ouml;">"#))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t<a\thref=\"&ouml;\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9144
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9144>
@@ -47624,22 +41106,6 @@ fn t628_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9144
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9144>
-/// <https://spec.commonmark.org/0.29/#example-628>
-fn t628_tab() {
-    init!("foo\t<a\thref=\"\\*\">\n");
-
-    assert_eq!(
-        // from spec: <p>foo <a href=\"\\*\"></p>
-        [
-            paragraph!(plain!("foo	"),	html!(r#"<a	href="\*">"#))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\t<a\thref=\"\\*\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9151
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9151>
@@ -47704,22 +41170,6 @@ fn t629_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9151
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9151>
-/// <https://spec.commonmark.org/0.29/#example-629>
-fn t629_tab() {
-    init!("<a\thref=\"\\\"\">\n");
-
-    assert_eq!(
-        // from spec: <p>&lt;a href=&quot;&quot;&quot;&gt;</p>
-        [
-            paragraph!(plain!("<a	href=\"\"\">"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\thref=\"\\\"\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9165
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9165>
@@ -47730,7 +41180,7 @@ fn t630() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo  \nbaz\n", ParserSettings::Html)),
     );
@@ -47746,7 +41196,7 @@ fn t630_cr() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo  \rbaz\r", ParserSettings::Html)),
     );
@@ -47762,7 +41212,7 @@ fn t630_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo  \r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -47778,7 +41228,7 @@ fn t630_no_nl() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo  \nbaz", ParserSettings::Html)),
     );
@@ -47794,7 +41244,7 @@ fn t631() {
     assert_eq!(
         // from spec: <p>foo<br />\nbaz</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("baz"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\nbaz\n", ParserSettings::Html)),
     );
@@ -47810,7 +41260,7 @@ fn t631_cr() {
     assert_eq!(
         // from spec: <p>foo<br />\nbaz</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("baz"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\rbaz\r", ParserSettings::Html)),
     );
@@ -47826,7 +41276,7 @@ fn t631_cr_nl() {
     assert_eq!(
         // from spec: <p>foo<br />\nbaz</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("baz"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -47842,7 +41292,7 @@ fn t631_no_nl() {
     assert_eq!(
         // from spec: <p>foo<br />\nbaz</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("baz"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\nbaz", ParserSettings::Html)),
     );
@@ -47858,7 +41308,7 @@ fn t632() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo       \nbaz\n", ParserSettings::Html)),
     );
@@ -47874,7 +41324,7 @@ fn t632_cr() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo       \rbaz\r", ParserSettings::Html)),
     );
@@ -47890,7 +41340,7 @@ fn t632_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo       \r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -47906,7 +41356,7 @@ fn t632_no_nl() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo       \nbaz", ParserSettings::Html)),
     );
@@ -47922,7 +41372,7 @@ fn t633() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo  \n     bar\n", ParserSettings::Html)),
     );
@@ -47938,7 +41388,7 @@ fn t633_cr() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo  \r     bar\r", ParserSettings::Html)),
     );
@@ -47954,7 +41404,7 @@ fn t633_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo  \r\n     bar\r\n", ParserSettings::Html)),
     );
@@ -47970,28 +41420,12 @@ fn t633_no_nl() {
     assert_eq!(
         // from spec: <p>foo\nbar</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo  \n     bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9199
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9199>
-/// <https://spec.commonmark.org/0.29/#example-633>
-fn t633_tab() {
-    init!("foo  \n    \tbar\n");
-
-    assert_eq!(
-        // from spec: <p>foo\nbar</p>
-        [
-            paragraph!(plain!("foo"), SoftBreak, plain!("bar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo  \n    \tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9208
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9208>
@@ -48002,7 +41436,7 @@ fn t634() {
     assert_eq!(
         // from spec: <p>foo<br />\nbar</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\n     bar\n", ParserSettings::Html)),
     );
@@ -48018,7 +41452,7 @@ fn t634_cr() {
     assert_eq!(
         // from spec: <p>foo<br />\nbar</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\r     bar\r", ParserSettings::Html)),
     );
@@ -48034,7 +41468,7 @@ fn t634_cr_nl() {
     assert_eq!(
         // from spec: <p>foo<br />\nbar</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\r\n     bar\r\n", ParserSettings::Html)),
     );
@@ -48050,28 +41484,12 @@ fn t634_no_nl() {
     assert_eq!(
         // from spec: <p>foo<br />\nbar</p>
         [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
+            paragraph!(plain!("foo"), hard_break!(), plain!("bar"))
         ].as_slice(),
         body(&mut StringData::new("foo\\\n     bar", ParserSettings::Html)),
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9208
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9208>
-/// <https://spec.commonmark.org/0.29/#example-634>
-fn t634_tab() {
-    init!("foo\\\n    \tbar\n");
-
-    assert_eq!(
-        // from spec: <p>foo<br />\nbar</p>
-        [
-            paragraph!(plain!("foo"), HardBreak, plain!("bar"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("foo\\\n    \tbar\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9220
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9220>
@@ -48082,7 +41500,7 @@ fn t635() {
     assert_eq!(
         // from spec: <p><em>foo\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo  \nbar*\n", ParserSettings::Html)),
     );
@@ -48098,7 +41516,7 @@ fn t635_cr() {
     assert_eq!(
         // from spec: <p><em>foo\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo  \rbar*\r", ParserSettings::Html)),
     );
@@ -48114,7 +41532,7 @@ fn t635_cr_nl() {
     assert_eq!(
         // from spec: <p><em>foo\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo  \r\nbar*\r\n", ParserSettings::Html)),
     );
@@ -48130,7 +41548,7 @@ fn t635_no_nl() {
     assert_eq!(
         // from spec: <p><em>foo\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), SoftBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), soft_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo  \nbar*", ParserSettings::Html)),
     );
@@ -48146,7 +41564,7 @@ fn t636() {
     assert_eq!(
         // from spec: <p><em>foo<br />\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), HardBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), hard_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo\\\nbar*\n", ParserSettings::Html)),
     );
@@ -48162,7 +41580,7 @@ fn t636_cr() {
     assert_eq!(
         // from spec: <p><em>foo<br />\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), HardBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), hard_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo\\\rbar*\r", ParserSettings::Html)),
     );
@@ -48178,7 +41596,7 @@ fn t636_cr_nl() {
     assert_eq!(
         // from spec: <p><em>foo<br />\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), HardBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), hard_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo\\\r\nbar*\r\n", ParserSettings::Html)),
     );
@@ -48194,7 +41612,7 @@ fn t636_no_nl() {
     assert_eq!(
         // from spec: <p><em>foo<br />\nbar</em></p>
         [
-            paragraph!(emph!(plain!("foo"), HardBreak, plain!("bar")))
+            paragraph!(emph!(plain!("foo"), hard_break!(), plain!("bar")))
         ].as_slice(),
         body(&mut StringData::new("*foo\\\nbar*", ParserSettings::Html)),
     );
@@ -48264,22 +41682,6 @@ fn t637_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9240
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9240>
-/// <https://spec.commonmark.org/0.29/#example-637>
-fn t637_tab() {
-    init!("`code\t\nspan`\n");
-
-    assert_eq!(
-        // from spec: <p><code>code  span</code></p>
-        [
-            paragraph!(code!("code\t\tspan"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("`code\t\nspan`\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9248
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9248>
@@ -48472,22 +41874,6 @@ fn t640_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9267
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9267>
-/// <https://spec.commonmark.org/0.29/#example-640>
-fn t640_tab() {
-    init!("<a\thref=\"foo\\\nbar\">\n");
-
-    assert_eq!(
-        // from spec: <p><a href=\"foo\\\nbar\"></p>
-        [
-            paragraph!(html!("<a	href=\"foo\\\nbar\">"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("<a\thref=\"foo\\\nbar\">\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9280
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9280>
@@ -48680,22 +42066,6 @@ fn t643_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9294
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9294>
-/// <https://spec.commonmark.org/0.29/#example-643>
-fn t643_tab() {
-    init!("###\tfoo\\\n");
-
-    assert_eq!(
-        // from spec: <h3>foo\\</h3>
-        [
-            heading!(3, plain!("foo\\"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("###\tfoo\\\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9301
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9301>
@@ -48760,22 +42130,6 @@ fn t644_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9301
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9301>
-/// <https://spec.commonmark.org/0.29/#example-644>
-fn t644_tab() {
-    init!("###\tfoo  \n");
-
-    assert_eq!(
-        // from spec: <h3>foo</h3>
-        [
-            heading!(3, plain!("foo"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("###\tfoo  \n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9316
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9316>
@@ -48786,7 +42140,7 @@ fn t645() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\nbaz\n", ParserSettings::Html)),
     );
@@ -48802,7 +42156,7 @@ fn t645_cr() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\rbaz\r", ParserSettings::Html)),
     );
@@ -48818,7 +42172,7 @@ fn t645_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\r\nbaz\r\n", ParserSettings::Html)),
     );
@@ -48834,7 +42188,7 @@ fn t645_no_nl() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo\nbaz", ParserSettings::Html)),
     );
@@ -48850,7 +42204,7 @@ fn t646() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo \n baz\n", ParserSettings::Html)),
     );
@@ -48866,7 +42220,7 @@ fn t646_cr() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo \r baz\r", ParserSettings::Html)),
     );
@@ -48882,7 +42236,7 @@ fn t646_cr_nl() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo \r\n baz\r\n", ParserSettings::Html)),
     );
@@ -48898,7 +42252,7 @@ fn t646_no_nl() {
     assert_eq!(
         // from spec: <p>foo\nbaz</p>
         [
-            paragraph!(plain!("foo"), SoftBreak, plain!("baz"))
+            paragraph!(plain!("foo"), soft_break!(), plain!("baz"))
         ].as_slice(),
         body(&mut StringData::new("foo \n baz", ParserSettings::Html)),
     );
@@ -48968,22 +42322,6 @@ fn t647_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9348
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9348>
-/// <https://spec.commonmark.org/0.29/#example-647>
-fn t647_tab() {
-    init!("hello\t$.;\'there\n");
-
-    assert_eq!(
-        // from spec: <p>hello $.;\'there</p>
-        [
-            paragraph!(plain!("hello	$.;\'there"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("hello\t$.;\'there\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9355
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9355>
@@ -49048,22 +42386,6 @@ fn t648_no_nl() {
     );
 }
 
-#[test]
-/// Test case generated from Commonmark Spec line 9355
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9355>
-/// <https://spec.commonmark.org/0.29/#example-648>
-fn t648_tab() {
-    init!("Foo\tχρῆν\n");
-
-    assert_eq!(
-        // from spec: <p>Foo χρῆν</p>
-        [
-            paragraph!(plain!("Foo	χρῆν"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Foo\t\u{3c7}\u{3c1}\u{1fc6}\u{3bd}\n", ParserSettings::Html))),
-    );
-}
-
 #[test]
 /// Test case generated from Commonmark Spec line 9364
 /// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9364>
@@ -49127,19 +42449,3 @@ fn t649_no_nl() {
         body(&mut StringData::new("Multiple     spaces", ParserSettings::Html)),
     );
 }
-
-#[test]
-/// Test case generated from Commonmark Spec line 9364
-/// <https://github.com/commonmark/commonmark-spec/blob/master/spec.txt#L9364>
-/// <https://spec.commonmark.org/0.29/#example-649>
-fn t649_tab() {
-    init!("Multiple\t\t\t\t\tspaces\n");
-
-    assert_eq!(
-        // from spec: <p>Multiple     spaces</p>
-        [
-            paragraph!(plain!("Multiple					spaces"))
-        ].as_slice(),
-        space2tab(body(&mut StringData::new("Multiple\t\t\t\t\tspaces\n", ParserSettings::Html))),
-    );
-}
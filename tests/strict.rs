@@ -0,0 +1,131 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+body_check!(
+    setext_heading, ParserSettings::SetextHeadings, ("Foo *bar*", "===", "baz"),
+    heading!(<0, 14> 1, plain!(<0, 4> "Foo "), emph!(<4, 9> plain!(<5, 8> "bar"))),
+    paragraph!(<14, 17> plain!(<14, 17> "baz"))
+);
+
+body_check!(
+    setext_heading_multiline, ParserSettings::SetextHeadings, ("Foo", "bar", "---"),
+    heading!(<0, 11> 2, plain!(<0, 3> "Foo"), soft_break!(<3, 4>), plain!(<4, 7> "bar"))
+);
+
+body_check!(
+    setext_heading_disabled, ParserSettings::default(), ("Foo", "---"),
+    paragraph!(<0, 4> plain!(<0, 3> "Foo")),
+    thematic_break!(<4, 7>)
+);
+
+body_check!(
+    setext_after_linkdef, ParserSettings::SetextHeadings, ("[foo]: /url", "==="),
+    linkdef!(<0, 12> <1, 4> <7, 11> <12, 12> "foo", "/url"),
+    paragraph!(<12, 15> plain!(<12, 15> "==="))
+);
+
+body_check!(
+    indented_code, ParserSettings::IndentedCode, ("    a", "", "      b", "  ", "", "c"),
+    code_block!(<4, 15> <4, 4> "", "a\n\n  b\n"),
+    paragraph!(<19, 20> plain!(<19, 20> "c"))
+);
+
+body_check!(
+    indented_code_no_interrupt, ParserSettings::IndentedCode, ("a", "    # b"),
+    paragraph!(<0, 9> plain!(<0, 1> "a"), soft_break!(<1, 2>), plain!(<6, 9> "# b"))
+);
+
+body_check!(
+    indented_code_disabled, ParserSettings::default(), ("    a", "", "    b"),
+    paragraph!(<4, 6> plain!(<4, 5> "a")),
+    paragraph!(<11, 12> plain!(<11, 12> "b"))
+);
+
+body_check!(
+    closing_sequence, ParserSettings::AtxClosingSequence, ("## foo ##  ", "# bar#", "### ###"),
+    heading!(<0, 12> 2, plain!(<3, 6> "foo")),
+    heading!(<12, 19> 1, plain!(<14, 18> "bar#")),
+    heading!(<19, 26> 3)
+);
+
+body_check!(
+    closing_sequence_and_attributes,
+    ParserSettings::AtxClosingSequence | ParserSettings::Attributes,
+    "# foo # {#x}",
+    heading!(<0, 12> 1, [attrs!(#"x")], plain!(<2, 5> "foo"))
+);
+
+body_check!(
+    list_content_indent, ParserSettings::ListContentIndent, ("1.  a", "", "    b", "    c"),
+    ordered_list!(
+        <0, 18> loose,
+        item!(<0, 18>
+            paragraph!(<4, 6> plain!(<4, 5> "a")),
+            paragraph!(<11, 18> plain!(<11, 12> "b"), soft_break!(<12, 13>), plain!(<17, 18> "c"))
+        )
+    )
+);
+
+body_check!(
+    list_content_indent_ends, ParserSettings::ListContentIndent, ("-   a", "  b"),
+    unordered_list!(<0, 6> '-', item!(<0, 6> paragraph!(<4, 6> plain!(<4, 5> "a")))),
+    paragraph!(<8, 9> plain!(<8, 9> "b"))
+);
+
+body_check!(
+    list_content_indent_code,
+    ParserSettings::ListContentIndent | ParserSettings::IndentedCode,
+    ("-     a", "", "   b"),
+    unordered_list!(
+        <0, 13> '-',
+        item!(<0, 13> code_block!(<6, 8> <6, 6> "", "a\n"), paragraph!(<12, 13> plain!(<12, 13> "b")))
+    )
+);
+
+body_check!(
+    list_empty_item, ParserSettings::ListContentIndent, ("-", "", "  foo"),
+    unordered_list!(<0, 3> '-', item!(<0, 3> )),
+    paragraph!(<5, 8> plain!(<5, 8> "foo"))
+);
+
+body_check!(
+    lazy_quote, ParserSettings::LazyContinuation, ("> a", "b", "# c"),
+    quote!(<0, 6> paragraph!(<2, 6> plain!(<2, 3> "a"), soft_break!(<3, 4>), plain!(<4, 5> "b"))),
+    heading!(<6, 9> 1, plain!(<8, 9> "c"))
+);
+
+body_check!(
+    lazy_quote_ends, ParserSettings::LazyContinuation, ("> # a", "b"),
+    quote!(<0, 6> heading!(<2, 6> 1, plain!(<4, 5> "a"))),
+    paragraph!(<6, 7> plain!(<6, 7> "b"))
+);
+
+body_check!(
+    lazy_quote_disabled, ParserSettings::default(), ("> # a", "b"),
+    quote!(
+        <0, 7>
+        heading!(<2, 6> 1, plain!(<4, 5> "a")),
+        paragraph!(<6, 7> plain!(<6, 7> "b"))
+    )
+);
+
+body_check!(
+    lazy_list_item,
+    ParserSettings::LazyContinuation | ParserSettings::ListContentIndent,
+    ("- a", "b", "- c"),
+    unordered_list!(
+        <0, 9> '-',
+        item!(<0, 6> paragraph!(<2, 6> plain!(<2, 3> "a"), soft_break!(<3, 4>), plain!(<4, 5> "b"))),
+        item!(<6, 9> paragraph!(<8, 9> plain!(<8, 9> "c")))
+    )
+);
+
+body_check!(
+    lazy_setext_underline,
+    ParserSettings::LazyContinuation | ParserSettings::SetextHeadings,
+    ("> a", "==="),
+    quote!(<0, 7> paragraph!(<2, 7> plain!(<2, 3> "a"), soft_break!(<3, 4>), plain!(<4, 7> "===")))
+);
@@ -0,0 +1,66 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+body_check!(
+    list_continuation, "- a\n\tb",
+    unordered_list!(
+        <0, 6> '-',
+        item!(<0, 6> paragraph!(<2, 6> plain!(<2, 3> "a"), soft_break!(<3, 4>), plain!(<5, 6> "b")))
+    )
+);
+
+body_check!(
+    ordered_list_continuation, "1. a\n\tb",
+    ordered_list!(
+        <0, 7>
+        item!(<0, 7> paragraph!(<3, 7> plain!(<3, 4> "a"), soft_break!(<4, 5>), plain!(<6, 7> "b")))
+    )
+);
+
+body_check!(
+    tab_after_spaces, "- a\n \tb",
+    unordered_list!(
+        <0, 7> '-',
+        item!(<0, 7> paragraph!(<2, 7> plain!(<2, 3> "a"), soft_break!(<3, 4>), plain!(<6, 7> "b")))
+    )
+);
+
+body_check!(
+    nested_list_in_tab, "- a\n  - b\n\t  - c",
+    unordered_list!(
+        <0, 16> '-',
+        item!(<0, 16>
+            paragraph!(<2, 6> plain!(<2, 3> "a")),
+            unordered_list!(
+                <6, 16> '-',
+                item!(<6, 16>
+                    paragraph!(<8, 11> plain!(<8, 9> "b")),
+                    unordered_list!(<13, 16> '-', item!(<13, 16> paragraph!(<15, 16> plain!(<15, 16> "c"))))
+                )
+            )
+        )
+    )
+);
+
+body_check!(
+    code_in_list, "- ```\n\tfoo\n  ```",
+    unordered_list!(<0, 16> '-', item!(<0, 16> code_block!(<2, 16> <5, 5> "", "  foo\n")))
+);
+
+body_check!(
+    code_indented_fence, "  ```\n\tfoo\n   bar\n  ```",
+    code_block!(<2, 23> <5, 5> "", "  foo\n bar\n")
+);
+
+body_check!(
+    code_tab_beyond_indent, " ```\n \t\tfoo\n ```",
+    code_block!(<1, 16> <4, 4> "", "\t\tfoo\n")
+);
+
+body_check!(
+    quote_with_tab, ">\t```\n>\t\tfoo\n>\t```",
+    quote!(<0, 18> code_block!(<2, 18> <5, 5> "", "\tfoo\n"))
+);
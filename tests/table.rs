@@ -0,0 +1,78 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+body_check!(
+    simple, ParserSettings::Tables, ("| a | b |", "| - | - |", "| c | d |"),
+    table!(
+        <0, 29> [None, None],
+        [[plain!(<2, 3> "a")], [plain!(<6, 7> "b")]],
+        [[plain!(<22, 23> "c")], [plain!(<26, 27> "d")]]
+    )
+);
+
+body_check!(
+    alignments, ParserSettings::Tables, ("a | b | c | d", ":- | :-: | -: | ---"),
+    table!(
+        <0, 33> [Left, Center, Right, None],
+        [[plain!(<0, 1> "a")], [plain!(<4, 5> "b")], [plain!(<8, 9> "c")], [plain!(<12, 13> "d")]]
+    )
+);
+
+body_check!(
+    inline_content, ParserSettings::Tables, ("| *a* | `b\\|c` |", "|---|---|"),
+    table!(
+        <0, 26> [None, None],
+        [[emph!(<2, 5> plain!(<3, 4> "a"))], [code!(<8, 14> "b|c")]]
+    )
+);
+
+body_check!(
+    escaped_pipe, ParserSettings::Tables, ("| a \\| b |", "|---|"),
+    table!(<0, 16> [None], [[plain!(<2, 8> "a | b")]])
+);
+
+body_check!(
+    cell_count, ParserSettings::Tables, ("| a | b |", "|---|---|", "| c |", "| d | e | f |"),
+    table!(
+        <0, 39> [None, None],
+        [[plain!(<2, 3> "a")], [plain!(<6, 7> "b")]],
+        [[plain!(<22, 23> "c")], []],
+        [[plain!(<28, 29> "d")], [plain!(<32, 33> "e")]]
+    )
+);
+
+body_check!(
+    ends_at_blank_line, ParserSettings::Tables, ("| a |", "|---|", "b", "", "c"),
+    table!(<0, 14> [None], [[plain!(<2, 3> "a")]], [[plain!(<12, 13> "b")]]),
+    paragraph!(<15, 16> plain!(<15, 16> "c"))
+);
+
+body_check!(
+    ends_at_block, ParserSettings::Tables, ("| a |", "|---|", "> b"),
+    table!(<0, 12> [None], [[plain!(<2, 3> "a")]]),
+    quote!(<12, 15> paragraph!(<14, 15> plain!(<14, 15> "b")))
+);
+
+body_check!(
+    column_mismatch, ParserSettings::Tables, ("| a | b |", "|---|"),
+    paragraph!(<0, 15> plain!(<0, 9> "| a | b |"), soft_break!(<9, 10>), plain!(<10, 15> "|---|"))
+);
+
+body_check!(
+    needs_pipe, ParserSettings::Tables, ("a", "---"),
+    paragraph!(<0, 2> plain!(<0, 1> "a")),
+    thematic_break!(<2, 5>)
+);
+
+body_check!(
+    tables_disabled, ParserSettings::default(), ("| a |", "|---|"),
+    paragraph!(<0, 11> plain!(<0, 5> "| a |"), soft_break!(<5, 6>), plain!(<6, 11> "|---|"))
+);
+
+body_check!(
+    in_quote, ParserSettings::Tables, ("> | a |", "> |---|", "> | b |"),
+    quote!(<0, 23> table!(<2, 23> [None], [[plain!(<4, 5> "a")]], [[plain!(<20, 21> "b")]]))
+);
@@ -0,0 +1,107 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+fn parse(inp: &str) -> Document {
+    init!(inp);
+    Document::parse("", &mut StringData::new(inp, ParserSettings::Attributes))
+}
+
+fn outline(toc: &[TocEntry]) -> Vec<(u8, String, String, usize)> {
+    let mut res = Vec::new();
+
+    for e in toc {
+        res.push((e.level, e.text.clone(), e.id.clone(), e.children.len()));
+        res.extend(outline(&e.children));
+    }
+
+    res
+}
+
+fn ids(doc: &Document) -> Vec<Option<String>> {
+    doc.body().iter()
+        .filter_map(|b| match b {
+            Block::Heading(_, _, attrs, _) => Some(attrs.id.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn assign_ids() {
+    let mut doc = parse(
+        "# Intro\n## Intro\n## Intro {#intro-1}\n# *Über* `uns`!\n# Intro\n"
+    );
+    doc.assign_heading_ids();
+
+    assert_eq!(
+        vec![
+            Some("intro".into()),
+            Some("intro-2".into()),
+            Some("intro-1".into()),
+            Some("über-uns".into()),
+            Some("intro-3".into()),
+        ],
+        ids(&doc)
+    );
+}
+
+#[test]
+fn nested_toc() {
+    let doc = parse("# A\n## B\n### C\n## D\n# E\n### F\n");
+
+    assert_eq!(
+        vec![
+            (1, "A".into(), "a".into(), 2),
+            (2, "B".into(), "b".into(), 1),
+            (3, "C".into(), "c".into(), 0),
+            (2, "D".into(), "d".into(), 0),
+            (1, "E".into(), "e".into(), 1),
+            (3, "F".into(), "f".into(), 0),
+        ],
+        outline(&doc.toc(1, 6))
+    );
+}
+
+#[test]
+fn level_range() {
+    let doc = parse("# Title\n## A\n### B\n#### C\n## A\n");
+
+    assert_eq!(
+        vec![
+            (2, "A".into(), "a".into(), 1),
+            (3, "B".into(), "b".into(), 0),
+            (2, "A".into(), "a-1".into(), 0),
+        ],
+        outline(&doc.toc(2, 3))
+    );
+}
+
+#[test]
+fn toc_matches_assigned_ids() {
+    let mut doc = parse("# A\n> # A\n\n# A {#x}\n");
+    let toc = outline(&doc.toc(1, 6));
+
+    doc.assign_heading_ids();
+    assert_eq!(toc, outline(&doc.toc(1, 6)));
+    assert_eq!(
+        vec![
+            (1, "A".into(), "a".into(), 0),
+            (1, "A".into(), "a-1".into(), 0),
+            (1, "A".into(), "x".into(), 0),
+        ],
+        toc
+    );
+}
+
+#[cfg(feature = "location")]
+#[test]
+fn location() {
+    let doc = parse("# A\n## B\n");
+    let toc = doc.toc(1, 6);
+
+    assert_eq!(Location { begin: 0.into(), end: 4.into() }, toc[0].location);
+    assert_eq!(Location { begin: 4.into(), end: 9.into() }, toc[0].children[0].location);
+}
@@ -0,0 +1,145 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+/// The inputs start with an empty line to skip the head
+fn try_parse(inp: &str) -> Result<Document, ParseError> {
+    init!(inp);
+    Document::try_parse("", &mut StringData::new(inp, ParserSettings::Embedded))
+}
+
+fn error(inp: &str) -> ParseError {
+    match try_parse(inp) {
+        Ok(_) => panic!("no error for {:?}", inp),
+        Err(err) => err,
+    }
+}
+
+#[cfg(feature = "location")]
+fn loc(begin: usize, end: usize) -> Location {
+    Location { begin: begin.into(), end: end.into() }
+}
+
+#[test]
+fn balanced() {
+    let doc = try_parse("\n@if x {\na\n} else {\nb\n}\n").unwrap();
+    assert_matches!(
+        doc.body().as_slice(),
+        [
+            Block::EmbeddedBlock(..), Block::Paragraph(..),
+            Block::EmbeddedBlock(..), Block::Paragraph(..),
+            Block::EmbeddedBlock(..),
+        ]
+    );
+    assert_eq!(doc.diagnostics(), &[]);
+}
+
+#[test]
+fn balanced_in_quote() {
+    let doc = try_parse("\n> @for a in b {\n> a\n> }\n").unwrap();
+    assert_matches!(doc.body().as_slice(), [Block::Quote(..)]);
+}
+
+#[test]
+fn unclosed() {
+    let err = error("\n@if x {\ntext\n");
+    assert_matches!(err, ParseError::UnclosedEmbedded(_));
+    assert_eq!(err.to_string(), "embedded block isn't closed");
+
+    #[cfg(feature = "location")]
+    assert_eq!(err.location(), &loc(2, 9));
+}
+
+#[test]
+fn unclosed_in_list_item() {
+    let err = error("\n- @if x {\n  a\n- b");
+    assert_matches!(err, ParseError::UnclosedEmbedded(_));
+
+    #[cfg(feature = "location")]
+    assert_eq!(err.location(), &loc(4, 13));
+}
+
+#[test]
+fn unclosed_at_end() {
+    for (inp, _end) in [("\n@{\nfoo", 4), ("\n@(\nfoo", 4), ("\n@/* x", 6)] {
+        let err = error(inp);
+        assert_matches!(err, ParseError::UnclosedEmbedded(_), "{:?}", inp);
+
+        #[cfg(feature = "location")]
+        assert_eq!(err.location(), &loc(2, _end), "{:?}", inp);
+    }
+}
+
+#[test]
+fn stray_close() {
+    let err = error("\ntext\n\n}\n");
+    assert_matches!(err, ParseError::StrayClose(_));
+
+    #[cfg(feature = "location")]
+    assert_eq!(err.location(), &loc(7, 8));
+}
+
+#[test]
+fn stray_else() {
+    let err = error("\n} else {\ntext");
+    assert_matches!(err, ParseError::StrayElse(_));
+
+    #[cfg(feature = "location")]
+    assert_eq!(err.location(), &loc(1, 9));
+}
+
+#[test]
+fn parse_reports_errors() {
+    let inp = "\n@if x {\ntext\n";
+    let doc = Document::parse("", &mut StringData::new(inp, ParserSettings::Embedded));
+
+    assert_matches!(doc.body().as_slice(), [Block::EmbeddedBlock(..), Block::Paragraph(..)]);
+    assert_eq!(doc.diagnostics().len(), 1);
+    assert_eq!(
+        ParseError::from_diagnostic(&doc.diagnostics()[0]),
+        try_parse(inp).err()
+    );
+}
+
+#[test]
+fn try_body_keeps_position_on_error() {
+    let mut data = StringData::new("@if x {\ntext\n", ParserSettings::Embedded);
+    assert_matches!(try_body(&mut data), Err(ParseError::UnclosedEmbedded(_)));
+    assert_eq!(data.pos(), 0);
+    assert_eq!(data.take_diagnostics(), []);
+}
+
+/// Inputs that made the parser panic
+#[test]
+fn no_panic() {
+    for (inp, settings) in [
+        ("\n# @if{if{", ParserSettings::Embedded),
+        ("\n@x😀", ParserSettings::Embedded),
+        ("\n@/*ä", ParserSettings::Embedded),
+        ("\n<a href=\"@", ParserSettings::Embedded | ParserSettings::Html),
+        ("\n    a\ta\n    ὐ", ParserSettings::IndentedCode),
+        ("\n**foo [*", ParserSettings::default()),
+        ("\n~~[++~~++", ParserSettings::Del | ParserSettings::Ins),
+        ("\n*[*_[<v>_", ParserSettings::Html),
+        ("\n*[\nä*", ParserSettings::default()),
+        ("\n1. -\n\n- ", ParserSettings::ListContentIndent),
+    ] {
+        let doc = Document::parse("", &mut StringData::new(inp, settings));
+        assert!(!doc.body().is_empty(), "{:?}", inp);
+    }
+}
+
+#[test]
+fn block_counts_open_embedded() {
+    let mut data = StringData::new("@if x {\n}\n", ParserSettings::Embedded);
+    let mut blocks = Vec::new();
+    let mut open = 0;
+
+    block(&mut data, &mut blocks, &mut open);
+    assert_eq!(open, 1);
+    block(&mut data, &mut blocks, &mut open);
+    assert_eq!(open, 0);
+    assert_eq!(data.take_diagnostics(), []);
+}
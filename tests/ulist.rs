@@ -0,0 +1,105 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+body_check!(
+    bullets, "* a\n* b\n+ c\n- d",
+    unordered_list!(
+        <0, 8> '*',
+        item!(<0, 4> paragraph!(<2, 4> plain!(<2, 3> "a"))),
+        item!(<4, 8> paragraph!(<6, 8> plain!(<6, 7> "b")))
+    ),
+    unordered_list!(<8, 12> '+', item!(<8, 12> paragraph!(<10, 12> plain!(<10, 11> "c")))),
+    unordered_list!(<12, 15> '-', item!(<12, 15> paragraph!(<14, 15> plain!(<14, 15> "d"))))
+);
+
+body_check!(
+    blank_line_between_items, "- a\n\n- b",
+    unordered_list!(
+        <0, 8> '-', loose,
+        item!(<0, 5> paragraph!(<2, 4> plain!(<2, 3> "a"))),
+        item!(<5, 8> paragraph!(<7, 8> plain!(<7, 8> "b")))
+    )
+);
+
+body_check!(
+    blank_line_between_blocks, "- a\n\n  b\n- c",
+    unordered_list!(
+        <0, 12> '-', loose,
+        item!(<0, 9>
+            paragraph!(<2, 4> plain!(<2, 3> "a")),
+            paragraph!(<7, 9> plain!(<7, 8> "b"))
+        ),
+        item!(<9, 12> paragraph!(<11, 12> plain!(<11, 12> "c")))
+    )
+);
+
+body_check!(
+    blank_line_after_last_item, "- a\n- b\n\nc",
+    unordered_list!(
+        <0, 9> '-',
+        item!(<0, 4> paragraph!(<2, 4> plain!(<2, 3> "a"))),
+        item!(<4, 9> paragraph!(<6, 8> plain!(<6, 7> "b")))
+    ),
+    paragraph!(<9, 10> plain!(<9, 10> "c"))
+);
+
+body_check!(
+    blank_line_in_nested_list, "- a\n  - b\n\n    c\n- d",
+    unordered_list!(
+        <0, 20> '-',
+        item!(<0, 17>
+            paragraph!(<2, 6> plain!(<2, 3> "a")),
+            unordered_list!(
+                <6, 17> '-', loose,
+                item!(<6, 17>
+                    paragraph!(<8, 10> plain!(<8, 9> "b")),
+                    paragraph!(<15, 17> plain!(<15, 16> "c"))
+                )
+            )
+        ),
+        item!(<17, 20> paragraph!(<19, 20> plain!(<19, 20> "d")))
+    )
+);
+
+body_check!(
+    blank_line_after_nested_list, "- a\n  - b\n\n  c",
+    unordered_list!(
+        <0, 14> '-', loose,
+        item!(<0, 14>
+            paragraph!(<2, 6> plain!(<2, 3> "a")),
+            unordered_list!(<6, 13> '-', item!(<6, 13> paragraph!(<8, 10> plain!(<8, 9> "b")))),
+            paragraph!(<13, 14> plain!(<13, 14> "c"))
+        )
+    )
+);
+
+body_check!(
+    blank_line_in_code, "- ```\n  a\n\n  b\n  ```\n- c",
+    unordered_list!(
+        <0, 24> '-',
+        item!(<0, 21> code_block!(<2, 21> <5, 5> "", "a\n\nb\n")),
+        item!(<21, 24> paragraph!(<23, 24> plain!(<23, 24> "c")))
+    )
+);
+
+body_check!(
+    empty_item_before_blank_line, "* a\n*\n\n* c",
+    unordered_list!(
+        <0, 10> '*', loose,
+        item!(<0, 4> paragraph!(<2, 4> plain!(<2, 3> "a"))),
+        item!(<4, 7> ),
+        item!(<7, 10> paragraph!(<9, 10> plain!(<9, 10> "c")))
+    )
+);
+
+body_check!(
+    ordered_loose, "1. a\n\n2. b",
+    ordered_list!(
+        <0, 10> loose,
+        item!(<0, 6> paragraph!(<3, 5> plain!(<3, 4> "a"))),
+        item!(<6, 10> paragraph!(<9, 10> plain!(<9, 10> "b")))
+    )
+);
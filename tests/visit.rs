@@ -0,0 +1,98 @@
+#![feature(assert_matches)]
+#![feature(decl_macro)]
+
+mod common;
+use common::*;
+
+fn parse(inp: &str) -> Vec<Block> {
+    init!(inp);
+    body(&mut StringData::new(inp, ParserSettings::default()))
+}
+
+#[derive(Default)]
+struct Collector {
+    texts: Vec<String>,
+    urls: Vec<String>,
+}
+
+impl Visit for Collector {
+    fn visit_text(&mut self, text: &str, _loc: &Location) {
+        self.texts.push(text.to_string());
+    }
+
+    fn visit_link(
+        &mut self, text: &[Inline], url: &str, _title: &str, _attrs: &Attributes, _loc: &Location,
+        _parts: &LinkParts
+    ) {
+        self.urls.push(url.to_string());
+        self.visit_inlines(text);
+    }
+}
+
+#[test]
+fn visit() {
+    let body = parse("# a\n\n> *b [c](/c)*\n\n- ![d](/d)\n- e\n\n```\nf\n```");
+    let mut coll = Collector::default();
+    coll.visit_blocks(&body);
+
+    assert_eq!(vec!["a", "b ", "c", "d", "e"], coll.texts);
+    assert_eq!(vec!["/c"], coll.urls);
+}
+
+struct Upper;
+
+impl VisitMut for Upper {
+    fn visit_text_mut(&mut self, text: &mut String, _loc: &mut Location) {
+        *text = text.to_uppercase();
+    }
+
+    fn visit_quote_mut(&mut self, _content: &mut Vec<Block>, _loc: &mut Location) {
+        // keep the quote unchanged
+    }
+}
+
+#[test]
+fn visit_mut() {
+    let mut body = parse("a **b**\n\n> c");
+    Upper.visit_blocks_mut(&mut body);
+
+    let mut coll = Collector::default();
+    coll.visit_blocks(&body);
+    assert_eq!(vec!["A ", "B", "c"], coll.texts);
+}
+
+/// Drops thematic breaks and replaces emphasis by its content
+struct Flatten;
+
+impl Fold for Flatten {
+    fn fold_block(&mut self, blk: Block) -> Vec<Block> {
+        match blk {
+            Block::Break(_) => vec![],
+            blk => vec![fold_block_content(self, blk)],
+        }
+    }
+
+    fn fold_inline(&mut self, el: Inline) -> Vec<Inline> {
+        match el {
+            Inline::Emph(ct, _) => fold_inlines(self, ct),
+            el => fold_inline_content(self, el),
+        }
+    }
+}
+
+#[test]
+fn fold() {
+    let body = fold_blocks(&mut Flatten, parse("a *b **c***\n\n***\n\n- *d*"));
+
+    assert_matches!(
+        &body[..],
+        [Block::Paragraph(par, ..), Block::UnorderedList(_, _, items, _)]
+            if matches!(
+                &par[..],
+                [Inline::Text(a, _), Inline::Text(b, _), Inline::Strong(..)] if a == "a " && b == "b "
+            ) && matches!(
+                &items[0].0[..],
+                [Block::Paragraph(ct, ..)] if matches!(&ct[..], [Inline::Text(d, _)] if d == "d")
+            )
+    );
+}